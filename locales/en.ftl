@@ -14,6 +14,7 @@ online-wallpapers = Online Wallpapers
     .tooltip-prev = Previous
     .tooltip-next = Next
     .tooltip-download = Download
+    .tooltip-owned = Already in library
     .tooltip-set-wallpaper = Set as wallpaper
     .tooltip-close = Close
     .search-placeholder = Search... (English only)
@@ -71,25 +72,80 @@ local-list = Local List
     .loading = Loading...
     .load-failed = Load Failed
     .no-wallpapers = No local wallpapers found
+    .find-duplicates = Find Similar Images
+    .trash = Trash
+    .no-matching-wallpapers = No wallpapers match the current filters
+    .sort-name = Name
+    .sort-date-added = Date Added
+    .sort-file-size = File Size
+    .sort-resolution = Resolution
+    .sort-aspect-ratio = Aspect Ratio
+    .sort-ascending = Ascending
+    .sort-descending = Descending
+    .min-resolution-any = Any Resolution
+    .orientation-any = Any Orientation
+    .orientation-landscape = Landscape
+    .orientation-portrait = Portrait
+    .orientation-square = Square
+    .ratio-group-any = Any Ratio
+    .format-any = Any Format
+    .color-label = Color
+    .name-pattern-placeholder = Filter by name...
+    .reset-filters = Reset Filters
     .no-valid-wallpapers = No valid wallpapers found
     .load-more = Load More...
     .all-loaded = All wallpapers loaded
     .loading-error = Loading Error
     .image-loading = Image loading
-    .delete-success = Deleted successfully
+    .avif-unavailable = Skipped {count} AVIF images: this build does not include AVIF support
+    .delete-success = Moved to trash
     .delete-failed = Delete failed
+    .undo = Undo
     .set-wallpaper-success = Wallpaper set successfully
     .set-wallpaper-failed = Failed to set wallpaper
     .tooltip-locate = Locate file
     .tooltip-set-wallpaper = Set as wallpaper
     .tooltip-delete = Delete file
     .delete-confirm-title = Confirm Delete
-    .delete-confirm-message = Are you sure you want to delete this wallpaper?
+    .delete-confirm-message = Are you sure you want to move this wallpaper to the trash?
     .delete-confirm-confirm = Confirm Delete
     .delete-confirm-cancel = Cancel
     .tooltip-prev = Previous
     .tooltip-next = Next
     .tooltip-close = Close
+    .tooltip-open-source = Open Wallhaven page
+    .metadata-id = ID
+    .metadata-uploader = Uploader
+    .metadata-no-tags = No tags
+    .tooltip-select = Select (Shift+click to select a range)
+    .tooltip-deselect = Deselect
+    .tooltip-favorite = Add to favorites
+    .tooltip-unfavorite = Remove from favorites
+    .tag-placeholder = Add tag...
+    .no-user-tags = No tags yet
+    .metadata-save-failed = Failed to save tags, favorite or rating
+    .selected-count = {count} selected
+    .bulk-tag-placeholder = Tag selected wallpapers...
+    .bulk-tag-add = Add Tag
+    .bulk-tag-added = Added tag {tag} to {count} wallpapers
+    .clear-selection = Clear Selection
+    .select-all = Select All
+    .move-to = Move to
+    .copy-to = Copy to
+    .copy-paths = Copy Paths
+    .export-zip = Export ZIP
+    .bulk-delete = Delete
+    .bulk-delete-confirm-message = Are you sure you want to move the {count} selected wallpapers to the trash?
+    .bulk-delete-success = Moved {count} wallpapers to the trash
+    .bulk-delete-failed = Moved {count} wallpapers to the trash, {failed} failed
+    .moved = Moved {count} wallpapers
+    .copied = Copied {count} wallpapers
+    .transfer-skipped = , skipped {count} already in the folder
+    .transfer-partially-failed = {count} wallpapers moved or copied, {failed} failed
+    .transfer-failed = Failed to move or copy wallpapers
+    .paths-copied = Copied {count} paths to the clipboard
+    .exported = Exported {count} wallpapers to {path}
+    .export-failed = Failed to export wallpapers
     .save-success = Saved successfully
     .save-failed = Save failed
     .file-not-found = File not found
@@ -113,7 +169,9 @@ download-tasks = Download Tasks
     .status-completed = Completed
     .status-failed = Failed
     .status-failed-error = Download error
+    .status-retrying = Retrying in {seconds}s ({attempt}/{max})
     .status-cancelled = Cancelled
+    .status-corrupt = Corrupt
     .header-filename = Filename
     .header-size = Size
     .header-status = Status
@@ -174,6 +232,54 @@ settings = Settings
     .open-path = Open
     .clear-path = Clear
     .restore-default = Default
+    .library-roots = Additional Library Folders:
+    .library-root = Library Folder:
+    .add-library-root = Add Folder
+    .library-root-recursive = Subfolders
+    .library-root-follow-symlinks = Follow symlinks
+    .library-root-remove = Remove
+    .library-root-patterns = Filters:
+    .library-root-include-placeholder = Include, e.g. *.png, anime/**
+    .library-root-exclude-placeholder = Exclude, e.g. .thumbnails, **/drafts/*
+    .library-root-exists = This folder is already a library folder
+    .library-roots-help = Local wallpapers and local auto-change scan the data folder (including subfolders) and every additional folder. Separate patterns with commas; patterns without / match file or folder names, patterns with / match the relative path, and ** spans multiple folders.
+    .download-config = Download Configuration
+    .file-name-template = File Name Template:
+    .file-name-template-preview = Preview:
+    .file-name-template-placeholders = Placeholders: {"{"}id{"}"} {"{"}ext{"}"} {"{"}category{"}"} {"{"}purity{"}"} {"{"}resolution{"}"} {"{"}width{"}"} {"{"}height{"}"} {"{"}ratio{"}"} {"{"}first_tag{"}"} {"{"}tags{"}"} {"{"}color{"}"} {"{"}colors{"}"} {"{"}date{"}"} {"{"}year{"}"} {"{"}month{"}"} {"{"}upload_date{"}"} {"{"}uploader{"}"}; use / to create subfolders
+    .write-sidecar = Write Metadata File:
+    .write-xmp-sidecar = Also Write XMP File:
+    .auto-retry = Auto Retry on Failure:
+    .retry-max-attempts = Max Retry Attempts:
+    .retry-base-delay = First Retry Delay:
+    .retry-max-delay = Max Retry Delay:
+    .times = times
+    .seconds = seconds
+    .disk-reserve = Keep Free Disk Space:
+    .library-quota = Data Path Quota, Including Subfolders (0 = unlimited):
+    .cache-quota = Cache Quota (0 = unlimited):
+    .megabytes = MB
+    .transcode-config = Convert After Download
+    .transcode-format = Convert To:
+    .transcode-quality = Quality:
+    .transcode-min-size = Only Files Larger Than:
+    .transcode-keep-original = Keep Original File:
+    .transcode-help = Converted files replace the download record; the original is kept when the converted file is not smaller. Quality applies to JPEG and WebP.
+    .hooks-config = Hook Commands
+    .post-download-hook = After Download:
+    .post-set-hook = After Setting Wallpaper:
+    .hook-command-placeholder = Command to run, e.g. notify-send {"{"}path{"}"}
+    .hook-timeout = Hook Timeout:
+    .hook-command-help = Runs via the system shell. Placeholders: {"{"}path{"}"} {"{"}id{"}"} {"{"}resolution{"}"} {"{"}source{"}"}; also available as environment variables WALLWARP_FILE_PATH, WALLWARP_WALLHAVEN_ID, WALLWARP_RESOLUTION, WALLWARP_SOURCE, WALLWARP_EVENT
+    .network-config = Network
+    .http-connect-timeout = Connect timeout:
+    .http-request-timeout = Request timeout (0 = unlimited):
+    .http-user-agent = User-Agent:
+    .http-user-agent-placeholder = Leave empty to use the default
+    .http2-only = Force HTTP/2:
+    .http-pool-max-idle = Idle connections per host:
+    .http-pool-idle-timeout = Keep idle connections for:
+    .network-help = All requests share one connection pool; changes apply from the next request. Turn off Force HTTP/2 if a server does not support it.
     .wallpaper-config = Wallpaper Configuration
     .wallpaper-mode = Wallpaper Mode:
     .auto-change-mode = Auto Change Mode:
@@ -184,6 +290,9 @@ settings = Settings
     .auto-change-query = Auto Change Query:
     .minutes = minutes
     .auto-change-query-placeholder = Enter search keywords (English only)...
+    .auto-change-local-config = Auto Change Local Config:
+    .auto-change-local-color = Color
+    .auto-change-local-color-hint = Only pick local wallpapers whose dominant colors match, only effective in local mode
     .auto-change-control = Auto Change Control:
     .start-auto-change = Start Auto Change
     .stop-auto-change = Stop Auto Change
@@ -238,6 +347,15 @@ close-action-options =
     .minimize-to-tray = Minimize to tray
     .close-app = Close app
 
+transcode-format-options =
+    .off = Off
+    .jpeg = JPEG
+    .webp = Lossy WebP
+    .webp-lossless = Lossless WebP
+
+transcode =
+    .failed = Conversion failed, original file kept
+
 theme-options =
     .dark = Dark
     .light = Light
@@ -257,6 +375,124 @@ path-clear-confirmation =
     .confirm = Confirm Clear
     .cancel = Cancel
 
+bulk-download =
+    .tooltip = Download all search results
+    .title = Download Search Results
+    .max-count = Maximum wallpapers
+    .max-size = Total size limit (MB, 0 = unlimited)
+    .skip-owned = Skip wallpapers already in library
+    .start = Collect
+    .cancel = Cancel
+    .collecting = Collecting search results: page {page}/{total}, {count} selected
+    .summary-title = Confirm Bulk Download
+    .summary = Checked {scanned} wallpapers, {count} will be downloaded ({size} in total)
+    .summary-skipped = Skipped: {owned} already owned, {queued} already in queue
+    .summary-size-cap = Total size limit reached, stopped collecting
+    .confirm = Start Download
+    .nothing-to-download = No wallpapers to download
+    .added = Added {count} wallpapers to the download queue
+    .failed = Failed to collect search results
+
+duplicates =
+    .title = Similar Images
+    .threshold = Similarity threshold (lower is stricter)
+    .rescan = Search Again
+    .scanning = Analyzing images, please wait...
+    .none = No similar images found
+    .summary = Found {groups} groups of similar images, {count} images in total
+    .keep = Keep
+    .remove = Delete
+    .delete = Delete {count} unkept images
+    .close = Close
+    .help = Each group keeps the image with the highest resolution and largest file by default; the others are moved to the trash after confirmation
+    .deleted = Moved {count} similar images to the trash
+    .delete-failed = Deleted {count} similar images, {failed} could not be deleted
+    .failed = Failed to find similar images
+
+trash =
+    .title = Trash
+    .retention = Days to keep (deleted permanently afterwards, 0 keeps forever)
+    .empty = The trash is empty
+    .summary = {count} wallpapers, {size} in total
+    .deleted-at = Deleted {time}
+    .restore = Restore to original location
+    .purge = Delete permanently
+    .empty-trash = Empty Trash
+    .close = Close
+    .restored = Restored {count} wallpapers
+    .restore-failed = Restore failed
+    .purged = Permanently deleted {count} wallpapers
+    .purge-failed = Failed to delete permanently
+    .load-failed = Failed to load the trash
+
+download-duplicate =
+    .title = Wallpaper Already Exists
+    .message = This wallpaper is already in your library:
+    .skip = Skip
+    .redownload = Re-download
+    .keep-both = Keep Both
+    .skipped = Skipped duplicate wallpaper
+
+download-import =
+    .button = Import Links
+    .title = Import Download Tasks
+    .hint = Paste Wallhaven wallpaper links or IDs (one per line or comma separated), or import from a .txt / .csv file
+    .placeholder = https://wallhaven.cc/w/8586my
+    .select-file = Import from File
+    .file-filter = Text files
+    .resolve = Fetch Wallpaper Info
+    .cancel = Cancel
+    .read-failed = Failed to read file
+    .no-ids = No valid Wallhaven wallpaper links or IDs found
+    .resolving = Fetching wallpaper info: {current}/{total}
+    .preview-title = Import Preview
+    .summary = {total} in total, {resolved} fetched, {count} selected ({size} in total)
+    .header-id = ID
+    .header-resolution = Resolution
+    .header-size = Size
+    .header-status = Status
+    .status-ready = Ready
+    .status-owned = Already owned
+    .status-in-queue = Already in queue
+    .status-failed = Fetch failed
+    .confirm = Add to Download Queue
+    .nothing-selected = No wallpapers selected
+    .added = Added {count} wallpapers to the download queue
+
+download-transfer =
+    .export = Export List
+    .import = Import List
+    .nothing-to-export = No download tasks to export
+    .exported = Exported {count} download tasks to {path}
+    .export-failed = Failed to export download tasks
+    .imported = Imported {imported} download tasks, skipped {skipped} (task or file already exists)
+    .import-failed = Failed to import download tasks
+
+download-space =
+    .low-disk = Not enough disk space, download queue paused: {path} has {available} free, {required} required
+    .quota-exceeded = {path} exceeds its quota ({used} of {quota} used), download queue paused
+    .resume-queue = Resume Queue
+
+download-stats =
+    .active = Downloading
+    .waiting = Waiting
+    .throughput = Speed
+    .remaining = Remaining
+    .eta = ETA
+    .session = This session: {files} files, {size}
+    .button = Statistics
+    .title = Download Statistics (Last 30 Days)
+    .date = Date
+    .files = Files
+    .bytes = Size
+    .total = Total
+    .empty = No downloads in the last 30 days
+    .close = Close
+    .load-failed = Failed to load download statistics
+
+hooks =
+    .failed = Hook command failed
+
 menu =
     .tray-show = Show window
     .tray-switch-previous = Switch to previous wallpaper
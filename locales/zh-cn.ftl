@@ -15,6 +15,7 @@ online-wallpapers = 在线壁纸
     .tooltip-next = 下一张
     .tooltip-download = 下载
     .tooltip-set-wallpaper = 设为壁纸
+    .tooltip-owned = 已在壁纸库中
     .tooltip-close = 关闭
     .search-placeholder = 搜索...（仅支持英文）
     .loading-placeholder = 加载中...
@@ -71,24 +72,79 @@ local-list = 本地列表
     .loading = 正在加载...
     .load-failed = 加载失败
     .no-wallpapers = 没有找到本地壁纸
+    .find-duplicates = 查找相似图片
+    .trash = 回收站
+    .no-matching-wallpapers = 没有符合筛选条件的壁纸
+    .sort-name = 文件名
+    .sort-date-added = 添加时间
+    .sort-file-size = 文件大小
+    .sort-resolution = 分辨率
+    .sort-aspect-ratio = 宽高比
+    .sort-ascending = 升序
+    .sort-descending = 降序
+    .min-resolution-any = 不限分辨率
+    .orientation-any = 不限方向
+    .orientation-landscape = 横向
+    .orientation-portrait = 纵向
+    .orientation-square = 方形
+    .ratio-group-any = 不限比例
+    .format-any = 不限格式
+    .color-label = 颜色
+    .name-pattern-placeholder = 按文件名筛选...
+    .reset-filters = 清除筛选
     .no-valid-wallpapers = 没有找到有效的壁纸
     .load-more = 加载更多...
     .all-loaded = 已加载全部壁纸
     .loading-error = 加载失败
     .image-loading = 图片加载中...
-    .delete-success = 删除成功
+    .avif-unavailable = 已跳过 {count} 张 AVIF 图片：当前版本不支持 AVIF 格式
+    .delete-success = 已移到回收站
     .delete-failed = 删除失败
+    .undo = 撤销
     .set-wallpaper-failed = 设置壁纸失败
     .tooltip-locate = 定位文件
     .tooltip-set-wallpaper = 设置为壁纸
     .tooltip-delete = 删除文件
     .delete-confirm-title = 确认删除
-    .delete-confirm-message = 确定要删除这张壁纸吗？
+    .delete-confirm-message = 确定要将这张壁纸移到回收站吗？
     .delete-confirm-confirm = 确认删除
     .delete-confirm-cancel = 取消
     .tooltip-prev = 上一张
     .tooltip-next = 下一张
     .tooltip-close = 关闭
+    .tooltip-open-source = 打开 Wallhaven 页面
+    .metadata-id = ID
+    .metadata-uploader = 上传者
+    .metadata-no-tags = 无标签
+    .tooltip-select = 选中（按住 Shift 点击选中一个范围）
+    .tooltip-deselect = 取消选中
+    .tooltip-favorite = 收藏
+    .tooltip-unfavorite = 取消收藏
+    .tag-placeholder = 添加标签...
+    .no-user-tags = 还没有标签
+    .metadata-save-failed = 保存标签、收藏或评分失败
+    .selected-count = 已选择 {count} 张
+    .bulk-tag-placeholder = 为选中的壁纸添加标签...
+    .bulk-tag-add = 添加标签
+    .bulk-tag-added = 已为 {count} 张壁纸添加标签: {tag}
+    .clear-selection = 取消选择
+    .select-all = 全选
+    .move-to = 移动到
+    .copy-to = 复制到
+    .copy-paths = 复制路径
+    .export-zip = 导出 ZIP
+    .bulk-delete = 删除
+    .bulk-delete-confirm-message = 确定要将选中的 {count} 张壁纸移到回收站吗？
+    .bulk-delete-success = 已将 {count} 张壁纸移到回收站
+    .bulk-delete-failed = 已将 {count} 张壁纸移到回收站，{failed} 张失败
+    .moved = 已移动 {count} 张壁纸
+    .copied = 已复制 {count} 张壁纸
+    .transfer-skipped = ，跳过 {count} 张已在该目录中的壁纸
+    .transfer-partially-failed = 已移动或复制 {count} 张壁纸，{failed} 张失败
+    .transfer-failed = 移动或复制壁纸失败
+    .paths-copied = 已复制 {count} 个路径到剪贴板
+    .exported = 已导出 {count} 张壁纸到 {path}
+    .export-failed = 导出壁纸失败
     .save-success = 保存成功
     .save-failed = 保存失败
     .file-not-found = 文件不存在
@@ -112,7 +168,9 @@ download-tasks = 下载任务
     .status-completed = 已完成
     .status-failed = 失败
     .status-failed-error = 下载出错
+    .status-retrying = {seconds} 秒后重试 ({attempt}/{max})
     .status-cancelled = 已取消
+    .status-corrupt = 文件损坏
     .header-filename = 文件名
     .header-size = 大小
     .header-status = 状态
@@ -173,6 +231,54 @@ settings = 设置
     .open-path = 查看
     .clear-path = 清空
     .restore-default = 默认
+    .library-roots = 额外壁纸库目录:
+    .library-root = 壁纸库目录:
+    .add-library-root = 添加目录
+    .library-root-recursive = 包含子目录
+    .library-root-follow-symlinks = 跟随符号链接
+    .library-root-remove = 移除
+    .library-root-patterns = 过滤模式:
+    .library-root-include-placeholder = 包含，例如 *.png, anime/**
+    .library-root-exclude-placeholder = 排除，例如 .thumbnails, **/drafts/*
+    .library-root-exists = 该目录已经是壁纸库目录
+    .library-roots-help = 本地壁纸和本地定时切换会扫描数据目录（包含子目录）和所有额外目录。多个模式用逗号分隔；不含 / 的模式匹配文件名或目录名，含 / 的模式匹配相对路径，** 可以跨越多级目录。
+    .download-config = 下载配置
+    .file-name-template = 文件名模板:
+    .file-name-template-preview = 预览:
+    .file-name-template-placeholders = 可用占位符: {"{"}id{"}"} {"{"}ext{"}"} {"{"}category{"}"} {"{"}purity{"}"} {"{"}resolution{"}"} {"{"}width{"}"} {"{"}height{"}"} {"{"}ratio{"}"} {"{"}first_tag{"}"} {"{"}tags{"}"} {"{"}color{"}"} {"{"}colors{"}"} {"{"}date{"}"} {"{"}year{"}"} {"{"}month{"}"} {"{"}upload_date{"}"} {"{"}uploader{"}"}，使用 / 创建子目录
+    .write-sidecar = 写入元数据文件:
+    .write-xmp-sidecar = 同时写入 XMP 文件:
+    .auto-retry = 失败自动重试:
+    .retry-max-attempts = 最大重试次数:
+    .retry-base-delay = 首次重试等待:
+    .retry-max-delay = 最长重试等待:
+    .times = 次
+    .seconds = 秒
+    .disk-reserve = 磁盘保留空间:
+    .library-quota = 数据路径配额，含子目录（0 表示不限制）:
+    .cache-quota = 缓存配额（0 表示不限制）:
+    .megabytes = MB
+    .transcode-config = 下载后转码
+    .transcode-format = 转码格式:
+    .transcode-quality = 转码质量:
+    .transcode-min-size = 仅转码大于:
+    .transcode-keep-original = 保留原文件:
+    .transcode-help = 转码后的文件会替换下载记录中的文件；转码后文件没有变小时保留原文件。质量设置用于 JPEG 和有损 WebP。
+    .hooks-config = 钩子命令
+    .post-download-hook = 下载完成后执行:
+    .post-set-hook = 设置壁纸后执行:
+    .hook-command-placeholder = 要执行的命令，例如 notify-send {"{"}path{"}"}
+    .hook-timeout = 命令超时时间:
+    .hook-command-help = 命令通过系统 shell 执行。可用占位符: {"{"}path{"}"} {"{"}id{"}"} {"{"}resolution{"}"} {"{"}source{"}"}；同时提供环境变量 WALLWARP_FILE_PATH、WALLWARP_WALLHAVEN_ID、WALLWARP_RESOLUTION、WALLWARP_SOURCE、WALLWARP_EVENT
+    .network-config = 网络
+    .http-connect-timeout = 连接超时时间:
+    .http-request-timeout = 请求超时时间（0 表示不限制）:
+    .http-user-agent = User-Agent:
+    .http-user-agent-placeholder = 留空使用默认值
+    .http2-only = 强制使用 HTTP/2:
+    .http-pool-max-idle = 每个主机的空闲连接数:
+    .http-pool-idle-timeout = 空闲连接保留时间:
+    .network-help = 所有请求共享同一个连接池，修改后从下一个请求开始生效。服务器不支持 HTTP/2 时请关闭强制 HTTP/2。
     .wallpaper-config = 壁纸配置
     .wallpaper-mode = 壁纸模式:
     .auto-change-mode = 定时切换模式:
@@ -183,6 +289,9 @@ settings = 设置
     .auto-change-query = 定时切换关键词:
     .minutes = 分钟
     .auto-change-query-placeholder = 输入搜索关键词（仅支持英文）...
+    .auto-change-local-config = 定时切换本地配置:
+    .auto-change-local-color = 颜色
+    .auto-change-local-color-hint = 只选择主色调匹配所选颜色的本地壁纸，仅在本地切换模式下生效
     .auto-change-control = 定时切换控制:
     .start-auto-change = 启动定时切换
     .stop-auto-change = 停止定时切换
@@ -237,6 +346,15 @@ close-action-options =
     .minimize-to-tray = 最小化到托盘
     .close-app = 关闭程序
 
+transcode-format-options =
+    .off = 不转码
+    .jpeg = JPEG
+    .webp = 有损 WebP
+    .webp-lossless = 无损 WebP
+
+transcode =
+    .failed = 转码失败，已保留原文件
+
 theme-options =
     .dark = 暗色
     .light = 亮色
@@ -256,6 +374,124 @@ path-clear-confirmation =
     .confirm = 确认清空
     .cancel = 取消
 
+bulk-download =
+    .tooltip = 下载全部搜索结果
+    .title = 批量下载搜索结果
+    .max-count = 最多下载数量
+    .max-size = 总大小上限（MB，0 表示不限制）
+    .skip-owned = 跳过已拥有的壁纸
+    .start = 开始获取
+    .cancel = 取消
+    .collecting = 正在获取搜索结果：第 {page}/{total} 页，已选择 {count} 张
+    .summary-title = 确认批量下载
+    .summary = 共检查 {scanned} 张壁纸，将下载 {count} 张（共 {size}）
+    .summary-skipped = 已跳过：已拥有 {owned} 张，已在下载队列中 {queued} 张
+    .summary-size-cap = 已达到总大小上限，停止获取
+    .confirm = 开始下载
+    .nothing-to-download = 没有需要下载的壁纸
+    .added = 已将 {count} 张壁纸添加到下载队列
+    .failed = 获取搜索结果失败
+
+duplicates =
+    .title = 相似图片
+    .threshold = 相似度阈值（越小越严格）
+    .rescan = 重新查找
+    .scanning = 正在分析图片，请稍候...
+    .none = 没有找到相似图片
+    .summary = 找到 {groups} 组相似图片，共 {count} 张
+    .keep = 保留
+    .remove = 删除
+    .delete = 删除未保留的 {count} 张图片
+    .close = 关闭
+    .help = 每组默认保留分辨率最高、文件最大的图片，其余图片确认后将被移到回收站
+    .deleted = 已将 {count} 张相似图片移到回收站
+    .delete-failed = 已删除 {count} 张相似图片，{failed} 张删除失败
+    .failed = 查找相似图片失败
+
+trash =
+    .title = 回收站
+    .retention = 保留天数（超过后彻底删除，0 表示一直保留）
+    .empty = 回收站是空的
+    .summary = 共 {count} 张壁纸，占用 {size}
+    .deleted-at = 删除于 {time}
+    .restore = 恢复到原位置
+    .purge = 彻底删除
+    .empty-trash = 清空回收站
+    .close = 关闭
+    .restored = 已恢复 {count} 张壁纸
+    .restore-failed = 恢复失败
+    .purged = 已彻底删除 {count} 张壁纸
+    .purge-failed = 彻底删除失败
+    .load-failed = 加载回收站失败
+
+download-duplicate =
+    .title = 壁纸已存在
+    .message = 该壁纸已存在于壁纸库中：
+    .skip = 跳过
+    .redownload = 重新下载
+    .keep-both = 保留两者
+    .skipped = 已跳过重复的壁纸
+
+download-import =
+    .button = 导入链接
+    .title = 导入下载任务
+    .hint = 粘贴 Wallhaven 壁纸链接或 ID（每行一个，也可用逗号分隔），或从 .txt / .csv 文件导入
+    .placeholder = https://wallhaven.cc/w/8586my
+    .select-file = 从文件导入
+    .file-filter = 文本文件
+    .resolve = 获取壁纸信息
+    .cancel = 取消
+    .read-failed = 读取文件失败
+    .no-ids = 未找到有效的 Wallhaven 壁纸链接或 ID
+    .resolving = 正在获取壁纸信息：{current}/{total}
+    .preview-title = 导入预览
+    .summary = 共 {total} 个，获取成功 {resolved} 个，已选择 {count} 个（共 {size}）
+    .header-id = ID
+    .header-resolution = 分辨率
+    .header-size = 大小
+    .header-status = 状态
+    .status-ready = 可下载
+    .status-owned = 已拥有
+    .status-in-queue = 已在下载队列中
+    .status-failed = 获取失败
+    .confirm = 添加到下载队列
+    .nothing-selected = 未选择任何壁纸
+    .added = 已将 {count} 张壁纸添加到下载队列
+
+download-transfer =
+    .export = 导出列表
+    .import = 导入列表
+    .nothing-to-export = 没有可导出的下载任务
+    .exported = 已导出 {count} 个下载任务到 {path}
+    .export-failed = 导出下载任务失败
+    .imported = 已导入 {imported} 个下载任务，跳过 {skipped} 个（任务已存在或文件已存在）
+    .import-failed = 导入下载任务失败
+
+download-space =
+    .low-disk = 磁盘空间不足，已暂停下载队列：{path} 可用 {available}，需要 {required}
+    .quota-exceeded = {path} 已超出配额（已用 {used} / 配额 {quota}），已暂停下载队列
+    .resume-queue = 继续下载队列
+
+download-stats =
+    .active = 下载中
+    .waiting = 等待中
+    .throughput = 速度
+    .remaining = 剩余
+    .eta = 预计剩余
+    .session = 本次运行：{files} 个文件，{size}
+    .button = 下载统计
+    .title = 下载统计（最近 30 天）
+    .date = 日期
+    .files = 文件数
+    .bytes = 大小
+    .total = 合计
+    .empty = 最近 30 天没有下载记录
+    .close = 关闭
+    .load-failed = 加载下载统计失败
+
+hooks =
+    .failed = 钩子命令执行失败
+
 menu =
     .tray-show = 显示窗口
     .tray-switch-previous = 切换上一张壁纸
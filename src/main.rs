@@ -6,6 +6,7 @@ use iced::{Size, Task, font, window};
 use tracing::{error, info};
 use wallwarp::i18n::I18n;
 use wallwarp::services::async_task::async_cleanup_cache;
+use wallwarp::services::image_format;
 use wallwarp::ui::main::MainMessage;
 use wallwarp::ui::{App, AppMessage};
 use wallwarp::utils::{assets, config, helpers, logger, single_instance::SingleInstanceGuard};
@@ -36,6 +37,9 @@ fn main() -> iced::Result {
     let cfg = config::Config::new(&i18n.current_lang, &i18n.available_langs);
     let _log_guard = logger::init_logger(cfg.global.enable_logging);
 
+    // 注册 JPEG XL 等额外的图片解码器（缩略图、预览和 iced 加载图片都会用到）
+    image_format::register_decoders();
+
     let (rgba, width, height) = assets::get_logo(LOGO_SIZE);
     let icon = window::icon::from_rgba(rgba, width, height).expect("生成 Iced 图标失败");
 
@@ -64,7 +68,7 @@ fn main() -> iced::Result {
             // 在 cfg 被移动之前先克隆一份用于清理任务
             let cleanup_config = cfg.clone();
 
-            let app = App::new_with_config(i18n, cfg);
+            let mut app = App::new_with_config(i18n, cfg);
 
             // 创建启动任务
             let load_font_task = font::load(assets::ICON_FONT).discard();
@@ -72,6 +76,8 @@ fn main() -> iced::Result {
             let listen_task = Task::perform(SingleInstanceGuard::listen(), |payload| {
                 MainMessage::ExternalInstanceTriggered(payload).into()
             });
+            // 在后台计算有配额的目录占用空间（检查配额时使用）
+            let directory_usage_task = app.refresh_directory_usage(false);
 
             // 创建缓存清理任务（在后台异步执行）
             let cleanup_task = Task::perform(
@@ -93,7 +99,13 @@ fn main() -> iced::Result {
 
             (
                 app,
-                Task::batch(vec![load_font_task, enable_resize_task, listen_task, cleanup_task]),
+                Task::batch(vec![
+                    load_font_task,
+                    enable_resize_task,
+                    listen_task,
+                    directory_usage_task,
+                    cleanup_task,
+                ]),
             )
         },
         App::update,
@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::trash;
 use crate::utils::config::Config;
 use std::error::Error;
 use std::fs;
@@ -13,7 +14,9 @@ use tracing::{error, info, warn};
 /// 1. 清理 thumbnail 目录中创建时间超过 7 天的文件
 /// 2. 清理 auto_change 目录中创建时间超过 3 天的文件（但跳过当前正在使用的壁纸）
 /// 3. 清理 online 目录中创建时间超过 7 天的文件
-/// 4. 清理 logs 目录中创建时间超过 3 天的文件
+/// 4. 清理 converted 目录（格式转换后的壁纸）中创建时间超过 3 天的文件（但跳过当前正在使用的壁纸）
+/// 5. 清理 logs 目录中创建时间超过 3 天的文件
+/// 6. 彻底删除回收站中超过保留天数的壁纸
 pub async fn async_cleanup_cache(config: Config) -> Result<(), Box<dyn Error + Send + Sync>> {
     info!("[缓存清理] 开始清理缓存");
 
@@ -48,7 +51,17 @@ pub async fn async_cleanup_cache(config: Config) -> Result<(), Box<dyn Error + S
         info!("[缓存清理] online 目录不存在，跳过");
     }
 
-    // 4. 清理 logs 目录（超过 3 天）
+    // 4. 清理 converted 目录（超过 3 天，排除当前使用的壁纸）
+    let converted_dir = cache_path.join("converted");
+    if converted_dir.exists() {
+        let current_wallpaper = get_current_wallpaper().await.ok();
+        let deleted = cleanup_directory_by_age(&converted_dir, 3, current_wallpaper.as_deref()).await?;
+        info!("[缓存清理] converted 目录清理完成，删除了 {} 个文件", deleted);
+    } else {
+        info!("[缓存清理] converted 目录不存在，跳过");
+    }
+
+    // 5. 清理 logs 目录（超过 3 天）
     let current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
     let logs_dir = current_dir.join("logs");
     if logs_dir.exists() {
@@ -58,6 +71,14 @@ pub async fn async_cleanup_cache(config: Config) -> Result<(), Box<dyn Error + S
         info!("[缓存清理] logs 目录不存在，跳过");
     }
 
+    // 6. 彻底删除回收站中超过保留天数的壁纸
+    let retention_days = config.local.trash_retention_days;
+    match tokio::task::spawn_blocking(move || trash::purge_expired(retention_days)).await {
+        Ok(Ok(deleted)) => info!("[缓存清理] 回收站清理完成，彻底删除了 {} 张壁纸", deleted),
+        Ok(Err(e)) => warn!("[缓存清理] 回收站清理失败: {}", e),
+        Err(e) => warn!("[缓存清理] 回收站清理任务执行失败: {}", e),
+    }
+
     info!("[缓存清理] 缓存清理任务完成");
     Ok(())
 }
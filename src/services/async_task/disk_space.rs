@@ -0,0 +1,12 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::disk_space;
+use std::collections::HashMap;
+use tokio::task::spawn_blocking;
+
+/// 异步计算各目录占用的空间
+pub async fn async_directory_usage(paths: Vec<String>) -> Result<HashMap<String, u64>, String> {
+    spawn_blocking(move || disk_space::directory_usage(&paths))
+        .await
+        .map_err(|e| format!("计算目录占用空间失败: {}", e))
+}
@@ -1,9 +1,9 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::download_progress::DOWNLOAD_PROGRESS;
+use crate::services::request_context::RequestContext;
 use iced::futures::StreamExt;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 use tracing::{error, info};
 
@@ -13,7 +13,7 @@ pub(super) async fn download_to_cache(
     cache_path: &str,
     proxy: Option<String>,
     task_id: usize,
-    cancel_token: Arc<AtomicBool>,
+    context: &RequestContext,
     downloaded_size: u64,
 ) -> Result<u64, String> {
     // 使用统一的代理客户端创建逻辑（支持环境变量回退）
@@ -29,17 +29,19 @@ pub(super) async fn download_to_cache(
         // 断点续传：使用 Range 请求头
         let range_header = format!("bytes={}-", downloaded_size);
         info!("[下载任务] [ID:{}] 断点续传：Range = {}", task_id, range_header);
-        let resp = client
-            .get(url)
-            .header("Range", range_header)
-            .send()
+        context
+            .run_until_cancelled(client.get(url).header("Range", range_header).send())
             .await
-            .map_err(|e| format!("请求失败: {}", e))?;
-        resp
+            .map_err(|_| "下载已取消".to_string())?
+            .map_err(|e| format!("请求失败: {}", e))?
     } else {
         // 新下载
         info!("[下载任务] [ID:{}] 新下载：从头开始", task_id);
-        client.get(url).send().await.map_err(|e| format!("请求失败: {}", e))?
+        context
+            .run_until_cancelled(client.get(url).send())
+            .await
+            .map_err(|_| "下载已取消".to_string())?
+            .map_err(|e| format!("请求失败: {}", e))?
     };
 
     // 检查响应状态
@@ -96,23 +98,30 @@ pub(super) async fn download_to_cache(
     };
 
     let mut downloaded: u64 = downloaded_size;
-    let mut progress_sent = 0i32;
     let start_time = std::time::Instant::now();
     let mut buffer = Vec::with_capacity(64 * 1024); // 64KB 缓冲区
-    let mut last_log_time = start_time;
 
     // 立即发送一次进度更新，确保 total_size 被正确设置
     if total_size > 0 {
         let speed = 0u64;
-        crate::services::send_download_progress(task_id, downloaded, total_size, speed);
+        DOWNLOAD_PROGRESS.report(task_id, downloaded, total_size, speed);
     }
 
-    while let Some(chunk_result) = stream.next().await {
-        // 检查是否被取消
-        if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
-            info!("[下载任务] [ID:{}] 下载被取消", task_id);
-            return Err("下载已取消".to_string());
-        }
+    loop {
+        // 等待下一块数据，被取消时立即停止
+        let chunk_result = match context.run_until_cancelled(stream.next()).await {
+            Ok(Some(chunk_result)) => chunk_result,
+            Ok(None) => break,
+            Err(_) => {
+                info!("[下载任务] [ID:{}] 下载被取消", task_id);
+                // 写入缓冲区中的数据，保证文件大小与已报告的进度一致，用于断点续传
+                if !buffer.is_empty() {
+                    let _ = file.write_all(&buffer).await;
+                    let _ = file.flush().await;
+                }
+                return Err("下载已取消".to_string());
+            }
+        };
 
         let chunk = chunk_result.map_err(|e| format!("读取数据流失败: {}", e))?;
 
@@ -130,28 +139,17 @@ pub(super) async fn download_to_cache(
             buffer.clear();
         }
 
-        // 计算进度百分比
+        // 报告最新进度（进度注册表只保留最新值，界面按固定帧率合并更新，无需在这里节流）
         if total_size > 0 {
-            let percent = ((downloaded as f32 / total_size as f32) * 100.0) as i32;
-            let current_time = std::time::Instant::now();
-            let elapsed_since_last_log = current_time.duration_since(last_log_time).as_secs_f64();
-
-            // 每完成5%或者距离上次日志输出超过1秒才输出进度
-            if percent >= progress_sent + 5 || elapsed_since_last_log >= 1.0 {
-                progress_sent = percent;
-                last_log_time = current_time;
-
-                // 计算下载速度
-                let elapsed = start_time.elapsed().as_secs_f64();
-                let speed = if elapsed > 0.0 {
-                    (downloaded as f64 / elapsed) as u64
-                } else {
-                    0
-                };
-
-                // 发送进度更新到UI
-                crate::services::send_download_progress(task_id, downloaded, total_size, speed);
-            }
+            // 计算下载速度
+            let elapsed = start_time.elapsed().as_secs_f64();
+            let speed = if elapsed > 0.0 {
+                (downloaded as f64 / elapsed) as u64
+            } else {
+                0
+            };
+
+            DOWNLOAD_PROGRESS.report(task_id, downloaded, total_size, speed);
         }
     }
 
@@ -0,0 +1,37 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::download_transfer::{self, TaskTransferRecord};
+
+/// 异步函数用于选择保存位置并导出下载任务
+///
+/// 用户取消选择时返回 `Ok(None)`，成功时返回导出文件的路径和导出的任务数量
+pub async fn async_export_download_tasks(
+    records: Vec<TaskTransferRecord>,
+    default_file_name: String,
+) -> Result<Option<(String, usize)>, String> {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("JSON", &["json"])
+        .add_filter("CSV", &["csv"])
+        .set_file_name(default_file_name)
+        .save_file()
+    else {
+        return Ok(None);
+    };
+
+    let exported = download_transfer::export_tasks(&records, &path)?;
+    Ok(Some((path.to_string_lossy().to_string(), exported)))
+}
+
+/// 异步函数用于选择导出文件并读取其中的下载任务
+///
+/// 用户取消选择时返回 `Ok(None)`
+pub async fn async_import_download_tasks() -> Result<Option<Vec<TaskTransferRecord>>, String> {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("JSON / CSV", &["json", "csv"])
+        .pick_file()
+    else {
+        return Ok(None);
+    };
+
+    download_transfer::import_tasks(&path).map(Some)
+}
@@ -1,11 +1,10 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::download::DownloadService;
-use std::path::PathBuf;
-use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
+use crate::services::request_context::RequestContext;
+use std::path::{Path, PathBuf};
 use tokio::io::AsyncWriteExt;
-use tracing::info;
+use tracing::{error, info, warn};
 
 /// 异步下载壁纸任务函数
 pub async fn async_download_wallpaper_task(
@@ -21,20 +20,8 @@ pub async fn async_download_wallpaper_task(
             .map_err(|e| format!("创建目录失败: {}", e))?;
     }
 
-    // 创建HTTP客户端（带代理）
-    let client = if let Some(proxy_url) = &proxy {
-        if !proxy_url.is_empty() {
-            if let Ok(p) = reqwest::Proxy::all(proxy_url) {
-                reqwest::Client::builder().proxy(p).build().map_err(|e| e.to_string())?
-            } else {
-                reqwest::Client::new()
-            }
-        } else {
-            reqwest::Client::new()
-        }
-    } else {
-        reqwest::Client::new()
-    };
+    // 获取共享HTTP客户端（带代理和环境变量回退）
+    let client = crate::services::proxy::create_client_with_env_fallback(proxy, &url, "下载任务", false);
 
     // 发送请求
     let response = client.get(&url).send().await.map_err(|e| format!("请求失败: {}", e))?;
@@ -61,18 +48,47 @@ pub async fn async_download_wallpaper_task(
     Ok(bytes.len() as u64)
 }
 
+/// 带进度下载任务的参数
+#[derive(Debug, Clone)]
+pub struct DownloadRequest {
+    /// 原图下载地址
+    pub url: String,
+    /// 保存路径
+    pub save_path: PathBuf,
+    /// 代理地址
+    pub proxy: Option<String>,
+    /// 任务ID（用于发送进度更新）
+    pub task_id: usize,
+    /// 请求上下文（用于暂停和取消）
+    pub context: RequestContext,
+    /// 已下载大小（断点续传的起始位置）
+    pub downloaded_size: u64,
+    /// 文件总大小（用于计算缓存路径，未知时为0）
+    pub total_size: u64,
+    /// 缓存目录
+    pub cache_path: String,
+    /// Wallhaven 提供的文件大小（用于完整性校验）
+    pub expected_size: u64,
+    /// 文件类型（用于完整性校验）
+    pub file_type: String,
+}
+
 /// 带进度更新的异步下载壁纸任务函数
 /// 使用 tokio::sync::mpsc 通道来发送进度更新
-pub async fn async_download_wallpaper_task_with_progress(
-    url: String,
-    save_path: PathBuf,
-    proxy: Option<String>,
-    task_id: usize,
-    cancel_token: Arc<AtomicBool>,
-    downloaded_size: u64,
-    total_size: u64,
-    cache_path: String,
-) -> Result<u64, String> {
+pub async fn async_download_wallpaper_task_with_progress(request: DownloadRequest) -> Result<u64, String> {
+    let DownloadRequest {
+        url,
+        save_path,
+        proxy,
+        task_id,
+        context,
+        downloaded_size,
+        total_size,
+        cache_path,
+        expected_size,
+        file_type,
+    } = request;
+
     info!("[下载任务] [ID:{}] 开始下载: {}", task_id, url);
     info!(
         "[下载任务] [ID:{}] 参数：downloaded_size = {} bytes, total_size = {} bytes",
@@ -87,40 +103,12 @@ pub async fn async_download_wallpaper_task_with_progress(
             .map_err(|e| format!("获取缓存路径失败: {}", e))?
     } else {
         // 新下载：先发送HEAD请求获取文件大小
-        let create_client = || -> reqwest::Client {
-            reqwest::Client::builder()
-                .pool_max_idle_per_host(10)
-                .pool_idle_timeout(std::time::Duration::from_secs(90))
-                .connect_timeout(std::time::Duration::from_secs(30))
-                .timeout(std::time::Duration::from_secs(300))
-                .tcp_nodelay(true)
-                .http2_prior_knowledge()
-                .gzip(true)
-                .brotli(true)
-                .build()
-                .unwrap_or_else(|_| reqwest::Client::new())
-        };
-
-        let client = if let Some(proxy_url) = &proxy {
-            if !proxy_url.is_empty() {
-                match reqwest::Proxy::all(proxy_url) {
-                    Ok(p) => reqwest::Client::builder()
-                        .proxy(p)
-                        .build()
-                        .unwrap_or_else(|_| create_client()),
-                    Err(_) => create_client(),
-                }
-            } else {
-                create_client()
-            }
-        } else {
-            create_client()
-        };
+        let client = crate::services::proxy::create_client_with_env_fallback(proxy.clone(), &url, "下载任务", false);
 
-        let head_response = client
-            .head(&url)
-            .send()
+        let head_response = context
+            .run_until_cancelled(client.head(&url).send())
             .await
+            .map_err(|_| "下载已取消".to_string())?
             .map_err(|e| format!("HEAD请求失败: {}", e))?;
         let file_size = head_response.content_length().unwrap_or(0);
         DownloadService::get_online_image_cache_path(&cache_path, &url, file_size)
@@ -137,16 +125,36 @@ pub async fn async_download_wallpaper_task_with_progress(
             .map_err(|e| format!("创建缓存目录失败: {}", e))?;
     }
 
-    // 步骤3: 下载图片到缓存目录
-    let actual_size = super::download_to_cache(
-        &url,
-        &temp_cache_path,
-        proxy.clone(),
-        task_id,
-        cancel_token.clone(),
-        downloaded_size,
-    )
-    .await?;
+    // 步骤3: 下载图片到缓存目录，并校验文件完整性（校验失败时丢弃缓存文件并从头重新下载）
+    let mut resume_from = downloaded_size;
+    let mut verify_attempt = 0;
+    let actual_size = loop {
+        let size =
+            super::download_to_cache(&url, &temp_cache_path, proxy.clone(), task_id, &context, resume_from).await?;
+
+        match super::verify_downloaded_file(&temp_cache_path, expected_size, &file_type, task_id).await {
+            Ok(()) => break size,
+            Err(reason) => {
+                // 丢弃已损坏的缓存文件
+                let _ = tokio::fs::remove_file(&temp_cache_path).await;
+
+                if verify_attempt >= super::MAX_VERIFY_RETRIES {
+                    error!(
+                        "[下载任务] [ID:{}] 文件校验失败，已重试 {} 次: {}",
+                        task_id, verify_attempt, reason
+                    );
+                    return Err(format!("{}: {}", super::DOWNLOAD_CORRUPT_PREFIX, reason));
+                }
+
+                verify_attempt += 1;
+                warn!(
+                    "[下载任务] [ID:{}] 文件校验失败，从头重新下载（第 {} 次）: {}",
+                    task_id, verify_attempt, reason
+                );
+                resume_from = 0;
+            }
+        }
+    };
 
     // 步骤4: 下载完成后，移除.download后缀
     let final_cache_path = DownloadService::get_online_image_cache_final_path(&cache_path, &url, actual_size)
@@ -174,11 +182,25 @@ pub async fn async_download_wallpaper_task_with_progress(
         final_cache_path,
         save_path.display()
     );
-    tokio::fs::copy(&final_cache_path, &save_path)
-        .await
-        .map_err(|e| format!("复制文件到目标路径失败: {}", e))?;
+    // 先复制到同目录的临时文件再重命名，目标位置已有文件（重新下载）时只在复制成功后才被替换
+    let part_path = part_file_path(&save_path);
+    if let Err(e) = tokio::fs::copy(&final_cache_path, &part_path).await {
+        let _ = tokio::fs::remove_file(&part_path).await;
+        return Err(format!("复制文件到目标路径失败: {}", e));
+    }
+    if let Err(e) = tokio::fs::rename(&part_path, &save_path).await {
+        let _ = tokio::fs::remove_file(&part_path).await;
+        return Err(format!("替换目标文件失败: {}", e));
+    }
 
     info!("[下载任务] [ID:{}] 下载完成，文件大小: {} bytes", task_id, actual_size);
 
     Ok(actual_size)
 }
+
+/// 复制到目标路径时使用的临时文件路径（目标文件名加 `.part` 后缀）
+fn part_file_path(save_path: &Path) -> PathBuf {
+    let mut file_name = save_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".part");
+    save_path.with_file_name(file_name)
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::database::{LibraryEntry, UserMetadata, UserMetadataRepository};
+use crate::services::duplicate_finder;
+use crate::services::library::{self, IndexStats};
+use crate::services::library_watcher::LibraryChanges;
+use crate::services::local::{LocalWallpaperService, Wallpaper};
+use crate::services::sidecar;
+use crate::utils::config::LibraryRoot;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use tokio::task::spawn_blocking;
+use tracing::warn;
+
+/// 壁纸库加载结果（壁纸路径列表、索引记录和用户数据）
+pub type LibraryIndex = (Vec<String>, Vec<LibraryEntry>, HashMap<String, UserMetadata>);
+
+/// 异步加载壁纸库索引
+///
+/// 返回壁纸路径列表、索引记录和标签等用户数据；索引为空时（首次打开、索引尚未建立）
+/// 直接扫描目录获取路径列表
+pub async fn async_load_library_index(roots: Vec<LibraryRoot>) -> Result<LibraryIndex, Box<dyn Error + Send + Sync>> {
+    spawn_blocking(move || {
+        // 用户数据加载失败时不影响壁纸列表显示
+        let user_metadata = UserMetadataRepository::global()
+            .and_then(|repository| repository.load_all())
+            .unwrap_or_else(|e| {
+                warn!("[壁纸库] 加载标签、收藏和评分失败: {}", e);
+                HashMap::new()
+            });
+
+        let entries = library::load_index(&roots)?;
+        if entries.is_empty() {
+            let paths = LocalWallpaperService::get_wallpaper_paths(&roots)?;
+            return Ok((paths, entries, user_metadata));
+        }
+        let paths = entries.iter().map(|entry| entry.path.clone()).collect();
+        Ok((paths, entries, user_metadata))
+    })
+    .await
+    .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?
+}
+
+/// 异步增量更新壁纸库索引
+pub async fn async_refresh_library_index(roots: Vec<LibraryRoot>, cache_path: String) -> Result<IndexStats, String> {
+    let full_cache_path = std::env::current_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join(&cache_path);
+
+    spawn_blocking(move || library::refresh_index(&roots, &full_cache_path))
+        .await
+        .map_err(|e| format!("更新壁纸库索引失败: {}", e))?
+}
+
+/// 异步根据文件监听得到的变化更新壁纸库索引，返回新增或修改过的文件的索引记录
+pub async fn async_apply_library_changes(
+    changes: LibraryChanges,
+    cache_path: String,
+) -> Result<Vec<LibraryEntry>, String> {
+    let full_cache_path = std::env::current_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join(&cache_path);
+
+    spawn_blocking(move || library::apply_changes(&changes.changed, &changes.removed, &full_cache_path))
+        .await
+        .map_err(|e| format!("更新壁纸库索引失败: {}", e))?
+}
+
+/// 异步查找壁纸库中的相似图片
+pub async fn async_find_similar_groups(
+    roots: Vec<LibraryRoot>,
+    threshold: u32,
+) -> Result<Vec<Vec<LibraryEntry>>, String> {
+    spawn_blocking(move || duplicate_finder::find_similar_groups(&roots, threshold))
+        .await
+        .map_err(|e| format!("查找相似图片失败: {}", e))?
+}
+
+/// 异步加载壁纸，已建立索引的壁纸直接使用索引中的尺寸和缩略图（不需要重新读取图片文件）
+///
+/// 没有索引记录或缩略图文件已被清理时退回到 [`super::async_load_single_wallpaper_with_fallback`]
+pub async fn async_load_indexed_wallpaper(
+    wallpaper_path: String,
+    entry: Option<LibraryEntry>,
+    cache_path: String,
+) -> Result<Wallpaper, Box<dyn Error + Send + Sync>> {
+    let Some(entry) = entry else {
+        return super::async_load_single_wallpaper_with_fallback(wallpaper_path, cache_path).await;
+    };
+    if !entry.valid {
+        return Ok(Wallpaper::new(
+            entry.path,
+            "加载失败".to_string(),
+            entry.file_size,
+            0,
+            0,
+        ));
+    }
+
+    let thumbnail_exists = !entry.thumbnail_path.is_empty() && Path::new(&entry.thumbnail_path).exists();
+    if !thumbnail_exists {
+        return super::async_load_single_wallpaper_with_fallback(entry.path, cache_path).await;
+    }
+
+    spawn_blocking(move || {
+        let mut wallpaper = Wallpaper::with_thumbnail(
+            entry.path,
+            entry.file_name,
+            entry.thumbnail_path,
+            entry.file_size,
+            entry.width,
+            entry.height,
+        );
+        // 读取下载时写入的元数据附属文件
+        wallpaper.metadata = sidecar::read_sidecar(Path::new(&wallpaper.path));
+        wallpaper
+    })
+    .await
+    .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+}
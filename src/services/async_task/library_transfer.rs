@@ -0,0 +1,37 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::library_transfer::{self, TransferMode, TransferReport};
+use tokio::task::spawn_blocking;
+
+/// 异步把壁纸移动或复制到目标目录
+pub async fn async_transfer_files(
+    paths: Vec<String>,
+    target_dir: String,
+    mode: TransferMode,
+) -> Result<TransferReport, String> {
+    spawn_blocking(move || library_transfer::transfer_files(&paths, &target_dir, mode))
+        .await
+        .map_err(|e| format!("传输壁纸失败: {}", e))?
+}
+
+/// 异步函数用于选择保存位置并把壁纸导出为 zip 文件
+///
+/// 用户取消选择时返回 `Ok(None)`，成功时返回 zip 文件的路径和导出的壁纸数量
+pub async fn async_export_wallpapers_zip(
+    paths: Vec<String>,
+    default_file_name: String,
+) -> Result<Option<(String, usize)>, String> {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("ZIP", &["zip"])
+        .set_file_name(default_file_name)
+        .save_file()
+    else {
+        return Ok(None);
+    };
+
+    let zip_path = path.clone();
+    let exported = spawn_blocking(move || library_transfer::export_zip(&paths, &zip_path))
+        .await
+        .map_err(|e| format!("导出壁纸失败: {}", e))??;
+    Ok(Some((path.to_string_lossy().to_string(), exported)))
+}
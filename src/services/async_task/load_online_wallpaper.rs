@@ -2,11 +2,10 @@
 
 use crate::services::download::DownloadService;
 use crate::services::request_context::RequestContext;
+use crate::services::wallhaven::rate_limit::{self, WALLHAVEN_RATE_LIMITER};
 use crate::services::wallhaven::{ColorOption, OnlineWallpaper, Sorting, TimeRange, WallhavenService};
 use iced::widget::image::Handle;
 use std::error::Error;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 
 /// 异步加载在线壁纸函数
 pub async fn async_load_online_wallpapers(
@@ -48,6 +47,112 @@ pub async fn async_load_online_wallpapers(
     }
 }
 
+/// 触发频率限制后的最大重试次数
+const MAX_RATE_LIMIT_RETRIES: usize = 2;
+
+/// 异步加载在线壁纸函数（遵守 API 频率限制，用于连续请求多页的批量操作）
+///
+/// 每次请求前等待频率限制器放行，触发 HTTP 429 时等待一段时间后重试
+pub async fn async_load_online_wallpapers_rate_limited(
+    categories: u32,
+    sorting: Sorting,
+    purities: u32,
+    color: ColorOption,
+    query: String,
+    time_range: TimeRange,
+    atleast: Option<String>,
+    resolutions: Option<String>,
+    ratios: Option<String>,
+    page: usize,
+    api_key: Option<String>,
+    proxy: Option<String>,
+    proxy_enabled: bool,
+    use_env_fallback: bool,
+    context: RequestContext,
+) -> Result<(Vec<OnlineWallpaper>, bool, usize, usize), Box<dyn Error + Send + Sync>> {
+    let mut attempt = 0;
+    loop {
+        // 等待频率限制器放行（等待期间被取消时直接返回）
+        context.run_until_cancelled(WALLHAVEN_RATE_LIMITER.acquire()).await?;
+
+        let result = async_load_online_wallpapers(
+            categories,
+            sorting,
+            purities,
+            color,
+            query.clone(),
+            time_range,
+            atleast.clone(),
+            resolutions.clone(),
+            ratios.clone(),
+            page,
+            api_key.clone(),
+            proxy.clone(),
+            proxy_enabled,
+            use_env_fallback,
+            context.clone(),
+        )
+        .await;
+
+        match result {
+            Err(e) if rate_limit::is_rate_limited_error(&e.to_string()) && attempt < MAX_RATE_LIMIT_RETRIES => {
+                attempt += 1;
+                tracing::warn!(
+                    "[Wallhaven API] [第{}页] 触发频率限制，{} 秒后重试 ({}/{})",
+                    page,
+                    rate_limit::RATE_LIMIT_BACKOFF.as_secs(),
+                    attempt,
+                    MAX_RATE_LIMIT_RETRIES
+                );
+                context
+                    .run_until_cancelled(tokio::time::sleep(rate_limit::RATE_LIMIT_BACKOFF))
+                    .await?;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// 异步获取单张壁纸详情（遵守 API 频率限制，用于按ID批量导入下载任务）
+///
+/// 每次请求前等待频率限制器放行，触发 HTTP 429 时等待一段时间后重试
+pub async fn async_get_online_wallpaper_rate_limited(
+    id: String,
+    api_key: Option<String>,
+    proxy: Option<String>,
+    proxy_enabled: bool,
+    use_env_fallback: bool,
+    context: RequestContext,
+) -> Result<OnlineWallpaper, String> {
+    let service = WallhavenService::new(api_key, proxy, proxy_enabled, use_env_fallback);
+    let mut attempt = 0;
+    loop {
+        // 等待频率限制器放行（等待期间被取消时直接返回）
+        context
+            .run_until_cancelled(WALLHAVEN_RATE_LIMITER.acquire())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        match service.get_wallpaper(&id, &context).await {
+            Err(e) if rate_limit::is_rate_limited_error(&e) && attempt < MAX_RATE_LIMIT_RETRIES => {
+                attempt += 1;
+                tracing::warn!(
+                    "[Wallhaven API] [ID:{}] 触发频率限制，{} 秒后重试 ({}/{})",
+                    id,
+                    rate_limit::RATE_LIMIT_BACKOFF.as_secs(),
+                    attempt,
+                    MAX_RATE_LIMIT_RETRIES
+                );
+                context
+                    .run_until_cancelled(tokio::time::sleep(rate_limit::RATE_LIMIT_BACKOFF))
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+            result => return result,
+        }
+    }
+}
+
 /// 异步加载在线壁纸缩略图函数（带缓存）
 pub async fn async_load_online_wallpaper_thumb_with_cache(
     url: String,
@@ -65,16 +170,13 @@ pub async fn async_load_online_wallpaper_thumb_with_cache_with_cancel(
     file_size: u64,
     cache_base_path: String,
     proxy: Option<String>,
-    cancel_token: Arc<AtomicBool>,
+    context: RequestContext,
 ) -> Result<Handle, Box<dyn Error + Send + Sync>> {
     // 在下载前检查取消状态
-    if cancel_token.load(Ordering::Relaxed) {
-        return Err(
-            Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                as Box<dyn Error + Send + Sync>,
-        );
+    if let Some(e) = context.error() {
+        return Err(Box::new(e));
     }
 
     // 使用DownloadService的智能缓存加载功能
-    DownloadService::load_thumb_with_cache_with_cancel(url, file_size, cache_base_path, proxy, cancel_token).await
+    DownloadService::load_thumb_with_cache_with_cancel(url, file_size, cache_base_path, proxy, context).await
 }
@@ -1,6 +1,8 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::image_format;
 use crate::services::local::{LocalWallpaperService, Wallpaper};
+use crate::services::sidecar;
 use std::error::Error;
 use std::path::{Path, PathBuf};
 use tokio::task::spawn_blocking;
@@ -29,7 +31,7 @@ pub async fn async_load_single_wallpaper_with_fallback(
             )?;
 
             // 获取图片尺寸
-            let (width, height) = image::image_dimensions(&wallpaper_path).unwrap_or((0, 0));
+            let (width, height) = image_format::image_dimensions(Path::new(&wallpaper_path)).unwrap_or((0, 0));
 
             Ok(Wallpaper::with_thumbnail(
                 wallpaper_path.clone(),
@@ -46,7 +48,11 @@ pub async fn async_load_single_wallpaper_with_fallback(
         })();
 
         match result {
-            Ok(wallpaper) => Ok(wallpaper),
+            Ok(mut wallpaper) => {
+                // 读取下载时写入的元数据附属文件
+                wallpaper.metadata = sidecar::read_sidecar(Path::new(&wallpaper.path));
+                Ok(wallpaper)
+            }
             Err(_) => {
                 // 如果加载失败，返回一个带有文件大小的失败状态
                 Ok(Wallpaper::new(
@@ -1,24 +1,39 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 mod cache_cleanup;
+mod disk_space;
 mod download_to_cache;
+mod download_transfer;
 mod download_wallpaper;
 mod get_supported_images;
+mod library_index;
+mod library_transfer;
 mod load_online_wallpaper;
 mod load_single_wallpaper;
-mod load_wallpaper_paths;
 mod open_folder;
+mod owned_wallpaper;
 mod set_wallpaper;
 mod streaming;
+mod trash;
+mod verify_download;
+mod wallpaper_metadata;
 
 use download_to_cache::*;
+use verify_download::*;
 
 pub use cache_cleanup::*;
+pub use disk_space::*;
+pub use download_transfer::*;
 pub use download_wallpaper::*;
 pub use get_supported_images::*;
+pub use library_index::*;
+pub use library_transfer::*;
 pub use load_online_wallpaper::*;
 pub use load_single_wallpaper::*;
-pub use load_wallpaper_paths::*;
 pub use open_folder::*;
+pub use owned_wallpaper::*;
 pub use set_wallpaper::*;
 pub use streaming::*;
+pub use trash::*;
+pub use verify_download::DOWNLOAD_CORRUPT_PREFIX;
+pub use wallpaper_metadata::*;
@@ -8,3 +8,20 @@ pub async fn select_folder_async() -> String {
         "".to_string() // 用户取消选择
     }
 }
+
+/// 异步函数用于选择文本文件并读取其内容
+///
+/// 用户取消选择时返回 `Ok(None)`
+pub async fn select_text_file_async(
+    filter_name: String,
+    extensions: &'static [&'static str],
+) -> Result<Option<String>, String> {
+    let Some(path) = rfd::FileDialog::new().add_filter(filter_name, extensions).pick_file() else {
+        return Ok(None);
+    };
+
+    tokio::fs::read_to_string(&path)
+        .await
+        .map(Some)
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
@@ -0,0 +1,15 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::owned_wallpaper::{self, OwnedWallpaperQuery};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::task::spawn_blocking;
+
+/// 异步查找壁纸库中已有的壁纸，返回已拥有的壁纸ID和已有文件的路径
+pub async fn async_find_owned_wallpapers(
+    queries: Vec<OwnedWallpaperQuery>,
+) -> Result<HashMap<String, PathBuf>, String> {
+    spawn_blocking(move || owned_wallpaper::find_owned_wallpapers(&queries))
+        .await
+        .map_err(|e| format!("查找已拥有的壁纸失败: {}", e))
+}
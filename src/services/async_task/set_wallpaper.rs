@@ -1,6 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::download::DownloadService;
+use crate::services::library;
 use crate::services::local::LocalWallpaperService;
 use crate::services::request_context::RequestContext;
 use crate::services::wallhaven;
@@ -13,24 +14,35 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::task::spawn_blocking;
 use tracing::{error, info};
 
-/// 异步设置壁纸函数
+/// 异步设置壁纸函数（`cache_path` 用于保存格式转换后的壁纸）
 pub async fn async_set_wallpaper(
     wallpaper_path: String,
     mode: WallpaperMode,
+    cache_path: String,
 ) -> Result<(), Box<dyn Error + Send + Sync>> {
-    spawn_blocking(move || LocalWallpaperService::set_wallpaper(&wallpaper_path, mode))
+    spawn_blocking(move || LocalWallpaperService::set_wallpaper(&wallpaper_path, mode, &cache_path))
         .await
         .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?
 }
 
 /// 异步随机设置壁纸函数
+///
+/// `color` 不是 Any 时只从主色调匹配该颜色的壁纸中选择
 pub async fn async_set_random_wallpaper(
     image_paths: Vec<String>,
     mode: WallpaperMode,
+    color: wallhaven::ColorOption,
+    cache_path: String,
 ) -> Result<String, Box<dyn Error + Send + Sync>> {
-    spawn_blocking(move || LocalWallpaperService::set_random_wallpaper(&image_paths, mode))
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?
+    spawn_blocking(move || {
+        let image_paths = library::filter_by_color(image_paths, color)?;
+        if image_paths.is_empty() {
+            return Err("没有主色调匹配所选颜色的壁纸".into());
+        }
+        LocalWallpaperService::set_random_wallpaper(&image_paths, mode, &cache_path)
+    })
+    .await
+    .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?
 }
 
 /// 异步随机设置在线壁纸函数（用于定时切换）
@@ -194,7 +206,11 @@ pub async fn async_set_random_online_wallpaper(
                 target_path.display()
             );
             let wallpaper_mode = config.wallpaper.mode;
-            LocalWallpaperService::set_wallpaper(&target_path.to_string_lossy().to_string(), wallpaper_mode)?;
+            LocalWallpaperService::set_wallpaper(
+                &target_path.to_string_lossy(),
+                wallpaper_mode,
+                &cache_path,
+            )?;
             return Ok(target_path.to_string_lossy().to_string());
         }
     }
@@ -222,6 +238,7 @@ pub async fn async_set_random_online_wallpaper(
                         LocalWallpaperService::set_wallpaper(
                             &target_path.to_string_lossy().to_string(),
                             wallpaper_mode,
+                            &cache_path,
                         )?;
                         return Ok(target_path.to_string_lossy().to_string());
                     }
@@ -256,7 +273,7 @@ pub async fn async_set_random_online_wallpaper(
 
     // 设置壁纸
     let wallpaper_mode = config.wallpaper.mode;
-    LocalWallpaperService::set_wallpaper(&target_path.to_string_lossy().to_string(), wallpaper_mode)?;
+    LocalWallpaperService::set_wallpaper(&target_path.to_string_lossy(), wallpaper_mode, &cache_path)?;
 
     info!("[定时切换] [在线] 壁纸设置成功: {}", target_path.display());
     Ok(target_path.to_string_lossy().to_string())
@@ -1,12 +1,11 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::request_context::RequestContext;
 use iced::futures::StreamExt;
 use iced::widget::image::Handle;
 use std::error::Error;
 use std::path::Path;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::io::AsyncWriteExt;
 use tracing::{debug, error, info};
 use xxhash_rust::xxh3::xxh3_128;
@@ -17,7 +16,7 @@ pub async fn async_load_online_wallpaper_image_with_streaming(
     file_size: u64,
     cache_path: String,
     proxy: Option<String>,
-    cancel_token: Arc<AtomicBool>,
+    context: RequestContext,
 ) -> Result<Handle, Box<dyn Error + Send + Sync>> {
     debug!(
         "[模态窗口图片下载] [URL:{}] 开始流式下载，文件大小: {} bytes",
@@ -62,7 +61,8 @@ pub async fn async_load_online_wallpaper_image_with_streaming(
     );
 
     // 步骤5: 发送请求
-    let response = client.get(&url).send().await.map_err(|e| {
+    let response = context.run_until_cancelled(client.get(&url).send()).await?;
+    let response = response.map_err(|e| {
         error!("[模态窗口图片下载] [URL:{}] 请求失败: {}", url, e);
         Box::new(e) as Box<dyn Error + Send + Sync>
     })?;
@@ -87,17 +87,19 @@ pub async fn async_load_online_wallpaper_image_with_streaming(
     let mut progress_sent = 0i32;
     let mut buffer = Vec::with_capacity(1024 * 1024); // 1MB缓冲区
 
-    while let Some(chunk_result) = stream.next().await {
-        // 检查是否被取消
-        if cancel_token.load(Ordering::Relaxed) {
-            info!("[模态窗口图片下载] [URL:{}] 下载被取消", url);
-            // 删除未完成的文件
-            let _ = tokio::fs::remove_file(&cache_file).await;
-            return Err(
-                Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                    as Box<dyn Error + Send + Sync>,
-            );
-        }
+    loop {
+        // 等待下一块数据，被取消时立即停止（不需要等到下一块数据到达）
+        let chunk_result = match context.run_until_cancelled(stream.next()).await {
+            Ok(Some(chunk_result)) => chunk_result,
+            Ok(None) => break,
+            Err(e) => {
+                info!("[模态窗口图片下载] [URL:{}] 下载被取消", url);
+                // 删除未完成的文件
+                drop(file);
+                let _ = tokio::fs::remove_file(&cache_file).await;
+                return Err(Box::new(e));
+            }
+        };
 
         let chunk = chunk_result.map_err(|e| {
             error!("[模态窗口图片下载] [URL:{}] 读取数据流失败: {}", url, e);
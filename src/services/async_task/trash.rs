@@ -0,0 +1,32 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::database::TrashItem;
+use crate::services::trash::{self, RestoreReport, TrashReport};
+use crate::utils::config::LibraryRoot;
+use std::path::PathBuf;
+use tokio::task::spawn_blocking;
+
+/// 异步把一张壁纸移动到回收站
+pub async fn async_delete_wallpaper(path: String, roots: Vec<LibraryRoot>) -> Result<TrashItem, String> {
+    spawn_blocking(move || trash::move_to_trash(&path, &roots))
+        .await
+        .map_err(|e| format!("移动到回收站失败: {}", e))?
+}
+
+/// 异步把多张壁纸移动到回收站
+pub async fn async_move_to_trash(paths: Vec<String>, roots: Vec<LibraryRoot>) -> Result<TrashReport, String> {
+    spawn_blocking(move || trash::move_all_to_trash(&paths, &roots))
+        .await
+        .map_err(|e| format!("移动到回收站失败: {}", e))
+}
+
+/// 异步恢复回收站中的壁纸，并为恢复的壁纸重新建立索引
+pub async fn async_restore_from_trash(ids: Vec<i64>, cache_path: String) -> Result<RestoreReport, String> {
+    let full_cache_path = std::env::current_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join(&cache_path);
+
+    spawn_blocking(move || trash::restore_all(&ids, &full_cache_path))
+        .await
+        .map_err(|e| format!("恢复失败: {}", e))?
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use std::io::Read;
+use tokio::task::spawn_blocking;
+use tracing::{debug, warn};
+
+/// 完整性校验失败时错误信息的前缀，用于和普通下载失败区分
+pub const DOWNLOAD_CORRUPT_PREFIX: &str = "文件校验失败";
+
+/// 校验失败后从头重新下载的最大次数
+pub(super) const MAX_VERIFY_RETRIES: usize = 2;
+
+/// 辅助函数：校验已下载到缓存目录的文件是否完整
+///
+/// 依次检查：
+/// 1. 文件大小是否与 Wallhaven 提供的 file_size 一致（expected_size 为 0 时跳过）
+/// 2. 文件头魔数是否与 file_type 一致
+/// 3. 图片能否被完整解码（检测截断的文件）
+pub(super) async fn verify_downloaded_file(
+    cache_path: &str,
+    expected_size: u64,
+    file_type: &str,
+    task_id: usize,
+) -> Result<(), String> {
+    // 检查文件大小
+    let actual_size = tokio::fs::metadata(cache_path)
+        .await
+        .map_err(|e| format!("读取文件信息失败: {}", e))?
+        .len();
+    if expected_size > 0 && actual_size != expected_size {
+        warn!(
+            "[下载校验] [ID:{}] 文件大小与 Wallhaven 记录不一致：期望 {} bytes，实际 {} bytes",
+            task_id, expected_size, actual_size
+        );
+        return Err(format!(
+            "文件大小不一致：期望 {} bytes，实际 {} bytes",
+            expected_size, actual_size
+        ));
+    }
+
+    let path = cache_path.to_string();
+    let file_type = file_type.to_lowercase();
+
+    // 魔数检查和完整解码都是阻塞操作，放到阻塞线程池中执行
+    spawn_blocking(move || {
+        // 检查文件头魔数
+        let mut header = [0u8; 12];
+        let mut file = std::fs::File::open(&path).map_err(|e| format!("打开文件失败: {}", e))?;
+        let read = file.read(&mut header).map_err(|e| format!("读取文件头失败: {}", e))?;
+        if !magic_bytes_match(&header[..read], &file_type) {
+            return Err(format!("文件头与类型 {} 不匹配", file_type));
+        }
+
+        // 完整解码图片，检测截断或损坏的数据
+        image::ImageReader::open(&path)
+            .map_err(|e| format!("打开图片失败: {}", e))?
+            .with_guessed_format()
+            .map_err(|e| format!("识别图片格式失败: {}", e))?
+            .decode()
+            .map_err(|e| format!("图片解码失败: {}", e))?;
+
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("校验任务执行失败: {}", e))??;
+
+    debug!("[下载校验] [ID:{}] 校验通过: {}", task_id, cache_path);
+    Ok(())
+}
+
+/// 检查文件头魔数是否与文件类型一致（未知类型不做检查）
+fn magic_bytes_match(header: &[u8], file_type: &str) -> bool {
+    match file_type {
+        "jpg" | "jpeg" => header.starts_with(&[0xFF, 0xD8, 0xFF]),
+        "png" => header.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]),
+        "webp" => header.len() >= 12 && &header[0..4] == b"RIFF" && &header[8..12] == b"WEBP",
+        "gif" => header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a"),
+        "bmp" => header.starts_with(b"BM"),
+        _ => true,
+    }
+}
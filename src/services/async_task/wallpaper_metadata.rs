@@ -0,0 +1,68 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use super::async_get_online_wallpaper_rate_limited;
+use crate::services::library;
+use crate::services::request_context::RequestContext;
+use crate::services::sidecar::{self, WallpaperMetadata};
+use std::path::{Path, PathBuf};
+use tokio::task::spawn_blocking;
+use tracing::{info, warn};
+
+/// 异步保存下载完成的壁纸的元数据：导入 Wallhaven 标签，并按设置写入元数据附属文件
+///
+/// 搜索结果中没有标签和上传者，保存前先获取壁纸详情（遵守 API 频率限制，获取失败时只保存已有的信息）。
+/// 失败只记录日志，不影响下载结果
+pub async fn async_save_wallpaper_metadata(
+    file_path: PathBuf,
+    mut metadata: WallpaperMetadata,
+    write_sidecar: bool,
+    write_xmp: bool,
+    api_key: Option<String>,
+    proxy: Option<String>,
+    proxy_enabled: bool,
+) {
+    if metadata.lacks_details() {
+        let result = async_get_online_wallpaper_rate_limited(
+            metadata.wallhaven_id.clone(),
+            api_key,
+            proxy,
+            proxy_enabled,
+            true, // 启用环境变量回退
+            RequestContext::new(),
+        )
+        .await;
+        match result {
+            Ok(wallpaper) => metadata.merge_details(&wallpaper),
+            Err(e) => warn!("[元数据] [壁纸ID:{}] 获取壁纸详情失败: {}", metadata.wallhaven_id, e),
+        }
+    }
+
+    let result = spawn_blocking(move || save_wallpaper_metadata(&file_path, &metadata, write_sidecar, write_xmp)).await;
+    if let Err(e) = result {
+        warn!("[元数据] 保存元数据失败: {}", e);
+    }
+}
+
+fn save_wallpaper_metadata(file_path: &Path, metadata: &WallpaperMetadata, write_sidecar: bool, write_xmp: bool) {
+    let tags = library::import_tags(&file_path.to_string_lossy(), &metadata.tags);
+    if !tags.is_empty() {
+        info!(
+            "[元数据] [壁纸ID:{}] 已导入 {} 个标签",
+            metadata.wallhaven_id,
+            tags.len()
+        );
+    }
+
+    if !write_sidecar {
+        return;
+    }
+
+    match sidecar::write_sidecar(file_path, metadata, write_xmp) {
+        Ok(()) => info!(
+            "[元数据] [壁纸ID:{}] 已写入附属文件: {}",
+            metadata.wallhaven_id,
+            sidecar::json_sidecar_path(file_path).display()
+        ),
+        Err(e) => warn!("[元数据] [壁纸ID:{}] {}", metadata.wallhaven_id, e),
+    }
+}
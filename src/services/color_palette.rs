@@ -0,0 +1,122 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 图片主色调模块
+//!
+//! 把缩小后的图片像素用 k-means 聚类，得到按占比从高到低排序的 5 种主色调；
+//! 筛选颜色时把主色调匹配到 Wallhaven 的 29 种颜色中最接近的一种（与在线壁纸的颜色筛选一致）
+
+use crate::services::image_format;
+use crate::services::wallhaven::ColorOption;
+use std::path::Path;
+
+/// 主色调数量
+pub const PALETTE_SIZE: usize = 5;
+/// 聚类前把图片缩小到的最大边长
+const SAMPLE_SIZE: u32 = 64;
+/// k-means 最大迭代次数
+const MAX_ITERATIONS: usize = 12;
+/// 占比低于该值的颜色不作为主色调（避免零星的颜色被匹配）
+const MIN_SHARE: f32 = 0.03;
+
+/// 提取图片的主色调（按占比从高到低排序，最多 [`PALETTE_SIZE`] 种）
+pub fn extract_palette(path: &Path) -> Result<Vec<[u8; 3]>, String> {
+    let img = image_format::open_image(path).map_err(|e| format!("打开图片失败: {}", e))?;
+    let pixels: Vec<[f32; 3]> = img
+        .thumbnail(SAMPLE_SIZE, SAMPLE_SIZE)
+        .into_rgb8()
+        .pixels()
+        .map(|pixel| [pixel[0] as f32, pixel[1] as f32, pixel[2] as f32])
+        .collect();
+    if pixels.is_empty() {
+        return Err("图片为空".to_string());
+    }
+
+    // 按亮度分位数选取初始中心（结果稳定，重新计算时主色调不会变化）
+    let mut sorted = pixels.clone();
+    sorted.sort_by(|a, b| luminance(a).total_cmp(&luminance(b)));
+    let mut centroids: Vec<[f32; 3]> = (0..PALETTE_SIZE)
+        .map(|i| sorted[(2 * i + 1) * sorted.len() / (2 * PALETTE_SIZE)])
+        .collect();
+
+    let mut assignments = vec![usize::MAX; pixels.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (pixel, assignment) in pixels.iter().zip(assignments.iter_mut()) {
+            let nearest = nearest_centroid(&centroids, pixel);
+            if *assignment != nearest {
+                *assignment = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = [[0.0f32; 3]; PALETTE_SIZE];
+        let mut counts = vec![0usize; PALETTE_SIZE];
+        for (pixel, &assignment) in pixels.iter().zip(&assignments) {
+            for channel in 0..3 {
+                sums[assignment][channel] += pixel[channel];
+            }
+            counts[assignment] += 1;
+        }
+        for (centroid, (sum, &count)) in centroids.iter_mut().zip(sums.iter().zip(&counts)) {
+            if count > 0 {
+                *centroid = sum.map(|value| value / count as f32);
+            }
+        }
+    }
+
+    let mut counts = vec![0usize; PALETTE_SIZE];
+    for &assignment in &assignments {
+        counts[assignment] += 1;
+    }
+    let mut clusters: Vec<(usize, [f32; 3])> = counts.into_iter().zip(centroids).collect();
+    clusters.sort_by_key(|cluster| std::cmp::Reverse(cluster.0));
+
+    let min_count = (pixels.len() as f32 * MIN_SHARE).ceil() as usize;
+    Ok(clusters
+        .into_iter()
+        .filter(|(count, _)| *count > 0 && *count >= min_count)
+        .map(|(_, centroid)| centroid.map(|value| value.round().clamp(0.0, 255.0) as u8))
+        .collect())
+}
+
+/// 与颜色最接近的 Wallhaven 颜色
+pub fn nearest_color_option(rgb: [u8; 3]) -> ColorOption {
+    ColorOption::all()
+        .into_iter()
+        .filter_map(|option| option.rgb().map(|option_rgb| (option, color_distance(rgb, option_rgb))))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(ColorOption::Any, |(option, _)| option)
+}
+
+/// 主色调中是否有颜色匹配指定的 Wallhaven 颜色（Any 总是匹配）
+pub fn palette_matches(palette: &[[u8; 3]], color: ColorOption) -> bool {
+    color == ColorOption::Any || palette.iter().any(|rgb| nearest_color_option(*rgb) == color)
+}
+
+/// 距离像素最近的聚类中心
+fn nearest_centroid(centroids: &[[f32; 3]], pixel: &[f32; 3]) -> usize {
+    centroids
+        .iter()
+        .enumerate()
+        .map(|(index, centroid)| {
+            let distance: f32 = (0..3).map(|channel| (pixel[channel] - centroid[channel]).powi(2)).sum();
+            (index, distance)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(0, |(index, _)| index)
+}
+
+/// 亮度（用于选取初始聚类中心）
+fn luminance(pixel: &[f32; 3]) -> f32 {
+    0.299 * pixel[0] + 0.587 * pixel[1] + 0.114 * pixel[2]
+}
+
+/// 两种颜色的感知距离（按红色均值加权的 RGB 距离，比直接计算 RGB 距离更接近人眼的感受）
+fn color_distance(a: [u8; 3], b: [u8; 3]) -> f32 {
+    let red_mean = (a[0] as f32 + b[0] as f32) / 2.0;
+    let [dr, dg, db] = [0, 1, 2].map(|channel| a[channel] as f32 - b[channel] as f32);
+    ((2.0 + red_mean / 256.0) * dr * dr + 4.0 * dg * dg + (2.0 + (255.0 - red_mean) / 256.0) * db * db).sqrt()
+}
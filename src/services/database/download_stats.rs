@@ -0,0 +1,112 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 下载统计数据库操作模块
+//!
+//! 按天记录下载完成的文件数和字节数
+
+use super::connection::DatabaseConnection;
+use rusqlite::params;
+
+/// 一天的下载统计
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DailyDownloadStats {
+    /// 日期（本地时间，格式：2024-01-01）
+    pub date: String,
+    /// 下载完成的字节数
+    pub bytes: u64,
+    /// 下载完成的文件数
+    pub files: u64,
+}
+
+/// 下载统计数据库仓库
+pub struct DownloadStatsRepository {
+    db: DatabaseConnection,
+}
+
+impl std::fmt::Debug for DownloadStatsRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DownloadStatsRepository {{ database }}")
+    }
+}
+
+impl DownloadStatsRepository {
+    /// 从数据库连接创建下载统计仓库
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 创建数据库表
+    ///
+    /// # 参数
+    /// - `db`: 数据库连接
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn create_tables(db: &DatabaseConnection) -> Result<(), String> {
+        let conn = db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS download_stats (
+                date TEXT PRIMARY KEY,
+                bytes INTEGER NOT NULL DEFAULT 0,
+                files INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .map_err(|e| format!("创建表失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 累加一个下载完成的文件到指定日期的统计中
+    ///
+    /// # 参数
+    /// - `date`: 日期（格式：2024-01-01）
+    /// - `bytes`: 文件大小（字节）
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn record_download(&self, date: &str, bytes: u64) -> Result<(), String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO download_stats (date, bytes, files) VALUES (?1, ?2, 1)
+             ON CONFLICT(date) DO UPDATE SET bytes = bytes + excluded.bytes, files = files + 1",
+            params![date, bytes as i64],
+        )
+        .map_err(|e| format!("保存下载统计失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 加载最近若干天的下载统计（按日期倒序，没有下载的日期不包含在内）
+    ///
+    /// # 参数
+    /// - `since_date`: 起始日期（包含，格式：2024-01-01）
+    ///
+    /// # 返回
+    /// 返回每天的下载统计列表
+    pub fn load_since(&self, since_date: &str) -> Result<Vec<DailyDownloadStats>, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT date, bytes, files FROM download_stats
+                 WHERE date >= ?1
+                 ORDER BY date DESC",
+            )
+            .map_err(|e| format!("查询下载统计失败: {}", e))?;
+
+        let rows = stmt
+            .query_map(params![since_date], |row| {
+                Ok(DailyDownloadStats {
+                    date: row.get(0)?,
+                    bytes: row.get::<_, i64>(1)? as u64,
+                    files: row.get::<_, i64>(2)? as u64,
+                })
+            })
+            .map_err(|e| format!("查询下载统计失败: {}", e))?;
+
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+}
@@ -29,6 +29,18 @@ pub struct DownloadTaskDB {
     pub proxy: Option<String>,
     /// 原始文件类型
     pub file_type: String,
+    /// Wallhaven 提供的文件大小（字节，用于完整性校验，0表示未知）
+    #[serde(default)]
+    pub expected_size: u64,
+    /// 已自动重试的次数
+    #[serde(default)]
+    pub retry_count: u32,
+    /// 最近一次失败的错误信息
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// 壁纸元数据（JSON 格式）
+    #[serde(default)]
+    pub metadata: Option<String>,
 }
 
 /// 下载任务数据库仓库
@@ -75,12 +87,22 @@ impl DownloadTasksRepository {
                 status TEXT NOT NULL DEFAULT 'Waiting',
                 created_at INTEGER NOT NULL,
                 proxy TEXT,
-                file_type TEXT NOT NULL
+                file_type TEXT NOT NULL,
+                expected_size INTEGER NOT NULL DEFAULT 0,
+                retry_count INTEGER NOT NULL DEFAULT 0,
+                last_error TEXT,
+                metadata TEXT
             )",
             [],
         )
         .map_err(|e| format!("创建表失败: {}", e))?;
 
+        // 兼容旧版本数据库：补齐新增的列
+        super::add_column_if_missing(&conn, "download_tasks", "expected_size", "INTEGER NOT NULL DEFAULT 0")?;
+        super::add_column_if_missing(&conn, "download_tasks", "retry_count", "INTEGER NOT NULL DEFAULT 0")?;
+        super::add_column_if_missing(&conn, "download_tasks", "last_error", "TEXT")?;
+        super::add_column_if_missing(&conn, "download_tasks", "metadata", "TEXT")?;
+
         // 创建索引以加速查询
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_download_tasks_status ON download_tasks(status)",
@@ -110,8 +132,8 @@ impl DownloadTasksRepository {
 
         conn.execute(
             "INSERT OR REPLACE INTO download_tasks
-             (id, file_name, url, save_path, total_size, status, created_at, proxy, file_type)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+             (id, file_name, url, save_path, total_size, status, created_at, proxy, file_type, expected_size, retry_count, last_error, metadata)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
             params![
                 task.id as i64,
                 &task.file_name,
@@ -122,6 +144,10 @@ impl DownloadTasksRepository {
                 task.created_at,
                 task.proxy.as_deref(),
                 &task.file_type,
+                task.expected_size as i64,
+                task.retry_count as i64,
+                task.last_error.as_deref(),
+                task.metadata.as_deref(),
             ],
         )
         .map_err(|e| format!("保存任务失败: {}", e))?;
@@ -139,7 +165,7 @@ impl DownloadTasksRepository {
             .map_err(|e| format!("获取数据库锁失败: {}", e))?;
 
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, url, save_path, total_size, status, created_at, proxy, file_type
+            "SELECT id, file_name, url, save_path, total_size, status, created_at, proxy, file_type, expected_size, retry_count, last_error, metadata
              FROM download_tasks
              ORDER BY id ASC"
         )
@@ -157,6 +183,10 @@ impl DownloadTasksRepository {
                     created_at: row.get(6)?,
                     proxy: row.get(7)?,
                     file_type: row.get(8)?,
+                    expected_size: row.get::<_, i64>(9)? as u64,
+                    retry_count: row.get::<_, i64>(10)? as u32,
+                    last_error: row.get(11)?,
+                    metadata: row.get(12)?,
                 })
             })
             .map_err(|e| format!("查询任务失败: {}", e))?;
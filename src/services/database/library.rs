@@ -0,0 +1,411 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 本地壁纸库索引数据库操作模块
+//!
+//! 记录壁纸库中每个文件的大小、修改时间、内容哈希、尺寸和缩略图路径，
+//! 重新扫描时只处理发生变化的文件。
+//!
+//! 图片的主色调按内容哈希保存在单独的表中（文件移动或重命名后不需要重新计算）
+
+use super::connection::DatabaseConnection;
+use rusqlite::params;
+use std::collections::HashMap;
+
+/// 壁纸库索引记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct LibraryEntry {
+    /// 文件路径（与扫描壁纸库目录得到的路径一致）
+    pub path: String,
+    /// 文件名
+    pub file_name: String,
+    /// 文件大小（字节）
+    pub file_size: u64,
+    /// 文件修改时间（Unix 时间戳，毫秒）
+    pub modified: i64,
+    /// 文件内容哈希（见 `LocalWallpaperService::calculate_file_hash`）
+    pub content_hash: String,
+    /// 图片宽度
+    pub width: u32,
+    /// 图片高度
+    pub height: u32,
+    /// 缩略图路径（图片无法解码时为空）
+    pub thumbnail_path: String,
+    /// 图片是否可以正常解码
+    pub valid: bool,
+    /// 按文件内容识别的图片格式（见 `ImageFormat::name`，旧版本索引的记录为空）
+    pub format: String,
+    /// 感知哈希（见 `duplicate_finder::perceptual_hash`，尚未计算时为 None）
+    pub perceptual_hash: Option<u64>,
+    /// 主色调（按占比从高到低，见 `color_palette::extract_palette`，尚未计算时为空）
+    pub palette: Vec<[u8; 3]>,
+}
+
+/// 壁纸库索引数据库仓库
+pub struct LibraryRepository {
+    db: DatabaseConnection,
+}
+
+impl std::fmt::Debug for LibraryRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LibraryRepository {{ database }}")
+    }
+}
+
+impl LibraryRepository {
+    /// 从数据库连接创建壁纸库索引仓库
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 使用全局数据库连接创建壁纸库索引仓库
+    ///
+    /// # 返回
+    /// 全局数据库未初始化时返回错误信息
+    pub fn global() -> Result<Self, String> {
+        super::DatabaseManager::try_get()
+            .map(|manager| Self::new(manager.connection().clone()))
+            .ok_or_else(|| "数据库未初始化".to_string())
+    }
+
+    /// 创建数据库表
+    ///
+    /// # 参数
+    /// - `db`: 数据库连接
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn create_tables(db: &DatabaseConnection) -> Result<(), String> {
+        let conn = db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS library (
+                path TEXT PRIMARY KEY,
+                file_name TEXT NOT NULL,
+                file_size INTEGER NOT NULL,
+                modified INTEGER NOT NULL,
+                content_hash TEXT NOT NULL DEFAULT '',
+                width INTEGER NOT NULL DEFAULT 0,
+                height INTEGER NOT NULL DEFAULT 0,
+                thumbnail_path TEXT NOT NULL DEFAULT '',
+                valid INTEGER NOT NULL DEFAULT 1,
+                perceptual_hash INTEGER,
+                format TEXT NOT NULL DEFAULT ''
+            )",
+            [],
+        )
+        .map_err(|e| format!("创建表失败: {}", e))?;
+
+        // 按文件大小和内容哈希查找重复文件
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_library_size_hash ON library (file_size, content_hash)",
+            [],
+        )
+        .map_err(|e| format!("创建索引失败: {}", e))?;
+
+        // 主色调（以逗号分隔的十六进制颜色，例如 `1a2b3c,ffffff`）
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS image_palettes (
+                content_hash TEXT PRIMARY KEY,
+                colors TEXT NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("创建表失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 保存索引记录（已存在的路径会被覆盖），同时保存已计算的主色调
+    ///
+    /// # 参数
+    /// - `entries`: 要保存的索引记录
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn save_entries(&self, entries: &[LibraryEntry]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT OR REPLACE INTO library
+                     (path, file_name, file_size, modified, content_hash, width, height, thumbnail_path, valid,
+                      perceptual_hash, format)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                )
+                .map_err(|e| format!("保存壁纸库索引失败: {}", e))?;
+
+            for entry in entries {
+                stmt.execute(params![
+                    entry.path,
+                    entry.file_name,
+                    entry.file_size as i64,
+                    entry.modified,
+                    entry.content_hash,
+                    entry.width,
+                    entry.height,
+                    entry.thumbnail_path,
+                    entry.valid,
+                    entry.perceptual_hash.map(|hash| hash as i64),
+                    entry.format,
+                ])
+                .map_err(|e| format!("保存壁纸库索引失败: {}", e))?;
+            }
+
+            let mut stmt = tx
+                .prepare("INSERT OR REPLACE INTO image_palettes (content_hash, colors) VALUES (?1, ?2)")
+                .map_err(|e| format!("保存主色调失败: {}", e))?;
+            for entry in entries
+                .iter()
+                .filter(|e| !e.content_hash.is_empty() && !e.palette.is_empty())
+            {
+                stmt.execute(params![entry.content_hash, encode_palette(&entry.palette)])
+                    .map_err(|e| format!("保存主色调失败: {}", e))?;
+            }
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 保存主色调
+    ///
+    /// # 参数
+    /// - `palettes`: 文件内容哈希和对应的主色调
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn save_palettes(&self, palettes: &[(String, Vec<[u8; 3]>)]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            let mut stmt = tx
+                .prepare("INSERT OR REPLACE INTO image_palettes (content_hash, colors) VALUES (?1, ?2)")
+                .map_err(|e| format!("保存主色调失败: {}", e))?;
+            for (content_hash, palette) in palettes {
+                stmt.execute(params![content_hash, encode_palette(palette)])
+                    .map_err(|e| format!("保存主色调失败: {}", e))?;
+            }
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 保存感知哈希
+    ///
+    /// # 参数
+    /// - `hashes`: 文件路径和对应的感知哈希
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn save_perceptual_hashes(&self, hashes: &[(String, u64)]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            let mut stmt = tx
+                .prepare("UPDATE library SET perceptual_hash = ?2 WHERE path = ?1")
+                .map_err(|e| format!("保存感知哈希失败: {}", e))?;
+            for (path, hash) in hashes {
+                stmt.execute(params![path, *hash as i64])
+                    .map_err(|e| format!("保存感知哈希失败: {}", e))?;
+            }
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 删除索引记录（不再被任何文件使用的主色调也会被删除）
+    ///
+    /// # 参数
+    /// - `paths`: 要删除的文件路径
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn delete_entries(&self, paths: &[String]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            let mut stmt = tx
+                .prepare("DELETE FROM library WHERE path = ?1")
+                .map_err(|e| format!("删除壁纸库索引失败: {}", e))?;
+            for path in paths {
+                stmt.execute(params![path])
+                    .map_err(|e| format!("删除壁纸库索引失败: {}", e))?;
+            }
+        }
+        tx.execute(
+            "DELETE FROM image_palettes WHERE content_hash NOT IN (SELECT content_hash FROM library)",
+            [],
+        )
+        .map_err(|e| format!("删除主色调失败: {}", e))?;
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 删除文件或目录（及其中所有文件）的索引记录（不再被任何文件使用的主色调也会被删除）
+    ///
+    /// # 参数
+    /// - `paths`: 已不存在的文件或目录路径
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn delete_entries_under(&self, paths: &[String]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            // 目录中的文件路径以 `目录/` 开头：按主键范围 [`目录/`, `目录` + 下一个字符) 查找
+            let mut stmt = tx
+                .prepare("DELETE FROM library WHERE path = ?1 OR (path >= ?2 AND path < ?3)")
+                .map_err(|e| format!("删除壁纸库索引失败: {}", e))?;
+            for path in paths {
+                let path = path.trim_end_matches(std::path::MAIN_SEPARATOR);
+                let lower = format!("{}{}", path, std::path::MAIN_SEPARATOR);
+                let upper = format!("{}{}", path, (std::path::MAIN_SEPARATOR as u8 + 1) as char);
+                stmt.execute(params![path, lower, upper])
+                    .map_err(|e| format!("删除壁纸库索引失败: {}", e))?;
+            }
+        }
+        tx.execute(
+            "DELETE FROM image_palettes WHERE content_hash NOT IN (SELECT content_hash FROM library)",
+            [],
+        )
+        .map_err(|e| format!("删除主色调失败: {}", e))?;
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 查找文件大小和内容哈希都相同的文件（按路径排序）
+    ///
+    /// # 参数
+    /// - `file_size`: 文件大小（字节）
+    /// - `content_hash`: 文件内容哈希
+    ///
+    /// # 返回
+    /// 返回匹配的文件路径
+    pub fn find_paths_by_content(&self, file_size: u64, content_hash: &str) -> Result<Vec<String>, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        let mut stmt = conn
+            .prepare("SELECT path FROM library WHERE file_size = ?1 AND content_hash = ?2 ORDER BY path")
+            .map_err(|e| format!("查询壁纸库索引失败: {}", e))?;
+        let rows = stmt
+            .query_map(params![file_size as i64, content_hash], |row| row.get::<_, String>(0))
+            .map_err(|e| format!("查询壁纸库索引失败: {}", e))?;
+
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+
+    /// 加载所有索引记录（按路径排序）
+    ///
+    /// # 返回
+    /// 返回所有索引记录
+    pub fn load_all(&self) -> Result<Vec<LibraryEntry>, String> {
+        self.load_entries("ORDER BY library.path")
+    }
+
+    /// 加载尚未计算主色调的有效图片（内容相同的图片只返回一条记录）
+    ///
+    /// # 返回
+    /// 返回缺少主色调的索引记录
+    pub fn load_missing_palettes(&self) -> Result<Vec<LibraryEntry>, String> {
+        self.load_entries(
+            "WHERE library.valid = 1 AND library.content_hash != ''
+               AND (image_palettes.colors IS NULL OR image_palettes.colors = '')
+             GROUP BY library.content_hash",
+        )
+    }
+
+    /// 加载已计算主色调的文件的主色调
+    ///
+    /// # 返回
+    /// 返回文件路径 -> 主色调
+    pub fn load_palettes(&self) -> Result<HashMap<String, Vec<[u8; 3]>>, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT library.path, image_palettes.colors
+                 FROM library
+                 JOIN image_palettes ON image_palettes.content_hash = library.content_hash",
+            )
+            .map_err(|e| format!("查询主色调失败: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, decode_palette(&row.get::<_, String>(1)?)))
+            })
+            .map_err(|e| format!("查询主色调失败: {}", e))?;
+
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+
+    /// 按查询条件加载索引记录
+    ///
+    /// # 参数
+    /// - `clause`: 拼接在查询语句后的 WHERE、GROUP BY 或 ORDER BY 子句（固定的 SQL，不包含用户输入）
+    fn load_entries(&self, clause: &str) -> Result<Vec<LibraryEntry>, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT library.path, library.file_name, library.file_size, library.modified, library.content_hash,
+                        library.width, library.height, library.thumbnail_path, library.valid, library.perceptual_hash,
+                        image_palettes.colors, library.format
+                 FROM library
+                 LEFT JOIN image_palettes ON image_palettes.content_hash = library.content_hash
+                 {}",
+                clause
+            ))
+            .map_err(|e| format!("查询壁纸库索引失败: {}", e))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(LibraryEntry {
+                    path: row.get(0)?,
+                    file_name: row.get(1)?,
+                    file_size: row.get::<_, i64>(2)? as u64,
+                    modified: row.get(3)?,
+                    content_hash: row.get(4)?,
+                    width: row.get(5)?,
+                    height: row.get(6)?,
+                    thumbnail_path: row.get(7)?,
+                    valid: row.get(8)?,
+                    format: row.get(11)?,
+                    perceptual_hash: row.get::<_, Option<i64>>(9)?.map(|hash| hash as u64),
+                    palette: row
+                        .get::<_, Option<String>>(10)?
+                        .map(|colors| decode_palette(&colors))
+                        .unwrap_or_default(),
+                })
+            })
+            .map_err(|e| format!("查询壁纸库索引失败: {}", e))?;
+
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+}
+
+/// 把主色调编码为以逗号分隔的十六进制颜色
+fn encode_palette(palette: &[[u8; 3]]) -> String {
+    palette
+        .iter()
+        .map(|[r, g, b]| format!("{:02x}{:02x}{:02x}", r, g, b))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 解析以逗号分隔的十六进制颜色（忽略无法解析的颜色）
+fn decode_palette(colors: &str) -> Vec<[u8; 3]> {
+    colors
+        .split(',')
+        .filter_map(|color| u32::from_str_radix(color.trim(), 16).ok())
+        .map(|value| [(value >> 16) as u8, (value >> 8) as u8, value as u8])
+        .collect()
+}
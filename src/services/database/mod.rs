@@ -7,10 +7,18 @@
 //! 使用单例模式管理数据库连接，避免多地方重复打开同一个文件
 
 pub mod connection;
+pub mod download_stats;
 pub mod download_tasks;
+pub mod library;
+pub mod trash;
+pub mod user_metadata;
 
 pub use connection::DatabaseConnection;
+pub use download_stats::{DailyDownloadStats, DownloadStatsRepository};
 pub use download_tasks::{DownloadTaskDB, DownloadTasksRepository};
+pub use library::{LibraryEntry, LibraryRepository};
+pub use trash::{TrashItem, TrashRepository};
+pub use user_metadata::{UserMetadata, UserMetadataRepository};
 
 use std::sync::OnceLock;
 
@@ -37,6 +45,10 @@ impl DatabaseManager {
         
         // 创建所有需要的表
         DownloadTasksRepository::create_tables(&connection)?;
+        DownloadStatsRepository::create_tables(&connection)?;
+        LibraryRepository::create_tables(&connection)?;
+        UserMetadataRepository::create_tables(&connection)?;
+        TrashRepository::create_tables(&connection)?;
         
         GLOBAL_DATABASE.get_or_init(|| DatabaseManager { connection });
         
@@ -53,6 +65,11 @@ impl DatabaseManager {
     pub fn get() -> &'static DatabaseManager {
         GLOBAL_DATABASE.get().expect("DatabaseManager 未初始化，请先调用 init()")
     }
+
+    /// 获取全局数据库管理器实例，未初始化（例如数据库打开失败）时返回 None
+    pub fn try_get() -> Option<&'static DatabaseManager> {
+        GLOBAL_DATABASE.get()
+    }
     
     /// 获取数据库连接
     pub fn connection(&self) -> &DatabaseConnection {
@@ -60,5 +77,38 @@ impl DatabaseManager {
     }
 }
 
+/// 如果表中不存在指定列，则添加该列（用于兼容旧版本数据库）
+///
+/// # 参数
+/// - `conn`: 已加锁的数据库连接
+/// - `table`: 表名
+/// - `column`: 列名
+/// - `definition`: 列定义（类型和约束）
+///
+/// # 返回
+/// 成功返回 Ok(())，失败返回错误信息
+pub(crate) fn add_column_if_missing(
+    conn: &rusqlite::Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| format!("查询表结构失败: {}", e))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("查询表结构失败: {}", e))?
+        .filter_map(|name| name.ok())
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition), [])
+            .map_err(|e| format!("添加列 {} 失败: {}", column, e))?;
+    }
+
+    Ok(())
+}
+
 /// 全局数据库管理器实例
 static GLOBAL_DATABASE: OnceLock<DatabaseManager> = OnceLock::new();
\ No newline at end of file
@@ -0,0 +1,195 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 回收站数据库操作模块
+//!
+//! 记录被删除（移动到回收站）的壁纸的原路径和在回收站中的位置，用于恢复和按保留天数清理
+
+use super::connection::DatabaseConnection;
+use rusqlite::params;
+
+/// 回收站中的壁纸
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrashItem {
+    /// 记录 ID（保存前为 0）
+    pub id: i64,
+    /// 原文件路径（与壁纸库索引中的路径一致）
+    pub original_path: String,
+    /// 回收站中保存该壁纸的目录（壁纸文件和附属文件以原文件名保存在其中）
+    pub trash_path: String,
+    /// 文件名
+    pub file_name: String,
+    /// 文件大小（字节）
+    pub file_size: u64,
+    /// 删除时间（Unix 时间戳，秒）
+    pub deleted_at: i64,
+}
+
+/// 回收站数据库仓库
+pub struct TrashRepository {
+    db: DatabaseConnection,
+}
+
+impl std::fmt::Debug for TrashRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TrashRepository {{ database }}")
+    }
+}
+
+impl TrashRepository {
+    /// 从数据库连接创建回收站仓库
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 使用全局数据库连接创建回收站仓库
+    ///
+    /// # 返回
+    /// 全局数据库未初始化时返回错误信息
+    pub fn global() -> Result<Self, String> {
+        super::DatabaseManager::try_get()
+            .map(|manager| Self::new(manager.connection().clone()))
+            .ok_or_else(|| "数据库未初始化".to_string())
+    }
+
+    /// 创建数据库表
+    ///
+    /// # 参数
+    /// - `db`: 数据库连接
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn create_tables(db: &DatabaseConnection) -> Result<(), String> {
+        let conn = db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS trash (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                original_path TEXT NOT NULL,
+                trash_path TEXT NOT NULL,
+                file_name TEXT NOT NULL,
+                file_size INTEGER NOT NULL DEFAULT 0,
+                deleted_at INTEGER NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("创建表失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 保存回收站记录
+    ///
+    /// # 参数
+    /// - `item`: 回收站记录（忽略其中的 ID）
+    ///
+    /// # 返回
+    /// 成功返回新记录的 ID，失败返回错误信息
+    pub fn insert(&self, item: &TrashItem) -> Result<i64, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO trash (original_path, trash_path, file_name, file_size, deleted_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                item.original_path,
+                item.trash_path,
+                item.file_name,
+                item.file_size as i64,
+                item.deleted_at,
+            ],
+        )
+        .map_err(|e| format!("保存回收站记录失败: {}", e))?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// 删除回收站记录
+    ///
+    /// # 参数
+    /// - `ids`: 要删除的记录 ID
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn delete(&self, ids: &[i64]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            let mut stmt = tx
+                .prepare("DELETE FROM trash WHERE id = ?1")
+                .map_err(|e| format!("删除回收站记录失败: {}", e))?;
+            for id in ids {
+                stmt.execute(params![id])
+                    .map_err(|e| format!("删除回收站记录失败: {}", e))?;
+            }
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 加载所有回收站记录（最近删除的排在最前面）
+    ///
+    /// # 返回
+    /// 返回所有回收站记录
+    pub fn load_all(&self) -> Result<Vec<TrashItem>, String> {
+        self.query(
+            "SELECT id, original_path, trash_path, file_name, file_size, deleted_at FROM trash
+             ORDER BY deleted_at DESC, id DESC",
+            params![],
+        )
+    }
+
+    /// 按 ID 加载回收站记录
+    ///
+    /// # 参数
+    /// - `ids`: 记录 ID（不存在的 ID 会被忽略）
+    ///
+    /// # 返回
+    /// 返回找到的回收站记录
+    pub fn load(&self, ids: &[i64]) -> Result<Vec<TrashItem>, String> {
+        let mut items = Vec::with_capacity(ids.len());
+        for id in ids {
+            items.extend(self.query(
+                "SELECT id, original_path, trash_path, file_name, file_size, deleted_at FROM trash WHERE id = ?1",
+                params![id],
+            )?);
+        }
+        Ok(items)
+    }
+
+    /// 加载在指定时间之前删除的回收站记录
+    ///
+    /// # 参数
+    /// - `before`: Unix 时间戳（秒）
+    ///
+    /// # 返回
+    /// 返回找到的回收站记录
+    pub fn load_deleted_before(&self, before: i64) -> Result<Vec<TrashItem>, String> {
+        self.query(
+            "SELECT id, original_path, trash_path, file_name, file_size, deleted_at FROM trash
+             WHERE deleted_at < ?1",
+            params![before],
+        )
+    }
+
+    fn query(&self, sql: &str, params: &[&dyn rusqlite::ToSql]) -> Result<Vec<TrashItem>, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        let mut stmt = conn.prepare(sql).map_err(|e| format!("查询回收站失败: {}", e))?;
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok(TrashItem {
+                    id: row.get(0)?,
+                    original_path: row.get(1)?,
+                    trash_path: row.get(2)?,
+                    file_name: row.get(3)?,
+                    file_size: row.get::<_, i64>(4)? as u64,
+                    deleted_at: row.get(5)?,
+                })
+            })
+            .map_err(|e| format!("查询回收站失败: {}", e))?;
+
+        Ok(rows.filter_map(|row| row.ok()).collect())
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 本地壁纸用户数据数据库操作模块
+//!
+//! 保存用户为本地壁纸设置的标签、收藏和评分（按文件路径，与壁纸库索引一致）
+
+use super::connection::DatabaseConnection;
+use rusqlite::params;
+use std::collections::HashMap;
+
+/// 评分的最大值（1–5 星，0 表示未评分）
+pub const MAX_RATING: u8 = 5;
+
+/// 本地壁纸的用户数据
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserMetadata {
+    /// 是否收藏
+    pub favorite: bool,
+    /// 评分（1–5 星，0 表示未评分）
+    pub rating: u8,
+    /// 标签（按名称排序）
+    pub tags: Vec<String>,
+}
+
+impl UserMetadata {
+    /// 是否包含指定标签（不区分大小写）
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// 本地壁纸用户数据数据库仓库
+pub struct UserMetadataRepository {
+    db: DatabaseConnection,
+}
+
+impl std::fmt::Debug for UserMetadataRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UserMetadataRepository {{ database }}")
+    }
+}
+
+impl UserMetadataRepository {
+    /// 从数据库连接创建用户数据仓库
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 使用全局数据库连接创建用户数据仓库
+    ///
+    /// # 返回
+    /// 全局数据库未初始化时返回错误信息
+    pub fn global() -> Result<Self, String> {
+        super::DatabaseManager::try_get()
+            .map(|manager| Self::new(manager.connection().clone()))
+            .ok_or_else(|| "数据库未初始化".to_string())
+    }
+
+    /// 创建数据库表
+    ///
+    /// # 参数
+    /// - `db`: 数据库连接
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn create_tables(db: &DatabaseConnection) -> Result<(), String> {
+        let conn = db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS user_metadata (
+                path TEXT PRIMARY KEY,
+                favorite INTEGER NOT NULL DEFAULT 0,
+                rating INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )
+        .map_err(|e| format!("创建表失败: {}", e))?;
+
+        // 同一张壁纸的标签不区分大小写
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS user_tags (
+                path TEXT NOT NULL,
+                tag TEXT NOT NULL COLLATE NOCASE,
+                PRIMARY KEY (path, tag)
+            )",
+            [],
+        )
+        .map_err(|e| format!("创建表失败: {}", e))?;
+
+        // 按标签查找壁纸
+        conn.execute("CREATE INDEX IF NOT EXISTS idx_user_tags_tag ON user_tags (tag)", [])
+            .map_err(|e| format!("创建索引失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 设置是否收藏
+    ///
+    /// # 参数
+    /// - `path`: 壁纸文件路径
+    /// - `favorite`: 是否收藏
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn set_favorite(&self, path: &str, favorite: bool) -> Result<(), String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO user_metadata (path, favorite) VALUES (?1, ?2)
+             ON CONFLICT(path) DO UPDATE SET favorite = excluded.favorite",
+            params![path, favorite],
+        )
+        .map_err(|e| format!("保存收藏失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 设置评分
+    ///
+    /// # 参数
+    /// - `path`: 壁纸文件路径
+    /// - `rating`: 评分（0 表示清除评分，超过 [`MAX_RATING`] 时按最大值保存）
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn set_rating(&self, path: &str, rating: u8) -> Result<(), String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO user_metadata (path, rating) VALUES (?1, ?2)
+             ON CONFLICT(path) DO UPDATE SET rating = excluded.rating",
+            params![path, rating.min(MAX_RATING)],
+        )
+        .map_err(|e| format!("保存评分失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 为多张壁纸添加标签（已有的标签会被忽略）
+    ///
+    /// # 参数
+    /// - `paths`: 壁纸文件路径
+    /// - `tags`: 要添加的标签
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn add_tags(&self, paths: &[String], tags: &[String]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        {
+            let mut stmt = tx
+                .prepare("INSERT OR IGNORE INTO user_tags (path, tag) VALUES (?1, ?2)")
+                .map_err(|e| format!("保存标签失败: {}", e))?;
+            for path in paths {
+                for tag in tags {
+                    stmt.execute(params![path, tag])
+                        .map_err(|e| format!("保存标签失败: {}", e))?;
+                }
+            }
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 删除壁纸的标签
+    ///
+    /// # 参数
+    /// - `path`: 壁纸文件路径
+    /// - `tag`: 要删除的标签（不区分大小写）
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn remove_tag(&self, path: &str, tag: &str) -> Result<(), String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+
+        conn.execute("DELETE FROM user_tags WHERE path = ?1 AND tag = ?2", params![path, tag])
+            .map_err(|e| format!("删除标签失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 删除壁纸的所有用户数据（标签、收藏和评分）
+    ///
+    /// # 参数
+    /// - `paths`: 壁纸文件路径
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn delete(&self, paths: &[String]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        for path in paths {
+            tx.execute("DELETE FROM user_metadata WHERE path = ?1", params![path])
+                .map_err(|e| format!("删除用户数据失败: {}", e))?;
+            tx.execute("DELETE FROM user_tags WHERE path = ?1", params![path])
+                .map_err(|e| format!("删除用户数据失败: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 把壁纸的用户数据（标签、收藏和评分）复制到新路径（移动或复制壁纸文件后使用）
+    ///
+    /// # 参数
+    /// - `pairs`: (原路径, 新路径)
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn copy_to(&self, pairs: &[(String, String)]) -> Result<(), String> {
+        let mut conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let tx = conn.transaction().map_err(|e| format!("开始事务失败: {}", e))?;
+
+        for (from, to) in pairs {
+            tx.execute(
+                "INSERT OR REPLACE INTO user_metadata (path, favorite, rating)
+                 SELECT ?2, favorite, rating FROM user_metadata WHERE path = ?1",
+                params![from, to],
+            )
+            .map_err(|e| format!("复制用户数据失败: {}", e))?;
+            tx.execute(
+                "INSERT OR IGNORE INTO user_tags (path, tag) SELECT ?2, tag FROM user_tags WHERE path = ?1",
+                params![from, to],
+            )
+            .map_err(|e| format!("复制用户数据失败: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("提交事务失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 加载所有壁纸的用户数据
+    ///
+    /// # 返回
+    /// 返回文件路径到用户数据的映射（只包含设置过标签、收藏或评分的壁纸）
+    pub fn load_all(&self) -> Result<HashMap<String, UserMetadata>, String> {
+        let conn = self.db.inner().lock().map_err(|e| format!("获取数据库锁失败: {}", e))?;
+        let mut result: HashMap<String, UserMetadata> = HashMap::new();
+
+        let mut stmt = conn
+            .prepare("SELECT path, favorite, rating FROM user_metadata WHERE favorite != 0 OR rating > 0")
+            .map_err(|e| format!("查询用户数据失败: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?, row.get::<_, u8>(2)?))
+            })
+            .map_err(|e| format!("查询用户数据失败: {}", e))?;
+        for (path, favorite, rating) in rows.filter_map(|row| row.ok()) {
+            let metadata = result.entry(path).or_default();
+            metadata.favorite = favorite;
+            metadata.rating = rating;
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT path, tag FROM user_tags ORDER BY path, tag")
+            .map_err(|e| format!("查询标签失败: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| format!("查询标签失败: {}", e))?;
+        for (path, tag) in rows.filter_map(|row| row.ok()) {
+            result.entry(path).or_default().tags.push(tag);
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 磁盘空间检查模块
+//!
+//! 开始下载前检查壁纸库和缓存目录所在磁盘的可用空间，以及目录是否超出配额
+//!
+//! 目录占用空间需要遍历所有文件，由 [`directory_usage`] 在后台线程中计算，检查时使用最近一次的结果
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use tracing::warn;
+
+/// 1 MB 的字节数（配置中的保留空间和配额以 MB 为单位）
+pub const BYTES_PER_MB: u64 = 1024 * 1024;
+
+/// 有下载任务时重新计算目录占用空间的间隔
+pub const DIRECTORY_USAGE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 需要检查空间的目录
+#[derive(Debug, Clone)]
+pub struct SpaceCheckTarget {
+    /// 目录路径（包括所有子目录）
+    pub path: String,
+    /// 目录配额（字节，0 表示不限制）
+    pub quota: u64,
+    /// 最近一次计算的目录占用空间（字节，None 表示还没有计算完成）
+    pub used: Option<u64>,
+}
+
+/// 空间不足的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpaceShortage {
+    /// 磁盘可用空间不足（目录, 可用字节数, 需要的字节数（含保留空间））
+    LowDiskSpace {
+        path: String,
+        available: u64,
+        required: u64,
+    },
+    /// 目录超出配额（目录, 已占用字节数, 配额字节数）
+    QuotaExceeded { path: String, used: u64, quota: u64 },
+}
+
+/// 检查各目录是否有足够空间保存指定大小的文件
+///
+/// # 参数
+/// - `targets`: 需要检查的目录（下载先写入缓存目录再复制到壁纸库，两个目录都需要检查）
+/// - `required`: 文件大小（字节）
+/// - `reserve`: 磁盘需要保留的最小可用空间（字节）
+///
+/// # 返回
+/// 空间足够返回 Ok(())，否则返回第一个不满足的原因；无法获取可用空间或目录占用空间还没有计算完成时视为空间足够
+pub fn check_space(targets: &[SpaceCheckTarget], required: u64, reserve: u64) -> Result<(), SpaceShortage> {
+    for target in targets {
+        if let Some(available) = available_space(Path::new(&target.path)) {
+            let needed = required.saturating_add(reserve);
+            if available < needed {
+                return Err(SpaceShortage::LowDiskSpace {
+                    path: target.path.clone(),
+                    available,
+                    required: needed,
+                });
+            }
+        }
+
+        let over_quota = |used: &u64| target.quota > 0 && used.saturating_add(required) > target.quota;
+        if let Some(used) = target.used.filter(over_quota) {
+            return Err(SpaceShortage::QuotaExceeded {
+                path: target.path.clone(),
+                used,
+                quota: target.quota,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// 获取路径所在磁盘的可用空间（字节），路径不存在时使用最近的已存在的上级目录
+pub fn available_space(path: &Path) -> Option<u64> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(path)
+    };
+    let existing = absolute.ancestors().find(|p| p.exists())?;
+
+    query_available_space(existing)
+}
+
+#[cfg(target_os = "windows")]
+fn query_available_space(path: &Path) -> Option<u64> {
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+    use windows::core::HSTRING;
+
+    let path = HSTRING::from(path);
+    let mut available: u64 = 0;
+    match unsafe { GetDiskFreeSpaceExW(&path, Some(&mut available as *mut u64), None, None) } {
+        Ok(()) => Some(available),
+        Err(e) => {
+            warn!("[磁盘空间] 获取可用空间失败: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn query_available_space(path: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        warn!("[磁盘空间] 获取可用空间失败: {}", std::io::Error::last_os_error());
+        return None;
+    }
+
+    // 非特权用户可用的块数 * 块大小
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+/// 计算各目录占用的空间（字节，需要遍历所有文件，应在后台线程中调用）
+///
+/// # 参数
+/// - `paths`: 目录路径
+///
+/// # 返回
+/// 目录路径 -> 占用字节数
+pub fn directory_usage(paths: &[String]) -> HashMap<String, u64> {
+    paths
+        .iter()
+        .map(|path| (path.clone(), directory_size(Path::new(path))))
+        .collect()
+}
+
+/// 递归计算目录中所有文件的总大小
+fn directory_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => directory_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
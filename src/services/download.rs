@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::request_context::RequestContext;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tracing::{debug, error, info, warn};
@@ -247,14 +248,11 @@ impl DownloadService {
         file_size: u64,
         cache_base_path: String,
         proxy: Option<String>,
-        cancel_token: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        context: RequestContext,
     ) -> Result<iced::widget::image::Handle, Box<dyn std::error::Error + Send + Sync>> {
         // 在下载前检查取消状态
-        if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
-            return Err(
-                Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                    as Box<dyn std::error::Error + Send + Sync>,
-            );
+        if let Some(e) = context.error() {
+            return Err(Box::new(e));
         }
 
         // 计算缓存路径
@@ -269,15 +267,7 @@ impl DownloadService {
         debug!("[缩略图缓存] [URL:{}] 缓存不存在，开始下载", url);
 
         // 下载并保存到缓存（带取消支持）
-        Self::download_thumb_to_cache_with_cancel(&url, &cache_path, proxy, cancel_token.clone()).await?;
-
-        // 再次检查取消状态（下载完成后）
-        if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
-            return Err(
-                Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                    as Box<dyn std::error::Error + Send + Sync>,
-            );
-        }
+        Self::download_thumb_to_cache_with_cancel(&url, &cache_path, proxy, &context).await?;
 
         // 返回缓存的图片Handle
         Ok(iced::widget::image::Handle::from_path(Path::new(&cache_path)))
@@ -288,10 +278,12 @@ impl DownloadService {
         url: &str,
         cache_path: &str,
         proxy: Option<String>,
-        cancel_token: std::sync::Arc<std::sync::atomic::AtomicBool>,
+        context: &RequestContext,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        // 获取并发控制许可
-        let _permit = crate::services::GLOBAL_CONCURRENCY_CONTROLLER.acquire().await;
+        // 获取并发控制许可（排队期间被取消时直接返回，不占用并发名额）
+        let _permit = context
+            .run_until_cancelled(crate::services::GLOBAL_CONCURRENCY_CONTROLLER.acquire())
+            .await?;
 
         debug!("[缩略图缓存] [URL:{}] 开始下载到: {}", url, cache_path);
 
@@ -312,20 +304,11 @@ impl DownloadService {
             false, // 使用 debug 级别
         );
 
-        // 直接下载图片（带取消支持，不使用重试机制）
+        // 直接下载图片（上下文被取消时立即停止，不使用重试机制）
         let bytes = {
             let client = client.clone();
             let url = url.to_string();
-            let cancel_token = cancel_token.clone();
             async move {
-                // 检查取消状态
-                if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
-                    return Err(
-                        Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                            as Box<dyn std::error::Error + Send + Sync>,
-                    );
-                }
-
                 let response = client.get(&url).send().await.map_err(|e| {
                     error!("[缩略图缓存] [URL:{}] 请求失败: {}", url, e);
                     Box::new(e) as Box<dyn std::error::Error + Send + Sync>
@@ -342,20 +325,12 @@ impl DownloadService {
                         as Box<dyn std::error::Error + Send + Sync>);
                 }
 
-                // 流式读取数据，定期检查取消状态
+                // 流式读取数据
                 use iced::futures::StreamExt;
                 let mut stream = response.bytes_stream();
                 let mut buffer = Vec::with_capacity(1024 * 1024); // 1MB缓冲区
 
                 while let Some(chunk_result) = stream.next().await {
-                    // 检查取消状态
-                    if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
-                        return Err(
-                            Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                                as Box<dyn std::error::Error + Send + Sync>,
-                        );
-                    }
-
                     let chunk = chunk_result.map_err(|e| {
                         error!("[缩略图缓存] [URL:{}] 读取数据流失败: {}", url, e);
                         Box::new(e) as Box<dyn std::error::Error + Send + Sync>
@@ -364,18 +339,10 @@ impl DownloadService {
                     buffer.extend_from_slice(&chunk);
                 }
 
-                Ok(buffer)
+                Ok::<_, Box<dyn std::error::Error + Send + Sync>>(buffer)
             }
-        }
-        .await?;
-
-        // 再次检查取消状态（下载完成后）
-        if cancel_token.load(std::sync::atomic::Ordering::Relaxed) {
-            return Err(
-                Box::new(std::io::Error::new(std::io::ErrorKind::Interrupted, "下载已取消"))
-                    as Box<dyn std::error::Error + Send + Sync>,
-            );
-        }
+        };
+        let bytes = context.run_until_cancelled(bytes).await??;
 
         debug!("[缩略图缓存] [URL:{}] 下载成功，数据大小: {} bytes", url, bytes.len());
 
@@ -0,0 +1,158 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 下载进度注册表模块
+//!
+//! 每个下载任务对应一个 `watch` channel，只保留最新的进度，不会因为界面处理不及时而丢失更新；
+//! 界面按固定帧率取出有变化的任务进度，合并为一条消息处理
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tokio::sync::{Notify, watch};
+
+/// 界面合并进度更新的时间间隔
+pub const PROGRESS_FRAME_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 下载进度更新
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DownloadProgressUpdate {
+    pub task_id: usize,
+    pub downloaded: u64,
+    pub total: u64,
+    pub speed: u64,
+}
+
+/// 下载进度注册表
+#[derive(Default)]
+pub struct DownloadProgressRegistry {
+    /// 任务ID -> 该任务的进度发送器
+    senders: Mutex<HashMap<usize, watch::Sender<DownloadProgressUpdate>>>,
+    /// 有任务进度更新时通知界面
+    updated: Notify,
+}
+
+/// 全局下载进度注册表
+pub static DOWNLOAD_PROGRESS: LazyLock<DownloadProgressRegistry> = LazyLock::new(DownloadProgressRegistry::default);
+
+impl DownloadProgressRegistry {
+    /// 报告任务的最新进度（覆盖尚未被界面取出的旧进度）
+    pub fn report(&self, task_id: usize, downloaded: u64, total: u64, speed: u64) {
+        let update = DownloadProgressUpdate {
+            task_id,
+            downloaded,
+            total,
+            speed,
+        };
+
+        if let Ok(mut senders) = self.senders.lock() {
+            match senders.get(&task_id) {
+                Some(sender) => {
+                    sender.send_replace(update);
+                }
+                None => {
+                    senders.insert(task_id, watch::Sender::new(update));
+                }
+            }
+        }
+
+        self.updated.notify_one();
+    }
+
+    /// 移除任务的进度 channel（任务结束后调用）
+    pub fn unregister(&self, task_id: usize) {
+        if let Ok(mut senders) = self.senders.lock() {
+            senders.remove(&task_id);
+        }
+
+        // 让界面尽快清理该任务的接收器
+        self.updated.notify_one();
+    }
+
+    /// 等待任意任务报告新的进度
+    pub async fn wait_for_update(&self) {
+        self.updated.notified().await;
+    }
+
+    /// 取出自上次调用后有变化的任务进度
+    ///
+    /// # 参数
+    /// - `receivers`: 调用方持有的接收器，新任务的接收器会自动加入，已移除任务的接收器会被清理
+    ///
+    /// # 返回
+    /// 返回有变化的任务进度列表
+    pub fn collect_updates(
+        &self,
+        receivers: &mut HashMap<usize, watch::Receiver<DownloadProgressUpdate>>,
+    ) -> Vec<DownloadProgressUpdate> {
+        let Ok(senders) = self.senders.lock() else {
+            return Vec::new();
+        };
+
+        receivers.retain(|task_id, _| senders.contains_key(task_id));
+
+        let mut updates = Vec::new();
+        for (task_id, sender) in senders.iter() {
+            match receivers.get_mut(task_id) {
+                // 任务移除后又以相同ID重新注册（暂停后继续、重试）时，旧接收器的 channel 已关闭，需要重新订阅
+                Some(receiver) if receiver.has_changed().is_ok() && receiver.same_channel(&sender.subscribe()) => {
+                    if receiver.has_changed().unwrap_or(false) {
+                        updates.push(*receiver.borrow_and_update());
+                    }
+                }
+                _ => {
+                    // 新注册或重新注册的任务，当前进度直接作为更新
+                    let receiver = sender.subscribe();
+                    updates.push(*receiver.borrow());
+                    receivers.insert(*task_id, receiver);
+                }
+            }
+        }
+
+        updates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_only_changed_tasks() {
+        let registry = DownloadProgressRegistry::default();
+        let mut receivers = HashMap::new();
+        registry.report(1, 10, 100, 5);
+        registry.report(2, 20, 100, 5);
+        assert_eq!(registry.collect_updates(&mut receivers).len(), 2);
+        assert!(registry.collect_updates(&mut receivers).is_empty());
+
+        registry.report(2, 30, 100, 5);
+        registry.report(2, 40, 100, 5);
+        let updates = registry.collect_updates(&mut receivers);
+        assert_eq!(updates.len(), 1);
+        assert_eq!((updates[0].task_id, updates[0].downloaded), (2, 40));
+    }
+
+    #[test]
+    fn reregistered_task_keeps_updating() {
+        let registry = DownloadProgressRegistry::default();
+        let mut receivers = HashMap::new();
+        registry.report(1, 10, 100, 5);
+        registry.collect_updates(&mut receivers);
+
+        // 暂停后继续：移除后在下次取出进度之前以相同ID重新注册
+        registry.unregister(1);
+        registry.report(1, 50, 100, 5);
+        let updates = registry.collect_updates(&mut receivers);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].downloaded, 50);
+
+        registry.report(1, 60, 100, 5);
+        let updates = registry.collect_updates(&mut receivers);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].downloaded, 60);
+
+        registry.unregister(1);
+        assert!(registry.collect_updates(&mut receivers).is_empty());
+        assert!(receivers.is_empty());
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 下载失败自动重试策略模块
+//!
+//! 根据错误信息判断失败是否为临时性错误，并计算指数退避的等待时间
+
+use crate::utils::config::DownloadConfig;
+use std::time::Duration;
+
+/// 下载失败自动重试策略
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// 是否启用自动重试
+    pub enabled: bool,
+    /// 最大重试次数
+    pub max_attempts: u32,
+    /// 首次重试的等待时间
+    pub base_delay: Duration,
+    /// 等待时间上限
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// 从下载配置创建重试策略
+    pub fn from_config(config: &DownloadConfig) -> Self {
+        Self {
+            enabled: config.auto_retry_enabled,
+            max_attempts: config.retry_max_attempts,
+            base_delay: Duration::from_secs(config.retry_base_delay_secs.max(1) as u64),
+            max_delay: Duration::from_secs(config.retry_max_delay_secs.max(config.retry_base_delay_secs) as u64),
+        }
+    }
+
+    /// 判断任务是否应该自动重试
+    ///
+    /// # 参数
+    /// - `attempts`: 已经自动重试过的次数
+    /// - `error`: 本次失败的错误信息
+    pub fn should_retry(&self, attempts: u32, error: &str) -> bool {
+        self.enabled && attempts < self.max_attempts && is_transient_error(error)
+    }
+
+    /// 计算第 attempt 次重试前的等待时间（从1开始计数，每次翻倍，不超过上限）
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        self.base_delay
+            .checked_mul(1u32 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
+/// 判断下载错误是否为临时性错误（超时、连接重置、5xx 等）
+///
+/// 404 等客户端错误、文件系统错误以及文件损坏均不视为临时性错误
+pub fn is_transient_error(error: &str) -> bool {
+    // HTTP 状态码错误：仅 408、429 和 5xx 可重试
+    if let Some(status) = error.strip_prefix("HTTP错误: ") {
+        return status
+            .split_whitespace()
+            .next()
+            .and_then(|code| code.parse::<u16>().ok())
+            .map(|code| code == 408 || code == 429 || (500..600).contains(&code))
+            .unwrap_or(false);
+    }
+
+    // 请求发送失败、数据流中断、下载不完整均属于网络问题
+    const TRANSIENT_PREFIXES: [&str; 4] = ["请求失败", "HEAD请求失败", "读取数据流失败", "文件大小不匹配"];
+    if TRANSIENT_PREFIXES.iter().any(|prefix| error.starts_with(prefix)) {
+        return true;
+    }
+
+    let lower = error.to_lowercase();
+    [
+        "timed out",
+        "timeout",
+        "connection reset",
+        "connection refused",
+        "connection closed",
+        "broken pipe",
+    ]
+    .iter()
+    .any(|keyword| lower.contains(keyword))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_status_errors() {
+        assert!(is_transient_error("HTTP错误: 503 Service Unavailable"));
+        assert!(is_transient_error("HTTP错误: 429 Too Many Requests"));
+        assert!(is_transient_error("HTTP错误: 408"));
+        assert!(!is_transient_error("HTTP错误: 404 Not Found"));
+        assert!(!is_transient_error("HTTP错误: 403 Forbidden"));
+        assert!(!is_transient_error("HTTP错误: unknown"));
+    }
+
+    #[test]
+    fn network_errors() {
+        assert!(is_transient_error("请求失败: error sending request"));
+        assert!(is_transient_error("读取数据流失败: unexpected EOF"));
+        assert!(is_transient_error("文件大小不匹配: 期望 100, 实际 50"));
+        assert!(is_transient_error("operation Timed Out"));
+        assert!(is_transient_error("Connection reset by peer"));
+    }
+
+    #[test]
+    fn permanent_errors() {
+        assert!(!is_transient_error("创建文件失败: Permission denied"));
+        assert!(!is_transient_error("图片已损坏"));
+        assert!(!is_transient_error(""));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay() {
+        let policy = RetryPolicy {
+            enabled: true,
+            max_attempts: 3,
+            base_delay: Duration::from_secs(5),
+            max_delay: Duration::from_secs(60),
+        };
+        assert_eq!(policy.backoff_delay(1), Duration::from_secs(5));
+        assert_eq!(policy.backoff_delay(2), Duration::from_secs(10));
+        assert_eq!(policy.backoff_delay(4), Duration::from_secs(40));
+        assert_eq!(policy.backoff_delay(5), Duration::from_secs(60));
+        assert_eq!(policy.backoff_delay(100), Duration::from_secs(60));
+        assert!(policy.should_retry(2, "HTTP错误: 502"));
+        assert!(!policy.should_retry(3, "HTTP错误: 502"));
+        assert!(!policy.should_retry(0, "HTTP错误: 404"));
+    }
+}
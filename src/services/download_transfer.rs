@@ -0,0 +1,349 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 下载任务导出/导入模块
+//!
+//! 将下载任务列表导出为 JSON 或 CSV 文件，并在其他设备上导入重建任务
+
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// CSV 文件的列名（与 [`TaskTransferRecord`] 的字段一一对应）
+const CSV_HEADER: [&str; 10] = [
+    "wallhaven_id",
+    "url",
+    "file_name",
+    "save_path",
+    "relative_path",
+    "status",
+    "total_size",
+    "expected_size",
+    "file_type",
+    "created_at",
+];
+
+/// 导出文件中的一条下载任务记录
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskTransferRecord {
+    /// Wallhaven 壁纸ID（从下载URL中提取，非 Wallhaven 任务为空）
+    #[serde(default)]
+    pub wallhaven_id: String,
+    /// 下载URL
+    pub url: String,
+    /// 文件名称
+    #[serde(default)]
+    pub file_name: String,
+    /// 导出时的保存路径
+    #[serde(default)]
+    pub save_path: String,
+    /// 相对于壁纸库目录的路径（保存路径不在壁纸库目录下时为空）
+    #[serde(default)]
+    pub relative_path: String,
+    /// 任务状态（与数据库中的状态字符串一致）
+    #[serde(default)]
+    pub status: String,
+    /// 文件总大小（字节）
+    #[serde(default)]
+    pub total_size: u64,
+    /// Wallhaven 提供的文件大小（字节，0表示未知）
+    #[serde(default)]
+    pub expected_size: u64,
+    /// 原始文件类型
+    #[serde(default)]
+    pub file_type: String,
+    /// 任务创建时间（RFC 3339 格式）
+    #[serde(default)]
+    pub created_at: String,
+}
+
+impl TaskTransferRecord {
+    /// 将记录的保存路径映射到本机的壁纸库目录
+    ///
+    /// 优先使用相对路径（保留文件名模板生成的子目录），否则只保留文件名。
+    /// 包含 `..` 或绝对路径的相对路径会被忽略，避免写到壁纸库目录之外
+    pub fn local_save_path(&self, data_path: &str) -> Option<PathBuf> {
+        let relative = Path::new(&self.relative_path);
+        let is_safe_relative = !self.relative_path.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if is_safe_relative {
+            return Some(Path::new(data_path).join(relative));
+        }
+
+        let file_name = if self.file_name.is_empty() {
+            // 兼容 Windows 和 Unix 的路径分隔符
+            self.save_path.rsplit(['/', '\\']).next().unwrap_or_default()
+        } else {
+            self.file_name.as_str()
+        };
+        let file_name = Path::new(file_name).file_name()?;
+        Some(Path::new(data_path).join(file_name))
+    }
+}
+
+/// 导出文件格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransferFormat {
+    Json,
+    Csv,
+}
+
+impl TransferFormat {
+    /// 根据文件扩展名判断格式（不区分大小写，未知扩展名按 JSON 处理）
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => TransferFormat::Csv,
+            _ => TransferFormat::Json,
+        }
+    }
+}
+
+/// 将下载任务记录写入文件（格式由扩展名决定），返回导出的记录数
+pub fn export_tasks(records: &[TaskTransferRecord], path: &Path) -> Result<usize, String> {
+    let content = match TransferFormat::from_path(path) {
+        TransferFormat::Json => {
+            serde_json::to_string_pretty(records).map_err(|e| format!("序列化下载任务失败: {}", e))?
+        }
+        TransferFormat::Csv => records_to_csv(records),
+    };
+
+    std::fs::write(path, content).map_err(|e| format!("写入文件失败: {}", e))?;
+    Ok(records.len())
+}
+
+/// 从文件读取下载任务记录（格式由扩展名决定）
+pub fn import_tasks(path: &Path) -> Result<Vec<TaskTransferRecord>, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("读取文件失败: {}", e))?;
+    // 去掉部分编辑器保存 CSV 时添加的 BOM
+    let content = content.trim_start_matches('\u{FEFF}');
+
+    let records: Vec<TaskTransferRecord> = match TransferFormat::from_path(path) {
+        TransferFormat::Json => serde_json::from_str(content).map_err(|e| format!("解析JSON失败: {}", e))?,
+        TransferFormat::Csv => records_from_csv(content)?,
+    };
+
+    Ok(records.into_iter().filter(|record| !record.url.is_empty()).collect())
+}
+
+/// 将记录转换为 CSV 文本（第一行为列名）
+fn records_to_csv(records: &[TaskTransferRecord]) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+
+    for record in records {
+        let fields = [
+            record.wallhaven_id.clone(),
+            record.url.clone(),
+            record.file_name.clone(),
+            record.save_path.clone(),
+            record.relative_path.clone(),
+            record.status.clone(),
+            record.total_size.to_string(),
+            record.expected_size.to_string(),
+            record.file_type.clone(),
+            record.created_at.clone(),
+        ];
+        let line: Vec<String> = fields.iter().map(|field| escape_csv_field(field)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// 解析 CSV 文本（按第一行的列名匹配字段，未知列会被忽略）
+fn records_from_csv(content: &str) -> Result<Vec<TaskTransferRecord>, String> {
+    let mut rows = parse_csv(content).into_iter();
+    let header = rows.next().ok_or_else(|| "CSV文件为空".to_string())?;
+    if !header.iter().any(|column| column == "url") {
+        return Err("CSV文件缺少 url 列".to_string());
+    }
+
+    let records = rows
+        .filter(|row| row.iter().any(|field| !field.is_empty()))
+        .map(|row| {
+            let mut record = TaskTransferRecord::default();
+            for (column, value) in header.iter().zip(row) {
+                match column.as_str() {
+                    "wallhaven_id" => record.wallhaven_id = value,
+                    "url" => record.url = value,
+                    "file_name" => record.file_name = value,
+                    "save_path" => record.save_path = value,
+                    "relative_path" => record.relative_path = value,
+                    "status" => record.status = value,
+                    "total_size" => record.total_size = value.parse().unwrap_or(0),
+                    "expected_size" => record.expected_size = value.parse().unwrap_or(0),
+                    "file_type" => record.file_type = value,
+                    "created_at" => record.created_at = value,
+                    _ => {}
+                }
+            }
+            record
+        })
+        .collect();
+
+    Ok(records)
+}
+
+/// 转义 CSV 字段（包含逗号、引号或换行时用引号包裹）
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 解析 CSV 文本为行列表（支持引号包裹的字段和字段内的换行）
+fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_round_trip() {
+        let records = vec![
+            TaskTransferRecord {
+                wallhaven_id: "8586my".to_string(),
+                url: "https://w.wallhaven.cc/full/85/wallhaven-8586my.jpg".to_string(),
+                file_name: "a, \"quoted\"\nname.jpg".to_string(),
+                save_path: "C:\\Wallpapers\\wallhaven-8586my.jpg".to_string(),
+                status: "completed".to_string(),
+                total_size: 1024,
+                expected_size: 2048,
+                ..Default::default()
+            },
+            TaskTransferRecord {
+                url: "https://example.com/b.png".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let parsed = records_from_csv(&records_to_csv(&records)).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].wallhaven_id, "8586my");
+        assert_eq!(parsed[0].file_name, records[0].file_name);
+        assert_eq!(parsed[0].save_path, records[0].save_path);
+        assert_eq!(parsed[0].total_size, 1024);
+        assert_eq!(parsed[0].expected_size, 2048);
+        assert_eq!(parsed[1].url, "https://example.com/b.png");
+        assert!(parsed[1].file_name.is_empty());
+    }
+
+    #[test]
+    fn csv_columns_by_header() {
+        let content =
+            "status,extra,url\r\ncompleted,x,https://example.com/a.jpg\r\n,,\r\nqueued,y,\"https://example.com/b.jpg\"";
+        let records = records_from_csv(content).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].status, "completed");
+        assert_eq!(records[0].url, "https://example.com/a.jpg");
+        assert_eq!(records[1].status, "queued");
+        assert_eq!(records[1].url, "https://example.com/b.jpg");
+    }
+
+    #[test]
+    fn csv_requires_url_column() {
+        assert!(records_from_csv("").is_err());
+        assert!(records_from_csv("wallhaven_id,status\n8586my,completed\n").is_err());
+    }
+
+    #[test]
+    fn parse_quoted_fields() {
+        assert_eq!(
+            parse_csv("a,\"b,c\",\"d\"\"e\"\n\"f\ng\",\n"),
+            vec![vec!["a", "b,c", "d\"e"], vec!["f\ng", ""]]
+        );
+    }
+
+    #[test]
+    fn local_save_path_stays_in_data_path() {
+        let mut record = TaskTransferRecord {
+            relative_path: "anime/wallhaven-8586my.jpg".to_string(),
+            save_path: "C:\\Old\\wallhaven-8586my.jpg".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            record.local_save_path("data"),
+            Some(Path::new("data").join("anime/wallhaven-8586my.jpg"))
+        );
+
+        record.relative_path = "../outside.jpg".to_string();
+        assert_eq!(
+            record.local_save_path("data"),
+            Some(Path::new("data").join("wallhaven-8586my.jpg"))
+        );
+
+        record.file_name = "../../evil.jpg".to_string();
+        assert_eq!(record.local_save_path("data"), Some(Path::new("data").join("evil.jpg")));
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(TransferFormat::from_path(Path::new("tasks.CSV")), TransferFormat::Csv);
+        assert_eq!(TransferFormat::from_path(Path::new("tasks.json")), TransferFormat::Json);
+        assert_eq!(TransferFormat::from_path(Path::new("tasks")), TransferFormat::Json);
+    }
+
+    #[test]
+    fn export_returns_record_count() {
+        let records = vec![
+            TaskTransferRecord {
+                url: "https://example.com/a.jpg".to_string(),
+                ..Default::default()
+            },
+            TaskTransferRecord {
+                url: "https://example.com/b.png".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        for extension in ["json", "csv"] {
+            let path = std::env::temp_dir().join(format!("wallwarp-tasks-{}.{}", std::process::id(), extension));
+            assert_eq!(export_tasks(&records, &path), Ok(2));
+            let imported = import_tasks(&path).unwrap();
+            let _ = std::fs::remove_file(&path);
+            assert_eq!(imported.len(), 2);
+            assert_eq!(imported[1].url, "https://example.com/b.png");
+        }
+    }
+}
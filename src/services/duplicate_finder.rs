@@ -0,0 +1,221 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 相似图片查找模块
+//!
+//! 使用差值哈希（dHash）计算图片的 64 位感知哈希，同一图片的不同分辨率或重新压缩的版本
+//! 感知哈希基本相同，按汉明距离把相似的图片分为一组
+
+use crate::services::database::{LibraryEntry, LibraryRepository};
+use crate::services::{image_format, library};
+use crate::utils::config::LibraryRoot;
+use image::imageops::FilterType;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::{info, warn};
+
+/// 计算感知哈希时缩小后的图片宽度（每行相邻像素比较得到 8 位）
+const HASH_WIDTH: u32 = 9;
+/// 计算感知哈希时缩小后的图片高度
+const HASH_HEIGHT: u32 = 8;
+
+/// 计算图片的感知哈希（差值哈希）
+///
+/// 把原图缩小为 9x8 的灰度图，每个像素与右侧像素比较亮度得到一位
+pub fn perceptual_hash(path: &Path) -> Result<u64, String> {
+    let img = image_format::open_image(path).map_err(|e| format!("打开图片失败: {}", e))?;
+    let gray = img
+        .resize_exact(HASH_WIDTH, HASH_HEIGHT, FilterType::Triangle)
+        .into_luma8();
+
+    let mut hash = 0u64;
+    for y in 0..HASH_HEIGHT {
+        for x in 0..HASH_WIDTH - 1 {
+            let left = gray.get_pixel(x, y)[0];
+            let right = gray.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | u64::from(left > right);
+        }
+    }
+    Ok(hash)
+}
+
+/// 两个感知哈希的汉明距离（不同的位数）
+pub fn hamming_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+/// 查找壁纸库中的相似图片
+///
+/// 先补齐索引中缺少的感知哈希，再把汉明距离不超过 `threshold` 的图片分为一组
+/// （A 与 B 相似、B 与 C 相似时三者在同一组）
+///
+/// # 返回
+/// 每组按保留优先级排序（分辨率最高、文件最大的在最前面），只返回至少包含两张图片的组
+pub fn find_similar_groups(roots: &[LibraryRoot], threshold: u32) -> Result<Vec<Vec<LibraryEntry>>, String> {
+    let start = std::time::Instant::now();
+    let mut entries: Vec<LibraryEntry> = library::load_index(roots)?
+        .into_iter()
+        .filter(|entry| entry.valid)
+        .collect();
+
+    fill_missing_hashes(&mut entries)?;
+    let entries: Vec<LibraryEntry> = entries
+        .into_iter()
+        .filter(|entry| entry.perceptual_hash.is_some())
+        .collect();
+
+    // 两两比较（64 位异或和计数，5 万张图片也只需要很短时间）
+    let hashes: Vec<u64> = entries.iter().filter_map(|entry| entry.perceptual_hash).collect();
+    let similar_pairs: Vec<(usize, usize)> = (0..hashes.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let hashes = &hashes;
+            (i + 1..hashes.len())
+                .filter(move |&j| hamming_distance(hashes[i], hashes[j]) <= threshold)
+                .map(move |j| (i, j))
+        })
+        .collect();
+
+    let mut groups = DisjointSet::new(entries.len());
+    for (i, j) in similar_pairs {
+        groups.union(i, j);
+    }
+
+    let mut grouped: Vec<Vec<LibraryEntry>> = vec![Vec::new(); entries.len()];
+    for (i, entry) in entries.into_iter().enumerate() {
+        let root = groups.find(i);
+        grouped[root].push(entry);
+    }
+
+    let mut result: Vec<Vec<LibraryEntry>> = grouped.into_iter().filter(|group| group.len() > 1).collect();
+    for group in &mut result {
+        group.sort_by(|a, b| {
+            let pixels_a = a.width as u64 * a.height as u64;
+            let pixels_b = b.width as u64 * b.height as u64;
+            pixels_b
+                .cmp(&pixels_a)
+                .then(b.file_size.cmp(&a.file_size))
+                .then(a.path.cmp(&b.path))
+        });
+    }
+    result.sort_by(|a, b| a[0].path.cmp(&b[0].path));
+
+    info!(
+        "[相似图片] 找到 {} 组相似图片（阈值 {}），耗时 {:?}",
+        result.len(),
+        threshold,
+        start.elapsed()
+    );
+    Ok(result)
+}
+
+/// 计算缺少感知哈希的图片（旧版本建立的索引），并保存到数据库
+fn fill_missing_hashes(entries: &mut [LibraryEntry]) -> Result<(), String> {
+    let missing: Vec<(String, u64)> = entries
+        .par_iter()
+        .filter(|entry| entry.perceptual_hash.is_none())
+        .filter_map(|entry| match perceptual_hash(Path::new(&entry.path)) {
+            Ok(hash) => Some((entry.path.clone(), hash)),
+            Err(e) => {
+                warn!("[相似图片] 计算感知哈希失败: {}, 错误: {}", entry.path, e);
+                None
+            }
+        })
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    LibraryRepository::global()?.save_perceptual_hashes(&missing)?;
+    let missing: HashMap<String, u64> = missing.into_iter().collect();
+    for entry in entries.iter_mut() {
+        if let Some(hash) = missing.get(&entry.path) {
+            entry.perceptual_hash = Some(*hash);
+        }
+    }
+    Ok(())
+}
+
+/// 并查集（用于把相似的图片合并为一组）
+struct DisjointSet {
+    parent: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // 路径压缩
+        let mut current = i;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a != root_b {
+            self.parent[root_b] = root_a;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming_distance_counts_different_bits() {
+        assert_eq!(hamming_distance(0, 0), 0);
+        assert_eq!(hamming_distance(0b1011, 0b0001), 2);
+        assert_eq!(hamming_distance(0, u64::MAX), 64);
+    }
+
+    #[test]
+    fn disjoint_set_merges_transitively() {
+        let mut groups = DisjointSet::new(5);
+        groups.union(0, 1);
+        groups.union(3, 1);
+        groups.union(2, 2);
+        assert_eq!(groups.find(0), groups.find(3));
+        assert_ne!(groups.find(0), groups.find(2));
+        assert_ne!(groups.find(2), groups.find(4));
+
+        groups.union(4, 2);
+        groups.union(2, 0);
+        assert!((1..5).all(|i| groups.find(i) == groups.find(0)));
+    }
+
+    #[test]
+    fn resized_image_has_similar_hash() {
+        let gradient = |width: u32, height: u32| {
+            image::RgbImage::from_fn(width, height, |x, y| {
+                let value = ((x * 255 / width) ^ (y * 255 / height)) as u8;
+                image::Rgb([value, value / 2, 255 - value])
+            })
+        };
+        let dir = std::env::temp_dir();
+        let large = dir.join(format!("wallwarp-phash-large-{}.png", std::process::id()));
+        let small = dir.join(format!("wallwarp-phash-small-{}.png", std::process::id()));
+        gradient(640, 360).save(&large).unwrap();
+        gradient(160, 90).save(&small).unwrap();
+
+        let distance = hamming_distance(perceptual_hash(&large).unwrap(), perceptual_hash(&small).unwrap());
+        let _ = std::fs::remove_file(&large);
+        let _ = std::fs::remove_file(&small);
+        assert!(distance <= 4, "distance: {}", distance);
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 钩子命令模块
+//!
+//! 在下载完成或设置壁纸后执行用户配置的命令（如调色、同步到 NAS 等后处理）。
+//!
+//! 命令通过系统 shell 执行（Windows 为 `cmd /C`，其他平台为 `sh -c`），壁纸信息通过以下方式传递：
+//! - 环境变量：`WALLWARP_EVENT`、`WALLWARP_FILE_PATH`、`WALLWARP_WALLHAVEN_ID`、`WALLWARP_RESOLUTION`、`WALLWARP_SOURCE`
+//! - 命令中的占位符：`{path}`、`{id}`、`{resolution}`、`{source}`（替换为带引号的环境变量引用，
+//!   shell 展开变量后不会再解释值中的特殊字符）
+
+use crate::services::{image_format, sidecar, wallhaven};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// 输出日志中保留的最大字符数，避免命令输出过多时刷屏
+const MAX_LOGGED_OUTPUT_CHARS: usize = 4000;
+
+/// 传递壁纸信息的环境变量
+const ENV_EVENT: &str = "WALLWARP_EVENT";
+const ENV_FILE_PATH: &str = "WALLWARP_FILE_PATH";
+const ENV_WALLHAVEN_ID: &str = "WALLWARP_WALLHAVEN_ID";
+const ENV_RESOLUTION: &str = "WALLWARP_RESOLUTION";
+const ENV_SOURCE: &str = "WALLWARP_SOURCE";
+
+/// 触发钩子的事件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookEvent {
+    /// 下载完成
+    Download,
+    /// 设置壁纸
+    SetWallpaper,
+}
+
+impl HookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookEvent::Download => "download",
+            HookEvent::SetWallpaper => "set-wallpaper",
+        }
+    }
+}
+
+/// 壁纸的来源（触发钩子的操作）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WallpaperSource {
+    /// 下载页面的下载任务
+    Download,
+    /// 用户在界面中手动设置
+    Manual,
+    /// 托盘菜单切换
+    Tray,
+    /// 定时切换
+    AutoChange,
+}
+
+impl WallpaperSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WallpaperSource::Download => "download",
+            WallpaperSource::Manual => "manual",
+            WallpaperSource::Tray => "tray",
+            WallpaperSource::AutoChange => "auto-change",
+        }
+    }
+}
+
+/// 传递给钩子命令的壁纸信息
+#[derive(Debug, Clone)]
+pub struct HookContext {
+    /// 触发事件
+    pub event: HookEvent,
+    /// 壁纸文件的绝对路径
+    pub file_path: String,
+    /// Wallhaven 壁纸ID（无法识别时为空）
+    pub wallhaven_id: String,
+    /// 分辨率（如 `3840x2160`，无法读取时为空）
+    pub resolution: String,
+    /// 壁纸来源
+    pub source: WallpaperSource,
+}
+
+impl HookContext {
+    /// 根据文件路径创建钩子信息，分辨率从图片文件头读取
+    ///
+    /// 文件名模板可能不包含壁纸ID，没有传入壁纸ID时依次从元数据附属文件、下载URL和文件名中识别
+    ///
+    /// # 参数
+    /// - `event`: 触发事件
+    /// - `file_path`: 壁纸文件路径
+    /// - `wallhaven_id`: 下载任务元数据中的壁纸ID（没有时传空字符串）
+    /// - `url`: 下载URL（没有时传空字符串）
+    /// - `source`: 壁纸来源
+    pub fn new(event: HookEvent, file_path: &str, wallhaven_id: &str, url: &str, source: WallpaperSource) -> Self {
+        let wallhaven_id = if wallhaven_id.is_empty() {
+            Self::find_wallhaven_id(file_path, url)
+        } else {
+            wallhaven_id.to_string()
+        };
+        let resolution = image_format::image_dimensions(Path::new(file_path))
+            .map(|(width, height)| format!("{}x{}", width, height))
+            .unwrap_or_default();
+
+        Self {
+            event,
+            file_path: file_path.to_string(),
+            wallhaven_id,
+            resolution,
+            source,
+        }
+    }
+
+    /// 从元数据附属文件、下载URL或文件名中识别壁纸ID，无法识别时返回空字符串
+    fn find_wallhaven_id(file_path: &str, url: &str) -> String {
+        let sidecar_id = sidecar::read_sidecar(Path::new(file_path))
+            .map(|metadata| metadata.wallhaven_id)
+            .unwrap_or_default();
+        if !sidecar_id.is_empty() {
+            return sidecar_id;
+        }
+
+        let file_name = Path::new(file_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        wallhaven::extract_wallpaper_ids(&format!("{} {}", url, file_name))
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// 替换命令中的占位符
+    ///
+    /// 占位符替换为带引号的环境变量引用而不是值本身：cmd 无法可靠转义值中的 `%`、`^`、`&` 等字符，
+    /// 而变量展开后的值不会再被解释（路径中包含空格时也能作为单个参数）
+    fn render_command(&self, command: &str) -> String {
+        command
+            .replace("{path}", &env_argument(ENV_FILE_PATH, &self.file_path))
+            .replace("{id}", &env_argument(ENV_WALLHAVEN_ID, &self.wallhaven_id))
+            .replace("{resolution}", &env_argument(ENV_RESOLUTION, &self.resolution))
+            .replace("{source}", &env_argument(ENV_SOURCE, self.source.as_str()))
+    }
+}
+
+/// 执行钩子命令，命令的输出和退出状态写入日志
+///
+/// # 参数
+/// - `command`: 用户配置的命令
+/// - `context`: 壁纸信息
+/// - `timeout`: 超时时间，超时后终止命令
+///
+/// # 返回
+/// 命令以退出码 0 结束时返回 Ok(())，否则返回错误信息
+pub async fn run_hook(command: String, context: HookContext, timeout: Duration) -> Result<(), String> {
+    let command_line = context.render_command(&command);
+    let event = context.event.as_str();
+
+    info!(
+        "[钩子命令] [{}] 执行: {} (文件: {}, 来源: {})",
+        event,
+        command_line,
+        context.file_path,
+        context.source.as_str()
+    );
+
+    let mut process = shell_command(&command_line);
+    process
+        .env(ENV_EVENT, event)
+        .env(ENV_FILE_PATH, &context.file_path)
+        .env(ENV_WALLHAVEN_ID, &context.wallhaven_id)
+        .env(ENV_RESOLUTION, &context.resolution)
+        .env(ENV_SOURCE, context.source.as_str())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // 超时后 future 被丢弃时终止子进程
+        .kill_on_drop(true);
+
+    let child = process.spawn().map_err(|e| {
+        error!("[钩子命令] [{}] 启动失败: {}", event, e);
+        format!("启动命令失败: {}", e)
+    })?;
+
+    let output = match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => {
+            error!("[钩子命令] [{}] 执行失败: {}", event, e);
+            return Err(format!("执行命令失败: {}", e));
+        }
+        Err(_) => {
+            warn!("[钩子命令] [{}] 执行超时 ({} 秒)，已终止", event, timeout.as_secs());
+            return Err(format!("命令执行超时 ({} 秒)", timeout.as_secs()));
+        }
+    };
+
+    let stdout = truncate_output(&String::from_utf8_lossy(&output.stdout));
+    let stderr = truncate_output(&String::from_utf8_lossy(&output.stderr));
+    if !stdout.is_empty() {
+        info!("[钩子命令] [{}] 标准输出:\n{}", event, stdout);
+    }
+    if !stderr.is_empty() {
+        warn!("[钩子命令] [{}] 错误输出:\n{}", event, stderr);
+    }
+
+    if output.status.success() {
+        info!("[钩子命令] [{}] 执行完成", event);
+        Ok(())
+    } else {
+        warn!("[钩子命令] [{}] 执行失败, 退出状态: {}", event, output.status);
+        Err(format!("命令退出状态: {}", output.status))
+    }
+}
+
+/// 创建通过系统 shell 执行命令的进程
+fn shell_command(command_line: &str) -> tokio::process::Command {
+    #[cfg(target_os = "windows")]
+    {
+        /// 不为控制台程序创建新窗口
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+        let mut process = tokio::process::Command::new("cmd");
+        process.arg("/C").raw_arg(command_line).creation_flags(CREATE_NO_WINDOW);
+        process
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut process = tokio::process::Command::new("sh");
+        process.arg("-c").arg(command_line);
+        process
+    }
+}
+
+/// 引用环境变量的命令参数（带引号），值为空时为空字符串参数
+///
+/// cmd 中未定义或值为空的变量不会被展开，因此值为空时直接使用空引号
+fn env_argument(name: &str, value: &str) -> String {
+    if value.is_empty() {
+        return "\"\"".to_string();
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        format!("\"%{}%\"", name)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        format!("\"${}\"", name)
+    }
+}
+
+/// 截断过长的命令输出
+fn truncate_output(output: &str) -> String {
+    let output = output.trim();
+    match output.char_indices().nth(MAX_LOGGED_OUTPUT_CHARS) {
+        Some((index, _)) => format!("{}...", &output[..index]),
+        None => output.to_string(),
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 共享 HTTP 客户端模块
+//!
+//! 每种代理配置只创建一个长期使用的 `reqwest::Client`，所有请求复用同一个连接池。
+//! 代理或网络设置修改后清空已创建的客户端，下次请求时按新设置重新创建
+
+use crate::utils::config::{HttpConfig, default_http_user_agent};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tracing::{debug, info};
+
+/// 代理 URL（None 表示不使用代理）-> 共享客户端
+struct ClientPool {
+    config: HttpConfig,
+    clients: HashMap<Option<String>, reqwest::Client>,
+}
+
+static CLIENT_POOL: LazyLock<Mutex<ClientPool>> = LazyLock::new(|| {
+    Mutex::new(ClientPool {
+        config: HttpConfig::default(),
+        clients: HashMap::new(),
+    })
+});
+
+/// 更新客户端配置，配置发生变化时丢弃已创建的客户端
+pub fn configure(config: &HttpConfig) {
+    let mut pool = CLIENT_POOL.lock().unwrap_or_else(|e| e.into_inner());
+    if pool.config == *config {
+        return;
+    }
+
+    info!("[HTTP客户端] 网络设置已更新，重新创建客户端: {:?}", config);
+    pool.config = config.clone();
+    pool.clients.clear();
+}
+
+/// 丢弃所有已创建的客户端（代理设置修改后调用，释放旧代理的连接）
+pub fn clear() {
+    let mut pool = CLIENT_POOL.lock().unwrap_or_else(|e| e.into_inner());
+    if !pool.clients.is_empty() {
+        info!("[HTTP客户端] 清空共享客户端: {} 个", pool.clients.len());
+        pool.clients.clear();
+    }
+}
+
+/// 获取指定代理的共享客户端，不存在时按当前配置创建
+///
+/// # 参数
+/// - `proxy_url`: 代理 URL（None 表示不使用代理）
+///
+/// # 返回
+/// 成功返回共享客户端（`reqwest::Client` 内部使用 Arc，克隆开销很小），代理 URL 无效时返回错误信息
+pub fn shared_client(proxy_url: Option<&str>) -> Result<reqwest::Client, String> {
+    let key = proxy_url.map(|url| url.to_string());
+    let mut pool = CLIENT_POOL.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(client) = pool.clients.get(&key) {
+        return Ok(client.clone());
+    }
+
+    let client = build_client(&pool.config, proxy_url)?;
+    debug!("[HTTP客户端] 创建共享客户端，代理: {}", proxy_url.unwrap_or("无"));
+    pool.clients.insert(key, client.clone());
+    Ok(client)
+}
+
+/// 按配置创建 HTTP 客户端
+fn build_client(config: &HttpConfig, proxy_url: Option<&str>) -> Result<reqwest::Client, String> {
+    let user_agent = if config.user_agent.trim().is_empty() {
+        default_http_user_agent()
+    } else {
+        config.user_agent.trim().to_string()
+    };
+
+    let mut builder = reqwest::Client::builder()
+        .user_agent(user_agent)
+        .pool_max_idle_per_host(config.pool_max_idle_per_host as usize)
+        .pool_idle_timeout(Duration::from_secs(config.pool_idle_timeout_secs as u64))
+        .connect_timeout(Duration::from_secs(config.connect_timeout_secs.max(1) as u64))
+        // TCP配置：启用TCP_NODELAY减少延迟
+        .tcp_nodelay(true)
+        .gzip(true)
+        .brotli(true);
+
+    // 0 表示不限制总超时时间（单个请求仍可以通过 RequestBuilder::timeout 设置）
+    if config.request_timeout_secs > 0 {
+        builder = builder.timeout(Duration::from_secs(config.request_timeout_secs as u64));
+    }
+
+    // 未强制 HTTP/2 时通过 ALPN 协商，兼容不支持 HTTP/2 的服务器
+    if config.http2_only {
+        builder = builder.http2_prior_knowledge();
+    }
+
+    if let Some(proxy_url) = proxy_url {
+        let proxy = reqwest::Proxy::all(proxy_url).map_err(|e| format!("代理地址无效: {}", e))?;
+        builder = builder.proxy(proxy);
+    }
+
+    builder.build().map_err(|e| format!("创建HTTP客户端失败: {}", e))
+}
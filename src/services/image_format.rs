@@ -0,0 +1,268 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 图片格式识别模块
+//!
+//! 按文件头（魔数）识别图片格式，不依赖扩展名（扩展名错误或没有扩展名的图片也能识别）。
+//! 扫描壁纸库时只读取图片扩展名或没有扩展名的文件的文件头，见 [`detect_candidate`]。
+//! 解码时同样按文件内容选择解码器，JPEG XL 通过 `jxl-oxide` 注册到 `image` 的解码器中，
+//! AVIF 需要启用 `avif` 特性（依赖系统中的 dav1d）
+
+use image::{DynamicImage, ImageReader};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Once;
+
+/// 识别格式时读取的文件头长度
+const HEADER_LEN: usize = 64;
+
+/// 本地壁纸支持的图片格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpg,
+    Png,
+    Bmp,
+    Webp,
+    /// 只使用第一帧
+    Gif,
+    Tiff,
+    Qoi,
+    Avif,
+    Jxl,
+}
+
+impl ImageFormat {
+    pub fn all() -> [ImageFormat; 9] {
+        [
+            ImageFormat::Jpg,
+            ImageFormat::Png,
+            ImageFormat::Bmp,
+            ImageFormat::Webp,
+            ImageFormat::Gif,
+            ImageFormat::Tiff,
+            ImageFormat::Qoi,
+            ImageFormat::Avif,
+            ImageFormat::Jxl,
+        ]
+    }
+
+    /// 当前构建可以解码的格式
+    pub fn supported() -> impl Iterator<Item = ImageFormat> {
+        Self::all().into_iter().filter(|format| format.is_decodable())
+    }
+
+    /// 格式名称（小写，与本地壁纸页面格式筛选的配置值一致）
+    pub fn name(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Bmp => "bmp",
+            ImageFormat::Webp => "webp",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Tiff => "tiff",
+            ImageFormat::Qoi => "qoi",
+            ImageFormat::Avif => "avif",
+            ImageFormat::Jxl => "jxl",
+        }
+    }
+
+    /// 按扩展名判断格式（不区分大小写）
+    pub fn from_extension(extension: &str) -> Option<ImageFormat> {
+        match extension.to_lowercase().as_str() {
+            "jpg" | "jpeg" | "jpe" | "jfif" => Some(ImageFormat::Jpg),
+            "png" => Some(ImageFormat::Png),
+            "bmp" => Some(ImageFormat::Bmp),
+            "webp" => Some(ImageFormat::Webp),
+            "gif" => Some(ImageFormat::Gif),
+            "tif" | "tiff" => Some(ImageFormat::Tiff),
+            "qoi" => Some(ImageFormat::Qoi),
+            "avif" => Some(ImageFormat::Avif),
+            "jxl" => Some(ImageFormat::Jxl),
+            _ => None,
+        }
+    }
+
+    /// 按文件头判断格式
+    pub fn sniff(header: &[u8]) -> Option<ImageFormat> {
+        const JXL_CONTAINER: &[u8] = &[0x00, 0x00, 0x00, 0x0C, b'J', b'X', b'L', b' ', 0x0D, 0x0A, 0x87, 0x0A];
+
+        if header.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(ImageFormat::Jpg)
+        } else if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(ImageFormat::Png)
+        } else if header.starts_with(b"RIFF") && header.get(8..12) == Some(b"WEBP") {
+            Some(ImageFormat::Webp)
+        } else if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+            Some(ImageFormat::Gif)
+        } else if header.starts_with(b"II*\0") || header.starts_with(b"MM\0*") {
+            Some(ImageFormat::Tiff)
+        } else if header.starts_with(b"qoif") {
+            Some(ImageFormat::Qoi)
+        } else if header.starts_with(&[0xFF, 0x0A]) || header.starts_with(JXL_CONTAINER) {
+            Some(ImageFormat::Jxl)
+        } else if is_avif(header) {
+            Some(ImageFormat::Avif)
+        } else if header.starts_with(b"BM") && header.len() >= 26 {
+            Some(ImageFormat::Bmp)
+        } else {
+            None
+        }
+    }
+
+    /// 当前构建是否可以解码该格式
+    pub fn is_decodable(&self) -> bool {
+        *self != ImageFormat::Avif || cfg!(feature = "avif")
+    }
+
+    /// 系统壁纸接口是否可以直接使用该格式（其他格式设置壁纸前需要转换）
+    pub fn is_wallpaper_compatible(&self) -> bool {
+        matches!(
+            self,
+            ImageFormat::Jpg | ImageFormat::Png | ImageFormat::Bmp | ImageFormat::Webp
+        )
+    }
+}
+
+/// AVIF 文件以 ftyp 盒子开头，主品牌或兼容品牌中包含 avif/avis
+fn is_avif(header: &[u8]) -> bool {
+    if header.get(4..8) != Some(b"ftyp") {
+        return false;
+    }
+    let box_size = header.get(0..4).map_or(0, |size| {
+        u32::from_be_bytes([size[0], size[1], size[2], size[3]]) as usize
+    });
+    let brands = header.get(8..box_size.min(header.len())).unwrap_or_default();
+    brands.chunks_exact(4).any(|brand| brand == b"avif" || brand == b"avis")
+}
+
+/// 读取文件头识别图片格式（文件无法读取或不是支持的图片时返回 None）
+pub fn detect(path: &Path) -> Option<ImageFormat> {
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    File::open(path)
+        .ok()?
+        .take(HEADER_LEN as u64)
+        .read_to_end(&mut header)
+        .ok()?;
+    ImageFormat::sniff(&header)
+}
+
+/// 识别可能是图片的文件的格式
+///
+/// 只读取图片扩展名（扩展名可能与内容不符）或没有扩展名的文件的文件头，
+/// 其他扩展名的文件（例如附属文件）直接跳过
+pub fn detect_candidate(path: &Path) -> Option<ImageFormat> {
+    let is_candidate = path
+        .extension()
+        .is_none_or(|ext| ext.to_str().and_then(ImageFormat::from_extension).is_some());
+    if !is_candidate {
+        return None;
+    }
+    detect(path)
+}
+
+/// 注册 `image` 默认不支持的解码器（启动时调用一次）
+pub fn register_decoders() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        jxl_oxide::integration::register_image_decoding_hook();
+    });
+}
+
+/// 按文件内容（而不是扩展名）解码图片，GIF 只解码第一帧
+pub fn open_image(path: &Path) -> Result<DynamicImage, image::ImageError> {
+    ImageReader::open(path)?.with_guessed_format()?.decode()
+}
+
+/// 按文件内容读取图片尺寸（不解码像素数据）
+pub fn image_dimensions(path: &Path) -> Result<(u32, u32), image::ImageError> {
+    ImageReader::open(path)?.with_guessed_format()?.into_dimensions()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ftyp 盒子（大小 + "ftyp" + 主品牌 + 次版本 + 兼容品牌）
+    fn ftyp(major: &[u8; 4], compatible: &[&[u8; 4]]) -> Vec<u8> {
+        let size = 16 + compatible.len() * 4;
+        let mut header = (size as u32).to_be_bytes().to_vec();
+        header.extend_from_slice(b"ftyp");
+        header.extend_from_slice(major);
+        header.extend_from_slice(&[0; 4]);
+        for brand in compatible {
+            header.extend_from_slice(*brand);
+        }
+        header
+    }
+
+    #[test]
+    fn sniff_magic_numbers() {
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8 ".to_vec();
+        assert_eq!(ImageFormat::sniff(&[0xFF, 0xD8, 0xFF, 0xE0]), Some(ImageFormat::Jpg));
+        assert_eq!(ImageFormat::sniff(b"\x89PNG\r\n\x1a\n\0\0"), Some(ImageFormat::Png));
+        assert_eq!(ImageFormat::sniff(&webp), Some(ImageFormat::Webp));
+        assert_eq!(ImageFormat::sniff(b"GIF89a"), Some(ImageFormat::Gif));
+        assert_eq!(ImageFormat::sniff(b"II*\0"), Some(ImageFormat::Tiff));
+        assert_eq!(ImageFormat::sniff(b"MM\0*"), Some(ImageFormat::Tiff));
+        assert_eq!(ImageFormat::sniff(b"qoif"), Some(ImageFormat::Qoi));
+        assert_eq!(ImageFormat::sniff(&[0xFF, 0x0A]), Some(ImageFormat::Jxl));
+        assert_eq!(
+            ImageFormat::sniff(&[0x00, 0x00, 0x00, 0x0C, b'J', b'X', b'L', b' ', 0x0D, 0x0A, 0x87, 0x0A]),
+            Some(ImageFormat::Jxl)
+        );
+        assert_eq!(
+            ImageFormat::sniff(&[b"BM".as_slice(), &[0; 24]].concat()),
+            Some(ImageFormat::Bmp)
+        );
+
+        webp[8..12].copy_from_slice(b"WAVE");
+        assert_eq!(ImageFormat::sniff(&webp), None);
+    }
+
+    #[test]
+    fn sniff_avif_brands() {
+        assert_eq!(ImageFormat::sniff(&ftyp(b"avif", &[b"mif1"])), Some(ImageFormat::Avif));
+        assert_eq!(
+            ImageFormat::sniff(&ftyp(b"mif1", &[b"miaf", b"avis"])),
+            Some(ImageFormat::Avif)
+        );
+        // HEIC 和 MP4 使用相同的 ftyp 盒子
+        assert_eq!(ImageFormat::sniff(&ftyp(b"heic", &[b"mif1", b"heic"])), None);
+        assert_eq!(ImageFormat::sniff(&ftyp(b"isom", &[b"mp41"])), None);
+    }
+
+    #[test]
+    fn sniff_rejects_unknown_and_short_headers() {
+        assert_eq!(ImageFormat::sniff(b""), None);
+        assert_eq!(ImageFormat::sniff(b"BM"), None);
+        assert_eq!(ImageFormat::sniff(b"{\"wallhaven_id\": \"8586my\"}"), None);
+        assert_eq!(ImageFormat::sniff(&[0xFF, 0xD8]), None);
+    }
+
+    #[test]
+    fn extension_matches_name() {
+        for format in ImageFormat::all() {
+            assert_eq!(ImageFormat::from_extension(format.name()), Some(format));
+        }
+        assert_eq!(ImageFormat::from_extension("JPEG"), Some(ImageFormat::Jpg));
+        assert_eq!(ImageFormat::from_extension("tif"), Some(ImageFormat::Tiff));
+        assert_eq!(ImageFormat::from_extension("json"), None);
+    }
+
+    #[test]
+    fn detect_candidate_skips_other_extensions() {
+        let dir = std::env::temp_dir().join(format!("wallwarp-image-format-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let png_header = b"\x89PNG\r\n\x1a\n";
+        for name in ["renamed.jpg", "no_extension", "sidecar.json"] {
+            std::fs::write(dir.join(name), png_header).unwrap();
+        }
+
+        assert_eq!(detect_candidate(&dir.join("renamed.jpg")), Some(ImageFormat::Png));
+        assert_eq!(detect_candidate(&dir.join("no_extension")), Some(ImageFormat::Png));
+        assert_eq!(detect_candidate(&dir.join("sidecar.json")), None);
+        assert_eq!(detect(&dir.join("sidecar.json")), Some(ImageFormat::Png));
+        assert_eq!(detect(&dir.join("missing.png")), None);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
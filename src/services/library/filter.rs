@@ -0,0 +1,378 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸库筛选
+//!
+//! 按本地壁纸页面的筛选条件和排序方式整理壁纸列表，以及本地定时切换的颜色筛选
+
+use crate::services::color_palette;
+use crate::services::database::{LibraryEntry, LibraryRepository};
+use crate::services::local::LocalWallpaperService;
+use crate::services::wallhaven::{self, AspectRatioGroup, ColorOption};
+use crate::utils::config::{LocalConfig, LocalFilter, LocalSort, Orientation};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 按本地壁纸页面的筛选条件和排序方式整理壁纸路径列表
+///
+/// 尚未建立索引（`entries` 中没有记录）的文件尺寸和颜色未知，不按分辨率、方向、比例和颜色筛选
+pub fn sort_and_filter(paths: &[String], entries: &HashMap<String, LibraryEntry>, config: &LocalConfig) -> Vec<String> {
+    let name_pattern = NamePattern::new(&config.filter.name_pattern);
+    let mut result: Vec<(&String, Option<&LibraryEntry>)> = paths
+        .iter()
+        .map(|path| (path, entries.get(path)))
+        .filter(|(path, entry)| matches_filter_with(path, *entry, &config.filter, &name_pattern))
+        .collect();
+
+    // 排序值相同时按路径排序，保证顺序稳定
+    match config.sort {
+        LocalSort::Name => result.sort_by_cached_key(|(path, _)| (file_name_lowercase(Path::new(path)), *path)),
+        LocalSort::DateAdded => sort_by_entry(&mut result, |entry| entry.modified),
+        LocalSort::FileSize => sort_by_entry(&mut result, |entry| entry.file_size),
+        LocalSort::Resolution => sort_by_entry(&mut result, |entry| entry.width as u64 * entry.height as u64),
+        LocalSort::AspectRatio => result.sort_by(|(path_a, a), (path_b, b)| {
+            let ratio = |entry: Option<&LibraryEntry>| {
+                entry
+                    .filter(|e| e.height > 0)
+                    .map_or(0.0, |e| e.width as f64 / e.height as f64)
+            };
+            ratio(*a).total_cmp(&ratio(*b)).then_with(|| path_a.cmp(path_b))
+        }),
+    }
+    if config.sort_descending {
+        result.reverse();
+    }
+
+    result.into_iter().map(|(path, _)| path.clone()).collect()
+}
+
+/// 按索引记录中的值排序（没有索引记录的排在最前面），值相同时按路径排序
+fn sort_by_entry<K: Ord>(items: &mut [(&String, Option<&LibraryEntry>)], key: impl Fn(&LibraryEntry) -> K) {
+    items.sort_by(|(path_a, a), (path_b, b)| a.map(&key).cmp(&b.map(&key)).then_with(|| path_a.cmp(path_b)));
+}
+
+/// 小写的文件名
+fn file_name_lowercase(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// 壁纸是否符合本地壁纸页面的筛选条件（`entry` 为 None 时不按尺寸筛选）
+pub fn matches_filter(path: &str, entry: Option<&LibraryEntry>, filter: &LocalFilter) -> bool {
+    matches_filter_with(path, entry, filter, &NamePattern::new(&filter.name_pattern))
+}
+
+fn matches_filter_with(
+    path: &str,
+    entry: Option<&LibraryEntry>,
+    filter: &LocalFilter,
+    name_pattern: &NamePattern,
+) -> bool {
+    let file_path = Path::new(path);
+    // 优先使用索引时按文件内容识别的格式，没有索引记录时按扩展名判断
+    let image_format = match entry.filter(|entry| !entry.format.is_empty()) {
+        Some(entry) => Some(entry.format.clone()),
+        None => LocalWallpaperService::image_format(file_path),
+    };
+    let format_matches = filter
+        .format
+        .as_ref()
+        .is_none_or(|format| image_format.as_ref() == Some(format));
+    if !format_matches || !name_pattern.matches(file_path) {
+        return false;
+    }
+
+    let Some(entry) = entry else {
+        return true;
+    };
+    // 无法解码的图片尺寸为 0、没有主色调，有尺寸或颜色相关的筛选条件时不显示
+    let (width, height) = (entry.width, entry.height);
+    let min_long_side = filter.min_width.max(filter.min_height);
+    let min_short_side = filter.min_width.min(filter.min_height);
+    width.max(height) >= min_long_side
+        && width.min(height) >= min_short_side
+        && filter
+            .orientation
+            .is_none_or(|orientation| entry.valid && Orientation::from_size(width, height) == orientation)
+        && filter
+            .aspect_ratio_group
+            .is_none_or(|group| AspectRatioGroup::from_size(width, height) == Some(group))
+        && filter
+            .color
+            .as_deref()
+            .is_none_or(|color| color_palette::palette_matches(&entry.palette, wallhaven::parse_color(color)))
+}
+
+/// 按颜色筛选壁纸（用于本地定时切换），只保留主色调匹配指定颜色的壁纸
+///
+/// 尚未建立索引或尚未计算主色调的壁纸不会被选中
+pub fn filter_by_color(paths: Vec<String>, color: ColorOption) -> Result<Vec<String>, String> {
+    if color == ColorOption::Any {
+        return Ok(paths);
+    }
+
+    let palettes = LibraryRepository::global()?.load_palettes()?;
+    Ok(paths
+        .into_iter()
+        .filter(|path| {
+            palettes
+                .get(path)
+                .is_some_and(|palette| color_palette::palette_matches(palette, color))
+        })
+        .collect())
+}
+
+/// 文件名筛选模式
+enum NamePattern {
+    /// 不筛选
+    Any,
+    /// 文件名中包含的内容
+    Contains(String),
+    /// 匹配整个文件名的通配符模式
+    Glob(Vec<char>),
+}
+
+impl NamePattern {
+    fn new(pattern: &str) -> Self {
+        let pattern = pattern.trim().to_lowercase();
+        if pattern.is_empty() {
+            NamePattern::Any
+        } else if pattern.contains(['*', '?']) {
+            NamePattern::Glob(pattern.chars().collect())
+        } else {
+            NamePattern::Contains(pattern)
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        match self {
+            NamePattern::Any => true,
+            NamePattern::Contains(text) => file_name_lowercase(path).contains(text.as_str()),
+            NamePattern::Glob(pattern) => {
+                let name: Vec<char> = file_name_lowercase(path).chars().collect();
+                glob_match(pattern, &name)
+            }
+        }
+    }
+}
+
+/// 通配符匹配（`*` 不跨越 `/`，`**` 可以跨越 `/`，`**/` 在目录边界处匹配零级或多级目录）
+///
+/// 使用双指针逐个字符匹配，不匹配时回溯到最近的 `*`（让它多匹配一个字符），
+/// `*` 无法再扩展（遇到 `/`）时回溯到最近的 `**`，最坏情况下的耗时为模式长度乘以文本长度
+pub(super) fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    // 回溯位置：(模式中通配符之后的位置, 通配符已匹配到的文本位置)
+    let mut star: Option<(usize, usize)> = None;
+    let mut globstar: Option<(usize, usize)> = None;
+    let (mut p, mut t) = (0, 0);
+
+    while p < pattern.len() || t < text.len() {
+        let at_boundary = t == 0 || text[t - 1] == '/';
+        match pattern.get(p) {
+            Some('*') if pattern[p..].starts_with(&['*', '*', '/']) && at_boundary => {
+                globstar = Some((p + 3, t));
+                star = None;
+                p += 3;
+                continue;
+            }
+            // `**/` 只能从目录边界开始匹配，不在边界时按不匹配处理
+            Some('*') if pattern[p..].starts_with(&['*', '*', '/']) => {}
+            Some('*') if pattern.get(p + 1) == Some(&'*') => {
+                globstar = Some((p + 2, t));
+                star = None;
+                p += 2;
+                continue;
+            }
+            Some('*') => {
+                star = Some((p + 1, t));
+                p += 1;
+                continue;
+            }
+            Some('?') if text.get(t).is_some_and(|c| *c != '/') => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            Some(c) if *c != '?' && text.get(t) == Some(c) => {
+                p += 1;
+                t += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        // `*` 多匹配一个字符（不跨越 `/`）
+        if let Some((star_p, star_t)) = star.filter(|(_, star_t)| text.get(*star_t).is_some_and(|c| *c != '/')) {
+            star = Some((star_p, star_t + 1));
+            p = star_p;
+            t = star_t + 1;
+            continue;
+        }
+
+        // `**` 多匹配一个字符，`**/` 多匹配一级目录
+        let Some((globstar_p, globstar_t)) = globstar else {
+            return false;
+        };
+        let next_t = if pattern[..globstar_p].ends_with(&['/']) {
+            text[globstar_t..]
+                .iter()
+                .position(|c| *c == '/')
+                .map(|i| globstar_t + i + 1)
+        } else {
+            Some(globstar_t + 1).filter(|next_t| *next_t <= text.len())
+        };
+        let Some(next_t) = next_t else {
+            return false;
+        };
+        globstar = Some((globstar_p, next_t));
+        star = None;
+        p = globstar_p;
+        t = next_t;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glob(pattern: &str, text: &str) -> bool {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        glob_match(&pattern, &text)
+    }
+
+    #[test]
+    fn glob_literal_and_question_mark() {
+        assert!(glob("a.jpg", "a.jpg"));
+        assert!(!glob("a.jpg", "b.jpg"));
+        assert!(glob("?.jpg", "a.jpg"));
+        assert!(!glob("?.jpg", "ab.jpg"));
+        assert!(!glob("a?b", "a/b"));
+        assert!(glob("", ""));
+        assert!(!glob("", "a"));
+    }
+
+    #[test]
+    fn glob_star_stays_in_one_directory() {
+        assert!(glob("*.jpg", "wallpaper.jpg"));
+        assert!(glob("*", ""));
+        assert!(glob("a*b*c", "axxbyyc"));
+        assert!(!glob("a*b*c", "axxbyy"));
+        assert!(!glob("*.jpg", "dir/wallpaper.jpg"));
+        assert!(glob("dir/*.jpg", "dir/wallpaper.jpg"));
+        assert!(!glob("dir/*.jpg", "dir/sub/wallpaper.jpg"));
+    }
+
+    #[test]
+    fn glob_double_star_crosses_directories() {
+        assert!(glob("**.jpg", "a/b/c.jpg"));
+        assert!(glob("**/*.jpg", "c.jpg"));
+        assert!(glob("**/*.jpg", "a/b/c.jpg"));
+        assert!(glob("a/**/c.jpg", "a/c.jpg"));
+        assert!(glob("a/**/c.jpg", "a/x/y/c.jpg"));
+        assert!(!glob("a/**/c.jpg", "ab/c.jpg"));
+        assert!(glob("temp/**", "temp/a/b.jpg"));
+        assert!(!glob("temp/**", "temporary/b.jpg"));
+        assert!(glob("**/thumbs/*", "x/y/thumbs/a.png"));
+        assert!(!glob("**/thumbs/*", "x/y/thumbs/z/a.png"));
+    }
+
+    #[test]
+    fn glob_backtracking_is_not_exponential() {
+        let text = "a".repeat(200);
+        assert!(!glob(&format!("{}b", "*a".repeat(30)), &text));
+        assert!(glob(&"*a".repeat(30), &text));
+    }
+
+    fn entry(path: &str, width: u32, height: u32, file_size: u64) -> LibraryEntry {
+        LibraryEntry {
+            path: path.to_string(),
+            file_name: path.to_string(),
+            file_size,
+            modified: 0,
+            content_hash: String::new(),
+            width,
+            height,
+            thumbnail_path: String::new(),
+            valid: true,
+            format: String::new(),
+            perceptual_hash: None,
+            palette: Vec::new(),
+        }
+    }
+
+    /// 三张已建立索引的壁纸和一张尚未建立索引的壁纸
+    fn library() -> (Vec<String>, HashMap<String, LibraryEntry>) {
+        let paths = ["b.jpg", "A.png", "c.jpg", "new.jpg"].map(String::from).to_vec();
+        let entries = [
+            entry("b.jpg", 3840, 2160, 300),
+            entry("A.png", 1080, 1920, 100),
+            entry("c.jpg", 1280, 720, 200),
+        ]
+        .into_iter()
+        .map(|entry| (entry.path.clone(), entry))
+        .collect();
+        (paths, entries)
+    }
+
+    fn sorted(config: &LocalConfig) -> Vec<String> {
+        let (paths, entries) = library();
+        sort_and_filter(&paths, &entries, config)
+    }
+
+    #[test]
+    fn sort_by_name_ignores_case() {
+        let mut config = LocalConfig::default();
+        assert_eq!(sorted(&config), ["A.png", "b.jpg", "c.jpg", "new.jpg"]);
+
+        config.sort_descending = true;
+        assert_eq!(sorted(&config), ["new.jpg", "c.jpg", "b.jpg", "A.png"]);
+    }
+
+    #[test]
+    fn sort_by_entry_puts_unindexed_first() {
+        let mut config = LocalConfig {
+            sort: LocalSort::FileSize,
+            ..Default::default()
+        };
+        assert_eq!(sorted(&config), ["new.jpg", "A.png", "c.jpg", "b.jpg"]);
+
+        config.sort = LocalSort::Resolution;
+        assert_eq!(sorted(&config), ["new.jpg", "c.jpg", "A.png", "b.jpg"]);
+
+        config.sort = LocalSort::AspectRatio;
+        assert_eq!(sorted(&config), ["new.jpg", "A.png", "b.jpg", "c.jpg"]);
+    }
+
+    #[test]
+    fn filter_by_size_and_orientation() {
+        let mut config = LocalConfig::default();
+        config.filter.min_width = 1920;
+        config.filter.min_height = 1080;
+        assert_eq!(sorted(&config), ["A.png", "b.jpg", "new.jpg"]);
+
+        config.filter = LocalFilter {
+            orientation: Some(Orientation::Portrait),
+            ..Default::default()
+        };
+        assert_eq!(sorted(&config), ["A.png", "new.jpg"]);
+    }
+
+    #[test]
+    fn filter_by_format_and_name() {
+        let mut config = LocalConfig::default();
+        config.filter.format = Some("jpg".to_string());
+        assert_eq!(sorted(&config), ["b.jpg", "c.jpg", "new.jpg"]);
+
+        config.filter = LocalFilter {
+            name_pattern: " NEW ".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(sorted(&config), ["new.jpg"]);
+
+        config.filter.name_pattern = "?.JPG".to_string();
+        assert_eq!(sorted(&config), ["b.jpg", "c.jpg"]);
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸库索引
+//!
+//! 扫描结果保存在数据库的壁纸库索引中（见 [`crate::services::database::library`]），
+//! 重新扫描时只处理新增或修改过的文件。新增的文件如果有下载时写入的元数据附属文件，
+//! 会把其中的 Wallhaven 标签导入为壁纸的标签。旧版本建立的索引缺少的主色调也会在扫描时补齐
+
+use super::scan::{LibraryScan, scan_library};
+use crate::services::color_palette;
+use crate::services::database::{LibraryEntry, LibraryRepository, UserMetadataRepository};
+use crate::services::local::LocalWallpaperService;
+use crate::services::sidecar;
+use crate::utils::config::LibraryRoot;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::UNIX_EPOCH;
+use tracing::{debug, info, warn};
+
+/// 每处理多少个文件写入一次数据库（扫描中途退出时已处理的文件不需要重新处理）
+const INDEX_BATCH_SIZE: usize = 200;
+
+/// 是否正在更新壁纸库索引（同一时间只进行一次扫描）
+static INDEXING: AtomicBool = AtomicBool::new(false);
+
+/// 壁纸库索引更新结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexStats {
+    /// 扫描到的文件总数
+    pub total: usize,
+    /// 新增的文件数
+    pub added: usize,
+    /// 修改过（重新处理）的文件数
+    pub updated: usize,
+    /// 已不存在（从索引中删除）的文件数
+    pub removed: usize,
+    /// 当前构建无法解码、没有加入索引的图片数（未启用 `avif` 特性时的 AVIF 图片）
+    pub undecodable: usize,
+    /// 已有扫描正在进行，本次没有扫描
+    pub skipped: bool,
+}
+
+impl IndexStats {
+    /// 索引内容是否发生了变化
+    pub fn has_changes(&self) -> bool {
+        self.added > 0 || self.updated > 0 || self.removed > 0
+    }
+}
+
+/// 加载壁纸库索引中属于指定壁纸库目录的记录（不访问壁纸文件，用于快速显示列表）
+pub fn load_index(roots: &[LibraryRoot]) -> Result<Vec<LibraryEntry>, String> {
+    let entries = LibraryRepository::global()?.load_all()?;
+    Ok(entries
+        .into_iter()
+        .filter(|entry| roots.iter().any(|root| Path::new(&entry.path).starts_with(&root.path)))
+        .collect())
+}
+
+/// 增量更新壁纸库索引
+///
+/// 扫描所有壁纸库目录，只有新增或者大小、修改时间发生变化的文件才会重新计算哈希、尺寸和缩略图，
+/// 已不存在的文件从索引中删除
+///
+/// # 参数
+/// - `roots`: 壁纸库目录
+/// - `cache_path`: 缓存目录（缩略图保存在其中）
+pub fn refresh_index(roots: &[LibraryRoot], cache_path: &Path) -> Result<IndexStats, String> {
+    if INDEXING.swap(true, Ordering::SeqCst) {
+        debug!("[壁纸库] 索引正在更新，跳过本次扫描");
+        return Ok(IndexStats {
+            skipped: true,
+            ..Default::default()
+        });
+    }
+    let _guard = scopeguard::guard((), |_| INDEXING.store(false, Ordering::SeqCst));
+
+    let start = std::time::Instant::now();
+    let repository = LibraryRepository::global()?;
+    let indexed: HashMap<String, (u64, i64)> = repository
+        .load_all()?
+        .into_iter()
+        .map(|entry| (entry.path, (entry.file_size, entry.modified)))
+        .collect();
+
+    let LibraryScan { files, undecodable } = scan_library(roots);
+    let mut stats = IndexStats {
+        total: files.len(),
+        undecodable,
+        ..Default::default()
+    };
+    let mut seen = HashSet::with_capacity(files.len());
+    let mut changed = Vec::new();
+    let mut added = Vec::new();
+
+    for file in files {
+        let path = file.to_string_lossy().to_string();
+        let Ok(metadata) = fs::metadata(&file) else {
+            continue;
+        };
+        let file_size = metadata.len();
+        let modified = modified_millis(&metadata);
+
+        match indexed.get(&path) {
+            Some(&(size, time)) if size == file_size && time == modified => {}
+            Some(_) => {
+                stats.updated += 1;
+                changed.push((path.clone(), file_size, modified));
+            }
+            None => {
+                stats.added += 1;
+                changed.push((path.clone(), file_size, modified));
+                added.push(file.clone());
+            }
+        }
+        seen.insert(path);
+    }
+
+    for batch in changed.chunks(INDEX_BATCH_SIZE) {
+        let entries = LocalWallpaperService::index_files(batch.to_vec(), cache_path);
+        repository.save_entries(&entries)?;
+    }
+
+    let removed: Vec<String> = indexed.into_keys().filter(|path| !seen.contains(path)).collect();
+    stats.removed = removed.len();
+    if !removed.is_empty() {
+        repository.delete_entries(&removed)?;
+    }
+
+    // 补齐旧版本建立的索引中缺少的主色调（失败不影响索引更新）
+    if let Err(e) = fill_missing_palettes(&repository) {
+        warn!("[壁纸库] 补齐主色调失败: {}", e);
+    }
+
+    // 导入新增文件附属文件中的 Wallhaven 标签（例如以前下载的壁纸）
+    for file in added {
+        if let Some(metadata) = sidecar::read_sidecar(&file) {
+            import_tags(&file.to_string_lossy(), &metadata.tags);
+        }
+    }
+
+    info!(
+        "[壁纸库] 索引更新完成: 共 {} 个文件，新增 {}，更新 {}，删除 {}，耗时 {:?}",
+        stats.total,
+        stats.added,
+        stats.updated,
+        stats.removed,
+        start.elapsed()
+    );
+    Ok(stats)
+}
+
+/// 根据文件监听得到的变化更新壁纸库索引
+///
+/// 删除已不存在的文件（或目录中的所有文件）的记录，重新处理新增或修改过的文件
+///
+/// # 返回
+/// 新增或修改过的文件的索引记录
+pub fn apply_changes(changed: &[String], removed: &[String], cache_path: &Path) -> Result<Vec<LibraryEntry>, String> {
+    let repository = LibraryRepository::global()?;
+
+    if !removed.is_empty() {
+        repository.delete_entries_under(removed)?;
+    }
+
+    let files: Vec<(String, u64, i64)> = changed
+        .iter()
+        .filter_map(|path| {
+            let metadata = fs::metadata(path).ok()?;
+            Some((path.clone(), metadata.len(), modified_millis(&metadata)))
+        })
+        .collect();
+    let entries = LocalWallpaperService::index_files(files, cache_path);
+    repository.save_entries(&entries)?;
+
+    Ok(entries)
+}
+
+/// 计算缺少主色调的图片的主色调，并保存到数据库
+///
+/// 已生成缩略图时优先使用缩略图计算（结果基本相同，速度快得多）
+fn fill_missing_palettes(repository: &LibraryRepository) -> Result<(), String> {
+    let missing = repository.load_missing_palettes()?;
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let start = std::time::Instant::now();
+    let palettes: Vec<(String, Vec<[u8; 3]>)> = missing
+        .par_iter()
+        .filter_map(|entry| {
+            let source = if Path::new(&entry.thumbnail_path).is_file() {
+                &entry.thumbnail_path
+            } else {
+                &entry.path
+            };
+            match color_palette::extract_palette(Path::new(source)) {
+                Ok(palette) => Some((entry.content_hash.clone(), palette)),
+                Err(e) => {
+                    warn!("[壁纸库] 提取主色调失败: {}, 错误: {}", entry.path, e);
+                    None
+                }
+            }
+        })
+        .collect();
+    repository.save_palettes(&palettes)?;
+
+    info!(
+        "[壁纸库] 已补齐 {} 张图片的主色调，耗时 {:?}",
+        palettes.len(),
+        start.elapsed()
+    );
+    Ok(())
+}
+
+/// 文件修改时间（Unix 时间戳，毫秒）
+fn modified_millis(metadata: &fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// 从壁纸库索引中删除文件（例如在本地壁纸页面把文件移到回收站后）
+///
+/// 文件的标签、收藏和评分在回收站中彻底删除时才删除（见 `trash::purge`）
+pub fn remove_from_index(path: &str) {
+    let paths = [path.to_string()];
+    let result = LibraryRepository::global().and_then(|repository| repository.delete_entries(&paths));
+    if let Err(e) = result {
+        warn!("[壁纸库] 从索引中删除文件失败: {}, 错误: {}", path, e);
+    }
+}
+
+/// 规范化标签：去掉首尾空白并把连续的空白合并为一个空格
+///
+/// # 返回
+/// 标签为空时返回 None
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.split_whitespace().collect::<Vec<_>>().join(" ");
+    if tag.is_empty() { None } else { Some(tag) }
+}
+
+/// 为壁纸导入标签（例如下载时得到的 Wallhaven 标签），失败只记录日志
+///
+/// # 返回
+/// 实际导入的（规范化后的）标签
+pub fn import_tags(path: &str, tags: &[String]) -> Vec<String> {
+    let tags: Vec<String> = tags.iter().filter_map(|tag| normalize_tag(tag)).collect();
+    if tags.is_empty() {
+        return tags;
+    }
+
+    let result =
+        UserMetadataRepository::global().and_then(|repository| repository.add_tags(&[path.to_string()], &tags));
+    match result {
+        Ok(()) => {
+            debug!("[壁纸库] 已导入 {} 个标签: {}", tags.len(), path);
+            tags
+        }
+        Err(e) => {
+            warn!("[壁纸库] 导入标签失败: {}, 错误: {}", path, e);
+            Vec::new()
+        }
+    }
+}
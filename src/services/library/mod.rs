@@ -0,0 +1,15 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸库模块
+//!
+//! - 扫描（scan）：按配置扫描多个壁纸库目录，支持递归扫描、包含/排除模式和符号链接策略
+//! - 索引（index）：扫描结果保存在数据库的壁纸库索引中，重新扫描时只处理新增或修改过的文件
+//! - 筛选（filter）：本地壁纸页面和本地定时切换的筛选与排序
+
+mod filter;
+mod index;
+mod scan;
+
+pub use filter::*;
+pub use index::*;
+pub use scan::*;
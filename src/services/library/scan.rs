@@ -0,0 +1,303 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸库扫描
+//!
+//! 按配置扫描多个壁纸库目录，支持递归扫描、包含/排除模式和符号链接策略。
+//!
+//! 模式语法（不区分大小写，路径分隔符统一为 `/`）：
+//! - `*` 匹配目录名或文件名中的任意字符，`?` 匹配单个字符，`**` 可以跨越多级目录
+//! - 不含 `/` 的模式匹配文件名或目录名（例如 `*.png`、`.thumbnails`）
+//! - 含 `/` 的模式匹配相对于壁纸库目录的路径（例如 `anime/**`、`**/drafts/*`）
+
+use super::filter::glob_match;
+use crate::services::image_format::{self, ImageFormat};
+use crate::services::local::LocalWallpaperService;
+use crate::services::trash;
+use crate::utils::config::{LibraryRoot, SymlinkPolicy};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+/// 壁纸库扫描结果
+#[derive(Debug, Default)]
+pub struct LibraryScan {
+    /// 可以解码的图片文件路径
+    pub files: Vec<PathBuf>,
+    /// 能识别格式但当前构建无法解码的图片数
+    pub undecodable: usize,
+}
+
+/// 扫描所有壁纸库目录，返回支持的图片文件路径
+///
+/// 多个目录重叠时同一个文件只返回一次；不存在的目录会被跳过
+pub fn scan_library(roots: &[LibraryRoot]) -> LibraryScan {
+    let mut seen_files = HashSet::new();
+    let mut scan = LibraryScan::default();
+
+    for root in roots {
+        let root_path = Path::new(&root.path);
+        if !root_path.is_dir() {
+            debug!("[壁纸库] 目录不存在，跳过: {}", root.path);
+            continue;
+        }
+
+        let mut root_files = scan_root(root_path, root);
+        root_files.sort_by(|a, b| a.path.cmp(&b.path));
+        debug!("[壁纸库] [{}] 找到 {} 张图片", root.path, root_files.len());
+
+        for file in root_files {
+            // 通过规范路径去重（同一文件可能经由不同的壁纸库目录或符号链接出现多次）
+            if !seen_files.insert(file.canonical_path) {
+                continue;
+            }
+            if file.format.is_decodable() {
+                scan.files.push(file.path);
+            } else {
+                debug!(
+                    "[壁纸库] 当前构建无法解码 {} 格式，跳过: {}",
+                    file.format.name(),
+                    file.path.display()
+                );
+                scan.undecodable += 1;
+            }
+        }
+    }
+
+    scan
+}
+
+/// 判断文件是否属于壁纸库（用于处理文件监听事件，规则与 [`scan_library`] 相同，但不检查符号链接）
+///
+/// `path` 需要是壁纸库目录路径拼接相对路径得到的路径（与扫描结果的形式一致）
+pub fn is_library_file(roots: &[LibraryRoot], path: &Path) -> bool {
+    if !LocalWallpaperService::is_supported_image(path) {
+        return false;
+    }
+
+    roots.iter().any(|root| {
+        let root_path = Path::new(&root.path);
+        let Ok(relative) = path.strip_prefix(root_path) else {
+            return false;
+        };
+        if !root.recursive && relative.components().count() != 1 {
+            return false;
+        }
+
+        // 任意一级上级目录被排除或是回收站目录时，文件也被排除
+        let filter = PatternFilter::new(root);
+        let excluded_dir = path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| *dir != root_path)
+            .any(|dir| is_trash_dir(dir) || filter.is_excluded(&relative_path(root_path, dir)));
+        !excluded_dir && filter.is_included(&relative_path(root_path, path))
+    })
+}
+
+/// 是否是回收站目录（回收站中的壁纸不属于壁纸库）
+fn is_trash_dir(dir: &Path) -> bool {
+    dir.file_name().is_some_and(|name| name == trash::TRASH_DIR_NAME)
+}
+
+/// 扫描到的图片文件
+struct ScannedFile {
+    /// 壁纸库目录路径拼接相对路径得到的路径
+    path: PathBuf,
+    /// 规范路径（用于去重）
+    canonical_path: PathBuf,
+    format: ImageFormat,
+}
+
+/// 扫描单个壁纸库目录，返回能识别格式的图片文件（包括当前构建无法解码的格式）
+///
+/// 只对目录和符号链接求规范路径，普通文件的规范路径由所在目录的规范路径拼接文件名得到
+fn scan_root(root_path: &Path, root: &LibraryRoot) -> Vec<ScannedFile> {
+    let filter = PatternFilter::new(root);
+    let mut visited_dirs = HashSet::new();
+    let mut pending_dirs = vec![root_path.to_path_buf()];
+    let mut files = Vec::new();
+
+    while let Some(dir) = pending_dirs.pop() {
+        // 跳过已扫描的目录（符号链接可能形成循环）
+        let canonical_dir = fs::canonicalize(&dir).unwrap_or_else(|_| dir.clone());
+        if !visited_dirs.insert(canonical_dir.clone()) {
+            continue;
+        }
+
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!("[壁纸库] 读取目录失败: {}, 错误: {}", dir.display(), e);
+                continue;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+
+            let (is_dir, is_file) = if file_type.is_symlink() {
+                if root.symlinks == SymlinkPolicy::Skip {
+                    continue;
+                }
+                // 跟随符号链接，失效的链接直接跳过
+                match fs::metadata(&path) {
+                    Ok(metadata) => (metadata.is_dir(), metadata.is_file()),
+                    Err(_) => continue,
+                }
+            } else {
+                (file_type.is_dir(), file_type.is_file())
+            };
+
+            let relative_path = relative_path(root_path, &path);
+
+            if is_dir {
+                if root.recursive && !is_trash_dir(&path) && !filter.is_excluded(&relative_path) {
+                    pending_dirs.push(path);
+                }
+            } else if is_file && filter.is_included(&relative_path) {
+                let Some(format) = image_format::detect_candidate(&path) else {
+                    continue;
+                };
+                let canonical_path = if file_type.is_symlink() {
+                    fs::canonicalize(&path).unwrap_or_else(|_| path.clone())
+                } else {
+                    canonical_dir.join(entry.file_name())
+                };
+                files.push(ScannedFile {
+                    path,
+                    canonical_path,
+                    format,
+                });
+            }
+        }
+    }
+
+    files
+}
+
+/// 相对于壁纸库目录的路径（小写，分隔符统一为 `/`）
+fn relative_path(root_path: &Path, path: &Path) -> String {
+    path.strip_prefix(root_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+        .to_lowercase()
+}
+
+/// 壁纸库目录的包含/排除模式
+struct PatternFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl PatternFilter {
+    fn new(root: &LibraryRoot) -> Self {
+        Self {
+            include: Pattern::parse_all(&root.include),
+            exclude: Pattern::parse_all(&root.exclude),
+        }
+    }
+
+    /// 文件是否应该包含在壁纸库中
+    fn is_included(&self, relative_path: &str) -> bool {
+        if self.is_excluded(relative_path) {
+            return false;
+        }
+        self.include.is_empty() || self.include.iter().any(|p| p.matches(relative_path))
+    }
+
+    /// 文件或目录是否被排除
+    fn is_excluded(&self, relative_path: &str) -> bool {
+        self.exclude.iter().any(|p| p.matches(relative_path))
+    }
+}
+
+/// 单个匹配模式
+struct Pattern {
+    chars: Vec<char>,
+    /// 模式中含 `/` 时匹配相对路径，否则只匹配文件名或目录名
+    match_path: bool,
+}
+
+impl Pattern {
+    fn parse_all(patterns: &[String]) -> Vec<Pattern> {
+        patterns
+            .iter()
+            .map(|p| p.trim().replace('\\', "/").to_lowercase())
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let p = p.trim_start_matches('/');
+                Pattern {
+                    chars: p.chars().collect(),
+                    match_path: p.contains('/'),
+                }
+            })
+            .collect()
+    }
+
+    fn matches(&self, relative_path: &str) -> bool {
+        let target = if self.match_path {
+            relative_path
+        } else {
+            relative_path.rsplit('/').next().unwrap_or(relative_path)
+        };
+        let target: Vec<char> = target.chars().collect();
+        glob_match(&self.chars, &target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(include: &[&str], exclude: &[&str]) -> LibraryRoot {
+        let mut root = LibraryRoot::new("wallpapers".to_string());
+        root.include = include.iter().map(|p| p.to_string()).collect();
+        root.exclude = exclude.iter().map(|p| p.to_string()).collect();
+        root
+    }
+
+    #[test]
+    fn empty_filter_includes_everything() {
+        let filter = PatternFilter::new(&root(&[], &[]));
+        assert!(filter.is_included("a.jpg"));
+        assert!(filter.is_included("sub/dir/a.png"));
+        assert!(!filter.is_excluded("sub"));
+    }
+
+    #[test]
+    fn patterns_without_slash_match_names() {
+        let filter = PatternFilter::new(&root(&["*.JPG", " "], &["thumbs", "*_small.*"]));
+        assert!(filter.is_included("a.jpg"));
+        assert!(filter.is_included("deep/dir/b.jpg"));
+        assert!(!filter.is_included("a.png"));
+        assert!(!filter.is_included("a_small.jpg"));
+        assert!(filter.is_excluded("x/thumbs"));
+        assert!(!filter.is_excluded("x/thumbs2"));
+    }
+
+    #[test]
+    fn patterns_with_slash_match_relative_paths() {
+        let filter = PatternFilter::new(&root(&[], &["/Archive/**", "**/raw/*.png", "temp\\*"]));
+        assert!(filter.is_excluded("archive/2020/a.jpg"));
+        assert!(!filter.is_excluded("other/archive/a.jpg"));
+        assert!(filter.is_excluded("raw/a.png"));
+        assert!(filter.is_excluded("x/y/raw/a.png"));
+        assert!(filter.is_excluded("temp/a.jpg"));
+        assert!(filter.is_excluded("temp/sub"));
+        assert!(!filter.is_excluded("temp/sub/a.jpg"));
+    }
+
+    #[test]
+    fn relative_paths_use_forward_slashes() {
+        let root_path = Path::new("wallpapers");
+        assert_eq!(
+            relative_path(root_path, &root_path.join("Sub").join("A.JPG")),
+            "sub/a.jpg"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸批量传输模块
+//!
+//! 把选中的本地壁纸（连同元数据附属文件）移动或复制到其他壁纸库目录，或导出为 zip 文件
+
+use crate::services::database::UserMetadataRepository;
+use crate::services::{library, sidecar};
+use crate::utils::helpers;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+/// 批量传输方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferMode {
+    /// 移动（原位置的文件被删除）
+    Move,
+    /// 复制
+    Copy,
+}
+
+/// 批量移动或复制的结果
+#[derive(Debug, Clone, Default)]
+pub struct TransferReport {
+    /// 成功的壁纸 (原路径, 新路径)
+    pub transferred: Vec<(String, String)>,
+    /// 已经在目标目录中而跳过的壁纸数量
+    pub skipped: usize,
+    /// 失败的壁纸数量
+    pub failed: usize,
+}
+
+/// 把壁纸（连同附属文件）移动或复制到目标目录
+///
+/// 目标目录中已有同名文件时在文件名后加序号，壁纸已经在目标目录中时跳过。
+/// 标签、收藏和评分跟随壁纸到新路径；移动时同时从壁纸库索引中删除原路径（新路径由调用方建立索引）
+///
+/// # 参数
+/// - `paths`: 壁纸文件路径
+/// - `target_dir`: 目标目录（壁纸库目录路径，新路径由它拼接文件名得到，与扫描结果的形式一致）
+/// - `mode`: 移动或复制
+///
+/// # 返回
+/// 成功返回传输结果，目标目录无法创建时返回错误信息
+pub fn transfer_files(paths: &[String], target_dir: &str, mode: TransferMode) -> Result<TransferReport, String> {
+    let full_target_dir = PathBuf::from(helpers::get_absolute_path(target_dir));
+    fs::create_dir_all(&full_target_dir).map_err(|e| format!("创建目录失败: {}", e))?;
+    let canonical_target_dir = fs::canonicalize(&full_target_dir).unwrap_or_else(|_| full_target_dir.clone());
+
+    let mut report = TransferReport::default();
+    for path in paths {
+        let full_path = PathBuf::from(helpers::get_absolute_path(path));
+        let in_target_dir = full_path
+            .parent()
+            .and_then(|dir| fs::canonicalize(dir).ok())
+            .is_some_and(|dir| dir == canonical_target_dir);
+        if in_target_dir {
+            report.skipped += 1;
+            continue;
+        }
+
+        let Some(file_name) = full_path.file_name() else {
+            report.failed += 1;
+            continue;
+        };
+        let file_name = unique_file_name(&full_target_dir, Path::new(file_name));
+        match transfer_file(&full_path, &full_target_dir.join(&file_name), mode) {
+            Ok(_) => {
+                let new_path = Path::new(target_dir).join(&file_name).to_string_lossy().to_string();
+                report.transferred.push((path.clone(), new_path));
+            }
+            Err(e) => {
+                warn!("[批量操作] 传输失败: {}, 错误: {}", path, e);
+                report.failed += 1;
+            }
+        }
+    }
+
+    // 标签、收藏和评分跟随壁纸（文件已经传输完成，失败时只记录日志）
+    let result = UserMetadataRepository::global().and_then(|repository| {
+        repository.copy_to(&report.transferred)?;
+        if mode == TransferMode::Move {
+            let old_paths: Vec<String> = report.transferred.iter().map(|(from, _)| from.clone()).collect();
+            repository.delete(&old_paths)?;
+        }
+        Ok(())
+    });
+    if let Err(e) = result {
+        warn!("[批量操作] 更新标签、收藏和评分失败: {}", e);
+    }
+
+    if mode == TransferMode::Move {
+        for (from, _) in &report.transferred {
+            library::remove_from_index(from);
+        }
+    }
+
+    info!(
+        "[批量操作] {:?} 到 {}: 成功 {}，跳过 {}，失败 {}",
+        mode,
+        target_dir,
+        report.transferred.len(),
+        report.skipped,
+        report.failed
+    );
+    Ok(report)
+}
+
+/// 把壁纸导出为 zip 文件
+///
+/// 图片本身已经压缩过，zip 中的文件不再压缩；文件名重复时在文件名后加序号，
+/// 读取失败的壁纸跳过。导出失败时删除未写完的 zip 文件
+///
+/// # 参数
+/// - `paths`: 壁纸文件路径
+/// - `zip_path`: zip 文件路径
+///
+/// # 返回
+/// 成功返回导出的壁纸数量，失败返回错误信息
+pub fn export_zip(paths: &[String], zip_path: &Path) -> Result<usize, String> {
+    let result = write_zip(paths, zip_path);
+    if result.is_err() {
+        let _ = fs::remove_file(zip_path);
+    }
+    result
+}
+
+fn write_zip(paths: &[String], zip_path: &Path) -> Result<usize, String> {
+    let file = File::create(zip_path).map_err(|e| format!("创建文件失败: {}", e))?;
+    let mut zip = ZipWriter::new(BufWriter::new(file));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    let mut used_names = HashSet::new();
+    let mut exported = 0;
+    for path in paths {
+        let full_path = PathBuf::from(helpers::get_absolute_path(path));
+        let mut source = match File::open(&full_path) {
+            Ok(source) => source,
+            Err(e) => {
+                warn!("[批量操作] 读取文件失败，跳过: {}, 错误: {}", path, e);
+                continue;
+            }
+        };
+        let Some(file_name) = full_path.file_name() else {
+            continue;
+        };
+
+        // zip 中的文件名不区分大小写去重（解压到 Windows 时不会互相覆盖）
+        let mut number = 0;
+        let mut entry_name = numbered_file_name(Path::new(file_name), number);
+        while !used_names.insert(entry_name.to_lowercase()) {
+            number += 1;
+            entry_name = numbered_file_name(Path::new(file_name), number);
+        }
+
+        zip.start_file(entry_name, options)
+            .map_err(|e| format!("写入 zip 文件失败: {}", e))?;
+        io::copy(&mut source, &mut zip).map_err(|e| format!("写入 zip 文件失败: {}", e))?;
+        exported += 1;
+    }
+
+    zip.finish().map_err(|e| format!("写入 zip 文件失败: {}", e))?;
+    info!("[批量操作] 已导出 {} 张壁纸到: {}", exported, zip_path.display());
+    Ok(exported)
+}
+
+/// 移动或复制壁纸文件，然后移动或复制附属文件（附属文件失败时只记录日志）
+fn transfer_file(from: &Path, to: &Path, mode: TransferMode) -> io::Result<()> {
+    match mode {
+        TransferMode::Move => helpers::move_file(from, to)?,
+        TransferMode::Copy => {
+            fs::copy(from, to)?;
+        }
+    }
+
+    for (sidecar_from, sidecar_to) in sidecar::sidecar_paths(from)
+        .iter()
+        .zip(sidecar::sidecar_paths(to).iter())
+    {
+        if !sidecar_from.exists() {
+            continue;
+        }
+        let result = match mode {
+            TransferMode::Move => helpers::move_file(sidecar_from, sidecar_to),
+            TransferMode::Copy => fs::copy(sidecar_from, sidecar_to).map(|_| ()),
+        };
+        if let Err(e) = result {
+            warn!("[批量操作] 传输附属文件失败: {}, 错误: {}", sidecar_from.display(), e);
+        }
+    }
+    Ok(())
+}
+
+/// 目标目录中不与已有文件（及其附属文件）重名的文件名
+pub(crate) fn unique_file_name(dir: &Path, file_name: &Path) -> String {
+    let is_free = |name: &str| {
+        let path = dir.join(name);
+        !path.exists()
+            && sidecar::sidecar_paths(&path)
+                .iter()
+                .all(|sidecar_path| !sidecar_path.exists())
+    };
+
+    let mut number = 0;
+    loop {
+        let name = numbered_file_name(file_name, number);
+        if is_free(&name) {
+            return name;
+        }
+        number += 1;
+    }
+}
+
+/// 在文件名后加序号，例如 `a.jpg` -> `a (1).jpg`（序号为 0 时不加）
+fn numbered_file_name(file_name: &Path, number: usize) -> String {
+    if number == 0 {
+        return file_name.to_string_lossy().to_string();
+    }
+
+    let stem = file_name.file_stem().unwrap_or_default().to_string_lossy();
+    match file_name.extension() {
+        Some(extension) => format!("{} ({}).{}", stem, number, extension.to_string_lossy()),
+        None => format!("{} ({})", stem, number),
+    }
+}
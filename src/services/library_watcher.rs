@@ -0,0 +1,181 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸库文件监听模块
+//!
+//! 监听所有壁纸库目录（Linux 使用 inotify，Windows 使用 ReadDirectoryChangesW），
+//! 把一段时间内的文件事件合并为一次变化，用于实时更新本地壁纸列表、壁纸库索引和定时切换的候选列表
+
+use crate::services::library;
+use crate::utils::config::LibraryRoot;
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use tracing::{debug, info, warn};
+
+/// 最后一个事件之后等待多久没有新事件才处理（复制大量文件时会连续产生事件）
+const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 持续有新事件时最多等待多久就处理一次
+const MAX_DEBOUNCE_DELAY: Duration = Duration::from_secs(5);
+
+/// 壁纸库文件变化
+///
+/// 路径与扫描壁纸库得到的路径形式一致（壁纸库目录路径拼接相对路径）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LibraryChanges {
+    /// 新增或修改过的壁纸文件
+    pub changed: Vec<String>,
+    /// 已不存在的文件或目录（删除目录时，其中的所有壁纸都需要移除）
+    pub removed: Vec<String>,
+    /// 有目录被创建或移入，需要重新扫描壁纸库
+    pub rescan: bool,
+}
+
+impl LibraryChanges {
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty() && !self.rescan
+    }
+}
+
+/// 单个文件事件
+struct WatchEvent {
+    path: PathBuf,
+    /// 创建、重命名或删除（目录的修改事件只表示其中的文件发生变化，不需要重新扫描）
+    structural: bool,
+}
+
+/// 壁纸库文件监听器
+pub struct LibraryWatcher {
+    /// 监听器被 drop 时停止监听
+    _watcher: RecommendedWatcher,
+    receiver: UnboundedReceiver<WatchEvent>,
+    roots: Vec<LibraryRoot>,
+    /// 与 `roots` 一一对应的绝对路径（文件事件中的路径是绝对路径）
+    absolute_roots: Vec<PathBuf>,
+}
+
+impl LibraryWatcher {
+    /// 开始监听壁纸库目录（不存在的目录会被跳过）
+    pub fn new(roots: Vec<LibraryRoot>) -> Result<Self, String> {
+        let (sender, receiver) = unbounded_channel();
+
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+            let event = match result {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("[壁纸库] [监听] 文件事件错误: {}", e);
+                    return;
+                }
+            };
+
+            let structural = match event.kind {
+                // 只读取文件不影响壁纸库
+                EventKind::Access(_) => return,
+                EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(_)) => true,
+                _ => false,
+            };
+            for path in event.paths {
+                // 接收端已关闭（监听结束）时忽略
+                let _ = sender.send(WatchEvent { path, structural });
+            }
+        })
+        .map_err(|e| format!("创建文件监听器失败: {}", e))?;
+
+        let absolute_roots: Vec<PathBuf> = roots
+            .iter()
+            .map(|root| std::path::absolute(&root.path).unwrap_or_else(|_| PathBuf::from(&root.path)))
+            .collect();
+
+        for (root, absolute_root) in roots.iter().zip(&absolute_roots) {
+            if !absolute_root.is_dir() {
+                debug!("[壁纸库] [监听] 目录不存在，跳过: {}", root.path);
+                continue;
+            }
+
+            let mode = if root.recursive {
+                RecursiveMode::Recursive
+            } else {
+                RecursiveMode::NonRecursive
+            };
+            match watcher.watch(absolute_root, mode) {
+                Ok(_) => info!("[壁纸库] [监听] 开始监听: {}", absolute_root.display()),
+                Err(e) => warn!("[壁纸库] [监听] 监听目录失败: {}, 错误: {}", absolute_root.display(), e),
+            }
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            receiver,
+            roots,
+            absolute_roots,
+        })
+    }
+
+    /// 等待下一次壁纸库变化
+    ///
+    /// 收到事件后继续等待，直到 [`DEBOUNCE_INTERVAL`] 内没有新事件（最多等待 [`MAX_DEBOUNCE_DELAY`]），
+    /// 再把这段时间内的所有事件合并为一次变化；监听器停止时返回 None
+    pub async fn next_changes(&mut self) -> Option<LibraryChanges> {
+        loop {
+            let first = self.receiver.recv().await?;
+            let start = Instant::now();
+            let mut events: HashMap<PathBuf, bool> = HashMap::new();
+            events.insert(first.path, first.structural);
+
+            while start.elapsed() < MAX_DEBOUNCE_DELAY {
+                match tokio::time::timeout(DEBOUNCE_INTERVAL, self.receiver.recv()).await {
+                    Ok(Some(event)) => *events.entry(event.path).or_default() |= event.structural,
+                    _ => break,
+                }
+            }
+
+            let changes = self.classify(events);
+            if !changes.is_empty() {
+                debug!(
+                    "[壁纸库] [监听] 变化: 修改 {} 个文件，删除 {} 个路径，重新扫描: {}",
+                    changes.changed.len(),
+                    changes.removed.len(),
+                    changes.rescan
+                );
+                return Some(changes);
+            }
+        }
+    }
+
+    /// 根据文件当前的状态判断事件对应的变化
+    fn classify(&self, events: HashMap<PathBuf, bool>) -> LibraryChanges {
+        let mut changes = LibraryChanges::default();
+
+        for (absolute_path, structural) in events {
+            let Some(library_path) = self.to_library_path(&absolute_path) else {
+                continue;
+            };
+
+            if !absolute_path.exists() {
+                changes.removed.push(library_path.to_string_lossy().to_string());
+            } else if absolute_path.is_dir() {
+                changes.rescan |= structural;
+            } else if absolute_path.is_file() && library::is_library_file(&self.roots, &library_path) {
+                changes.changed.push(library_path.to_string_lossy().to_string());
+            }
+        }
+
+        changes.changed.sort();
+        changes.removed.sort();
+        changes
+    }
+
+    /// 把事件中的绝对路径转换为壁纸库路径
+    fn to_library_path(&self, absolute_path: &Path) -> Option<PathBuf> {
+        self.roots
+            .iter()
+            .zip(&self.absolute_roots)
+            .find_map(|(root, absolute_root)| {
+                let relative = absolute_path.strip_prefix(absolute_root).ok()?;
+                Some(Path::new(&root.path).join(relative))
+            })
+    }
+}
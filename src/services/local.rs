@@ -1,5 +1,10 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::database::LibraryEntry;
+use crate::services::image_format::{self, ImageFormat};
+use crate::services::sidecar::WallpaperMetadata;
+use crate::services::{color_palette, duplicate_finder, library};
+use crate::utils::config::LibraryRoot;
 use fast_image_resize as fr;
 use image;
 use rayon::prelude::*;
@@ -10,7 +15,6 @@ use std::path::Path;
 use tracing::{debug, error};
 use xxhash_rust::xxh3::xxh3_128;
 
-const SUPPORTED_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "bmp", "webp"];
 const THREAD_POOL_SIZE: usize = 3;
 const HASH_CHUNK_SIZE: u64 = 64 * 1024;
 const THUMBNAIL_MAX_WIDTH: u32 = 256;
@@ -26,6 +30,8 @@ pub struct Wallpaper {
     pub height: u32,
     /// 缓存的图片 Handle，避免每次渲染都重新创建
     pub image_handle: Option<iced::widget::image::Handle>,
+    /// 从附属文件读取的壁纸元数据（非下载的壁纸为 None）
+    pub metadata: Option<WallpaperMetadata>,
 }
 
 impl Wallpaper {
@@ -38,6 +44,7 @@ impl Wallpaper {
             width,
             height,
             image_handle: None,
+            metadata: None,
         }
     }
 
@@ -60,6 +67,7 @@ impl Wallpaper {
             width,
             height,
             image_handle,
+            metadata: None,
         }
     }
 }
@@ -80,11 +88,15 @@ impl LocalWallpaperService {
     }
 
     /// 设置壁纸
+    ///
+    /// 系统壁纸接口无法直接使用的格式（AVIF、JPEG XL 等）会先转换为 PNG 保存在 `cache_path` 中
     pub fn set_wallpaper(
         image_path: &str,
         mode: crate::utils::config::WallpaperMode,
+        cache_path: &str,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        use std::path::Path;
+        let converted_path = Self::wallpaper_compatible_path(image_path, cache_path)?;
+        let image_path = converted_path.as_str();
 
         let path = Path::new(image_path);
         let absolute_path = if path.is_absolute() {
@@ -119,25 +131,46 @@ impl LocalWallpaperService {
         }
     }
 
-    /// 获取支持的图片文件列表（仅根据文件后缀筛选）
-    pub fn get_supported_image_paths(data_path: &str) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
-        let path = Path::new(data_path);
-
-        if !path.exists() {
-            return Ok(Vec::new());
+    /// 返回系统壁纸接口可以使用的图片路径
+    ///
+    /// 可以直接使用的格式返回原路径，其他格式解码后以 PNG 保存到缓存目录的 converted 子目录
+    /// （以内容哈希命名，已转换过时直接返回）
+    fn wallpaper_compatible_path(
+        image_path: &str,
+        cache_path: &str,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let path = Path::new(image_path);
+        if image_format::detect(path).is_none_or(|format| format.is_wallpaper_compatible()) {
+            return Ok(image_path.to_string());
         }
 
-        let entries = fs::read_dir(path).map_err(to_boxed_error)?;
-        let mut image_paths = Vec::new();
+        let converted_dir = Path::new(cache_path).join("converted");
+        fs::create_dir_all(&converted_dir).map_err(to_boxed_error)?;
+        let file_hash = Self::calculate_file_hash(path)?;
+        let converted_path = converted_dir.join(format!("{}.png", file_hash));
+
+        if !converted_path.exists() {
+            debug!("转换壁纸格式: {} -> {}", image_path, converted_path.display());
+            // 先写入临时文件，避免转换中断后留下不完整的图片
+            let temp_path = converted_dir.join(format!("{}.png.tmp", file_hash));
+            image_format::open_image(path)?
+                .into_rgba8()
+                .save_with_format(&temp_path, image::ImageFormat::Png)?;
+            fs::rename(&temp_path, &converted_path).map_err(to_boxed_error)?;
+        }
 
-        for entry in entries {
-            let entry = entry.map_err(to_boxed_error)?;
-            let file_path = entry.path();
+        Ok(converted_path.to_string_lossy().to_string())
+    }
 
-            if file_path.is_file() && Self::is_supported_image(&file_path) {
-                image_paths.push(file_path.to_string_lossy().to_string());
-            }
-        }
+    /// 获取所有壁纸库目录中支持的图片文件列表（按文件内容识别格式）
+    pub fn get_supported_image_paths(
+        roots: &[LibraryRoot],
+    ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+        let image_paths: Vec<String> = library::scan_library(roots)
+            .files
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect();
 
         debug!("找到 {} 张支持的壁纸", image_paths.len());
         Ok(image_paths)
@@ -147,6 +180,7 @@ impl LocalWallpaperService {
     pub fn set_random_wallpaper(
         image_paths: &[String],
         mode: crate::utils::config::WallpaperMode,
+        cache_path: &str,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         if image_paths.is_empty() {
             return Err("没有可用的壁纸".into());
@@ -159,33 +193,28 @@ impl LocalWallpaperService {
         debug!("随机选择壁纸: {}", selected_path);
 
         // 在设置壁纸前验证图片是否可以正常加载
-        if image::open(selected_path).is_err() {
+        if image_format::open_image(Path::new(selected_path)).is_err() {
             debug!("跳过损坏的图片: {}", selected_path);
             return Err("选择的图片已损坏".into());
         }
 
         // 设置壁纸
-        Self::set_wallpaper(selected_path, mode)?;
+        Self::set_wallpaper(selected_path, mode, cache_path)?;
 
         Ok(selected_path.clone())
     }
 }
 
 impl LocalWallpaperService {
-    pub fn load_wallpapers_from_path(
-        data_path: &str,
+    pub fn load_wallpapers_from_roots(
+        roots: &[LibraryRoot],
         cache_path: &str,
     ) -> Result<Vec<Wallpaper>, Box<dyn std::error::Error + Send + Sync>> {
-        let path = Path::new(data_path);
         let cache_dir = Path::new(cache_path);
 
         fs::create_dir_all(cache_dir).map_err(to_boxed_error)?;
 
-        if !path.exists() {
-            return Ok(Vec::new());
-        }
-
-        let wallpapers = Self::collect_wallpapers(path)?;
+        let wallpapers = Self::collect_wallpapers(roots)?;
 
         let pool = rayon::ThreadPoolBuilder::new()
             .num_threads(THREAD_POOL_SIZE)
@@ -214,26 +243,13 @@ impl LocalWallpaperService {
         })
     }
 
-    pub fn get_wallpaper_paths(data_path: &str) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
-        let path = Path::new(data_path);
-
-        if !path.exists() {
-            return Ok(Vec::new());
-        }
-
-        let entries = fs::read_dir(path).map_err(to_boxed_error)?;
-        let mut wallpaper_paths = Vec::new();
-
-        for entry in entries {
-            let entry = entry.map_err(to_boxed_error)?;
-            let file_path = entry.path();
-
-            if file_path.is_file() && Self::is_supported_image(&file_path) {
-                wallpaper_paths.push(file_path.to_string_lossy().to_string());
-            }
-        }
-
-        Ok(wallpaper_paths)
+    /// 获取所有壁纸库目录中的壁纸路径
+    pub fn get_wallpaper_paths(roots: &[LibraryRoot]) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(library::scan_library(roots)
+            .files
+            .into_iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect())
     }
 
     pub fn generate_thumbnail_for_path(
@@ -247,7 +263,66 @@ impl LocalWallpaperService {
         Self::generate_thumbnail(&Path::new(wallpaper_path), cache_dir)
     }
 
-    fn calculate_file_hash(file_path: &Path) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    /// 为壁纸库索引处理文件：计算内容哈希、读取尺寸、生成缩略图并提取主色调
+    ///
+    /// # 参数
+    /// - `files`: 需要处理的文件（路径, 文件大小, 修改时间）
+    /// - `cache_path`: 缓存目录（缩略图保存在其中的 thumbnail 子目录）
+    ///
+    /// # 返回
+    /// 返回索引记录，无法解码的图片标记为无效（仍然返回记录，避免每次扫描都重新处理）
+    pub fn index_files(files: Vec<(String, u64, i64)>, cache_path: &Path) -> Vec<LibraryEntry> {
+        let index_file = |(path, file_size, modified): (String, u64, i64)| {
+            let file_path = Path::new(&path);
+            let content_hash = Self::calculate_file_hash(file_path).unwrap_or_default();
+            let thumbnail_path = if content_hash.is_empty() {
+                None
+            } else {
+                Self::generate_thumbnail_with_hash(file_path, cache_path, &content_hash).ok()
+            };
+            let (width, height) = image_format::image_dimensions(file_path).unwrap_or((0, 0));
+            let format = image_format::detect(file_path)
+                .map(|format| format.name().to_string())
+                .unwrap_or_default();
+            // 感知哈希使用原图缩小后计算，与查找相似图片时补齐的感知哈希一致
+            let perceptual_hash = if thumbnail_path.is_some() {
+                duplicate_finder::perceptual_hash(file_path).ok()
+            } else {
+                None
+            };
+
+            LibraryEntry {
+                file_name: file_path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+                path,
+                file_size,
+                modified,
+                content_hash,
+                width,
+                height,
+                valid: thumbnail_path.is_some(),
+                format,
+                perceptual_hash,
+                // 使用缩略图提取主色调（比解码原图快得多）
+                palette: thumbnail_path
+                    .as_deref()
+                    .and_then(|thumbnail| color_palette::extract_palette(Path::new(thumbnail)).ok())
+                    .unwrap_or_default(),
+                thumbnail_path: thumbnail_path.unwrap_or_default(),
+            }
+        };
+
+        match rayon::ThreadPoolBuilder::new().num_threads(THREAD_POOL_SIZE).build() {
+            Ok(pool) => pool.install(|| files.into_par_iter().map(index_file).collect()),
+            Err(_) => files.into_iter().map(index_file).collect(),
+        }
+    }
+
+    /// 计算文件内容哈希（读取文件首尾各一段数据，用于快速比较文件是否相同）
+    pub fn calculate_file_hash(file_path: &Path) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         use std::io::Seek;
 
         let mut file = fs::File::open(file_path).map_err(to_boxed_error)?;
@@ -283,11 +358,20 @@ impl LocalWallpaperService {
     fn generate_thumbnail(
         file_path: &Path,
         cache_dir: &Path,
+    ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+        let file_hash = Self::calculate_file_hash(file_path)?;
+        Self::generate_thumbnail_with_hash(file_path, cache_dir, &file_hash)
+    }
+
+    /// 使用已计算的内容哈希生成缩略图（缩略图以内容哈希命名，已存在时直接返回）
+    fn generate_thumbnail_with_hash(
+        file_path: &Path,
+        cache_dir: &Path,
+        file_hash: &str,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         let thumbnail_dir = cache_dir.join("thumbnail");
         fs::create_dir_all(&thumbnail_dir).map_err(to_boxed_error)?;
 
-        let file_hash = Self::calculate_file_hash(file_path)?;
         let thumbnail_path = thumbnail_dir.join(format!("{}.webp", file_hash));
 
         if thumbnail_path.exists() {
@@ -295,7 +379,7 @@ impl LocalWallpaperService {
         }
 
         let start = std::time::Instant::now();
-        let img = image::open(file_path)?.into_rgba8();
+        let img = image_format::open_image(file_path)?.into_rgba8();
         debug!("{thumbnail_path:?}Load image: {:?}", start.elapsed());
         let (src_w, src_h) = img.dimensions();
 
@@ -327,42 +411,63 @@ impl LocalWallpaperService {
         Ok(thumbnail_path.to_string_lossy().to_string())
     }
 
-    fn collect_wallpapers(path: &Path) -> Result<Vec<Wallpaper>, Box<dyn std::error::Error + Send + Sync>> {
-        let entries = fs::read_dir(path).map_err(to_boxed_error)?;
+    fn collect_wallpapers(roots: &[LibraryRoot]) -> Result<Vec<Wallpaper>, Box<dyn std::error::Error + Send + Sync>> {
         let mut wallpapers = Vec::new();
 
-        for entry in entries {
-            let entry = entry.map_err(to_boxed_error)?;
-            let file_path = entry.path();
-
-            if file_path.is_file() && Self::is_supported_image(&file_path) {
-                if let Some(file_name) = file_path.file_name() {
-                    if let Some(name) = file_name.to_str() {
-                        let file_size = fs::metadata(&file_path).map_err(to_boxed_error)?.len();
-
-                        let (width, height) = image::image_dimensions(&file_path).unwrap_or((0, 0));
-
-                        wallpapers.push(Wallpaper::new(
-                            file_path.to_string_lossy().to_string(),
-                            name.to_string(),
-                            file_size,
-                            width,
-                            height,
-                        ));
-                    }
-                }
+        for file_path in library::scan_library(roots).files {
+            if let Some(name) = file_path.file_name().and_then(|file_name| file_name.to_str()) {
+                let file_size = fs::metadata(&file_path).map_err(to_boxed_error)?.len();
+
+                let (width, height) = image_format::image_dimensions(&file_path).unwrap_or((0, 0));
+
+                wallpapers.push(Wallpaper::new(
+                    file_path.to_string_lossy().to_string(),
+                    name.to_string(),
+                    file_size,
+                    width,
+                    height,
+                ));
             }
         }
 
         Ok(wallpapers)
     }
 
-    fn is_supported_image(file_path: &Path) -> bool {
-        file_path
+    /// 按扩展名判断的图片格式名称（见 `ImageFormat::name`）
+    pub fn image_format(file_path: &Path) -> Option<String> {
+        let ext = file_path.extension()?.to_str()?;
+        ImageFormat::from_extension(ext).map(|format| format.name().to_string())
+    }
+
+    /// 文件是否是可以解码的图片（按文件内容识别格式，见 [`image_format::detect_candidate`]）
+    pub(crate) fn is_supported_image(file_path: &Path) -> bool {
+        image_format::detect_candidate(file_path).is_some_and(|format| format.is_decodable())
+    }
+
+    /// 创建预览大图的 Handle
+    ///
+    /// 扩展名与文件内容一致时由 iced 按路径加载，否则（扩展名错误或没有扩展名）先解码再创建 Handle
+    pub fn preview_handle(path: &str) -> iced::widget::image::Handle {
+        let file_path = Path::new(path);
+        let sniffed = image_format::detect(file_path);
+        let by_extension = file_path
             .extension()
             .and_then(|ext| ext.to_str())
-            .map(|ext| SUPPORTED_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
-            .unwrap_or(false)
+            .and_then(ImageFormat::from_extension);
+        if sniffed.is_none() || sniffed == by_extension {
+            return iced::widget::image::Handle::from_path(path);
+        }
+
+        match image_format::open_image(file_path) {
+            Ok(img) => {
+                let rgba = img.into_rgba8();
+                iced::widget::image::Handle::from_rgba(rgba.width(), rgba.height(), rgba.into_raw())
+            }
+            Err(e) => {
+                error!("解码预览图片失败: {}, {}", path, e);
+                iced::widget::image::Handle::from_path(path)
+            }
+        }
     }
 }
 
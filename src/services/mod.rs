@@ -1,11 +1,27 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 pub mod async_task;
+pub mod color_palette;
 pub mod database;
+pub mod disk_space;
 pub mod download;
+pub mod download_progress;
+pub mod download_retry;
+pub mod download_transfer;
+pub mod duplicate_finder;
+pub mod hooks;
+pub mod image_format;
+pub mod http_client;
+pub mod library;
+pub mod library_transfer;
+pub mod library_watcher;
 pub mod local;
+pub mod owned_wallpaper;
 pub mod proxy;
 pub mod request_context;
+pub mod sidecar;
+pub mod transcode;
+pub mod trash;
 pub mod wallhaven;
 
 use std::sync::Arc;
@@ -48,37 +64,3 @@ impl Default for ConcurrencyController {
 /// 使用LazyLock实现线程安全的延迟初始化
 pub static GLOBAL_CONCURRENCY_CONTROLLER: std::sync::LazyLock<ConcurrencyController> =
     std::sync::LazyLock::new(|| ConcurrencyController::new(5));
-
-/// 下载进度更新消息
-#[derive(Debug, Clone)]
-pub struct DownloadProgressUpdate {
-    pub task_id: usize,
-    pub downloaded: u64,
-    pub total: u64,
-    pub speed: u64,
-}
-
-/// 全局下载进度channel发送器
-pub static DOWNLOAD_PROGRESS_TX: std::sync::OnceLock<tokio::sync::broadcast::Sender<DownloadProgressUpdate>> =
-    std::sync::OnceLock::new();
-
-/// 初始化全局下载进度channel
-pub fn init_download_progress_channel() {
-    DOWNLOAD_PROGRESS_TX.get_or_init(|| {
-        let (tx, _rx) = tokio::sync::broadcast::channel(100);
-        tx
-    });
-}
-
-/// 发送下载进度更新
-pub fn send_download_progress(task_id: usize, downloaded: u64, total: u64, speed: u64) {
-    if let Some(tx) = DOWNLOAD_PROGRESS_TX.get() {
-        let update = DownloadProgressUpdate {
-            task_id,
-            downloaded,
-            total,
-            speed,
-        };
-        let _ = tx.send(update);
-    }
-}
@@ -0,0 +1,73 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 已拥有壁纸查找模块
+//!
+//! 判断在线壁纸是否已经下载到壁纸库中：先检查可能的下载位置，
+//! 再按原图缓存文件的大小和内容哈希查询壁纸库索引（处理被重命名或移动的文件）
+
+use crate::services::database::LibraryRepository;
+use crate::services::local::LocalWallpaperService;
+use crate::utils::helpers;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// 查找一张在线壁纸所需的信息（在 UI 线程中收集，文件检查在后台线程中进行）
+#[derive(Debug, Clone)]
+pub struct OwnedWallpaperQuery {
+    /// 壁纸ID
+    pub id: String,
+    /// 原图文件大小（字节）
+    pub file_size: u64,
+    /// 壁纸可能已下载到的位置（按优先级排序）
+    pub candidate_paths: Vec<PathBuf>,
+    /// 原图缓存文件路径
+    pub cache_file_path: Option<PathBuf>,
+}
+
+/// 查找壁纸库中已有的壁纸
+///
+/// # 参数
+/// - `queries`: 要查找的壁纸
+///
+/// # 返回
+/// 返回已拥有的壁纸ID和已有文件的路径
+pub fn find_owned_wallpapers(queries: &[OwnedWallpaperQuery]) -> HashMap<String, PathBuf> {
+    // 索引不可用时只检查可能的下载位置
+    let repository = LibraryRepository::global()
+        .inspect_err(|e| warn!("[壁纸库] 无法查询壁纸库索引: {}", e))
+        .ok();
+
+    queries
+        .iter()
+        .filter_map(|query| find_owned_wallpaper(query, repository.as_ref()).map(|path| (query.id.clone(), path)))
+        .collect()
+}
+
+fn find_owned_wallpaper(query: &OwnedWallpaperQuery, repository: Option<&LibraryRepository>) -> Option<PathBuf> {
+    let size_matches = |path: &Path| {
+        std::fs::metadata(path)
+            .map(|metadata| metadata.is_file() && metadata.len() == query.file_size)
+            .unwrap_or(false)
+    };
+
+    // 1. 可能的下载位置
+    if let Some(path) = query.candidate_paths.iter().find(|path| size_matches(path)) {
+        return Some(path.clone());
+    }
+
+    // 2. 已缓存原图时，在壁纸库索引中查找大小和内容哈希都相同的文件
+    let repository = repository?;
+    let cache_file_path = query.cache_file_path.as_deref().filter(|path| size_matches(path))?;
+    let cache_hash = LocalWallpaperService::calculate_file_hash(cache_file_path).ok()?;
+    let paths = repository
+        .find_paths_by_content(query.file_size, &cache_hash)
+        .inspect_err(|e| warn!("[壁纸库] 查询壁纸库索引失败: {}", e))
+        .ok()?;
+
+    // 索引可能还没有收到文件删除的通知，确认文件仍然存在
+    paths
+        .into_iter()
+        .find(|path| size_matches(Path::new(&helpers::get_absolute_path(path))))
+        .map(PathBuf::from)
+}
@@ -1,39 +1,201 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 //! 请求上下文模块，提供类似 Golang 中 context 的取消机制
+//!
+//! 上下文可以派生子上下文：取消父上下文时所有子上下文一起取消，子上下文的截止时间不会晚于父上下文。
+//! 异步任务可以等待 `cancelled()`，不需要轮询取消状态
 
-use std::sync::Arc;
+use std::fmt;
+use std::future::Future;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// 上下文结束的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextError {
+    /// 上下文（或其父上下文）被取消
+    Cancelled,
+    /// 超过截止时间
+    DeadlineExceeded,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextError::Cancelled => write!(f, "请求已取消"),
+            ContextError::DeadlineExceeded => write!(f, "请求超时，请检查网络连接或设置代理"),
+        }
+    }
+}
+
+impl std::error::Error for ContextError {}
+
+struct ContextInner {
+    /// 是否已取消
+    cancelled: AtomicBool,
+    /// 截止时间（已合并父上下文的截止时间）
+    deadline: Option<Instant>,
+    /// 取消时唤醒等待 `cancelled()` 的任务
+    notify: Notify,
+    /// 子上下文（不持有子上下文，子上下文全部释放后自动清理）
+    children: Mutex<Vec<Weak<ContextInner>>>,
+}
+
+impl ContextInner {
+    fn cancel(&self) {
+        if self.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        self.notify.notify_waiters();
+
+        let children = match self.children.lock() {
+            Ok(mut children) => std::mem::take(&mut *children),
+            Err(_) => return,
+        };
+        for child in children.iter().filter_map(Weak::upgrade) {
+            child.cancel();
+        }
+    }
+}
 
 /// 请求上下文，用于控制异步请求的生命周期
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct RequestContext {
-    /// 是否已取消
-    cancelled: Arc<AtomicBool>,
+    inner: Arc<ContextInner>,
 }
 
 impl RequestContext {
-    /// 创建一个新的请求上下文
+    /// 创建一个新的根请求上下文（没有截止时间）
     pub fn new() -> Self {
+        Self::with_deadline_inner(None)
+    }
+
+    fn with_deadline_inner(deadline: Option<Instant>) -> Self {
         Self {
-            cancelled: Arc::new(AtomicBool::new(false)),
+            inner: Arc::new(ContextInner {
+                cancelled: AtomicBool::new(false),
+                deadline,
+                notify: Notify::new(),
+                children: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// 派生一个子上下文，取消当前上下文时子上下文一起取消
+    pub fn child(&self) -> Self {
+        self.child_with_deadline_inner(self.inner.deadline)
+    }
+
+    /// 派生一个带超时时间的子上下文
+    ///
+    /// # 参数
+    /// - `timeout`: 从现在开始的超时时间（父上下文的截止时间更早时使用父上下文的截止时间）
+    pub fn child_with_timeout(&self, timeout: Duration) -> Self {
+        self.child_with_deadline(Instant::now() + timeout)
+    }
+
+    /// 派生一个带截止时间的子上下文
+    ///
+    /// # 参数
+    /// - `deadline`: 截止时间（父上下文的截止时间更早时使用父上下文的截止时间）
+    pub fn child_with_deadline(&self, deadline: Instant) -> Self {
+        let deadline = match self.inner.deadline {
+            Some(parent_deadline) => parent_deadline.min(deadline),
+            None => deadline,
+        };
+        self.child_with_deadline_inner(Some(deadline))
+    }
+
+    fn child_with_deadline_inner(&self, deadline: Option<Instant>) -> Self {
+        let child = Self::with_deadline_inner(deadline);
+
+        if let Ok(mut children) = self.inner.children.lock() {
+            // 顺便清理已经释放的子上下文
+            children.retain(|c| c.strong_count() > 0);
+            children.push(Arc::downgrade(&child.inner));
         }
+
+        // 父上下文已取消时（包括在注册过程中被取消），子上下文直接处于取消状态
+        if self.inner.cancelled.load(Ordering::SeqCst) {
+            child.cancel();
+        }
+
+        child
     }
 
-    /// 检查请求是否已被取消
+    /// 检查请求是否已被取消（包括超过截止时间）
     pub fn is_cancelled(&self) -> bool {
-        self.cancelled.load(Ordering::Relaxed)
+        self.error().is_some()
     }
 
-    /// 取消请求
+    /// 返回上下文结束的原因，未结束时返回 None
+    pub fn error(&self) -> Option<ContextError> {
+        if self.inner.cancelled.load(Ordering::SeqCst) {
+            Some(ContextError::Cancelled)
+        } else if self.inner.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            Some(ContextError::DeadlineExceeded)
+        } else {
+            None
+        }
+    }
+
+    /// 取消请求（同时取消所有子上下文）
     pub fn cancel(&self) {
-        self.cancelled.store(true, Ordering::Relaxed);
+        self.inner.cancel();
     }
 
-    /// 检查并返回取消状态，如果已取消则返回 Some(())
-    #[inline]
-    pub fn check_cancelled(&self) -> Option<()> {
-        self.is_cancelled().then(|| ())
+    /// 截止时间
+    pub fn deadline(&self) -> Option<Instant> {
+        self.inner.deadline
+    }
+
+    /// 距离截止时间的剩余时间，没有截止时间时返回 None
+    pub fn remaining(&self) -> Option<Duration> {
+        self.inner
+            .deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// 等待上下文被取消或超过截止时间
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.inner.notify.notified();
+            tokio::pin!(notified);
+            // 先注册等待，再检查状态，避免错过检查之后发生的取消
+            notified.as_mut().enable();
+
+            if self.is_cancelled() {
+                return;
+            }
+
+            match self.inner.deadline {
+                Some(deadline) => {
+                    tokio::select! {
+                        _ = notified => {}
+                        _ = tokio::time::sleep_until(deadline.into()) => {}
+                    }
+                }
+                None => notified.await,
+            }
+        }
+    }
+
+    /// 执行异步操作，上下文被取消或超过截止时间时立即停止
+    ///
+    /// # 返回
+    /// 操作完成返回其结果，被取消返回取消原因
+    pub async fn run_until_cancelled<F: Future>(&self, future: F) -> Result<F::Output, ContextError> {
+        if let Some(e) = self.error() {
+            return Err(e);
+        }
+
+        tokio::select! {
+            biased;
+            _ = self.cancelled() => Err(self.error().unwrap_or(ContextError::Cancelled)),
+            output = future => Ok(output),
+        }
     }
 }
 
@@ -42,3 +204,12 @@ impl Default for RequestContext {
         Self::new()
     }
 }
+
+impl fmt::Debug for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestContext")
+            .field("cancelled", &self.inner.cancelled.load(Ordering::Relaxed))
+            .field("deadline", &self.inner.deadline)
+            .finish()
+    }
+}
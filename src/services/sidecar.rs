@@ -0,0 +1,184 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 壁纸元数据附属文件模块
+//!
+//! 下载完成后在壁纸文件旁写入 `<文件名>.json`（可选 `<文件名>.xmp`），保存 Wallhaven 的壁纸信息，
+//! 壁纸库加载时读取 JSON 文件恢复这些信息
+
+use crate::services::wallhaven::OnlineWallpaper;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// JSON 附属文件的扩展名（追加在壁纸文件名之后）
+const JSON_SIDECAR_EXTENSION: &str = "json";
+/// XMP 附属文件的扩展名（追加在壁纸文件名之后）
+const XMP_SIDECAR_EXTENSION: &str = "xmp";
+
+/// 保存在附属文件中的壁纸元数据
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WallpaperMetadata {
+    /// Wallhaven 壁纸ID
+    #[serde(default)]
+    pub wallhaven_id: String,
+    /// 壁纸详情页面地址
+    #[serde(default)]
+    pub source_url: String,
+    /// 原图下载地址
+    #[serde(default)]
+    pub image_url: String,
+    /// 分类（general / anime / people）
+    #[serde(default)]
+    pub category: String,
+    /// 纯净度（sfw / sketchy / nsfw）
+    #[serde(default)]
+    pub purity: String,
+    /// 分辨率（如 `3840x2160`）
+    #[serde(default)]
+    pub resolution: String,
+    /// 标签
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 主要颜色（如 `#424153`）
+    #[serde(default)]
+    pub colors: Vec<String>,
+    /// 上传者用户名（为空表示未知）
+    #[serde(default)]
+    pub uploader: String,
+    /// 上传时间
+    #[serde(default)]
+    pub uploaded_at: String,
+}
+
+impl WallpaperMetadata {
+    /// 从在线壁纸数据创建元数据
+    pub fn from_wallpaper(wallpaper: &OnlineWallpaper) -> Self {
+        Self {
+            wallhaven_id: wallpaper.id.clone(),
+            source_url: wallpaper.url.clone(),
+            image_url: wallpaper.path.clone(),
+            category: wallpaper.category.clone(),
+            purity: wallpaper.purity.clone(),
+            resolution: wallpaper.resolution.clone(),
+            tags: wallpaper.tags.clone(),
+            colors: wallpaper.colors.clone(),
+            uploader: wallpaper.uploader.clone(),
+            uploaded_at: wallpaper.created_at.clone(),
+        }
+    }
+
+    /// 是否缺少只有壁纸详情接口才返回的信息（搜索结果不包含标签和上传者）
+    pub fn lacks_details(&self) -> bool {
+        !self.wallhaven_id.is_empty() && self.uploader.is_empty()
+    }
+
+    /// 补充壁纸详情中的标签和上传者
+    pub fn merge_details(&mut self, wallpaper: &OnlineWallpaper) {
+        self.tags = wallpaper.tags.clone();
+        self.uploader = wallpaper.uploader.clone();
+    }
+}
+
+/// 获取壁纸文件对应的 JSON 附属文件路径（`wallpaper.jpg` -> `wallpaper.jpg.json`）
+pub fn json_sidecar_path(file_path: &Path) -> PathBuf {
+    sidecar_path(file_path, JSON_SIDECAR_EXTENSION)
+}
+
+/// 获取壁纸文件对应的 XMP 附属文件路径（`wallpaper.jpg` -> `wallpaper.jpg.xmp`）
+pub fn xmp_sidecar_path(file_path: &Path) -> PathBuf {
+    sidecar_path(file_path, XMP_SIDECAR_EXTENSION)
+}
+
+fn sidecar_path(file_path: &Path, extension: &str) -> PathBuf {
+    let mut path = file_path.as_os_str().to_owned();
+    path.push(".");
+    path.push(extension);
+    PathBuf::from(path)
+}
+
+/// 写入壁纸的附属文件
+///
+/// # 参数
+/// - `file_path`: 壁纸文件路径
+/// - `metadata`: 壁纸元数据
+/// - `write_xmp`: 是否同时写入 XMP 附属文件
+///
+/// # 返回
+/// 成功返回 Ok(())，失败返回错误信息
+pub fn write_sidecar(file_path: &Path, metadata: &WallpaperMetadata, write_xmp: bool) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(metadata).map_err(|e| format!("序列化元数据失败: {}", e))?;
+    std::fs::write(json_sidecar_path(file_path), json).map_err(|e| format!("写入元数据文件失败: {}", e))?;
+
+    if write_xmp {
+        std::fs::write(xmp_sidecar_path(file_path), metadata_to_xmp(metadata))
+            .map_err(|e| format!("写入XMP文件失败: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// 读取壁纸的 JSON 附属文件（文件不存在或无法解析时返回 None）
+pub fn read_sidecar(file_path: &Path) -> Option<WallpaperMetadata> {
+    let content = std::fs::read_to_string(json_sidecar_path(file_path)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// 壁纸文件对应的所有附属文件路径（附属文件不一定存在）
+pub fn sidecar_paths(file_path: &Path) -> [PathBuf; 2] {
+    [json_sidecar_path(file_path), xmp_sidecar_path(file_path)]
+}
+
+/// 生成 XMP 附属文件内容（标签写入 dc:subject，详情页面写入 dc:source，上传者写入 dc:creator）
+fn metadata_to_xmp(metadata: &WallpaperMetadata) -> String {
+    let mut description = String::new();
+
+    if !metadata.source_url.is_empty() {
+        description.push_str(&format!(
+            "   <dc:source>{}</dc:source>\n",
+            escape_xml(&metadata.source_url)
+        ));
+    }
+    if !metadata.uploader.is_empty() {
+        description.push_str(&format!(
+            "   <dc:creator>\n    <rdf:Seq>\n     <rdf:li>{}</rdf:li>\n    </rdf:Seq>\n   </dc:creator>\n",
+            escape_xml(&metadata.uploader)
+        ));
+    }
+    if !metadata.tags.is_empty() {
+        description.push_str("   <dc:subject>\n    <rdf:Bag>\n");
+        for tag in &metadata.tags {
+            description.push_str(&format!("     <rdf:li>{}</rdf:li>\n", escape_xml(tag)));
+        }
+        description.push_str("    </rdf:Bag>\n   </dc:subject>\n");
+    }
+
+    format!(
+        concat!(
+            "<?xpacket begin=\"\u{FEFF}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n",
+            "<x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n",
+            " <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n",
+            "  <rdf:Description rdf:about=\"\"\n",
+            "    xmlns:dc=\"http://purl.org/dc/elements/1.1/\"\n",
+            "    xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\"\n",
+            "    xmp:Identifier=\"{}\"\n",
+            "    xmp:Label=\"{}\">\n",
+            "{}",
+            "  </rdf:Description>\n",
+            " </rdf:RDF>\n",
+            "</x:xmpmeta>\n",
+            "<?xpacket end=\"w\"?>\n"
+        ),
+        escape_xml(&metadata.wallhaven_id),
+        escape_xml(&metadata.category),
+        description
+    )
+}
+
+/// 转义 XML 特殊字符
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,249 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 下载后转码模块
+//!
+//! 下载完成后按配置把较大的壁纸转码为 JPEG 或 WebP（有损或无损），转码在有并发限制的阻塞线程池中执行
+
+use crate::services::disk_space::BYTES_PER_MB;
+use crate::services::library_transfer;
+use crate::utils::config::{TranscodeConfig, TranscodeFormat};
+use image::ImageEncoder;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use tokio::sync::Semaphore;
+use tracing::info;
+
+/// 同时进行的转码任务数（解码和编码大图都很占用内存和 CPU）
+static TRANSCODE_WORKERS: LazyLock<Semaphore> = LazyLock::new(|| {
+    let workers = std::thread::available_parallelism()
+        .map(|n| (n.get() / 2).max(1))
+        .unwrap_or(1);
+    Semaphore::new(workers)
+});
+
+/// 转码结果
+#[derive(Debug, Clone)]
+pub struct TranscodeOutcome {
+    /// 转码后的文件路径（未转码时为原路径）
+    pub path: PathBuf,
+    /// 转码后的文件扩展名
+    pub file_type: String,
+    /// 转码后的文件大小（字节）
+    pub size: u64,
+    /// 是否生成了新文件（转码后文件没有变小时保留原文件）
+    pub transcoded: bool,
+}
+
+/// 检查文件是否需要转码
+///
+/// # 参数
+/// - `config`: 转码配置
+/// - `file_path`: 文件路径
+/// - `size`: 文件大小（字节）
+pub fn should_transcode(config: &TranscodeConfig, file_path: &Path, size: u64) -> bool {
+    if config.format == TranscodeFormat::Off {
+        return false;
+    }
+    if size <= config.min_size_mb as u64 * BYTES_PER_MB {
+        return false;
+    }
+
+    // 已经是目标格式的无损 WebP 不需要再转码（JPEG 和有损 WebP 可以按质量设置重新压缩）
+    let extension = file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+    !(config.format == TranscodeFormat::WebpLossless && extension == "webp")
+}
+
+/// 在转码线程池中转码文件
+pub async fn async_transcode(file_path: PathBuf, config: TranscodeConfig) -> Result<TranscodeOutcome, String> {
+    let _permit = TRANSCODE_WORKERS
+        .acquire()
+        .await
+        .map_err(|e| format!("获取转码线程失败: {}", e))?;
+
+    tokio::task::spawn_blocking(move || transcode_file(&file_path, &config))
+        .await
+        .map_err(|e| format!("转码任务执行失败: {}", e))?
+}
+
+/// 转码文件
+///
+/// 先写入临时文件，转码后的文件比原文件小时才替换原文件（或按配置保留原文件）
+///
+/// # 参数
+/// - `file_path`: 原文件路径
+/// - `config`: 转码配置
+///
+/// # 返回
+/// 成功返回转码结果，失败返回错误信息（原文件保持不变）
+pub fn transcode_file(file_path: &Path, config: &TranscodeConfig) -> Result<TranscodeOutcome, String> {
+    let original_size = std::fs::metadata(file_path)
+        .map_err(|e| format!("读取文件信息失败: {}", e))?
+        .len();
+    let extension = config.format.extension();
+    let target_path = target_path(file_path, extension);
+    let temp_path = file_path.with_extension(format!("{}.transcoding", extension));
+
+    let image = image::open(file_path).map_err(|e| format!("解码图片失败: {}", e))?;
+    let result = encode_image(&image, &temp_path, config);
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(e);
+    }
+
+    let transcoded_size = std::fs::metadata(&temp_path)
+        .map_err(|e| format!("读取转码文件信息失败: {}", e))?
+        .len();
+
+    // 转码后没有变小，保留原文件
+    if transcoded_size >= original_size {
+        let _ = std::fs::remove_file(&temp_path);
+        info!(
+            "[转码] 转码后文件没有变小（{} -> {} bytes），保留原文件: {}",
+            original_size,
+            transcoded_size,
+            file_path.display()
+        );
+        return Ok(TranscodeOutcome {
+            path: file_path.to_path_buf(),
+            file_type: file_extension(file_path),
+            size: original_size,
+            transcoded: false,
+        });
+    }
+
+    // 保留原文件时，若目标路径与原文件相同（按质量重新压缩），先把原文件改名
+    if config.keep_original && target_path == file_path {
+        let original_path = original_backup_path(file_path);
+        std::fs::rename(file_path, &original_path).map_err(|e| format!("重命名原文件失败: {}", e))?;
+    }
+
+    std::fs::rename(&temp_path, &target_path).map_err(|e| {
+        let _ = std::fs::remove_file(&temp_path);
+        format!("保存转码文件失败: {}", e)
+    })?;
+
+    if !config.keep_original && target_path != file_path {
+        let _ = std::fs::remove_file(file_path);
+    }
+
+    info!(
+        "[转码] {} -> {} ({} -> {} bytes)",
+        file_path.display(),
+        target_path.display(),
+        original_size,
+        transcoded_size
+    );
+
+    Ok(TranscodeOutcome {
+        file_type: file_extension(&target_path),
+        path: target_path,
+        size: transcoded_size,
+        transcoded: true,
+    })
+}
+
+/// 按目标格式编码图片并写入文件
+fn encode_image(image: &image::DynamicImage, path: &Path, config: &TranscodeConfig) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("创建转码文件失败: {}", e))?;
+    let writer = BufWriter::new(file);
+
+    match config.format {
+        TranscodeFormat::Jpeg => {
+            // JPEG 不支持透明通道
+            let rgb = image.to_rgb8();
+            let quality = config.quality.clamp(1, 100) as u8;
+            image::codecs::jpeg::JpegEncoder::new_with_quality(writer, quality)
+                .write_image(rgb.as_raw(), rgb.width(), rgb.height(), image::ExtendedColorType::Rgb8)
+                .map_err(|e| format!("JPEG 编码失败: {}", e))
+        }
+        TranscodeFormat::Webp => {
+            let rgba = image.to_rgba8();
+            let quality = config.quality.clamp(1, 100) as f32;
+            let encoded = webp::Encoder::from_rgba(rgba.as_raw(), rgba.width(), rgba.height()).encode(quality);
+            let mut writer = writer;
+            writer
+                .write_all(&encoded)
+                .and_then(|_| writer.flush())
+                .map_err(|e| format!("写入转码文件失败: {}", e))
+        }
+        TranscodeFormat::WebpLossless => {
+            let rgba = image.to_rgba8();
+            image::codecs::webp::WebPEncoder::new_lossless(writer)
+                .write_image(
+                    rgba.as_raw(),
+                    rgba.width(),
+                    rgba.height(),
+                    image::ExtendedColorType::Rgba8,
+                )
+                .map_err(|e| format!("WebP 编码失败: {}", e))
+        }
+        TranscodeFormat::Off => Err("未设置转码格式".to_string()),
+    }
+}
+
+/// 转码后的文件路径
+///
+/// 扩展名不变时（`jpg` 和 `jpeg` 视为相同）直接替换原文件并保留原扩展名，否则换成目标扩展名，
+/// 并避开同名的已有文件（例如同一张壁纸的 PNG 和 JPG 转码为同一个 WebP 文件名）
+fn target_path(file_path: &Path, extension: &str) -> PathBuf {
+    if normalized_extension(&file_extension(file_path)) == normalized_extension(extension) {
+        return file_path.to_path_buf();
+    }
+    unique_path(&file_path.with_extension(extension))
+}
+
+/// 保留的原文件路径（`wallpaper.jpg` -> `wallpaper.original.jpg`）
+fn original_backup_path(file_path: &Path) -> PathBuf {
+    let stem = file_path.file_stem().and_then(|s| s.to_str()).unwrap_or("wallpaper");
+    let extension = file_extension(file_path);
+    unique_path(&file_path.with_file_name(format!("{}.original.{}", stem, extension)))
+}
+
+/// 不与已有文件重名的路径
+fn unique_path(path: &Path) -> PathBuf {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let file_name = path.file_name().map(Path::new).unwrap_or(path);
+    dir.join(library_transfer::unique_file_name(dir, file_name))
+}
+
+/// 统一同一格式的不同扩展名写法
+fn normalized_extension(extension: &str) -> &str {
+    match extension {
+        "jpeg" => "jpg",
+        other => other,
+    }
+}
+
+fn file_extension(file_path: &Path) -> String {
+    file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_format_keeps_source_extension() {
+        let dir = Path::new("transcode-test-missing-dir");
+        assert_eq!(target_path(&dir.join("a.jpeg"), "jpg"), dir.join("a.jpeg"));
+        assert_eq!(target_path(&dir.join("a.JPG"), "jpg"), dir.join("a.JPG"));
+        assert_eq!(target_path(&dir.join("a.webp"), "webp"), dir.join("a.webp"));
+    }
+
+    #[test]
+    fn other_format_uses_target_extension() {
+        let dir = Path::new("transcode-test-missing-dir");
+        assert_eq!(target_path(&dir.join("a.png"), "jpg"), dir.join("a.jpg"));
+        assert_eq!(target_path(&dir.join("a.jpeg"), "webp"), dir.join("a.webp"));
+    }
+}
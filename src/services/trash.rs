@@ -0,0 +1,269 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 回收站模块
+//!
+//! 删除本地壁纸时不直接删除文件，而是把壁纸文件和元数据附属文件移动到壁纸所在的壁纸库目录下的
+//! `.trash` 子目录（每张壁纸一个子目录，与壁纸在同一个磁盘上，移动时只需要重命名），
+//! 之后可以撤销删除或在回收站中恢复。扫描壁纸库时跳过 `.trash` 目录。
+//!
+//! 壁纸的标签、收藏和评分在彻底删除前一直保留，恢复后不需要重新设置；
+//! 超过保留天数的壁纸在启动清理缓存时彻底删除
+
+use crate::services::database::{LibraryEntry, TrashItem, TrashRepository, UserMetadataRepository};
+use crate::services::{library, sidecar};
+use crate::utils::config::LibraryRoot;
+use crate::utils::helpers;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use xxhash_rust::xxh3::xxh3_64;
+
+/// 回收站目录名（壁纸库目录下的子目录）
+pub const TRASH_DIR_NAME: &str = ".trash";
+
+/// 壁纸所在的回收站目录
+///
+/// 使用包含壁纸的最内层壁纸库目录下的 `.trash` 子目录，壁纸不在任何壁纸库目录中时使用壁纸所在目录下的
+pub fn trash_dir(file_path: &Path, roots: &[LibraryRoot]) -> PathBuf {
+    let library_root = roots
+        .iter()
+        .map(|root| PathBuf::from(helpers::get_absolute_path(&root.path)))
+        .filter(|root_path| file_path.starts_with(root_path))
+        .max_by_key(|root_path| root_path.components().count());
+    library_root
+        .or_else(|| file_path.parent().map(Path::to_path_buf))
+        .unwrap_or_default()
+        .join(TRASH_DIR_NAME)
+}
+
+/// 批量移到回收站的结果
+#[derive(Debug, Clone, Default)]
+pub struct TrashReport {
+    /// 成功移到回收站的壁纸
+    pub trashed: Vec<TrashItem>,
+    /// 失败的壁纸数量
+    pub failed: usize,
+}
+
+/// 从回收站恢复的结果
+#[derive(Debug, Clone, Default)]
+pub struct RestoreReport {
+    /// 已恢复的回收站记录 ID
+    pub restored_ids: Vec<i64>,
+    /// 已恢复的壁纸重新建立的索引记录
+    pub entries: Vec<LibraryEntry>,
+    /// 恢复失败的数量
+    pub failed: usize,
+    /// 最后一次恢复失败的错误信息
+    pub last_error: Option<String>,
+}
+
+/// 把多张壁纸移动到回收站（单张失败时记录日志并继续处理其余的壁纸）
+///
+/// # 参数
+/// - `paths`: 壁纸文件路径
+/// - `roots`: 壁纸库目录（用于选择回收站目录）
+///
+/// # 返回
+/// 返回成功的回收站记录和失败的数量
+pub fn move_all_to_trash(paths: &[String], roots: &[LibraryRoot]) -> TrashReport {
+    let mut report = TrashReport::default();
+    for path in paths {
+        match move_to_trash(path, roots) {
+            Ok(item) => report.trashed.push(item),
+            Err(e) => {
+                warn!("[回收站] 移动到回收站失败: {}, 错误: {}", path, e);
+                report.failed += 1;
+            }
+        }
+    }
+    report
+}
+
+/// 把壁纸（连同附属文件）移动到回收站，并从壁纸库索引中移除
+///
+/// # 参数
+/// - `path`: 壁纸文件路径（与壁纸库索引中的路径一致）
+/// - `roots`: 壁纸库目录（用于选择回收站目录，见 [`trash_dir`]）
+///
+/// # 返回
+/// 成功返回回收站记录，失败返回错误信息（失败时文件保持原样）
+pub fn move_to_trash(path: &str, roots: &[LibraryRoot]) -> Result<TrashItem, String> {
+    let full_path = PathBuf::from(helpers::get_absolute_path(path));
+    let file_name = full_path
+        .file_name()
+        .ok_or_else(|| format!("无效的文件路径: {}", path))?
+        .to_owned();
+    let file_size = fs::metadata(&full_path)
+        .map_err(|e| format!("读取文件信息失败: {}", e))?
+        .len();
+
+    // 子目录以删除时间和原路径哈希命名，同名文件多次删除也不会冲突
+    let now = chrono::Local::now();
+    let item_dir =
+        trash_dir(&full_path, roots).join(format!("{}-{:016x}", now.timestamp_millis(), xxh3_64(path.as_bytes())));
+    fs::create_dir_all(&item_dir).map_err(|e| format!("创建回收站目录失败: {}", e))?;
+
+    if let Err(e) = helpers::move_file(&full_path, &item_dir.join(&file_name)) {
+        let _ = fs::remove_dir(&item_dir);
+        return Err(format!("移动到回收站失败: {}", e));
+    }
+    for sidecar_path in sidecar::sidecar_paths(&full_path) {
+        let Some(sidecar_name) = sidecar_path.file_name() else {
+            continue;
+        };
+        if sidecar_path.exists() {
+            let result = helpers::move_file(&sidecar_path, &item_dir.join(sidecar_name));
+            if let Err(e) = result {
+                warn!("[回收站] 移动附属文件失败: {}, 错误: {}", sidecar_path.display(), e);
+            }
+        }
+    }
+
+    let mut item = TrashItem {
+        id: 0,
+        original_path: path.to_string(),
+        trash_path: item_dir.to_string_lossy().to_string(),
+        file_name: file_name.to_string_lossy().to_string(),
+        file_size,
+        deleted_at: now.timestamp(),
+    };
+    match TrashRepository::global().and_then(|repository| repository.insert(&item)) {
+        Ok(id) => item.id = id,
+        Err(e) => {
+            // 没有记录就无法恢复，把文件移回原位置
+            let _ = move_back(&item_dir, &full_path);
+            return Err(e);
+        }
+    }
+
+    library::remove_from_index(path);
+    info!("[回收站] 已移动到回收站: {} -> {}", path, item.trash_path);
+    Ok(item)
+}
+
+/// 把回收站中的壁纸恢复到原位置
+///
+/// # 参数
+/// - `item`: 回收站记录
+///
+/// # 返回
+/// 成功返回 Ok(())，原位置已存在同名文件或移动失败时返回错误信息
+pub fn restore(item: &TrashItem) -> Result<(), String> {
+    let original_path = PathBuf::from(helpers::get_absolute_path(&item.original_path));
+    if original_path.exists() {
+        return Err(format!("原位置已存在同名文件: {}", item.original_path));
+    }
+
+    move_back(Path::new(&item.trash_path), &original_path)?;
+    TrashRepository::global().and_then(|repository| repository.delete(&[item.id]))?;
+
+    info!("[回收站] 已恢复: {}", item.original_path);
+    Ok(())
+}
+
+/// 把多张回收站中的壁纸恢复到原位置，并为恢复的壁纸重新建立索引（单张失败时继续处理其余的壁纸）
+///
+/// # 参数
+/// - `ids`: 回收站记录 ID
+/// - `cache_path`: 缓存目录（保存缩略图）
+///
+/// # 返回
+/// 返回恢复结果，无法读取回收站记录时返回错误信息
+pub fn restore_all(ids: &[i64], cache_path: &Path) -> Result<RestoreReport, String> {
+    let items = TrashRepository::global().and_then(|repository| repository.load(ids))?;
+
+    let mut report = RestoreReport::default();
+    let mut restored_paths = Vec::new();
+    for item in items {
+        match restore(&item) {
+            Ok(()) => {
+                report.restored_ids.push(item.id);
+                restored_paths.push(item.original_path);
+            }
+            Err(e) => {
+                warn!("[回收站] 恢复失败: {}, 错误: {}", item.original_path, e);
+                report.failed += 1;
+                report.last_error = Some(e);
+            }
+        }
+    }
+
+    // 移到回收站时删除了索引记录，不依赖文件监听，直接重新建立（失败时文件监听或下次扫描时再建立）
+    if !restored_paths.is_empty() {
+        match library::apply_changes(&restored_paths, &[], cache_path) {
+            Ok(entries) => report.entries = entries,
+            Err(e) => warn!("[回收站] 为恢复的壁纸建立索引失败: {}", e),
+        }
+    }
+    Ok(report)
+}
+
+/// 彻底删除回收站中的壁纸
+///
+/// 原位置没有同名文件时同时删除壁纸的标签、收藏和评分
+///
+/// # 参数
+/// - `items`: 回收站记录
+///
+/// # 返回
+/// 成功返回 Ok(())，失败返回错误信息
+pub fn purge(items: &[TrashItem]) -> Result<(), String> {
+    for item in items {
+        // 目录已经不存在（例如被手动删除）时只删除记录
+        match fs::remove_dir_all(&item.trash_path) {
+            Err(e) if Path::new(&item.trash_path).exists() => return Err(format!("删除文件失败: {}", e)),
+            _ => {}
+        }
+    }
+
+    let ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+    TrashRepository::global().and_then(|repository| repository.delete(&ids))?;
+
+    let orphaned: Vec<String> = items
+        .iter()
+        .filter(|item| !Path::new(&helpers::get_absolute_path(&item.original_path)).exists())
+        .map(|item| item.original_path.clone())
+        .collect();
+    UserMetadataRepository::global().and_then(|repository| repository.delete(&orphaned))?;
+
+    info!("[回收站] 已彻底删除 {} 张壁纸", items.len());
+    Ok(())
+}
+
+/// 彻底删除超过保留天数的壁纸
+///
+/// # 参数
+/// - `retention_days`: 保留天数（0 表示不自动删除）
+///
+/// # 返回
+/// 成功返回删除的壁纸数量，失败返回错误信息
+pub fn purge_expired(retention_days: u32) -> Result<usize, String> {
+    if retention_days == 0 {
+        return Ok(0);
+    }
+
+    let before = chrono::Local::now().timestamp() - retention_days as i64 * 24 * 60 * 60;
+    let items = TrashRepository::global().and_then(|repository| repository.load_deleted_before(before))?;
+    if !items.is_empty() {
+        purge(&items)?;
+    }
+    Ok(items.len())
+}
+
+/// 把回收站子目录中的所有文件（壁纸和附属文件）移动到原壁纸所在的目录，然后删除该子目录
+fn move_back(item_dir: &Path, original_path: &Path) -> Result<(), String> {
+    let target_dir = original_path
+        .parent()
+        .ok_or_else(|| format!("无效的文件路径: {}", original_path.display()))?;
+    fs::create_dir_all(target_dir).map_err(|e| format!("创建目录失败: {}", e))?;
+
+    let entries = fs::read_dir(item_dir).map_err(|e| format!("读取回收站目录失败: {}", e))?;
+    for entry in entries.flatten() {
+        helpers::move_file(&entry.path(), &target_dir.join(entry.file_name()))
+            .map_err(|e| format!("恢复文件失败: {}", e))?;
+    }
+
+    let _ = fs::remove_dir(item_dir);
+    Ok(())
+}
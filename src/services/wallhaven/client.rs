@@ -179,8 +179,7 @@ impl WallhavenClient {
     /// # 参数
     /// - `url`: 请求 URL
     /// - `identifier`: 请求标识符（用于日志）
-    /// - `context`: 请求上下文（用于取消操作）
-    /// - `timeout_secs`: 可选的超时时间（秒），None 表示不设置超时
+    /// - `context`: 请求上下文（用于取消操作，上下文的截止时间作为请求的超时时间）
     ///
     /// # 返回
     /// 返回响应文本或错误信息
@@ -189,31 +188,27 @@ impl WallhavenClient {
         url: String,
         identifier: String,
         context: &RequestContext,
-        timeout_secs: Option<u64>,
     ) -> Result<String, String> {
         // 检查取消状态
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
+        if let Some(e) = context.error() {
+            return Err(e.to_string());
         }
 
-        // 构建请求
+        // 构建请求，上下文有截止时间时请求不超过剩余时间
         let request = self.client.get(&url);
-
-        // 应用超时设置（如果指定）
-        let request = if let Some(timeout) = timeout_secs {
-            request.timeout(std::time::Duration::from_secs(timeout))
-        } else {
-            request
+        let request = match context.remaining() {
+            Some(remaining) => request.timeout(remaining),
+            None => request,
         };
 
-        let response = request.send().await.map_err(|e| {
+        let response = context
+            .run_until_cancelled(request.send())
+            .await
+            .map_err(|e| e.to_string())?;
+        let response = response.map_err(|e| {
             // 检查是否是超时错误
             if e.is_timeout() {
-                error!(
-                    "[Wallhaven API] [{}] 请求超时（{}秒）",
-                    identifier,
-                    timeout_secs.unwrap_or(0)
-                );
+                error!("[Wallhaven API] [{}] 请求超时", identifier);
                 format!("请求超时，请检查网络连接或设置代理")
             } else if e.is_connect() {
                 error!("[Wallhaven API] [{}] 连接失败: {}", identifier, e);
@@ -230,10 +225,14 @@ impl WallhavenClient {
             return Err(format!("API返回错误: {}", response.status()));
         }
 
-        response.text().await.map_err(|e| {
-            error!("[Wallhaven API] [{}] 读取响应失败: {}", identifier, e);
-            format!("读取响应失败: {}", e)
-        })
+        context
+            .run_until_cancelled(response.text())
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| {
+                error!("[Wallhaven API] [{}] 读取响应失败: {}", identifier, e);
+                format!("读取响应失败: {}", e)
+            })
     }
 
     /// 执行 HTTP GET 请求（带重试）
@@ -241,47 +240,38 @@ impl WallhavenClient {
     /// # 参数
     /// - `url`: 请求 URL
     /// - `identifier`: 请求标识符（用于日志）
-    /// - `context`: 请求上下文（用于取消操作）
-    /// - `timeout_secs`: 可选的超时时间（秒），None 表示不设置超时
+    /// - `context`: 请求上下文（用于取消操作，上下文的截止时间作为所有重试的总超时时间）
     ///
     /// # 返回
     /// 返回响应文本或错误信息
-    pub async fn get(
-        &self,
-        url: String,
-        identifier: String,
-        context: &RequestContext,
-        timeout_secs: Option<u64>,
-    ) -> Result<String, String> {
-        Self::retry_with_backoff(&identifier, "HTTP GET", 3, || {
+    pub async fn get(&self, url: String, identifier: String, context: &RequestContext) -> Result<String, String> {
+        // 重试等待期间也要响应取消
+        let retry = Self::retry_with_backoff(&identifier, "HTTP GET", 3, || {
             let client = self.client.clone();
             let url = url.clone();
             let identifier = identifier.clone();
             let context = context.clone();
             async move {
                 // 每次重试前检查取消状态
-                if let Some(()) = context.check_cancelled() {
-                    return Err("请求已取消".to_string());
+                if let Some(e) = context.error() {
+                    return Err(e.to_string());
                 }
 
-                // 构建请求
+                // 构建请求，上下文有截止时间时请求不超过剩余时间
                 let request = client.get(&url);
-
-                // 应用超时设置（如果指定）
-                let request = if let Some(timeout) = timeout_secs {
-                    request.timeout(std::time::Duration::from_secs(timeout))
-                } else {
-                    request
+                let request = match context.remaining() {
+                    Some(remaining) => request.timeout(remaining),
+                    None => request,
                 };
 
-                let response = request.send().await.map_err(|e| {
+                let response = context
+                    .run_until_cancelled(request.send())
+                    .await
+                    .map_err(|e| e.to_string())?;
+                let response = response.map_err(|e| {
                     // 检查是否是超时错误
                     if e.is_timeout() {
-                        error!(
-                            "[Wallhaven API] [{}] 请求超时（{}秒）",
-                            identifier,
-                            timeout_secs.unwrap_or(0)
-                        );
+                        error!("[Wallhaven API] [{}] 请求超时", identifier);
                         format!("请求超时，请检查网络连接或设置代理")
                     } else if e.is_connect() {
                         error!("[Wallhaven API] [{}] 连接失败: {}", identifier, e);
@@ -298,13 +288,18 @@ impl WallhavenClient {
                     return Err(format!("API返回错误: {}", response.status()));
                 }
 
-                response.text().await.map_err(|e| {
-                    error!("[Wallhaven API] [{}] 读取响应失败: {}", identifier, e);
-                    format!("读取响应失败: {}", e)
-                })
+                context
+                    .run_until_cancelled(response.text())
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map_err(|e| {
+                        error!("[Wallhaven API] [{}] 读取响应失败: {}", identifier, e);
+                        format!("读取响应失败: {}", e)
+                    })
             }
-        })
-        .await
+        });
+
+        context.run_until_cancelled(retry).await.map_err(|e| e.to_string())?
     }
 
     /// 获取客户端引用
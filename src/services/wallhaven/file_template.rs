@@ -0,0 +1,344 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 下载文件名模板模块
+//!
+//! 根据模板和壁纸信息生成下载文件的相对保存路径，模板中的 `/` 表示子目录。
+//!
+//! 支持的占位符：
+//! - `{id}` `{ext}`：壁纸ID、文件扩展名
+//! - `{category}` `{purity}`：分类、纯净度
+//! - `{resolution}` `{width}` `{height}` `{ratio}`：分辨率、宽、高、比例
+//! - `{first_tag}` `{tags}`：第一个标签、前三个标签
+//! - `{color}` `{colors}`：主色调、全部颜色（不含 `#`）
+//! - `{date}` `{year}` `{month}`：下载日期
+//! - `{upload_date}`：上传日期
+//! - `{uploader}`：上传者
+//!
+//! 搜索结果不包含标签和上传者，模板用到这些占位符时需要先获取壁纸详情（见 [`template_needs_details`]）
+
+use super::OnlineWallpaper;
+use std::path::PathBuf;
+
+/// 默认文件名模板（与旧版本的命名方式保持一致）
+pub const DEFAULT_FILE_NAME_TEMPLATE: &str = "wallhaven-{id}.{ext}";
+
+/// 占位符缺少对应数据时使用的值
+const UNKNOWN_VALUE: &str = "unknown";
+
+/// 需要壁纸详情才能填充的占位符
+const DETAIL_PLACEHOLDERS: [&str; 3] = ["{first_tag}", "{tags}", "{uploader}"];
+
+/// 单个路径片段的最大长度
+const MAX_COMPONENT_LEN: usize = 120;
+
+/// Windows 保留的设备文件名
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9", "LPT1", "LPT2",
+    "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// 模板是否用到了需要壁纸详情的占位符（标签、上传者）
+pub fn template_needs_details(template: &str) -> bool {
+    DETAIL_PLACEHOLDERS
+        .iter()
+        .any(|placeholder| template.contains(placeholder))
+}
+
+/// 根据模板生成下载文件的相对路径
+///
+/// # 参数
+/// - `template`: 文件名模板，为空时使用默认模板
+/// - `wallpaper`: 在线壁纸信息
+///
+/// # 返回
+/// 相对于数据目录的文件路径，所有片段都已去除不安全字符
+pub fn render_file_template(template: &str, wallpaper: &OnlineWallpaper) -> PathBuf {
+    let template = if template.trim().is_empty() {
+        DEFAULT_FILE_NAME_TEMPLATE
+    } else {
+        template.trim()
+    };
+
+    let mut path = PathBuf::new();
+    for component in template.split(['/', '\\']) {
+        let rendered = sanitize_component(&render_component(component, wallpaper));
+        if !rendered.is_empty() {
+            path.push(rendered);
+        }
+    }
+
+    // 模板渲染结果为空时回退到默认文件名
+    if path.as_os_str().is_empty() {
+        path.push(super::generate_file_name(
+            &wallpaper.id,
+            &file_extension(&wallpaper.file_type),
+        ));
+    }
+
+    path
+}
+
+/// 从 MIME 类型中提取文件扩展名（如 image/jpeg -> jpeg）
+pub fn file_extension(file_type: &str) -> String {
+    file_type.split('/').next_back().unwrap_or("jpg").to_string()
+}
+
+/// 替换单个路径片段中的占位符
+fn render_component(component: &str, wallpaper: &OnlineWallpaper) -> String {
+    let mut result = String::with_capacity(component.len());
+    let mut rest = component;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) => {
+                let key = &after[..end];
+                match placeholder_value(key, wallpaper) {
+                    Some(value) => result.push_str(&sanitize_value(&value)),
+                    // 未知占位符原样保留
+                    None => {
+                        result.push('{');
+                        result.push_str(key);
+                        result.push('}');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                result.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// 获取占位符对应的值
+fn placeholder_value(key: &str, wallpaper: &OnlineWallpaper) -> Option<String> {
+    let non_empty = |value: &str| {
+        if value.trim().is_empty() {
+            UNKNOWN_VALUE.to_string()
+        } else {
+            value.trim().to_string()
+        }
+    };
+    let strip_hash = |color: &String| color.trim_start_matches('#').to_string();
+    let today = chrono::Local::now();
+
+    let value = match key {
+        "id" => non_empty(&wallpaper.id),
+        "ext" => file_extension(&wallpaper.file_type),
+        "category" => non_empty(&wallpaper.category),
+        "purity" => non_empty(&wallpaper.purity),
+        "resolution" => non_empty(&wallpaper.resolution),
+        "width" => wallpaper.width.to_string(),
+        "height" => wallpaper.height.to_string(),
+        "ratio" => non_empty(&wallpaper.ratio),
+        "first_tag" => non_empty(wallpaper.tags.first().map(String::as_str).unwrap_or("")),
+        "tags" => non_empty(&wallpaper.tags.iter().take(3).cloned().collect::<Vec<_>>().join("-")),
+        "color" => non_empty(&wallpaper.colors.first().map(strip_hash).unwrap_or_default()),
+        "colors" => non_empty(&wallpaper.colors.iter().map(strip_hash).collect::<Vec<_>>().join("-")),
+        "date" => today.format("%Y-%m-%d").to_string(),
+        "year" => today.format("%Y").to_string(),
+        "month" => today.format("%m").to_string(),
+        // created_at 格式为 "2024-01-01 12:00:00"，只取日期部分
+        "upload_date" => non_empty(wallpaper.created_at.split_whitespace().next().unwrap_or("")),
+        "uploader" => non_empty(&wallpaper.uploader),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// 去除占位符值中的路径分隔符，避免意外创建子目录
+fn sanitize_value(value: &str) -> String {
+    value.replace(['/', '\\'], "_")
+}
+
+/// 去除路径片段中的不安全字符
+fn sanitize_component(component: &str) -> String {
+    let cleaned: String = component
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    // Windows 不允许以点或空格结尾
+    let cleaned = cleaned.trim().trim_end_matches(['.', ' ']).to_string();
+
+    // 禁止 "." 和 ".." 等相对路径片段
+    if cleaned.is_empty() || cleaned.chars().all(|c| c == '.') {
+        return String::new();
+    }
+
+    let stem = cleaned.split('.').next().unwrap_or("").to_uppercase();
+    let cleaned = if RESERVED_NAMES.contains(&stem.as_str()) {
+        format!("_{}", cleaned)
+    } else {
+        cleaned
+    };
+
+    // 限制片段长度（按字符截断，保留扩展名）
+    if cleaned.chars().count() > MAX_COMPONENT_LEN {
+        let (stem, ext) = match cleaned.rfind('.') {
+            Some(dot) if dot > 0 && cleaned.len() - dot <= 6 => (&cleaned[..dot], &cleaned[dot..]),
+            _ => (cleaned.as_str(), ""),
+        };
+        let keep = MAX_COMPONENT_LEN.saturating_sub(ext.chars().count());
+        return format!("{}{}", stem.chars().take(keep).collect::<String>(), ext);
+    }
+
+    cleaned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallpaper() -> OnlineWallpaper {
+        OnlineWallpaper {
+            id: "abc123".to_string(),
+            url: String::new(),
+            path: String::new(),
+            thumb_large: String::new(),
+            thumb_original: String::new(),
+            thumb_small: String::new(),
+            width: 3840,
+            height: 2160,
+            resolution: "3840x2160".to_string(),
+            ratio: "1.78".to_string(),
+            file_size: 0,
+            file_type: "image/png".to_string(),
+            category: "anime".to_string(),
+            purity: "sfw".to_string(),
+            views: 0,
+            favorites: 0,
+            colors: vec!["#424153".to_string(), "#ffffff".to_string()],
+            tags: Vec::new(),
+            created_at: "2024-01-02 12:00:00".to_string(),
+            uploader: String::new(),
+            image_handle: None,
+        }
+    }
+
+    #[test]
+    fn default_template() {
+        let wallpaper = wallpaper();
+        assert_eq!(
+            render_file_template("", &wallpaper),
+            PathBuf::from("wallhaven-abc123.png")
+        );
+        assert_eq!(
+            render_file_template("  ", &wallpaper),
+            PathBuf::from("wallhaven-abc123.png")
+        );
+    }
+
+    #[test]
+    fn placeholders_and_subdirectories() {
+        let wallpaper = wallpaper();
+        assert_eq!(
+            render_file_template("{category}/{resolution}/{id}_{color}.{ext}", &wallpaper),
+            ["anime", "3840x2160", "abc123_424153.png"].iter().collect::<PathBuf>()
+        );
+        assert_eq!(
+            render_file_template("{upload_date}\\{colors}-{width}x{height}.{ext}", &wallpaper),
+            ["2024-01-02", "424153-ffffff-3840x2160.png"]
+                .iter()
+                .collect::<PathBuf>()
+        );
+    }
+
+    #[test]
+    fn detail_placeholders() {
+        let mut wallpaper = wallpaper();
+        assert_eq!(
+            render_file_template("{uploader}/{first_tag}-{tags}.{ext}", &wallpaper),
+            ["unknown", "unknown-unknown.png"].iter().collect::<PathBuf>()
+        );
+
+        wallpaper.tags = ["landscape", "nature", "sky", "sea"].map(String::from).to_vec();
+        wallpaper.uploader = "wallhaven".to_string();
+        assert_eq!(
+            render_file_template("{uploader}/{first_tag}-{tags}.{ext}", &wallpaper),
+            ["wallhaven", "landscape-landscape-nature-sky.png"]
+                .iter()
+                .collect::<PathBuf>()
+        );
+    }
+
+    #[test]
+    fn detect_detail_placeholders() {
+        assert!(template_needs_details("{uploader}/{id}.{ext}"));
+        assert!(template_needs_details("{tags}.{ext}"));
+        assert!(!template_needs_details(DEFAULT_FILE_NAME_TEMPLATE));
+        assert!(!template_needs_details("{category}/{id}.{ext}"));
+    }
+
+    #[test]
+    fn unknown_and_missing_values() {
+        let mut wallpaper = wallpaper();
+        wallpaper.category = " ".to_string();
+        wallpaper.colors.clear();
+        assert_eq!(
+            render_file_template("{category}-{color}-{nope}-{id", &wallpaper),
+            PathBuf::from("unknown-unknown-{nope}-{id")
+        );
+    }
+
+    #[test]
+    fn values_cannot_create_directories() {
+        let mut wallpaper = wallpaper();
+        wallpaper.ratio = "16/9".to_string();
+        assert_eq!(
+            render_file_template("{ratio}.{ext}", &wallpaper),
+            PathBuf::from("16_9.png")
+        );
+    }
+
+    #[test]
+    fn relative_components_are_dropped() {
+        let wallpaper = wallpaper();
+        assert_eq!(
+            render_file_template("../../{id}.{ext}", &wallpaper),
+            PathBuf::from("abc123.png")
+        );
+        assert_eq!(
+            render_file_template("./..", &wallpaper),
+            PathBuf::from("wallhaven-abc123.png")
+        );
+    }
+
+    #[test]
+    fn sanitize_invalid_characters() {
+        assert_eq!(sanitize_component("a<b>c:d\"e|f?g*h"), "a_b_c_d_e_f_g_h");
+        assert_eq!(sanitize_component("tab\there"), "tab_here");
+        assert_eq!(sanitize_component(" name. . "), "name");
+        assert_eq!(sanitize_component(".."), "");
+        assert_eq!(sanitize_component(""), "");
+    }
+
+    #[test]
+    fn sanitize_reserved_names() {
+        assert_eq!(sanitize_component("CON"), "_CON");
+        assert_eq!(sanitize_component("nul.png"), "_nul.png");
+        assert_eq!(sanitize_component("console.png"), "console.png");
+    }
+
+    #[test]
+    fn sanitize_truncates_long_components() {
+        let long_name = format!("{}.jpeg", "a".repeat(200));
+        let truncated = sanitize_component(&long_name);
+        assert_eq!(truncated.chars().count(), MAX_COMPONENT_LEN);
+        assert!(truncated.ends_with(".jpeg"));
+
+        let long_dir = "目".repeat(200);
+        assert_eq!(sanitize_component(&long_dir).chars().count(), MAX_COMPONENT_LEN);
+    }
+}
@@ -6,6 +6,46 @@ pub fn generate_file_name(id: &str, file_type: &str) -> String {
     format!("wallhaven-{}.{}", id, file_type)
 }
 
+/// 从文本中提取 Wallhaven 壁纸ID（按出现顺序去重）
+///
+/// 支持以下格式，多个条目之间可用空白、逗号、分号或引号分隔（可直接读取 .txt / .csv 文件内容）：
+/// - 壁纸页面链接：`https://wallhaven.cc/w/8586my`、`https://whvn.cc/8586my`
+/// - 原图链接或文件名：`https://w.wallhaven.cc/full/85/wallhaven-8586my.jpg`
+/// - 单独的壁纸ID：`8586my`（6 位小写字母或数字，且至少包含一个数字，避免误识别普通单词）
+pub fn extract_wallpaper_ids(text: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+
+    let tokens = text.split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '"' | '\'' | '<' | '>' | '(' | ')'));
+    for token in tokens.filter(|token| !token.is_empty()) {
+        let id = if let Some(pos) = token.rfind("wallhaven-") {
+            leading_id(&token[pos + "wallhaven-".len()..])
+        } else if let Some(pos) = token.find("wallhaven.cc/w/") {
+            leading_id(&token[pos + "wallhaven.cc/w/".len()..])
+        } else if let Some(pos) = token.find("whvn.cc/") {
+            leading_id(&token[pos + "whvn.cc/".len()..])
+        } else if token.len() == 6
+            && token.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && token.chars().any(|c| c.is_ascii_digit())
+        {
+            Some(token)
+        } else {
+            None
+        };
+
+        if let Some(id) = id.filter(|id| !ids.iter().any(|existing| existing == id)) {
+            ids.push(id.to_string());
+        }
+    }
+
+    ids
+}
+
+/// 取字符串开头连续的字母数字作为壁纸ID
+fn leading_id(text: &str) -> Option<&str> {
+    let end = text.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(text.len());
+    (end > 0).then(|| &text[..end])
+}
+
 /// 解析分类位掩码
 pub fn parse_category_bitmask(category: &str) -> u32 {
     let mut result = 0u32;
@@ -95,3 +135,33 @@ pub fn parse_time_range(time_range: &str) -> TimeRange {
         _ => TimeRange::Month,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_ids_from_links_and_file_names() {
+        let text = "https://wallhaven.cc/w/8586my\nhttps://whvn.cc/zyx9qk?x=1\n\
+                    https://w.wallhaven.cc/full/85/wallhaven-p2o3m5.jpg\nC:\\walls\\wallhaven-abcd12.png";
+        assert_eq!(extract_wallpaper_ids(text), ["8586my", "zyx9qk", "p2o3m5", "abcd12"]);
+    }
+
+    #[test]
+    fn extract_bare_ids_with_separators() {
+        assert_eq!(
+            extract_wallpaper_ids("\"8586my\",l83o92;  (k7q1d3)"),
+            ["8586my", "l83o92", "k7q1d3"]
+        );
+    }
+
+    #[test]
+    fn extract_ids_skips_words_and_duplicates() {
+        assert_eq!(
+            extract_wallpaper_ids("sunset forest 8586MY 1234567 8586my https://wallhaven.cc/w/8586my"),
+            ["8586my"]
+        );
+        assert!(extract_wallpaper_ids("").is_empty());
+        assert!(extract_wallpaper_ids("https://wallhaven.cc/w/").is_empty());
+    }
+}
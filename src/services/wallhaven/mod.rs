@@ -6,15 +6,19 @@
 //! - 数据模型（models）：Category, Sorting, Purity, Resolution, Ratio, ColorOption, TimeRange
 //! - API 类型（types）：API 响应数据结构
 //! - HTTP 客户端（client）：HTTP 请求处理和重试逻辑
+//! - 频率限制（rate_limit）：批量请求时遵守 API 的请求频率限制
 //! - 服务层（service）：Wallhaven API 服务接口
 
 pub mod client;
+pub mod file_template;
 pub mod helper;
 pub mod model;
+pub mod rate_limit;
 pub mod service;
 pub mod types;
 
 // 重新导出常用类型
+pub use file_template::*;
 pub use helper::*;
 pub use model::*;
 pub use service::WallhavenService;
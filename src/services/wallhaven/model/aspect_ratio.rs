@@ -89,7 +89,8 @@ impl std::fmt::Display for AspectRatio {
 }
 
 // 比例分组
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AspectRatioGroup {
     Wide,
     Ultrawide,
@@ -98,6 +99,36 @@ pub enum AspectRatioGroup {
 }
 
 impl AspectRatioGroup {
+    pub fn all() -> [AspectRatioGroup; 4] {
+        [
+            AspectRatioGroup::Wide,
+            AspectRatioGroup::Ultrawide,
+            AspectRatioGroup::Portrait,
+            AspectRatioGroup::Square,
+        ]
+    }
+
+    /// 按图片尺寸判断比例分组（比例介于两组之间时归入较近的一组）
+    pub fn from_size(width: u32, height: u32) -> Option<AspectRatioGroup> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let ratio = width as f64 / height as f64;
+        Some(if ratio < 0.9 {
+            // 9x16、10x16、9x18
+            AspectRatioGroup::Portrait
+        } else if ratio < 1.55 {
+            // 1x1、5x4、4x3、3x2
+            AspectRatioGroup::Square
+        } else if ratio < 2.0 {
+            // 16x10、16x9
+            AspectRatioGroup::Wide
+        } else {
+            // 21x9、32x9、48x9
+            AspectRatioGroup::Ultrawide
+        })
+    }
+
     pub fn display_name(&self) -> &'static str {
         match self {
             AspectRatioGroup::Wide => "online-wallpapers.ratio-group-wide",
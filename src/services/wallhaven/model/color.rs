@@ -105,6 +105,12 @@ impl ColorOption {
             ColorOption::Color424153 => "424153",
         }
     }
+
+    /// 颜色的 RGB 值（Any 返回 None）
+    pub fn rgb(&self) -> Option<[u8; 3]> {
+        let value = u32::from_str_radix(self.value(), 16).ok()?;
+        Some([(value >> 16) as u8, (value >> 8) as u8, value as u8])
+    }
 }
 
 impl std::fmt::Display for ColorOption {
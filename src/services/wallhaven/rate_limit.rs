@@ -0,0 +1,58 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! Wallhaven API 请求频率限制模块
+//!
+//! Wallhaven API 限制每分钟最多 45 次请求，批量请求（如批量下载搜索结果）前需要先获取请求时间片
+
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Wallhaven API 每分钟允许的最大请求数
+pub const REQUESTS_PER_MINUTE: u32 = 45;
+
+/// 触发频率限制（HTTP 429）后的等待时间
+pub const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(60);
+
+/// 全局 Wallhaven API 频率限制器
+pub static WALLHAVEN_RATE_LIMITER: LazyLock<RateLimiter> = LazyLock::new(|| RateLimiter::new(REQUESTS_PER_MINUTE));
+
+/// 请求频率限制器：保证相邻两次请求之间至少间隔固定时间
+pub struct RateLimiter {
+    /// 相邻两次请求的最小间隔
+    interval: Duration,
+    /// 下一个可用的请求时间
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// 创建频率限制器
+    ///
+    /// # 参数
+    /// - `requests_per_minute`: 每分钟允许的最大请求数
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(60) / requests_per_minute.max(1),
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// 等待直到可以发送下一次请求
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let slot = next_slot.map_or(now, |slot| slot.max(now));
+            *next_slot = Some(slot + self.interval);
+            slot.saturating_duration_since(now)
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 判断错误是否由 Wallhaven API 频率限制（HTTP 429）引起
+pub fn is_rate_limited_error(error: &str) -> bool {
+    error.contains("API返回错误: 429")
+}
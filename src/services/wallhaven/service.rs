@@ -8,8 +8,12 @@ use super::client::WallhavenClient;
 use super::model::{ColorOption, Sorting, TimeRange};
 use super::types::{OnlineWallpaper, WallhavenResponse, WallpaperData};
 use crate::services::request_context::RequestContext;
+use std::time::Duration;
 use tracing::{debug, error, info};
 
+/// 搜索请求的超时时间
+const SEARCH_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Wallhaven 服务
 pub struct WallhavenService {
     client: WallhavenClient,
@@ -100,17 +104,15 @@ impl WallhavenService {
         context: &RequestContext,
     ) -> Result<(Vec<OnlineWallpaper>, bool, usize, usize), String> {
         // 检查是否已取消
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
+        if let Some(e) = context.error() {
+            return Err(e.to_string());
         }
 
-        // 获取并发控制许可
-        let _permit = crate::services::GLOBAL_CONCURRENCY_CONTROLLER.acquire().await;
-
-        // 再次检查是否已取消
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
-        }
+        // 获取并发控制许可（等待期间被取消时直接返回）
+        let _permit = context
+            .run_until_cancelled(crate::services::GLOBAL_CONCURRENCY_CONTROLLER.acquire())
+            .await
+            .map_err(|e| e.to_string())?;
 
         // 构建搜索 URL
         let url = self.client.build_search_url(
@@ -144,15 +146,16 @@ impl WallhavenService {
         let masked_url = Self::mask_api_key_in_url(&url);
         info!("[Wallhaven API] [{}] 请求URL: {}", search_tag, masked_url);
 
-        // 执行请求（设置5秒超时，使用 get_single 不进行重试）
+        // 执行请求（设置超时，使用 get_single 不进行重试）
+        let request_context = context.child_with_timeout(SEARCH_REQUEST_TIMEOUT);
         let text = self
             .client
-            .get_single(url, search_tag.clone(), context, Some(5))
+            .get_single(url, search_tag.clone(), &request_context)
             .await?;
 
         // 解析前检查取消状态
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
+        if let Some(e) = context.error() {
+            return Err(e.to_string());
         }
 
         // 解析响应
@@ -201,28 +204,26 @@ impl WallhavenService {
     /// 返回壁纸详情
     pub async fn get_wallpaper(&self, id: &str, context: &RequestContext) -> Result<OnlineWallpaper, String> {
         // 检查是否已取消
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
+        if let Some(e) = context.error() {
+            return Err(e.to_string());
         }
 
-        // 获取并发控制许可
-        let _permit = crate::services::GLOBAL_CONCURRENCY_CONTROLLER.acquire().await;
-
-        // 再次检查是否已取消
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
-        }
+        // 获取并发控制许可（等待期间被取消时直接返回）
+        let _permit = context
+            .run_until_cancelled(crate::services::GLOBAL_CONCURRENCY_CONTROLLER.acquire())
+            .await
+            .map_err(|e| e.to_string())?;
 
         let url = format!("{}/w/{}", "https://wallhaven.cc/api/v1", id);
 
         debug!("[Wallhaven API] [ID:{}] 获取壁纸详情 - URL: {}", id, url);
 
-        // 执行请求（不设置超时，因为这是获取壁纸详情，可能需要较长时间）
-        let text = self.client.get(url, id.to_string(), context, None).await?;
+        // 执行请求（不额外设置超时，因为这是获取壁纸详情，可能需要较长时间；调用方可以通过上下文设置截止时间）
+        let text = self.client.get(url, id.to_string(), context).await?;
 
         // 解析前检查取消状态
-        if let Some(()) = context.check_cancelled() {
-            return Err("请求已取消".to_string());
+        if let Some(e) = context.error() {
+            return Err(e.to_string());
         }
 
         // 解析响应
@@ -41,6 +41,11 @@ pub struct WallpaperData {
     pub colors: Vec<String>,
     #[serde(default)]
     pub tags: Option<Vec<WallhavenTag>>,
+    #[serde(default)]
+    pub created_at: String,
+    /// 上传者信息（仅壁纸详情接口返回）
+    #[serde(default)]
+    pub uploader: Option<WallhavenUploader>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -55,6 +60,11 @@ pub struct WallhavenTag {
     pub name: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WallhavenUploader {
+    pub username: String,
+}
+
 /// 在线壁纸数据结构（UI层使用）
 #[derive(Debug, Clone)]
 pub struct OnlineWallpaper {
@@ -76,6 +86,10 @@ pub struct OnlineWallpaper {
     pub favorites: u32,
     pub colors: Vec<String>,
     pub tags: Vec<String>,
+    /// 上传时间（格式：2024-01-01 12:00:00）
+    pub created_at: String,
+    /// 上传者用户名（搜索结果中不包含，为空表示未知）
+    pub uploader: String,
     /// 缓存的缩略图 Handle，避免每次渲染都重新创建
     pub image_handle: Option<iced::widget::image::Handle>,
 }
@@ -101,6 +115,8 @@ impl From<WallpaperData> for OnlineWallpaper {
             favorites: data.favorites,
             colors: data.colors,
             tags: data.tags.unwrap_or_default().into_iter().map(|t| t.name).collect(),
+            created_at: data.created_at,
+            uploader: data.uploader.map(|u| u.username).unwrap_or_default(),
             image_handle: None, // Handle 将在后续加载时设置
         }
     }
@@ -57,6 +57,9 @@ impl App {
 
         let tray_manager = TrayManager::new(&i18n);
 
+        // 按网络设置配置共享 HTTP 客户端（需要在创建 Wallhaven 客户端之前）
+        crate::services::http_client::configure(&config.http);
+
         // 根据配置文件中的主题配置初始化主题
         let theme_config = match config.global.theme {
             Theme::Dark => style::ThemeConfig::new(style::Theme::Dark),
@@ -113,6 +116,7 @@ impl App {
 
         // 初始化下载任务数据库
         app.init_download_database();
+        app.download_state.retry_max_attempts = app.config.download.retry_max_attempts;
 
         // 初始化托盘菜单项的状态
         app.update_tray_menu_items();
@@ -1,6 +1,8 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::async_task;
+use crate::services::hooks::WallpaperSource;
+use crate::services::wallhaven;
 use crate::ui::auto_change::AutoChangeMessage;
 use crate::ui::main::MainMessage;
 use crate::ui::{App, AppMessage, NotificationType};
@@ -9,18 +11,31 @@ use tracing::{error, info, warn};
 
 impl App {
     /// 处理获取支持的图片文件列表成功
-    pub(in crate::ui::auto_change) fn get_supported_images_success(&mut self, paths: Vec<String>) -> Task<AppMessage> {
+    pub(in crate::ui::auto_change) fn get_supported_images_success(
+        &mut self,
+        paths: Vec<String>,
+        source: WallpaperSource,
+    ) -> Task<AppMessage> {
         if !paths.is_empty() {
             // 记录找到的壁纸数量
             info!("[定时切换] [获取] 找到 {} 张壁纸", paths.len());
+            // 缓存候选列表，之后由壁纸库文件监听实时更新
+            self.auto_change_state
+                .set_candidates(self.config.data.library_roots(), paths.clone());
 
             // 获取成功，立即设置一张随机壁纸
             let wallpaper_mode = self.config.wallpaper.mode;
+            let color = wallhaven::parse_color(&self.config.wallpaper.auto_change_local_color);
 
             Task::perform(
-                async_task::async_set_random_wallpaper(paths, wallpaper_mode),
-                |result| match result {
-                    Ok(path) => AutoChangeMessage::SetRandomWallpaperSuccess(path).into(),
+                async_task::async_set_random_wallpaper(
+                    paths,
+                    wallpaper_mode,
+                    color,
+                    self.config.data.cache_path.clone(),
+                ),
+                move |result| match result {
+                    Ok(path) => AutoChangeMessage::SetRandomWallpaperSuccess(path, source).into(),
                     Err(e) => AutoChangeMessage::SetRandomWallpaperFailed(e.to_string()).into(),
                 },
             )
@@ -41,11 +56,15 @@ impl App {
     }
 
     /// 处理随机设置壁纸成功
-    pub(in crate::ui::auto_change) fn set_random_wallpaper_success(&mut self, path: String) -> Task<AppMessage> {
+    pub(in crate::ui::auto_change) fn set_random_wallpaper_success(
+        &mut self,
+        path: String,
+        source: WallpaperSource,
+    ) -> Task<AppMessage> {
         info!("[定时切换] [成功] 已设置壁纸: {}", path);
 
         // 将壁纸路径添加到历史记录
-        Task::done(MainMessage::AddToWallpaperHistory(path).into())
+        Task::done(MainMessage::AddToWallpaperHistory(path, source).into())
     }
 
     /// 处理随机设置壁纸失败
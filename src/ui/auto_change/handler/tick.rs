@@ -1,6 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::async_task;
+use crate::services::hooks::WallpaperSource;
 use crate::ui::auto_change::AutoChangeMessage;
 use crate::ui::{App, AppMessage};
 use crate::utils::config::WallpaperAutoChangeMode;
@@ -36,20 +37,23 @@ impl App {
         // 4. 根据模式直接执行切换任务
         match self.config.wallpaper.auto_change_mode {
             WallpaperAutoChangeMode::Local => {
-                let data_path = self.config.data.data_path.clone();
-                Task::perform(
-                    async_task::async_get_supported_images(data_path),
-                    |result| match result {
-                        Ok(paths) => {
-                            if paths.is_empty() {
-                                AutoChangeMessage::GetSupportedImagesFailed("没有找到支持的壁纸文件".to_string()).into()
-                            } else {
-                                AutoChangeMessage::GetSupportedImagesSuccess(paths).into()
-                            }
+                let roots = self.config.data.library_roots();
+                // 优先使用缓存的候选列表，避免每次切换都扫描壁纸库
+                if let Some(paths) = self.auto_change_state.cached_candidates(&roots) {
+                    return Task::done(
+                        AutoChangeMessage::GetSupportedImagesSuccess(paths, WallpaperSource::AutoChange).into(),
+                    );
+                }
+                Task::perform(async_task::async_get_supported_images(roots), |result| match result {
+                    Ok(paths) => {
+                        if paths.is_empty() {
+                            AutoChangeMessage::GetSupportedImagesFailed("没有找到支持的壁纸文件".to_string()).into()
+                        } else {
+                            AutoChangeMessage::GetSupportedImagesSuccess(paths, WallpaperSource::AutoChange).into()
                         }
-                        Err(e) => AutoChangeMessage::GetSupportedImagesFailed(e.to_string()).into(),
-                    },
-                )
+                    }
+                    Err(e) => AutoChangeMessage::GetSupportedImagesFailed(e.to_string()).into(),
+                })
             }
             WallpaperAutoChangeMode::Online => {
                 let config = self.config.clone();
@@ -57,7 +61,9 @@ impl App {
                 Task::perform(
                     async_task::async_set_random_online_wallpaper(config, auto_change_running),
                     |result| match result {
-                        Ok(path) => AutoChangeMessage::SetRandomWallpaperSuccess(path).into(),
+                        Ok(path) => {
+                            AutoChangeMessage::SetRandomWallpaperSuccess(path, WallpaperSource::AutoChange).into()
+                        }
                         Err(e) => AutoChangeMessage::SetRandomWallpaperFailed(e.to_string()).into(),
                     },
                 )
@@ -37,6 +37,8 @@ impl AutoChangeState {
             last_executed_time: None,
             auto_detect_color_mode: config.global.theme == Theme::Auto,
             auto_change_running: Arc::new(AtomicBool::new(false)),
+            candidate_paths: None,
+            candidate_roots: Vec::new(),
         }
     }
 }
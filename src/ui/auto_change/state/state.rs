@@ -1,5 +1,8 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::library_watcher::LibraryChanges;
+use crate::utils::config::LibraryRoot;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
@@ -16,6 +19,10 @@ pub struct AutoChangeState {
     pub auto_detect_color_mode: bool,
     /// 定时切换执行标志，防止任务并行执行
     pub auto_change_running: Arc<AtomicBool>,
+    /// 本地模式的候选壁纸列表（由壁纸库文件监听实时更新，为 None 时重新扫描壁纸库）
+    pub candidate_paths: Option<Vec<String>>,
+    /// 候选壁纸列表对应的壁纸库目录（壁纸库目录配置变化后候选列表失效）
+    pub candidate_roots: Vec<LibraryRoot>,
 }
 
 impl Default for AutoChangeState {
@@ -26,6 +33,48 @@ impl Default for AutoChangeState {
             last_executed_time: None,
             auto_detect_color_mode: false,
             auto_change_running: Arc::new(AtomicBool::new(false)),
+            candidate_paths: None,
+            candidate_roots: Vec::new(),
+        }
+    }
+}
+
+impl AutoChangeState {
+    /// 获取指定壁纸库目录的候选壁纸列表（没有缓存或列表为空时返回 None）
+    pub fn cached_candidates(&self, roots: &[LibraryRoot]) -> Option<Vec<String>> {
+        if self.candidate_roots != roots {
+            return None;
+        }
+        self.candidate_paths.clone().filter(|paths| !paths.is_empty())
+    }
+
+    /// 缓存扫描得到的候选壁纸列表
+    pub fn set_candidates(&mut self, roots: Vec<LibraryRoot>, paths: Vec<String>) {
+        self.candidate_roots = roots;
+        self.candidate_paths = Some(paths);
+    }
+
+    /// 根据壁纸库文件变化更新候选壁纸列表
+    pub fn apply_library_changes(&mut self, changes: &LibraryChanges) {
+        if changes.rescan {
+            // 有目录被创建或移入，下次切换时重新扫描
+            self.candidate_paths = None;
+            return;
+        }
+
+        let Some(paths) = self.candidate_paths.as_mut() else {
+            return;
+        };
+        paths.retain(|path| {
+            !changes
+                .removed
+                .iter()
+                .any(|removed| Path::new(path).starts_with(removed))
+        });
+        for path in &changes.changed {
+            if !paths.contains(path) {
+                paths.push(path.clone());
+            }
         }
     }
 }
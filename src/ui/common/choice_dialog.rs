@@ -0,0 +1,95 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::style::{
+    BORDER_COLOR_GRAY, DIALOG_BORDER_RADIUS, DIALOG_BORDER_WIDTH, DIALOG_BUTTON_SPACING, DIALOG_INNER_PADDING,
+    DIALOG_MAX_WIDTH, DIALOG_MESSAGE_SIZE, DIALOG_PADDING, DIALOG_SPACING, DIALOG_TITLE_SIZE, MASK_ALPHA,
+};
+use iced::widget::{Row, column, container, text};
+use iced::{Alignment, Color, Element, Length};
+
+/// 创建带多个选项按钮的模态对话框
+///
+/// # 参数
+/// - `title`: 对话框标题
+/// - `message`: 对话框提示信息
+/// - `buttons`: 按钮列表（按钮文本, 按钮颜色, 点击消息），按顺序从左到右排列
+pub fn create_choice_dialog<'a, Message>(
+    title: String,
+    message: String,
+    buttons: Vec<(String, Color, Message)>,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    let button_row = buttons.into_iter().fold(Row::new(), |row, (label, color, msg)| {
+        row.push(super::create_colored_button(label, color, msg))
+    });
+
+    let dialog_content = column![
+        text(title)
+            .size(DIALOG_TITLE_SIZE)
+            .width(Length::Fill)
+            .align_x(Alignment::Center),
+        text(message)
+            .size(DIALOG_MESSAGE_SIZE)
+            .width(Length::Fill)
+            .align_x(Alignment::Center),
+        button_row.spacing(DIALOG_BUTTON_SPACING).align_y(Alignment::Center),
+    ]
+    .padding(DIALOG_PADDING)
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .width(Length::Shrink)
+    .max_width(DIALOG_MAX_WIDTH);
+
+    create_modal_dialog(dialog_content.into())
+}
+
+/// 创建模态对话框外框（白色对话框 + 半透明遮罩），对话框居中显示并阻止下层交互
+///
+/// # 参数
+/// - `dialog_content`: 对话框内容
+pub fn create_modal_dialog<'a, Message>(dialog_content: Element<'a, Message>) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    let modal_dialog = container(dialog_content)
+        .width(Length::Shrink)
+        .height(Length::Shrink)
+        .padding(DIALOG_INNER_PADDING)
+        .style(|_theme: &iced::Theme| iced::widget::container::Style {
+            background: Some(iced::Background::Color(iced::Color::WHITE)),
+            border: iced::border::Border {
+                radius: iced::border::Radius::from(DIALOG_BORDER_RADIUS),
+                width: DIALOG_BORDER_WIDTH,
+                color: iced::Color::from_rgb(BORDER_COLOR_GRAY, BORDER_COLOR_GRAY, BORDER_COLOR_GRAY),
+            },
+            ..Default::default()
+        });
+
+    let modal_content = container(iced::widget::stack(vec![
+        container(iced::widget::Space::new())
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_theme: &iced::Theme| iced::widget::container::Style {
+                background: Some(iced::Background::Color(iced::Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: MASK_ALPHA,
+                })),
+                ..Default::default()
+            })
+            .into(),
+        container(modal_dialog)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .center_y(Length::Fill)
+            .into(),
+    ]))
+    .width(Length::Fill)
+    .height(Length::Fill);
+
+    iced::widget::opaque(modal_content)
+}
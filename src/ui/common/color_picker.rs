@@ -0,0 +1,143 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::wallhaven::ColorOption;
+use crate::ui::style::ThemeColors;
+use crate::ui::style::{COLOR_NO_COLOR_STROKE, COLOR_PICKER_ACTIVE};
+use iced::border::{Border, Radius};
+use iced::widget::{Space, button, column, container, opaque, row, text};
+use iced::{Alignment, Color, Element, Font, Length};
+use iced_aw::{DropDown, drop_down};
+
+/// 颜色网格每行的颜色数量
+const GRID_COLUMNS: usize = 6;
+
+/// 创建 Wallhaven 颜色选择器（29 种颜色 + 不限），触发按钮的背景为当前选中的颜色
+///
+/// # 参数
+/// - `label`: 触发按钮上的文本
+/// - `selected`: 当前选中的颜色
+/// - `expanded`: 是否展开颜色网格
+/// - `on_toggle`: 点击触发按钮时的消息
+/// - `on_dismiss`: 点击颜色网格以外区域时的消息
+/// - `on_select`: 选择颜色时的回调
+/// - `theme_colors`: 主题颜色
+pub fn create_color_picker<'a, Message>(
+    label: String,
+    selected: ColorOption,
+    expanded: bool,
+    on_toggle: Message,
+    on_dismiss: Message,
+    on_select: impl Fn(ColorOption) -> Message,
+    theme_colors: ThemeColors,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    let trigger_bg = color_of(selected).unwrap_or(theme_colors.light_button);
+
+    // 创建触发按钮（underlay）
+    let underlay = row![
+        text(label).size(14).color(theme_colors.light_text),
+        Space::new().width(Length::Fill),
+        container(text("⏷").color(theme_colors.light_text_sub))
+            .height(Length::Fill)
+            .padding(iced::Padding {
+                top: -2.0,
+                bottom: 0.0,
+                left: 0.0,
+                right: 0.0,
+            }),
+    ]
+    .spacing(4)
+    .align_y(Alignment::Center)
+    .padding(iced::Padding {
+        top: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+        right: -2.0,
+    });
+
+    let trigger = button(underlay)
+        .padding(6)
+        .width(Length::Fixed(80.0))
+        .on_press(on_toggle)
+        .style(move |_theme, _status| button::Style {
+            background: Some(iced::Background::Color(trigger_bg)),
+            text_color: theme_colors.light_text,
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: 0.0,
+                radius: Radius::from(4.0),
+            },
+            ..button::text(_theme, _status)
+        });
+
+    // 创建颜色网格（overlay），不限放在最后
+    let options: Vec<ColorOption> = ColorOption::all()
+        .into_iter()
+        .filter(|option| *option != ColorOption::Any)
+        .chain(std::iter::once(ColorOption::Any))
+        .collect();
+    let grid = options.chunks(GRID_COLUMNS).fold(column![].spacing(2), |grid, chunk| {
+        let cells = chunk.iter().map(|option| {
+            let is_selected = *option == selected;
+            let cell: Element<'a, Message> = if *option == ColorOption::Any {
+                text("\u{F659}")
+                    .font(Font::with_name("bootstrap-icons"))
+                    .size(14)
+                    .color(COLOR_NO_COLOR_STROKE)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .into()
+            } else {
+                Space::new().width(Length::Fill).height(Length::Fill).into()
+            };
+            let cell_bg = color_of(*option).unwrap_or(theme_colors.light_button);
+
+            button(cell)
+                .padding(0)
+                .width(Length::Fixed(40.0))
+                .height(Length::Fixed(24.0))
+                .on_press(on_select(*option))
+                .style(move |_theme, _status| button::Style {
+                    background: Some(iced::Background::Color(cell_bg)),
+                    border: Border {
+                        color: if is_selected {
+                            COLOR_PICKER_ACTIVE
+                        } else {
+                            theme_colors.light_text_sub
+                        },
+                        width: if is_selected { 2.0 } else { 0.5 },
+                        radius: Radius::from(2.0),
+                    },
+                    ..button::text(_theme, _status)
+                })
+                .into()
+        });
+        grid.push(row(cells).spacing(2))
+    });
+
+    let picker_content = container(grid)
+        .padding(12)
+        .style(move |_theme: &iced::Theme| container::Style {
+            background: Some(iced::Background::Color(theme_colors.light_button)),
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: 0.0,
+                radius: Radius::from(8.0),
+            },
+            ..Default::default()
+        });
+
+    DropDown::new(trigger, opaque(picker_content), expanded)
+        .on_dismiss(on_dismiss)
+        .alignment(drop_down::Alignment::Bottom)
+        .into()
+}
+
+/// 颜色选项对应的颜色（不限返回 None）
+pub fn color_of(option: ColorOption) -> Option<Color> {
+    option.rgb().map(|[r, g, b]| Color::from_rgb8(r, g, b))
+}
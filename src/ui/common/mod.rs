@@ -1,6 +1,8 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 mod bordered_container;
+mod choice_dialog;
+mod color_picker;
 mod colored_button;
 mod confirmation_dialog;
 mod icon_button;
@@ -8,6 +10,8 @@ mod tooltip_button;
 mod tooltip_radio;
 
 pub use bordered_container::*;
+pub use choice_dialog::*;
+pub use color_picker::*;
 pub use colored_button::*;
 pub use confirmation_dialog::*;
 pub use icon_button::*;
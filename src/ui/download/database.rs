@@ -8,14 +8,15 @@
 //!
 //! 注意：现在使用全局单例数据库管理器，避免多处打开同一数据库文件
 
-use crate::services::database::{DatabaseManager, DownloadTasksRepository};
+use crate::services::database::{DatabaseManager, DownloadStatsRepository, DownloadTasksRepository};
 
 // 重新导出类型以便其他模块使用
-pub use crate::services::database::DownloadTaskDB;
+pub use crate::services::database::{DailyDownloadStats, DownloadTaskDB};
 
 /// 下载任务数据库管理器
 pub struct DownloadDatabase {
     repository: DownloadTasksRepository,
+    stats_repository: DownloadStatsRepository,
 }
 
 impl std::fmt::Debug for DownloadDatabase {
@@ -39,13 +40,8 @@ impl DownloadDatabase {
         // 初始化全局数据库管理器
         DatabaseManager::init(db_path)?;
         
-        // 创建下载任务仓库实例
-        let db_manager = DatabaseManager::get();
-        let repository = DownloadTasksRepository::new(
-            db_manager.connection().clone()
-        );
-        
-        Ok(Self { repository })
+        // 创建下载任务和下载统计仓库实例
+        Ok(Self::get())
     }
 
     /// 获取下载任务数据库管理器实例（使用全局连接）
@@ -60,7 +56,11 @@ impl DownloadDatabase {
         let repository = DownloadTasksRepository::new(
             db_manager.connection().clone()
         );
-        Self { repository }
+        let stats_repository = DownloadStatsRepository::new(db_manager.connection().clone());
+        Self {
+            repository,
+            stats_repository,
+        }
     }
 
     /// 保存任务到数据库
@@ -108,4 +108,28 @@ impl DownloadDatabase {
     pub fn clear_all(&self) -> Result<(), String> {
         self.repository.clear_all()
     }
+
+    /// 记录一个下载完成的文件到当天的下载统计
+    ///
+    /// # 参数
+    /// - `bytes`: 文件大小（字节）
+    ///
+    /// # 返回
+    /// 成功返回 Ok(())，失败返回错误信息
+    pub fn record_download(&self, bytes: u64) -> Result<(), String> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        self.stats_repository.record_download(&today, bytes)
+    }
+
+    /// 加载最近若干天的下载统计（按日期倒序）
+    ///
+    /// # 参数
+    /// - `days`: 天数（包含今天）
+    ///
+    /// # 返回
+    /// 返回每天的下载统计列表
+    pub fn load_recent_stats(&self, days: i64) -> Result<Vec<DailyDownloadStats>, String> {
+        let since = chrono::Local::now().date_naive() - chrono::Duration::days(days.max(1) - 1);
+        self.stats_repository.load_since(&since.format("%Y-%m-%d").to_string())
+    }
 }
\ No newline at end of file
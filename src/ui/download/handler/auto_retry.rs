@@ -0,0 +1,38 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::download::DownloadStatus;
+use crate::ui::{App, AppMessage};
+use iced::Task;
+use std::time::Instant;
+
+impl App {
+    /// 检查等待自动重试的任务，到期后重新开始下载
+    pub(in crate::ui::download) fn auto_retry_tick(&mut self) -> Task<AppMessage> {
+        let now = Instant::now();
+        let mut due_ids = Vec::new();
+
+        for task_full in self.download_state.tasks.iter_mut() {
+            let Some(retry_at) = task_full.task.next_retry_at else {
+                continue;
+            };
+
+            if !matches!(task_full.task.status, DownloadStatus::Failed(_)) {
+                // 任务状态已被用户改变，放弃本次自动重试
+                task_full.task.next_retry_at = None;
+            } else if retry_at <= now {
+                task_full.task.next_retry_at = None;
+                due_ids.push(task_full.task.id);
+            }
+        }
+
+        let tasks: Vec<Task<AppMessage>> = due_ids
+            .into_iter()
+            .map(|id| {
+                tracing::info!("[下载任务] [ID:{}] 开始自动重试", id);
+                self.retry_download_task(id)
+            })
+            .collect();
+
+        Task::batch(tasks)
+    }
+}
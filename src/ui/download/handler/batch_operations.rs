@@ -78,7 +78,7 @@ impl crate::ui::App {
 
     /// 批量重新开始选中的任务
     ///
-    /// 仅对暂停中和下载失败和已取消的任务生效
+    /// 仅对暂停中、下载失败、文件损坏和已取消的任务生效
     pub fn batch_retry_selected_tasks(&mut self) -> Task<AppMessage> {
         // 收集所有可以重新开始的任务ID
         let task_ids: Vec<usize> = self
@@ -89,7 +89,10 @@ impl crate::ui::App {
                 self.download_state.selected_task_ids.contains(&task.task.id)
                     && matches!(
                         task.task.status,
-                        DownloadStatus::Paused | DownloadStatus::Failed(_) | DownloadStatus::Cancelled
+                        DownloadStatus::Paused
+                            | DownloadStatus::Failed(_)
+                            | DownloadStatus::Corrupt(_)
+                            | DownloadStatus::Cancelled
                     )
             })
             .map(|task| task.task.id)
@@ -106,6 +109,8 @@ impl crate::ui::App {
             if let Some(task) = self.download_state.tasks.iter_mut().find(|t| t.task.id == *task_id) {
                 // 更新排队顺序
                 task.task.queue_order = index;
+                // 手动重试时重新计算自动重试次数
+                self.download_state.reset_retry_state(*task_id);
                 // 调用重新开始任务方法并收集返回的 Task
                 let task = self.retry_download_task(*task_id);
                 tasks.push(task);
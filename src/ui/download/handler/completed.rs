@@ -2,11 +2,16 @@
 
 use crate::services::async_task;
 use crate::services::download::DownloadService;
+use crate::services::download_progress::DOWNLOAD_PROGRESS;
+use crate::services::download_retry::RetryPolicy;
+use crate::services::hooks::{HookEvent, WallpaperSource};
+use crate::services::sidecar::WallpaperMetadata;
+use crate::services::transcode;
 use crate::ui::download::{DownloadMessage, DownloadStatus};
 use crate::ui::main::MainMessage;
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 impl App {
@@ -16,6 +21,105 @@ impl App {
         size: u64,
         error: Option<String>,
     ) -> Task<AppMessage> {
+        // 任务已结束，移除其进度 channel
+        DOWNLOAD_PROGRESS.unregister(id);
+
+        let task = self.finish_download_task(id, size, error);
+
+        // 记录下载统计
+        let completed_size = self
+            .download_state
+            .tasks
+            .iter()
+            .find(|t| t.task.id == id && t.task.status == DownloadStatus::Completed)
+            .map(|t| t.task.total_size);
+        if let Some(completed_size) = completed_size {
+            self.download_state.record_completed_download(completed_size);
+        }
+
+        // 下载成功后按设置转码，转码完成后再写入附属文件和执行钩子命令（使用转码后的文件路径）
+        let Some(save_path) = completed_size.and_then(|_| {
+            self.download_state
+                .tasks
+                .iter()
+                .find(|t| t.task.id == id)
+                .map(|t| t.task.save_path.clone())
+        }) else {
+            return task;
+        };
+
+        let transcode_config = self.config.transcode.clone();
+        if transcode::should_transcode(&transcode_config, Path::new(&save_path), completed_size.unwrap_or(0)) {
+            let transcode_task = Task::perform(
+                transcode::async_transcode(PathBuf::from(save_path), transcode_config),
+                move |result| DownloadMessage::TranscodeFinished(id, result).into(),
+            );
+            return Task::batch([task, transcode_task]);
+        }
+
+        Task::batch([task, self.finish_completed_download(id)])
+    }
+
+    /// 保存下载完成任务的元数据（标签和附属文件），并执行下载完成钩子命令
+    pub(in crate::ui::download) fn finish_completed_download(&self, id: usize) -> Task<AppMessage> {
+        let Some(completed) = self
+            .download_state
+            .tasks
+            .iter()
+            .find(|t| t.task.id == id && t.task.status == DownloadStatus::Completed)
+        else {
+            return Task::none();
+        };
+
+        self.finish_downloaded_file(
+            &completed.task.save_path,
+            &completed.task.url,
+            completed.metadata.as_ref(),
+        )
+    }
+
+    /// 从缓存复制到壁纸库的文件与下载完成的任务走相同的收尾流程：
+    /// 按设置转码，然后写入附属文件并执行下载完成钩子命令
+    ///
+    /// # 参数
+    /// - `file_path`: 复制后的文件路径
+    /// - `url`: 原图下载地址
+    /// - `metadata`: 壁纸元数据
+    pub(in crate::ui) fn finish_cache_copy(
+        &self,
+        file_path: PathBuf,
+        url: String,
+        metadata: WallpaperMetadata,
+    ) -> Task<AppMessage> {
+        let size = std::fs::metadata(&file_path).map(|m| m.len()).unwrap_or(0);
+        let transcode_config = self.config.transcode.clone();
+        if transcode::should_transcode(&transcode_config, &file_path, size) {
+            return Task::perform(
+                transcode::async_transcode(file_path.clone(), transcode_config),
+                move |result| DownloadMessage::CacheCopyTranscoded(file_path, url, Box::new(metadata), result).into(),
+            );
+        }
+
+        self.finish_downloaded_file(&file_path.to_string_lossy(), &url, Some(&metadata))
+    }
+
+    /// 写入下载文件的元数据（标签和附属文件），然后执行下载完成钩子命令
+    pub(in crate::ui::download) fn finish_downloaded_file(
+        &self,
+        file_path: &str,
+        url: &str,
+        metadata: Option<&WallpaperMetadata>,
+    ) -> Task<AppMessage> {
+        // 先写入附属文件，钩子命令可以读取其中的元数据
+        let metadata_task = match metadata {
+            Some(metadata) => self.save_wallpaper_metadata(Path::new(file_path), metadata),
+            None => Task::none(),
+        };
+        metadata_task.chain(self.run_hook_command(HookEvent::Download, file_path, url, WallpaperSource::Download))
+    }
+
+    /// 更新完成（成功或失败）的下载任务状态，并开始下一个等待中的任务
+    fn finish_download_task(&mut self, id: usize, size: u64, error: Option<String>) -> Task<AppMessage> {
         let task_index = self.download_state.find_task_index(id);
         if let Some(index) = task_index {
             // 先保存URL和大小等数据，避免借用冲突
@@ -42,8 +146,29 @@ impl App {
                         if current_status != DownloadStatus::Paused {
                             task.task.status = DownloadStatus::Cancelled;
                         }
+                    } else if error_msg.starts_with(async_task::DOWNLOAD_CORRUPT_PREFIX) {
+                        // 完整性校验失败且自动重新下载次数已用尽，损坏的缓存文件已在下载任务中删除
+                        tracing::warn!("[下载任务] [ID:{}] 文件损坏: {}", id, error_msg);
+                        task.task.status = DownloadStatus::Corrupt(error_msg.clone());
+                        task.task.last_error = Some(error_msg.clone());
                     } else {
                         task.task.status = DownloadStatus::Failed(error_msg.clone());
+                        task.task.last_error = Some(error_msg.clone());
+
+                        // 临时性错误按重试策略安排自动重试
+                        let retry_policy = RetryPolicy::from_config(&self.config.download);
+                        if retry_policy.should_retry(task.task.retry_count, &error_msg) {
+                            task.task.retry_count += 1;
+                            let delay = retry_policy.backoff_delay(task.task.retry_count);
+                            task.task.next_retry_at = Some(Instant::now() + delay);
+                            tracing::info!(
+                                "[下载任务] [ID:{}] 将在 {} 秒后自动重试 ({}/{})",
+                                id,
+                                delay.as_secs(),
+                                task.task.retry_count,
+                                retry_policy.max_attempts
+                            );
+                        }
 
                         // 清除未完成的下载文件
                         let cache_path = self.config.data.cache_path.clone();
@@ -72,6 +197,9 @@ impl App {
                     task.task.total_size = actual_size;
                     task.task.downloaded_size = actual_size;
 
+                    // 更新在线壁纸列表中的已拥有标记
+                    self.online_state.mark_owned_by_url(&url);
+
                     // 检查是否需要自动设置壁纸
                     let file_name = std::path::Path::new(&task.task.save_path)
                         .file_name()
@@ -90,9 +218,15 @@ impl App {
 
                             // 异步设置壁纸
                             return Task::perform(
-                                async_task::async_set_wallpaper(full_path.clone(), wallpaper_mode),
+                                async_task::async_set_wallpaper(
+                                    full_path.clone(),
+                                    wallpaper_mode,
+                                    self.config.data.cache_path.clone(),
+                                ),
                                 move |result| match result {
-                                    Ok(_) => MainMessage::AddToWallpaperHistory(full_path).into(),
+                                    Ok(_) => {
+                                        MainMessage::AddToWallpaperHistory(full_path, WallpaperSource::Manual).into()
+                                    }
                                     Err(e) => MainMessage::ShowNotification(
                                         format!("{}: {}", failed_message, e),
                                         NotificationType::Error,
@@ -114,15 +248,39 @@ impl App {
         // 减少正在下载的任务计数
         self.download_state.decrement_downloading();
 
-        // 检查是否有等待中的任务需要开始
+        // 检查是否有等待中的任务需要开始（下载队列暂停或磁盘空间不足时不自动开始）
+        let next_required = self
+            .download_state
+            .waiting_task_ids()
+            .first()
+            .and_then(|next_id| self.download_state.tasks.iter().find(|t| t.task.id == *next_id))
+            .map(|t| t.remaining_size());
+        let can_start_next = match next_required {
+            Some(required) => {
+                if self.download_state.queue_paused_reason.is_some() {
+                    false
+                } else if self.preflight_disk_space(required) {
+                    true
+                } else {
+                    return self.notify_queue_paused();
+                }
+            }
+            None => false,
+        };
+        if !can_start_next {
+            return Task::none();
+        }
+
         if let Some(next_task) = self.download_state.get_next_waiting_task() {
             let next_url = next_task.task.url.clone();
             let next_save_path = PathBuf::from(&next_task.task.save_path);
             let next_proxy = next_task.proxy.clone();
             let next_task_id = next_task.task.id;
-            let next_cancel_token = next_task.task.cancel_token.clone().unwrap();
+            let next_context = next_task.task.renew_request_context();
             let next_downloaded_size = next_task.task.downloaded_size;
             let next_total_size = next_task.task.total_size;
+            let next_expected_size = next_task.expected_size;
+            let next_file_type = next_task.file_type.clone();
             next_task.task.status = DownloadStatus::Downloading;
             next_task.task.start_time = Some(Instant::now());
             self.download_state.increment_downloading();
@@ -135,16 +293,18 @@ impl App {
 
             // 启动下一个下载任务
             return Task::perform(
-                async_task::async_download_wallpaper_task_with_progress(
-                    next_url.to_string(),
-                    next_save_path,
-                    next_proxy,
-                    next_task_id,
-                    next_cancel_token,
-                    next_downloaded_size,
-                    next_total_size,
+                async_task::async_download_wallpaper_task_with_progress(async_task::DownloadRequest {
+                    url: next_url.to_string(),
+                    save_path: next_save_path,
+                    proxy: next_proxy,
+                    task_id: next_task_id,
+                    context: next_context,
+                    downloaded_size: next_downloaded_size,
+                    total_size: next_total_size,
                     cache_path,
-                ),
+                    expected_size: next_expected_size,
+                    file_type: next_file_type,
+                }),
                 move |result| match result {
                     Ok(s) => {
                         tracing::info!("[下载任务] [ID:{}] 下载成功, 文件大小: {} bytes", next_task_id, s);
@@ -0,0 +1,150 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::disk_space::{self, BYTES_PER_MB, SpaceCheckTarget, SpaceShortage};
+use crate::ui::download::DownloadMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use crate::utils::helpers;
+use iced::Task;
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+impl App {
+    /// 需要检查空间的目录（下载先写入缓存目录再复制到数据路径，两个目录都需要检查）
+    ///
+    /// 数据路径的配额包括文件名模板生成的子目录；额外的壁纸库目录不会保存下载的文件，不计入配额
+    fn space_check_targets(&self) -> [SpaceCheckTarget; 2] {
+        let download_config = &self.config.download;
+        let target = |path: &String, quota_mb: u32| SpaceCheckTarget {
+            path: path.clone(),
+            quota: quota_mb as u64 * BYTES_PER_MB,
+            used: self.download_state.directory_usage.get(path).copied(),
+        };
+        [
+            target(&self.config.data.data_path, download_config.library_quota_mb),
+            target(&self.config.data.cache_path, download_config.cache_quota_mb),
+        ]
+    }
+
+    /// 开始下载任务前检查磁盘空间和目录配额
+    ///
+    /// 空间不足时暂停下载队列（不再自动开始等待中的任务），空间足够时恢复队列；
+    /// 目录占用空间使用最近一次在后台计算的结果，见 [`App::refresh_directory_usage`]
+    ///
+    /// # 返回
+    /// 可以开始下载返回 true
+    pub(in crate::ui::download) fn preflight_disk_space(&mut self, required: u64) -> bool {
+        let targets = self.space_check_targets();
+        let reserve = self.config.download.disk_reserve_mb as u64 * BYTES_PER_MB;
+
+        match disk_space::check_space(&targets, required, reserve) {
+            Ok(()) => {
+                self.download_state.queue_paused_reason = None;
+                true
+            }
+            Err(shortage) => {
+                warn!("[磁盘空间] 空间不足，暂停下载队列: {:?}", shortage);
+                let reason = match shortage {
+                    SpaceShortage::LowDiskSpace {
+                        path,
+                        available,
+                        required,
+                    } => self
+                        .i18n
+                        .t("download-space.low-disk")
+                        .replace("{path}", &path)
+                        .replace("{available}", &helpers::format_file_size(available))
+                        .replace("{required}", &helpers::format_file_size(required)),
+                    SpaceShortage::QuotaExceeded { path, used, quota } => self
+                        .i18n
+                        .t("download-space.quota-exceeded")
+                        .replace("{path}", &path)
+                        .replace("{used}", &helpers::format_file_size(used))
+                        .replace("{quota}", &helpers::format_file_size(quota)),
+                };
+                self.download_state.queue_paused_reason = Some(reason);
+                false
+            }
+        }
+    }
+
+    /// 下载队列因空间不足暂停时显示通知
+    pub(in crate::ui::download) fn notify_queue_paused(&mut self) -> Task<AppMessage> {
+        match self.download_state.queue_paused_reason.clone() {
+            Some(reason) => self.show_notification(reason, NotificationType::Error),
+            None => Task::none(),
+        }
+    }
+
+    /// 在后台重新计算有配额的目录占用空间
+    ///
+    /// # 参数
+    /// - `resume_queue`: 计算完成后是否恢复下载队列
+    pub fn refresh_directory_usage(&mut self, resume_queue: bool) -> Task<AppMessage> {
+        let paths: Vec<String> = self
+            .space_check_targets()
+            .into_iter()
+            .filter(|target| target.quota > 0)
+            .map(|target| target.path)
+            .collect();
+
+        if paths.is_empty() {
+            return if resume_queue {
+                self.start_waiting_downloads()
+            } else {
+                Task::none()
+            };
+        }
+        if self.download_state.directory_usage_refreshing && !resume_queue {
+            return Task::none();
+        }
+
+        self.download_state.directory_usage_refreshing = true;
+        Task::perform(async_task::async_directory_usage(paths), move |result| {
+            DownloadMessage::DirectoryUsageLoaded(result, resume_queue).into()
+        })
+    }
+
+    /// 目录占用空间计算完成
+    pub(in crate::ui::download) fn directory_usage_loaded(
+        &mut self,
+        result: Result<HashMap<String, u64>, String>,
+        resume_queue: bool,
+    ) -> Task<AppMessage> {
+        self.download_state.directory_usage_refreshing = false;
+        match result {
+            Ok(usage) => self.download_state.directory_usage.extend(usage),
+            Err(e) => warn!("[磁盘空间] {}", e),
+        }
+
+        if resume_queue {
+            self.start_waiting_downloads()
+        } else {
+            Task::none()
+        }
+    }
+
+    /// 恢复下载队列：重新计算目录占用（用户可能已清理了磁盘）后按排队顺序开始等待中的任务
+    pub(in crate::ui::download) fn resume_download_queue(&mut self) -> Task<AppMessage> {
+        info!("[磁盘空间] 恢复下载队列");
+        self.download_state.queue_paused_reason = None;
+        self.refresh_directory_usage(true)
+    }
+
+    /// 按排队顺序开始等待中的任务，直到达到并行限制或空间不足
+    fn start_waiting_downloads(&mut self) -> Task<AppMessage> {
+        let mut tasks = Vec::new();
+        for id in self.download_state.waiting_task_ids() {
+            if !self.download_state.can_start_download() {
+                break;
+            }
+            tasks.push(self.resume_download_task(id));
+            // 空间仍然不足时停止（resume_download_task 已显示通知）
+            if self.download_state.queue_paused_reason.is_some() {
+                break;
+            }
+        }
+
+        Task::batch(tasks)
+    }
+}
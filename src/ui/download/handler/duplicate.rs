@@ -0,0 +1,186 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::download::DownloadService;
+use crate::services::owned_wallpaper::{self, OwnedWallpaperQuery};
+use crate::services::sidecar::WallpaperMetadata;
+use crate::services::wallhaven::{self, OnlineWallpaper};
+use crate::ui::download::{DownloadDuplicate, DownloadStatus, DuplicateAction};
+use crate::ui::online::OnlineMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+impl App {
+    /// 下载前检查单张壁纸是否重复
+    ///
+    /// 先按 URL / 壁纸ID 检查下载任务列表，再检查可能的下载位置和壁纸库索引。
+    /// 只检查少量文件，直接在当前线程中进行；检查多张壁纸时使用 `async_find_owned_wallpapers`
+    pub fn find_download_duplicate(&self, wallpaper: &OnlineWallpaper) -> Option<DownloadDuplicate> {
+        if self.is_download_queued(wallpaper) {
+            return Some(DownloadDuplicate::InQueue);
+        }
+
+        self.find_owned_wallpaper(wallpaper).map(DownloadDuplicate::Owned)
+    }
+
+    /// 相同壁纸的任务是否已在下载队列中（未完成、未取消且未失败）
+    pub fn is_download_queued(&self, wallpaper: &OnlineWallpaper) -> bool {
+        self.download_state.tasks.iter().any(|task| {
+            is_same_wallpaper(&task.task.url, wallpaper)
+                && task.task.status != DownloadStatus::Completed
+                && task.task.status != DownloadStatus::Cancelled
+                && !matches!(task.task.status, DownloadStatus::Failed(_))
+                && !matches!(task.task.status, DownloadStatus::Corrupt(_))
+        })
+    }
+
+    /// 查找壁纸库中已有的相同壁纸，返回已有文件的路径
+    pub fn find_owned_wallpaper(&self, wallpaper: &OnlineWallpaper) -> Option<PathBuf> {
+        owned_wallpaper::find_owned_wallpapers(&[self.owned_wallpaper_query(wallpaper)])
+            .into_values()
+            .next()
+    }
+
+    /// 刷新在线壁纸列表中已拥有的壁纸（用于列表中的已拥有标记，在后台线程中检查）
+    pub fn refresh_owned_wallpapers(&self) -> Task<AppMessage> {
+        let queries = self
+            .online_state
+            .wallpapers_data
+            .iter()
+            .map(|wallpaper| self.owned_wallpaper_query(wallpaper))
+            .collect();
+
+        Task::perform(
+            async_task::async_find_owned_wallpapers(queries),
+            |result| match result {
+                Ok(owned) => OnlineMessage::OwnedWallpapersFound(owned.into_keys().collect()).into(),
+                Err(e) => {
+                    warn!("[在线壁纸] 检查已拥有的壁纸失败: {}", e);
+                    AppMessage::None
+                }
+            },
+        )
+    }
+
+    /// 收集查找壁纸库中已有壁纸所需的信息
+    ///
+    /// 依次检查按当前文件名模板生成的路径、默认文件名（修改模板之前下载的文件）、
+    /// 下载记录中已完成的相同壁纸，最后按原图缓存的内容哈希查找被重命名的文件
+    pub fn owned_wallpaper_query(&self, wallpaper: &OnlineWallpaper) -> OwnedWallpaperQuery {
+        let mut candidate_paths = vec![
+            self.resolve_download_path(wallpaper),
+            PathBuf::from(&self.config.data.data_path).join(wallhaven::generate_file_name(
+                &wallpaper.id,
+                &wallhaven::file_extension(&wallpaper.file_type),
+            )),
+        ];
+        candidate_paths.extend(
+            self.download_state
+                .tasks
+                .iter()
+                .filter(|task| {
+                    task.task.status == DownloadStatus::Completed && is_same_wallpaper(&task.task.url, wallpaper)
+                })
+                .map(|task| PathBuf::from(&task.task.save_path)),
+        );
+
+        let cache_file_path = DownloadService::get_online_image_cache_final_path(
+            &self.config.data.cache_path,
+            &wallpaper.path,
+            wallpaper.file_size,
+        )
+        .ok()
+        .map(PathBuf::from);
+
+        OwnedWallpaperQuery {
+            id: wallpaper.id.clone(),
+            file_size: wallpaper.file_size,
+            candidate_paths,
+            cache_file_path,
+        }
+    }
+
+    /// 按用户选择处理等待中的重复下载
+    pub(in crate::ui::download) fn resolve_download_duplicate(&mut self, action: DuplicateAction) -> Task<AppMessage> {
+        let Some(pending) = self.download_state.pending_duplicate.take() else {
+            return Task::none();
+        };
+        let wallpaper = pending.wallpaper;
+
+        let target_path = match action {
+            DuplicateAction::Skip => {
+                info!(
+                    "[下载任务] [壁纸ID:{}] 跳过重复下载: {}",
+                    wallpaper.id,
+                    pending.existing_path.display()
+                );
+                let skipped_message = self.i18n.t("download-duplicate.skipped").to_string();
+                return self.show_notification(skipped_message, NotificationType::Info);
+            }
+            // 旧文件保留到新文件下载并校验成功后才被替换，下载失败或取消时不会丢失
+            DuplicateAction::Redownload => self.resolve_download_path(&wallpaper),
+            DuplicateAction::KeepBoth => {
+                let target_path = self.resolve_download_path(&wallpaper);
+                if target_path.exists() {
+                    unique_file_path(&target_path)
+                } else {
+                    target_path
+                }
+            }
+        };
+
+        info!(
+            "[下载任务] [壁纸ID:{}] 重复壁纸继续下载: {}",
+            wallpaper.id,
+            target_path.display()
+        );
+
+        if let Some(target_dir) = target_path.parent() {
+            let _ = std::fs::create_dir_all(target_dir);
+        }
+
+        let add_to_queue_message = self.i18n.t("download-tasks.added-to-download-queue").to_string();
+        let download_task = self.start_download(
+            wallpaper.path.clone(),
+            target_path,
+            &wallpaper.file_type,
+            wallpaper.file_size,
+            Some(WallpaperMetadata::from_wallpaper(&wallpaper)),
+        );
+
+        Task::batch([
+            download_task,
+            self.show_notification(add_to_queue_message, NotificationType::Info),
+        ])
+    }
+}
+
+/// 判断下载任务的 URL 是否对应指定壁纸（按 URL 或壁纸ID匹配）
+fn is_same_wallpaper(task_url: &str, wallpaper: &OnlineWallpaper) -> bool {
+    task_url == wallpaper.path
+        || (!wallpaper.id.is_empty() && task_url.contains(&format!("wallhaven-{}.", wallpaper.id)))
+}
+
+/// 生成不与已有文件冲突的路径（如 `name (1).jpg`）
+fn unique_file_path(path: &Path) -> PathBuf {
+    let parent = path.parent().map(PathBuf::from).unwrap_or_default();
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut index = 1;
+    loop {
+        let candidate = parent.join(format!("{} ({}){}", stem, index, extension));
+        if !candidate.exists() {
+            return candidate;
+        }
+        index += 1;
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::request_context::RequestContext;
+use crate::services::wallhaven::{self, OnlineWallpaper};
+use crate::ui::download::{DownloadDuplicate, DownloadMessage, ImportEntry, ImportStage, ImportState};
+use crate::ui::main::MainMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use iced::widget::text_editor;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::{error, info, warn};
+
+/// 导入文件支持的扩展名
+const IMPORT_FILE_EXTENSIONS: &[&str] = &["txt", "csv"];
+
+impl App {
+    pub(in crate::ui::download) fn show_download_import(&mut self) -> Task<AppMessage> {
+        self.download_state.import.request_context.cancel();
+        self.download_state.import = ImportState {
+            stage: ImportStage::Input,
+            ..ImportState::default()
+        };
+        Task::none()
+    }
+
+    pub(in crate::ui::download) fn close_download_import(&mut self) -> Task<AppMessage> {
+        let import = &mut self.download_state.import;
+        import.request_context.cancel();
+        import.stage = ImportStage::Closed;
+        import.entries.clear();
+        Task::none()
+    }
+
+    pub(in crate::ui::download) fn select_download_import_file(&mut self) -> Task<AppMessage> {
+        let filter_name = self.i18n.t("download-import.file-filter").to_string();
+        Task::perform(
+            async_task::select_text_file_async(filter_name, IMPORT_FILE_EXTENSIONS),
+            |result| DownloadMessage::ImportFileLoaded(result).into(),
+        )
+    }
+
+    pub(in crate::ui::download) fn download_import_file_loaded(
+        &mut self,
+        result: Result<Option<String>, String>,
+    ) -> Task<AppMessage> {
+        match result {
+            Ok(Some(content)) => {
+                // 追加到已输入的内容之后，便于合并多个文件
+                let existing = self.download_state.import.input.text();
+                let existing = existing.trim_end();
+                let merged = if existing.is_empty() {
+                    content
+                } else {
+                    format!("{}\n{}", existing, content)
+                };
+                self.download_state.import.input = text_editor::Content::with_text(&merged);
+                Task::none()
+            }
+            Ok(None) => Task::none(),
+            Err(e) => {
+                error!("[导入下载任务] 读取文件失败: {}", e);
+                let failed_message = format!("{}: {}", self.i18n.t("download-import.read-failed"), e);
+                self.show_notification(failed_message, NotificationType::Error)
+            }
+        }
+    }
+
+    pub(in crate::ui::download) fn start_download_import_resolve(&mut self) -> Task<AppMessage> {
+        let ids = wallhaven::extract_wallpaper_ids(&self.download_state.import.input.text());
+        if ids.is_empty() {
+            let message = self.i18n.t("download-import.no-ids").to_string();
+            return self.show_notification(message, NotificationType::Info);
+        }
+
+        info!("[导入下载任务] 提取到 {} 个壁纸ID，开始获取壁纸详情", ids.len());
+
+        let import = &mut self.download_state.import;
+        import.request_context.cancel();
+        import.request_context = RequestContext::new();
+        import.stage = ImportStage::Resolving;
+        import.resolved_count = 0;
+        import.entries = ids
+            .into_iter()
+            .map(|id| ImportEntry {
+                id,
+                wallpaper: None,
+                error: None,
+                duplicate: None,
+                selected: false,
+            })
+            .collect();
+
+        self.resolve_next_import_entry()
+    }
+
+    pub(in crate::ui::download) fn download_import_resolved(
+        &mut self,
+        id: String,
+        result: Result<OnlineWallpaper, String>,
+    ) -> Task<AppMessage> {
+        let import = &self.download_state.import;
+        let is_current = import.stage == ImportStage::Resolving
+            && import
+                .entries
+                .get(import.resolved_count)
+                .is_some_and(|entry| entry.id == id);
+        if !is_current {
+            // 对话框已关闭或重新开始，丢弃结果
+            return Task::none();
+        }
+
+        let import = &mut self.download_state.import;
+        let entry = &mut import.entries[import.resolved_count];
+        match result {
+            Ok(wallpaper) => entry.wallpaper = Some(wallpaper),
+            Err(e) => {
+                warn!("[导入下载任务] [壁纸ID:{}] 获取壁纸详情失败: {}", id, e);
+                entry.error = Some(e);
+            }
+        }
+        import.resolved_count += 1;
+
+        if import.resolved_count >= import.entries.len() {
+            info!(
+                "[导入下载任务] 获取完成, 共 {} 个, 成功 {} 个",
+                import.entries.len(),
+                import.entries.iter().filter(|entry| entry.wallpaper.is_some()).count()
+            );
+            return self.check_import_owned();
+        }
+
+        self.resolve_next_import_entry()
+    }
+
+    /// 所有壁纸的重复情况检查完成，标记重复的壁纸并显示预览
+    pub(in crate::ui::download) fn download_import_owned_checked(
+        &mut self,
+        owned: HashMap<String, PathBuf>,
+    ) -> Task<AppMessage> {
+        let import = &self.download_state.import;
+        if import.stage != ImportStage::Resolving || import.resolved_count < import.entries.len() {
+            return Task::none();
+        }
+
+        // 先检查下载队列，避免与下面的可变借用冲突
+        let in_queue: Vec<bool> = import
+            .entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .wallpaper
+                    .as_ref()
+                    .is_some_and(|wallpaper| self.is_download_queued(wallpaper))
+            })
+            .collect();
+
+        let import = &mut self.download_state.import;
+        for (entry, in_queue) in import.entries.iter_mut().zip(in_queue) {
+            if entry.wallpaper.is_none() {
+                continue;
+            }
+            entry.duplicate = if in_queue {
+                Some(DownloadDuplicate::InQueue)
+            } else {
+                owned.get(&entry.id).cloned().map(DownloadDuplicate::Owned)
+            };
+            // 默认只选中不重复的壁纸
+            entry.selected = entry.duplicate.is_none();
+        }
+        import.stage = ImportStage::Preview;
+        Task::none()
+    }
+
+    pub(in crate::ui::download) fn confirm_download_import(&mut self) -> Task<AppMessage> {
+        let selected: Vec<OnlineWallpaper> = self.download_state.import.selected_wallpapers().cloned().collect();
+        if selected.is_empty() {
+            let message = self.i18n.t("download-import.nothing-selected").to_string();
+            return self.show_notification(message, NotificationType::Info);
+        }
+
+        let import = &mut self.download_state.import;
+        import.stage = ImportStage::Closed;
+        import.entries.clear();
+
+        info!("[导入下载任务] 添加 {} 个下载任务", selected.len());
+
+        let added_message = self
+            .i18n
+            .t("download-import.added")
+            .replace("{count}", &selected.len().to_string());
+        let add_tasks: Vec<Task<AppMessage>> = selected
+            .into_iter()
+            .map(|wallpaper| Task::done(DownloadMessage::AddTask(Box::new(wallpaper)).into()))
+            .collect();
+
+        // 所有任务添加完成后再显示汇总通知，避免被单个任务的通知覆盖
+        Task::batch(add_tasks).chain(Task::done(
+            MainMessage::ShowNotification(added_message, NotificationType::Success).into(),
+        ))
+    }
+
+    /// 在后台线程中一次检查所有获取成功的壁纸是否已拥有
+    fn check_import_owned(&self) -> Task<AppMessage> {
+        let queries = self
+            .download_state
+            .import
+            .entries
+            .iter()
+            .filter_map(|entry| entry.wallpaper.as_ref())
+            .map(|wallpaper| self.owned_wallpaper_query(wallpaper))
+            .collect();
+
+        Task::perform(async_task::async_find_owned_wallpapers(queries), |result| {
+            let owned = result.unwrap_or_else(|e| {
+                warn!("[导入下载任务] 检查已拥有的壁纸失败: {}", e);
+                HashMap::new()
+            });
+            DownloadMessage::ImportOwnedChecked(owned).into()
+        })
+    }
+
+    /// 获取下一个待获取壁纸的详情（遵守 API 频率限制）
+    fn resolve_next_import_entry(&self) -> Task<AppMessage> {
+        let import = &self.download_state.import;
+        let Some(entry) = import.entries.get(import.resolved_count) else {
+            return Task::none();
+        };
+
+        let api_key = if self.config.wallhaven.api_key.is_empty() {
+            None
+        } else {
+            Some(self.config.wallhaven.api_key.clone())
+        };
+        let proxy = if self.config.global.proxy_enabled && !self.config.global.proxy.is_empty() {
+            Some(self.config.global.proxy.clone())
+        } else {
+            None
+        };
+
+        let id = entry.id.clone();
+        Task::perform(
+            async_task::async_get_online_wallpaper_rate_limited(
+                id.clone(),
+                api_key,
+                proxy,
+                self.config.global.proxy_enabled,
+                true, // 启用环境变量回退
+                import.request_context.clone(),
+            ),
+            move |result| DownloadMessage::ImportResolved(id, result.map(Box::new)).into(),
+        )
+    }
+}
@@ -1,18 +1,27 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 mod add_task;
+mod auto_retry;
 mod batch_operations;
 mod cancel_task;
 mod completed;
 mod copy_link;
 mod delete_task;
+mod disk_space;
+mod duplicate;
+mod import_tasks;
 mod pause_task;
 mod progress;
 mod resume_task;
 mod retry_task;
 mod selection;
 mod set_wallpaper;
+mod sidecar;
 mod sort;
 mod start_download;
+mod statistics;
+mod task_transfer;
+mod template_details;
+mod transcode;
 mod update_speed;
 mod view_file;
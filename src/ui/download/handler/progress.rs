@@ -1,17 +1,18 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::download_progress::DownloadProgressUpdate;
 use crate::ui::{App, AppMessage};
 use iced::Task;
 
 impl App {
     pub(in crate::ui::download) fn update_download_progress(
         &mut self,
-        id: usize,
-        downloaded: u64,
-        total: u64,
-        speed: u64,
+        updates: Vec<DownloadProgressUpdate>,
     ) -> Task<AppMessage> {
-        self.download_state.update_progress(id, downloaded, total, speed);
+        for update in updates {
+            self.download_state
+                .update_progress(update.task_id, update.downloaded, update.total, update.speed);
+        }
         Task::none()
     }
 }
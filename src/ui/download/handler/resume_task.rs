@@ -2,18 +2,28 @@
 
 use crate::services::async_task;
 use crate::services::download::DownloadService;
+use crate::services::request_context::RequestContext;
 use crate::ui::download::{DownloadMessage, DownloadStatus};
 use crate::ui::{App, AppMessage};
 use iced::Task;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 
 impl App {
     pub(in crate::ui::download) fn resume_download_task(&mut self, id: usize) -> Task<AppMessage> {
         // 使用索引查找任务
         // 先检查是否可以开始下载并保存所有需要的数据
-        let can_start = self.download_state.can_start_download();
+        // 已取消、失败或文件损坏的任务会从头下载，需要完整的文件大小
+        let required = self
+            .download_state
+            .tasks
+            .iter()
+            .find(|t| t.task.id == id)
+            .map(|t| match t.task.status {
+                DownloadStatus::Waiting | DownloadStatus::Paused => t.remaining_size(),
+                _ => t.remaining_size() + t.task.downloaded_size,
+            })
+            .unwrap_or(0);
+        let can_start = self.download_state.can_start_download() && self.preflight_disk_space(required);
         let current_status = self
             .download_state
             .tasks
@@ -35,22 +45,18 @@ impl App {
                 || current_status == Some(DownloadStatus::Paused)
                 || current_status == Some(DownloadStatus::Cancelled)
                 || matches!(current_status, Some(DownloadStatus::Failed(_)))
+                || matches!(current_status, Some(DownloadStatus::Corrupt(_)))
             {
                 if can_start {
                     // 更新状态为下载中
                     let should_reset = current_status == Some(DownloadStatus::Cancelled)
-                        || matches!(current_status, Some(DownloadStatus::Failed(_)));
+                        || matches!(current_status, Some(DownloadStatus::Failed(_)))
+                        || matches!(current_status, Some(DownloadStatus::Corrupt(_)));
                     if let Some(task_full) = self.download_state.tasks.iter_mut().find(|t| t.task.id == id) {
                         task_full.task.status = DownloadStatus::Downloading;
                         task_full.task.start_time = Some(std::time::Instant::now());
 
-                        // 重置取消令牌
-                        if let Some(cancel_token) = &task_full.task.cancel_token {
-                            let cancel_token: &Arc<AtomicBool> = cancel_token;
-                            cancel_token.store(false, Ordering::Relaxed);
-                        }
-
-                        // 如果任务已取消或失败，重置已下载大小和进度
+                        // 如果任务已取消、失败或文件损坏，重置已下载大小和进度
                         if should_reset {
                             task_full.task.downloaded_size = 0;
                             task_full.task.progress = 0.0;
@@ -66,12 +72,17 @@ impl App {
                         let _ = self.download_state.save_to_database(&task_full_clone);
                     }
 
-                    // 获取取消令牌、文件总大小
-                    let (cancel_token, total_size) =
-                        if let Some(task) = self.download_state.tasks.iter().find(|t| t.task.id == task_id) {
-                            (task.task.cancel_token.clone().unwrap(), task.task.total_size)
+                    // 创建新的请求上下文，获取文件总大小和校验信息
+                    let (context, total_size, expected_size, file_type) =
+                        if let Some(task) = self.download_state.tasks.iter_mut().find(|t| t.task.id == task_id) {
+                            (
+                                task.task.renew_request_context(),
+                                task.task.total_size,
+                                task.expected_size,
+                                task.file_type.clone(),
+                            )
                         } else {
-                            (Arc::new(AtomicBool::new(false)), 0)
+                            (RequestContext::new(), 0, 0, String::new())
                         };
 
                     // 读取实际文件大小作为下载偏移量
@@ -118,16 +129,18 @@ impl App {
 
                     self.download_state.increment_downloading();
                     return Task::perform(
-                        async_task::async_download_wallpaper_task_with_progress(
-                            url.to_string(),
+                        async_task::async_download_wallpaper_task_with_progress(async_task::DownloadRequest {
+                            url: url.to_string(),
                             save_path,
                             proxy,
                             task_id,
-                            cancel_token,
-                            actual_file_size,
+                            context,
+                            downloaded_size: actual_file_size,
                             total_size,
                             cache_path,
-                        ),
+                            expected_size,
+                            file_type,
+                        }),
                         move |result| match result {
                             Ok(size) => {
                                 tracing::info!("[下载任务] [ID:{}] 下载成功, 文件大小: {} bytes", task_id, size);
@@ -150,6 +163,7 @@ impl App {
                         let task_full_clone = task_full.clone();
                         let _ = self.download_state.save_to_database(&task_full_clone);
                     }
+                    return self.notify_queue_paused();
                 }
             }
         }
@@ -2,19 +2,26 @@
 
 use crate::services::async_task;
 use crate::services::download::DownloadService;
+use crate::services::request_context::RequestContext;
 use crate::ui::download::{DownloadMessage, DownloadStatus};
 use crate::ui::{App, AppMessage};
 use iced::Task;
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Instant;
 
 impl App {
     pub(in crate::ui::download) fn retry_download_task(&mut self, id: usize) -> Task<AppMessage> {
         // 重新下载：清空已下载文件，从头开始下载
         // 先检查是否可以开始下载并保存所有需要的数据
-        let can_start = self.download_state.can_start_download();
+        // 重新下载需要完整的文件大小
+        let required = self
+            .download_state
+            .tasks
+            .iter()
+            .find(|t| t.task.id == id)
+            .map(|t| t.remaining_size() + t.task.downloaded_size)
+            .unwrap_or(0);
+        let can_start = self.download_state.can_start_download() && self.preflight_disk_space(required);
         let task_data = self.download_state.tasks.iter().find(|t| t.task.id == id).map(|t| {
             (
                 t.task.url.clone(),
@@ -29,17 +36,12 @@ impl App {
                 if let Some(task_full) = self.download_state.tasks.iter_mut().find(|t| t.task.id == id) {
                     // 重置任务状态和进度
                     task_full.task.status = DownloadStatus::Downloading;
+                    task_full.task.next_retry_at = None;
                     task_full.task.start_time = Some(Instant::now());
                     task_full.task.downloaded_size = 0;
                     task_full.task.progress = 0.0;
                     task_full.task.speed = 0;
 
-                    // 重置取消令牌
-                    if let Some(cancel_token) = &task_full.task.cancel_token {
-                        let cancel_token: &Arc<AtomicBool> = cancel_token;
-                        cancel_token.store(false, Ordering::Relaxed);
-                    }
-
                     // 清空缓存文件（cache_path/online中的文件）
                     let cache_path = self.config.data.cache_path.clone();
                     if let Ok(cache_file_path) = DownloadService::get_online_image_cache_path(&cache_path, &url, 0) {
@@ -59,26 +61,33 @@ impl App {
 
                 self.download_state.increment_downloading();
 
-                // 获取取消令牌和文件总大小（已下载大小为0，因为要重新下载）
-                let (cancel_token, total_size) =
-                    if let Some(task) = self.download_state.tasks.iter().find(|t| t.task.id == task_id) {
-                        (task.task.cancel_token.clone().unwrap(), task.task.total_size)
+                // 创建新的请求上下文，获取文件总大小和校验信息（已下载大小为0，因为要重新下载）
+                let (context, total_size, expected_size, file_type) =
+                    if let Some(task) = self.download_state.tasks.iter_mut().find(|t| t.task.id == task_id) {
+                        (
+                            task.task.renew_request_context(),
+                            task.task.total_size,
+                            task.expected_size,
+                            task.file_type.clone(),
+                        )
                     } else {
-                        (Arc::new(AtomicBool::new(false)), 0)
+                        (RequestContext::new(), 0, 0, String::new())
                     };
 
                 let cache_path = self.config.data.cache_path.clone();
                 return Task::perform(
-                    async_task::async_download_wallpaper_task_with_progress(
-                        url.to_string(),
+                    async_task::async_download_wallpaper_task_with_progress(async_task::DownloadRequest {
+                        url: url.to_string(),
                         save_path,
                         proxy,
                         task_id,
-                        cancel_token,
-                        0,          // 重新下载，从0开始
-                        total_size, // 保留文件总大小，用于缓存路径计算
+                        context,
+                        downloaded_size: 0, // 重新下载，从0开始
+                        total_size,         // 保留文件总大小，用于缓存路径计算
                         cache_path,
-                    ),
+                        expected_size,
+                        file_type,
+                    }),
                     move |result| match result {
                         Ok(size) => {
                             tracing::info!("[下载任务] [ID:{}] 重新下载成功, 文件大小: {} bytes", task_id, size);
@@ -94,12 +103,14 @@ impl App {
                 // 无法立即开始下载，加入排队
                 if let Some(task_full) = self.download_state.tasks.iter_mut().find(|t| t.task.id == id) {
                     task_full.task.status = DownloadStatus::Waiting;
+                    task_full.task.next_retry_at = None;
                     task_full.task.queue_order = self.download_state.queue_counter;
                     self.download_state.queue_counter += 1;
                     // 保存状态到数据库
                     let task_full_clone = task_full.clone();
                     let _ = self.download_state.save_to_database(&task_full_clone);
                 }
+                return self.notify_queue_paused();
             }
         }
         Task::none()
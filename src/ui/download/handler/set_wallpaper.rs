@@ -1,6 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::async_task;
+use crate::services::hooks::WallpaperSource;
 use crate::ui::main::MainMessage;
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
@@ -20,9 +21,13 @@ impl App {
 
                 // 异步设置壁纸
                 return Task::perform(
-                    async_task::async_set_wallpaper(full_path.clone(), wallpaper_mode),
+                    async_task::async_set_wallpaper(
+                        full_path.clone(),
+                        wallpaper_mode,
+                        self.config.data.cache_path.clone(),
+                    ),
                     move |result| match result {
-                        Ok(_) => MainMessage::AddToWallpaperHistory(full_path).into(),
+                        Ok(_) => MainMessage::AddToWallpaperHistory(full_path, WallpaperSource::Manual).into(),
                         Err(e) => {
                             MainMessage::ShowNotification(format!("{}: {}", failed_message, e), NotificationType::Error)
                                 .into()
@@ -0,0 +1,48 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::sidecar::WallpaperMetadata;
+use crate::ui::{App, AppMessage};
+use iced::Task;
+use std::path::Path;
+
+impl App {
+    /// 保存下载完成的壁纸的元数据：导入 Wallhaven 标签，并按设置写入元数据附属文件
+    /// （在后台进行，搜索结果缺少的标签和上传者会先从壁纸详情中获取；失败只记录日志，不影响下载结果）
+    ///
+    /// # 参数
+    /// - `file_path`: 壁纸文件路径
+    /// - `metadata`: 壁纸元数据
+    ///
+    /// # 返回
+    /// 元数据保存完成后结束的任务
+    pub(in crate::ui) fn save_wallpaper_metadata(
+        &self,
+        file_path: &Path,
+        metadata: &WallpaperMetadata,
+    ) -> Task<AppMessage> {
+        let api_key = if self.config.wallhaven.api_key.is_empty() {
+            None
+        } else {
+            Some(self.config.wallhaven.api_key.clone())
+        };
+        let proxy = if self.config.global.proxy_enabled && !self.config.global.proxy.is_empty() {
+            Some(self.config.global.proxy.clone())
+        } else {
+            None
+        };
+
+        Task::perform(
+            async_task::async_save_wallpaper_metadata(
+                file_path.to_path_buf(),
+                metadata.clone(),
+                self.config.download.write_sidecar,
+                self.config.download.write_xmp_sidecar,
+                api_key,
+                proxy,
+                self.config.global.proxy_enabled,
+            ),
+            |_| AppMessage::None,
+        )
+    }
+}
@@ -50,7 +50,7 @@ impl App {
                     a.task.total_size.cmp(&b.task.total_size)
                 }
                 SortColumn::Status => {
-                    // 状态排序：Waiting < Downloading < Paused < Completed < Failed < Cancelled
+                    // 状态排序：Waiting < Downloading < Paused < Completed < Failed < Corrupt < Cancelled
                     status_order(&a.task.status).cmp(&status_order(&b.task.status))
                 }
                 SortColumn::CreatedAt => {
@@ -76,6 +76,7 @@ fn status_order(status: &crate::ui::download::state::DownloadStatus) -> u8 {
         DownloadStatus::Paused => 2,
         DownloadStatus::Completed => 3,
         DownloadStatus::Failed(_) => 4,
-        DownloadStatus::Cancelled => 5,
+        DownloadStatus::Corrupt(_) => 5,
+        DownloadStatus::Cancelled => 6,
     }
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::async_task;
-use crate::services::wallhaven;
-use crate::ui::download::{DownloadMessage, DownloadStatus};
+use crate::services::sidecar::WallpaperMetadata;
+use crate::services::wallhaven::{self, OnlineWallpaper};
+use crate::ui::download::{DownloadMessage, DownloadStatus, DownloadTaskSpec};
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
 use std::path::PathBuf;
@@ -10,33 +11,44 @@ use std::time::Instant;
 
 impl App {
     /// 辅助方法：开始下载壁纸（支持并行限制和进度更新）
-    pub fn start_download(&mut self, url: String, id: &str, file_type: &str) -> Task<AppMessage> {
-        let file_name = wallhaven::generate_file_name(id, file_type.split('/').last().unwrap_or("jpg"));
-        let data_path = self.config.data.data_path.clone();
+    ///
+    /// `full_save_path` 为按文件名模板生成的完整保存路径，见 [`App::resolve_download_path`]；
+    /// `metadata` 在下载完成后写入附属文件
+    pub fn start_download(
+        &mut self,
+        url: String,
+        full_save_path: PathBuf,
+        file_type: &str,
+        file_size: u64,
+        metadata: Option<WallpaperMetadata>,
+    ) -> Task<AppMessage> {
+        let file_name = full_save_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
         let cache_path = self.config.data.cache_path.clone();
         let proxy = if self.config.global.proxy_enabled && !self.config.global.proxy.is_empty() {
             Some(self.config.global.proxy.clone())
         } else {
             None
         };
-        let file_type = file_type.split('/').last().unwrap_or("jpg").to_string();
-
-        // 生成完整保存路径
-        let full_save_path = PathBuf::from(&data_path).join(&file_name);
+        let file_type = wallhaven::file_extension(file_type);
 
         // 添加任务（倒序排列）
-        self.download_state.add_task(
-            url.clone(),
-            full_save_path.to_string_lossy().to_string(),
-            file_name.clone(),
-            proxy.clone(),
-            file_type.clone(),
-        );
+        self.download_state.add_task(DownloadTaskSpec {
+            url: url.clone(),
+            save_path: full_save_path.to_string_lossy().to_string(),
+            file_name: file_name.clone(),
+            proxy: proxy.clone(),
+            file_type: file_type.clone(),
+            expected_size: file_size,
+            metadata,
+        });
 
         // 获取任务ID
         let task_id = self.download_state.next_id.saturating_sub(1);
 
-        if self.download_state.can_start_download() {
+        if self.download_state.can_start_download() && self.preflight_disk_space(file_size) {
             // 可以开始下载 - 使用索引查找任务
             let task_index = self.download_state.find_task_index(task_id);
             if let Some(index) = task_index {
@@ -47,9 +59,11 @@ impl App {
                     let save_path = PathBuf::from(&task_full.task.save_path);
                     let proxy = task_full.proxy.clone();
                     let task_id = task_full.task.id;
-                    let cancel_token = task_full.task.cancel_token.clone().unwrap();
+                    let context = task_full.task.renew_request_context();
                     let downloaded_size = task_full.task.downloaded_size;
                     let total_size = task_full.task.total_size;
+                    let expected_size = task_full.expected_size;
+                    let file_type = task_full.file_type.clone();
                     let cache_path = cache_path.clone();
 
                     // 更新状态
@@ -65,16 +79,18 @@ impl App {
 
                     // 启动异步下载任务（带进度更新）
                     return Task::perform(
-                        async_task::async_download_wallpaper_task_with_progress(
-                            url.to_string(),
+                        async_task::async_download_wallpaper_task_with_progress(async_task::DownloadRequest {
+                            url: url.to_string(),
                             save_path,
                             proxy,
                             task_id,
-                            cancel_token,
+                            context,
                             downloaded_size,
                             total_size,
                             cache_path,
-                        ),
+                            expected_size,
+                            file_type,
+                        }),
                         move |result| match result {
                             Ok(size) => {
                                 tracing::info!("[下载任务] [ID:{}] 下载成功, 文件大小: {} bytes", task_id, size);
@@ -90,7 +106,17 @@ impl App {
             }
         }
 
-        // 显示通知
+        // 显示通知（磁盘空间不足时提示下载队列已暂停）
+        if self.download_state.queue_paused_reason.is_some() {
+            return self.notify_queue_paused();
+        }
         self.show_notification(format!("已添加到下载队列 (等待中)"), NotificationType::Success)
     }
+
+    /// 按配置的文件名模板生成壁纸的完整保存路径（合并已获取的壁纸详情，见 [`App::fetch_template_details`]）
+    pub fn resolve_download_path(&self, wallpaper: &OnlineWallpaper) -> PathBuf {
+        let wallpaper = self.with_template_details(wallpaper);
+        let relative_path = wallhaven::render_file_template(&self.config.download.file_name_template, &wallpaper);
+        PathBuf::from(&self.config.data.data_path).join(relative_path)
+    }
 }
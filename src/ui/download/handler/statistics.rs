@@ -0,0 +1,30 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use tracing::error;
+
+/// 统计面板显示的天数
+const STATISTICS_DAYS: i64 = 30;
+
+impl App {
+    /// 从数据库加载最近 30 天的下载统计并打开统计面板
+    pub(in crate::ui::download) fn show_download_statistics(&mut self) -> Task<AppMessage> {
+        let result = match &self.download_state.database {
+            Some(db) => db.load_recent_stats(STATISTICS_DAYS),
+            None => Err("数据库未初始化".to_string()),
+        };
+
+        match result {
+            Ok(daily_stats) => {
+                self.download_state.statistics.daily_stats = Some(daily_stats);
+                Task::none()
+            }
+            Err(e) => {
+                error!("[下载统计] 加载失败: {}", e);
+                let message = format!("{}: {}", self.i18n.t("download-stats.load-failed"), e);
+                self.show_notification(message, NotificationType::Error)
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::download_transfer::TaskTransferRecord;
+use crate::ui::download::DownloadMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use std::collections::HashSet;
+use tracing::{error, info};
+
+impl App {
+    pub(in crate::ui::download) fn export_download_tasks(&mut self) -> Task<AppMessage> {
+        let records = self.download_state.export_records(&self.config.data.data_path);
+        if records.is_empty() {
+            let message = self.i18n.t("download-transfer.nothing-to-export").to_string();
+            return self.show_notification(message, NotificationType::Info);
+        }
+
+        let default_file_name = format!("download-tasks-{}.json", chrono::Local::now().format("%Y%m%d"));
+        Task::perform(
+            async_task::async_export_download_tasks(records, default_file_name),
+            |result| DownloadMessage::ExportTasksFinished(result).into(),
+        )
+    }
+
+    pub(in crate::ui::download) fn export_download_tasks_finished(
+        &mut self,
+        result: Result<Option<(String, usize)>, String>,
+    ) -> Task<AppMessage> {
+        match result {
+            Ok(Some((path, exported))) => {
+                info!("[下载任务] 导出 {} 个下载任务到: {}", exported, path);
+                let message = self
+                    .i18n
+                    .t("download-transfer.exported")
+                    .replace("{count}", &exported.to_string())
+                    .replace("{path}", &path);
+                self.show_notification(message, NotificationType::Success)
+            }
+            Ok(None) => Task::none(),
+            Err(e) => {
+                error!("[下载任务] 导出下载任务失败: {}", e);
+                let message = format!("{}: {}", self.i18n.t("download-transfer.export-failed"), e);
+                self.show_notification(message, NotificationType::Error)
+            }
+        }
+    }
+
+    pub(in crate::ui::download) fn import_download_tasks(&mut self) -> Task<AppMessage> {
+        Task::perform(async_task::async_import_download_tasks(), |result| {
+            DownloadMessage::ImportTasksLoaded(result).into()
+        })
+    }
+
+    /// 按导入的记录重建下载任务
+    ///
+    /// 保存路径映射到本机的壁纸库目录；下载列表中已有相同 URL 的任务或目标文件已存在时跳过
+    pub(in crate::ui::download) fn import_download_tasks_loaded(
+        &mut self,
+        result: Result<Option<Vec<TaskTransferRecord>>, String>,
+    ) -> Task<AppMessage> {
+        let records = match result {
+            Ok(Some(records)) => records,
+            Ok(None) => return Task::none(),
+            Err(e) => {
+                error!("[下载任务] 导入下载任务失败: {}", e);
+                let message = format!("{}: {}", self.i18n.t("download-transfer.import-failed"), e);
+                return self.show_notification(message, NotificationType::Error);
+            }
+        };
+
+        let data_path = self.config.data.data_path.clone();
+        let proxy = if self.config.global.proxy_enabled && !self.config.global.proxy.is_empty() {
+            Some(self.config.global.proxy.clone())
+        } else {
+            None
+        };
+
+        let mut existing_urls: HashSet<String> = self
+            .download_state
+            .tasks
+            .iter()
+            .map(|task| task.task.url.clone())
+            .collect();
+        let mut imported = 0;
+        let mut skipped = 0;
+
+        for record in &records {
+            let save_path = match record.local_save_path(&data_path) {
+                Some(save_path) if !existing_urls.contains(&record.url) && !save_path.exists() => save_path,
+                _ => {
+                    skipped += 1;
+                    continue;
+                }
+            };
+
+            self.download_state
+                .add_imported_task(record, save_path.to_string_lossy().to_string(), proxy.clone());
+            existing_urls.insert(record.url.clone());
+            imported += 1;
+        }
+
+        info!(
+            "[下载任务] 导入下载任务: 共 {} 条, 导入 {} 条, 跳过 {} 条",
+            records.len(),
+            imported,
+            skipped
+        );
+
+        let message = self
+            .i18n
+            .t("download-transfer.imported")
+            .replace("{imported}", &imported.to_string())
+            .replace("{skipped}", &skipped.to_string());
+        self.show_notification(message, NotificationType::Success)
+    }
+}
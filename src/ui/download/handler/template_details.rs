@@ -0,0 +1,98 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::request_context::RequestContext;
+use crate::services::wallhaven::{self, OnlineWallpaper};
+use crate::ui::download::DownloadMessage;
+use crate::ui::{App, AppMessage};
+use iced::Task;
+use tracing::warn;
+
+impl App {
+    /// 文件名模板用到标签或上传者时，先获取搜索结果中缺少的壁纸详情
+    ///
+    /// # 参数
+    /// - `wallpaper`: 要下载的在线壁纸
+    /// - `then`: 详情获取完成后重新处理的消息
+    ///
+    /// # 返回
+    /// 需要获取详情时返回获取任务，否则返回 `None`（可以直接生成保存路径）
+    pub fn fetch_template_details(&self, wallpaper: &OnlineWallpaper, then: AppMessage) -> Option<Task<AppMessage>> {
+        if !wallhaven::template_needs_details(&self.config.download.file_name_template)
+            || wallpaper.id.is_empty()
+            || !wallpaper.uploader.is_empty()
+            || self.download_state.wallpaper_details.contains_key(&wallpaper.id)
+        {
+            return None;
+        }
+
+        let api_key = if self.config.wallhaven.api_key.is_empty() {
+            None
+        } else {
+            Some(self.config.wallhaven.api_key.clone())
+        };
+        let proxy = if self.config.global.proxy_enabled && !self.config.global.proxy.is_empty() {
+            Some(self.config.global.proxy.clone())
+        } else {
+            None
+        };
+
+        let id = wallpaper.id.clone();
+        Some(Task::perform(
+            async_task::async_get_online_wallpaper_rate_limited(
+                id.clone(),
+                api_key,
+                proxy,
+                self.config.global.proxy_enabled,
+                true, // 启用环境变量回退
+                RequestContext::new(),
+            ),
+            move |result| DownloadMessage::TemplateDetailsLoaded(id, result.map(Box::new), Box::new(then)).into(),
+        ))
+    }
+
+    /// 返回合并了已获取详情（标签、上传者）的壁纸信息
+    pub fn with_template_details(&self, wallpaper: &OnlineWallpaper) -> OnlineWallpaper {
+        let mut wallpaper = wallpaper.clone();
+        if let Some(Some(details)) = self.download_state.wallpaper_details.get(&wallpaper.id) {
+            merge_details(&mut wallpaper, details);
+        }
+        wallpaper
+    }
+
+    /// 壁纸详情获取完成：记录详情并同步到在线列表，然后继续处理原来的消息
+    pub(in crate::ui::download) fn template_details_loaded(
+        &mut self,
+        id: String,
+        result: Result<OnlineWallpaper, String>,
+        then: AppMessage,
+    ) -> Task<AppMessage> {
+        let details = match result {
+            Ok(details) => {
+                for wallpaper in self
+                    .online_state
+                    .wallpapers_data
+                    .iter_mut()
+                    .filter(|wallpaper| wallpaper.id == id)
+                {
+                    merge_details(wallpaper, &details);
+                }
+                Some(details)
+            }
+            Err(e) => {
+                // 获取失败时按缺少数据处理（占位符使用 unknown），不阻止下载
+                warn!("[下载任务] [ID:{}] 获取壁纸详情失败: {}", id, e);
+                None
+            }
+        };
+        self.download_state.wallpaper_details.insert(id, details);
+
+        Task::done(then)
+    }
+}
+
+/// 把详情中的标签和上传者合并到搜索结果的壁纸信息中
+fn merge_details(wallpaper: &mut OnlineWallpaper, details: &OnlineWallpaper) {
+    wallpaper.tags = details.tags.clone();
+    wallpaper.uploader = details.uploader.clone();
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::sidecar::WallpaperMetadata;
+use crate::services::transcode::TranscodeOutcome;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+impl App {
+    /// 下载后转码完成，更新任务记录的文件路径和类型，然后写入附属文件并执行钩子命令
+    ///
+    /// 转码失败时保留原文件，只提示错误
+    pub(in crate::ui::download) fn download_transcode_finished(
+        &mut self,
+        id: usize,
+        result: Result<TranscodeOutcome, String>,
+    ) -> Task<AppMessage> {
+        let notification = match result {
+            Ok(outcome) => {
+                if outcome.transcoded {
+                    if let Some(task_full) = self.download_state.tasks.iter_mut().find(|t| t.task.id == id) {
+                        info!(
+                            "[转码] [ID:{}] 已更新任务文件: {} -> {}",
+                            id,
+                            task_full.task.save_path,
+                            outcome.path.display()
+                        );
+                        task_full.task.save_path = outcome.path.to_string_lossy().to_string();
+                        task_full.task.file_name = outcome
+                            .path
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .unwrap_or(&task_full.task.file_name)
+                            .to_string();
+                        task_full.task.total_size = outcome.size;
+                        task_full.task.downloaded_size = outcome.size;
+                        task_full.file_type = outcome.file_type;
+                    }
+                    if let Some(task_full) = self.download_state.tasks.iter().find(|t| t.task.id == id) {
+                        let _ = self.download_state.save_to_database(task_full);
+                    }
+                }
+                Task::none()
+            }
+            Err(e) => {
+                warn!("[转码] [ID:{}] 转码失败，保留原文件: {}", id, e);
+                let message = format!("{}: {}", self.i18n.t("transcode.failed"), e);
+                self.show_notification(message, NotificationType::Error)
+            }
+        };
+
+        Task::batch([notification, self.finish_completed_download(id)])
+    }
+
+    /// 从缓存复制的文件转码完成，使用转码后的文件写入附属文件并执行钩子命令
+    ///
+    /// 转码失败时保留原文件，只提示错误
+    pub(in crate::ui::download) fn cache_copy_transcoded(
+        &mut self,
+        file_path: PathBuf,
+        url: String,
+        metadata: WallpaperMetadata,
+        result: Result<TranscodeOutcome, String>,
+    ) -> Task<AppMessage> {
+        let (file_path, notification) = match result {
+            Ok(outcome) => {
+                if outcome.transcoded {
+                    info!("[转码] 已转码: {} -> {}", file_path.display(), outcome.path.display());
+                }
+                (outcome.path, Task::none())
+            }
+            Err(e) => {
+                warn!("[转码] 转码失败，保留原文件 {}: {}", file_path.display(), e);
+                let message = format!("{}: {}", self.i18n.t("transcode.failed"), e);
+                (file_path, self.show_notification(message, NotificationType::Error))
+            }
+        };
+
+        Task::batch([
+            notification,
+            self.finish_downloaded_file(&file_path.to_string_lossy(), &url, Some(&metadata)),
+        ])
+    }
+}
@@ -9,8 +9,8 @@ use crate::ui::{App, AppMessage};
 /// 下载页面消息
 #[derive(Debug, Clone)]
 pub enum DownloadMessage {
-    /// 添加新下载任务 (url, save_path, file_name, proxy, file_type)
-    AddTask(String, String, String, Option<String>, String),
+    /// 添加在线壁纸下载任务（保存路径按文件名模板生成）
+    AddTask(Box<crate::services::wallhaven::OnlineWallpaper>),
     /// 暂停任务
     PauseTask(usize),
     /// 继续任务（断点续传）
@@ -27,10 +27,12 @@ pub enum DownloadMessage {
     ClearCompleted,
     /// 下载完成 (任务ID, 文件大小, 错误信息)
     DownloadCompleted(usize, u64, Option<String>),
-    /// 下载进度更新
-    DownloadProgress(usize, u64, u64, u64),
+    /// 下载进度更新（按帧合并的多个任务的最新进度）
+    DownloadProgress(Vec<crate::services::download_progress::DownloadProgressUpdate>),
     /// 更新下载速度（定时触发）
     UpdateSpeed,
+    /// 检查并执行到期的自动重试（定时触发）
+    AutoRetryTick,
     /// 复制下载链接
     CopyDownloadLink(usize),
     /// 设为壁纸
@@ -57,6 +59,63 @@ pub enum DownloadMessage {
     BatchCancel,
     /// 批量删除选中的任务
     BatchDelete,
+    /// 处理重复下载（用户在对话框中的选择）
+    ResolveDuplicate(crate::ui::download::state::DuplicateAction),
+    /// 打开导入下载任务对话框
+    ShowImport,
+    /// 关闭导入下载任务对话框（取消未完成的获取）
+    CloseImport,
+    /// 导入输入框编辑操作
+    ImportInputAction(iced::widget::text_editor::Action),
+    /// 选择要导入的 .txt / .csv 文件
+    ImportSelectFile,
+    /// 导入文件读取完成（None表示用户取消选择）
+    ImportFileLoaded(Result<Option<String>, String>),
+    /// 提取壁纸ID并开始获取壁纸详情
+    StartImportResolve,
+    /// 单张壁纸详情获取完成 (壁纸ID, 结果)
+    ImportResolved(String, Result<Box<crate::services::wallhaven::OnlineWallpaper>, String>),
+    /// 导入列表中的壁纸已检查是否已拥有 (壁纸ID -> 已有文件的路径)
+    ImportOwnedChecked(std::collections::HashMap<String, std::path::PathBuf>),
+    /// 切换导入列表中壁纸的选中状态 (列表索引)
+    ToggleImportEntry(usize),
+    /// 将选中的壁纸添加到下载队列
+    ConfirmImport,
+    /// 导出下载任务列表（JSON / CSV）
+    ExportTasks,
+    /// 导出完成（None表示用户取消选择，否则为导出文件的路径和导出的任务数量）
+    ExportTasksFinished(Result<Option<(String, usize)>, String>),
+    /// 从导出文件导入下载任务列表
+    ImportTasks,
+    /// 导出文件读取完成（None表示用户取消选择）
+    ImportTasksLoaded(Result<Option<Vec<crate::services::download_transfer::TaskTransferRecord>>, String>),
+    /// 记录一次吞吐量采样（定时触发）
+    ThroughputTick,
+    /// 打开下载统计面板
+    ShowStatistics,
+    /// 关闭下载统计面板
+    CloseStatistics,
+    /// 恢复因磁盘空间不足而暂停的下载队列
+    ResumeQueue,
+    /// 重新计算有配额的目录占用空间（定时触发）
+    RefreshDirectoryUsage,
+    /// 目录占用空间计算完成 (目录路径 -> 占用字节数, 是否接着恢复下载队列)
+    DirectoryUsageLoaded(Result<std::collections::HashMap<String, u64>, String>, bool),
+    /// 下载后转码完成 (任务ID, 转码结果)
+    TranscodeFinished(usize, Result<crate::services::transcode::TranscodeOutcome, String>),
+    /// 从缓存复制的文件转码完成 (文件路径, 原图下载地址, 壁纸元数据, 转码结果)
+    CacheCopyTranscoded(
+        std::path::PathBuf,
+        String,
+        Box<crate::services::sidecar::WallpaperMetadata>,
+        Result<crate::services::transcode::TranscodeOutcome, String>,
+    ),
+    /// 文件名模板所需的壁纸详情获取完成 (壁纸ID, 结果, 之后继续处理的消息)
+    TemplateDetailsLoaded(
+        String,
+        Result<Box<crate::services::wallhaven::OnlineWallpaper>, String>,
+        Box<AppMessage>,
+    ),
 }
 
 impl From<DownloadMessage> for AppMessage {
@@ -69,21 +128,22 @@ impl App {
     /// 处理下载相关消息
     pub fn handle_download_message(&mut self, msg: DownloadMessage) -> iced::Task<AppMessage> {
         match msg {
-            DownloadMessage::AddTask(url, save_path, file_name, _proxy, file_type) => {
-                self.add_download_task(url, save_path, file_name, file_type)
-            }
+            DownloadMessage::AddTask(wallpaper) => self.add_download_task(*wallpaper),
             DownloadMessage::PauseTask(id) => self.pause_download_task(id),
             DownloadMessage::ResumeTask(id) => self.resume_download_task(id),
-            DownloadMessage::RetryTask(id) => self.retry_download_task(id),
+            DownloadMessage::RetryTask(id) => {
+                // 手动重试时重新计算自动重试次数
+                self.download_state.reset_retry_state(id);
+                self.retry_download_task(id)
+            }
             DownloadMessage::CancelTask(id) => self.cancel_download_task(id),
             DownloadMessage::DeleteTask(id) => self.delete_download_task(id),
             DownloadMessage::OpenFileLocation(id) => self.view_downloaded_file(id),
             DownloadMessage::ClearCompleted => self.clear_download_completed_tasks(),
             DownloadMessage::DownloadCompleted(id, size, error) => self.download_completed(id, size, error),
-            DownloadMessage::DownloadProgress(id, downloaded, total, speed) => {
-                self.update_download_progress(id, downloaded, total, speed)
-            }
+            DownloadMessage::DownloadProgress(updates) => self.update_download_progress(updates),
             DownloadMessage::UpdateSpeed => self.update_download_speed(),
+            DownloadMessage::AutoRetryTick => self.auto_retry_tick(),
             DownloadMessage::CopyDownloadLink(id) => self.copy_download_link(id),
             DownloadMessage::SetAsWallpaper(id) => self.set_downloaded_as_wallpaper(id),
             DownloadMessage::ToggleStatusFilter => {
@@ -122,6 +182,50 @@ impl App {
                 self.batch_delete_selected_tasks();
                 iced::Task::none()
             }
+            DownloadMessage::ResolveDuplicate(action) => self.resolve_download_duplicate(action),
+            DownloadMessage::ShowImport => self.show_download_import(),
+            DownloadMessage::CloseImport => self.close_download_import(),
+            DownloadMessage::ImportInputAction(action) => {
+                self.download_state.import.input.perform(action);
+                iced::Task::none()
+            }
+            DownloadMessage::ImportSelectFile => self.select_download_import_file(),
+            DownloadMessage::ImportFileLoaded(result) => self.download_import_file_loaded(result),
+            DownloadMessage::StartImportResolve => self.start_download_import_resolve(),
+            DownloadMessage::ImportResolved(id, result) => self.download_import_resolved(id, result.map(|w| *w)),
+            DownloadMessage::ImportOwnedChecked(owned) => self.download_import_owned_checked(owned),
+            DownloadMessage::ToggleImportEntry(index) => {
+                if let Some(entry) = self.download_state.import.entries.get_mut(index) {
+                    entry.selected = !entry.selected && entry.is_selectable();
+                }
+                iced::Task::none()
+            }
+            DownloadMessage::ConfirmImport => self.confirm_download_import(),
+            DownloadMessage::ExportTasks => self.export_download_tasks(),
+            DownloadMessage::ExportTasksFinished(result) => self.export_download_tasks_finished(result),
+            DownloadMessage::ImportTasks => self.import_download_tasks(),
+            DownloadMessage::ImportTasksLoaded(result) => self.import_download_tasks_loaded(result),
+            DownloadMessage::ThroughputTick => {
+                self.download_state.sample_throughput();
+                iced::Task::none()
+            }
+            DownloadMessage::ShowStatistics => self.show_download_statistics(),
+            DownloadMessage::CloseStatistics => {
+                self.download_state.statistics.daily_stats = None;
+                iced::Task::none()
+            }
+            DownloadMessage::ResumeQueue => self.resume_download_queue(),
+            DownloadMessage::RefreshDirectoryUsage => self.refresh_directory_usage(false),
+            DownloadMessage::DirectoryUsageLoaded(result, resume_queue) => {
+                self.directory_usage_loaded(result, resume_queue)
+            }
+            DownloadMessage::TranscodeFinished(id, result) => self.download_transcode_finished(id, result),
+            DownloadMessage::CacheCopyTranscoded(path, url, metadata, result) => {
+                self.cache_copy_transcoded(path, url, *metadata, result)
+            }
+            DownloadMessage::TemplateDetailsLoaded(id, result, then) => {
+                self.template_details_loaded(id, result.map(|w| *w), *then)
+            }
         }
     }
 }
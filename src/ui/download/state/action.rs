@@ -4,8 +4,12 @@ use super::DownloadStateFull;
 use super::DownloadStatus;
 use super::DownloadTask;
 use super::DownloadTaskFull;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use super::DownloadTaskSpec;
+use super::THROUGHPUT_HISTORY_LEN;
+use crate::services::download_transfer::TaskTransferRecord;
+use crate::services::request_context::RequestContext;
+use crate::services::wallhaven;
+use std::path::Path;
 
 impl DownloadStateFull {
     /// 初始化HTTP客户端
@@ -44,10 +48,13 @@ impl DownloadStateFull {
                 // 解析状态
                 let mut status = Self::parse_status(&task_db.status);
 
-                // 程序启动时，所有未完成（非 Completed、Cancelled和Failed）的任务都应该处于暂停状态
+                // 程序启动时，所有未完成（非 Completed、Cancelled、Failed和Corrupt）的任务都应该处于暂停状态
                 let is_completed = matches!(
                     status,
-                    DownloadStatus::Completed | DownloadStatus::Cancelled | DownloadStatus::Failed(_)
+                    DownloadStatus::Completed
+                        | DownloadStatus::Cancelled
+                        | DownloadStatus::Failed(_)
+                        | DownloadStatus::Corrupt(_)
                 );
 
                 if !is_completed {
@@ -71,17 +78,25 @@ impl DownloadStateFull {
                     speed: 0, // 启动时速度重置为0
                     status,
                     start_time: None,
-                    cancel_token: Some(Arc::new(AtomicBool::new(false))),
+                    request_context: RequestContext::new(),
                     created_at: chrono::DateTime::from_timestamp(task_db.created_at, 0)
                         .map(|dt| dt.with_timezone(&chrono::Local))
                         .unwrap_or_else(chrono::Local::now),
                     queue_order: 0, // 启动时初始化为0，恢复下载时会重新设置
+                    retry_count: task_db.retry_count,
+                    last_error: task_db.last_error,
+                    next_retry_at: None, // 待执行的重试不跨进程保留
                 };
 
                 self.tasks.push(DownloadTaskFull {
                     task,
                     proxy: task_db.proxy,
                     file_type: task_db.file_type,
+                    expected_size: task_db.expected_size,
+                    metadata: task_db
+                        .metadata
+                        .as_deref()
+                        .and_then(|metadata| serde_json::from_str(metadata).ok()),
                 });
 
                 // 更新 next_id 为最大 ID + 1
@@ -125,6 +140,13 @@ impl DownloadStateFull {
                 created_at: task_full.task.created_at.timestamp(),
                 proxy: task_full.proxy.clone(),
                 file_type: task_full.file_type.clone(),
+                expected_size: task_full.expected_size,
+                retry_count: task_full.task.retry_count,
+                last_error: task_full.task.last_error.clone(),
+                metadata: task_full
+                    .metadata
+                    .as_ref()
+                    .and_then(|metadata| serde_json::to_string(metadata).ok()),
             };
             db.save_task(&task_db)
         } else {
@@ -167,6 +189,9 @@ impl DownloadStateFull {
             "Paused" => DownloadStatus::Paused,
             "Completed" => DownloadStatus::Completed,
             "Cancelled" => DownloadStatus::Cancelled,
+            s if s.starts_with("Corrupt(") && s.ends_with(')') => {
+                DownloadStatus::Corrupt(s["Corrupt(".len()..s.len() - 1].to_string())
+            }
             _ => DownloadStatus::Failed(status_str.to_string()),
         }
     }
@@ -180,6 +205,7 @@ impl DownloadStateFull {
             DownloadStatus::Completed => "Completed".to_string(),
             DownloadStatus::Cancelled => "Cancelled".to_string(),
             DownloadStatus::Failed(msg) => format!("Failed({})", msg),
+            DownloadStatus::Corrupt(msg) => format!("Corrupt({})", msg),
         }
     }
 
@@ -206,31 +232,33 @@ impl DownloadStateFull {
     }
 
     /// 添加新下载任务（倒序插入到列表开头）
-    pub fn add_task(
-        &mut self,
-        url: String,
-        save_path: String,
-        file_name: String,
-        proxy: Option<String>,
-        file_type: String,
-    ) {
+    pub fn add_task(&mut self, spec: DownloadTaskSpec) {
         let task = DownloadTask {
             id: self.next_id,
-            file_name: file_name.clone(),
-            url: url.clone(),
-            save_path: save_path.clone(),
+            file_name: spec.file_name,
+            url: spec.url,
+            save_path: spec.save_path,
             downloaded_size: 0,
             total_size: 0,
             progress: 0.0,
             speed: 0,
             status: DownloadStatus::Waiting,
             start_time: None,
-            cancel_token: Some(Arc::new(AtomicBool::new(false))),
+            request_context: RequestContext::new(),
             created_at: chrono::Local::now(),
             queue_order: self.queue_counter,
+            retry_count: 0,
+            last_error: None,
+            next_retry_at: None,
         };
 
-        let task_full = DownloadTaskFull { task, proxy, file_type };
+        let task_full = DownloadTaskFull {
+            task,
+            proxy: spec.proxy,
+            file_type: spec.file_type,
+            expected_size: spec.expected_size,
+            metadata: spec.metadata,
+        };
 
         // 倒序插入：添加到列表开头
         self.tasks.insert(0, task_full.clone());
@@ -242,6 +270,110 @@ impl DownloadStateFull {
         self.queue_counter += 1;
     }
 
+    /// 将所有任务转换为导出记录
+    ///
+    /// # 参数
+    /// - `data_path`: 壁纸库目录（用于计算相对路径，便于在其他设备上重新映射保存路径）
+    pub fn export_records(&self, data_path: &str) -> Vec<TaskTransferRecord> {
+        self.tasks
+            .iter()
+            .map(|task_full| {
+                let relative_path = Path::new(&task_full.task.save_path)
+                    .strip_prefix(data_path)
+                    .map(|path| path.to_string_lossy().replace('\\', "/"))
+                    .unwrap_or_default();
+
+                TaskTransferRecord {
+                    wallhaven_id: wallhaven::extract_wallpaper_ids(&task_full.task.url)
+                        .into_iter()
+                        .next()
+                        .unwrap_or_default(),
+                    url: task_full.task.url.clone(),
+                    file_name: task_full.task.file_name.clone(),
+                    save_path: task_full.task.save_path.clone(),
+                    relative_path,
+                    status: Self::status_to_string(&task_full.task.status),
+                    total_size: task_full.task.total_size,
+                    expected_size: task_full.expected_size,
+                    file_type: task_full.file_type.clone(),
+                    created_at: task_full.task.created_at.to_rfc3339(),
+                }
+            })
+            .collect()
+    }
+
+    /// 添加从导出文件导入的任务
+    ///
+    /// 已完成和未完成的任务导入后均为暂停状态，由用户决定何时开始下载；
+    /// 失败、已取消和文件损坏的任务保留原状态，便于查看历史记录
+    ///
+    /// # 参数
+    /// - `record`: 导出记录
+    /// - `save_path`: 映射到本机壁纸库目录后的保存路径
+    /// - `proxy`: 本机的代理设置
+    pub fn add_imported_task(&mut self, record: &TaskTransferRecord, save_path: String, proxy: Option<String>) {
+        let status = match Self::parse_status(&record.status) {
+            DownloadStatus::Waiting
+            | DownloadStatus::Downloading
+            | DownloadStatus::Paused
+            | DownloadStatus::Completed => DownloadStatus::Paused,
+            status => status,
+        };
+        let file_name = Path::new(&save_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let file_type = if record.file_type.is_empty() {
+            Path::new(&file_name)
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_string())
+                .unwrap_or_else(|| "jpg".to_string())
+        } else {
+            wallhaven::file_extension(&record.file_type)
+        };
+
+        let task = DownloadTask {
+            id: self.next_id,
+            file_name,
+            url: record.url.clone(),
+            save_path,
+            total_size: record.total_size,
+            status,
+            request_context: RequestContext::new(),
+            created_at: chrono::DateTime::parse_from_rfc3339(&record.created_at)
+                .map(|dt| dt.with_timezone(&chrono::Local))
+                .unwrap_or_else(|_| chrono::Local::now()),
+            queue_order: self.queue_counter,
+            ..DownloadTask::default()
+        };
+
+        let task_full = DownloadTaskFull {
+            task,
+            proxy,
+            file_type,
+            expected_size: record.expected_size,
+            metadata: None,
+        };
+
+        self.tasks.insert(0, task_full.clone());
+        let _ = self.save_to_database(&task_full);
+
+        self.next_id += 1;
+        self.queue_counter += 1;
+    }
+
+    /// 获取等待中的任务ID（按排队顺序，先排队的在前）
+    pub fn waiting_task_ids(&self) -> Vec<usize> {
+        let mut waiting: Vec<_> = self
+            .tasks
+            .iter()
+            .filter(|t| t.task.status == DownloadStatus::Waiting)
+            .map(|t| (t.task.queue_order, t.task.id))
+            .collect();
+        waiting.sort();
+        waiting.into_iter().map(|(_, id)| id).collect()
+    }
+
     /// 获取下一个等待中的任务（按排队顺序，先排队的先开始）
     pub fn get_next_waiting_task(&mut self) -> Option<&mut DownloadTaskFull> {
         // 查找所有 Waiting 状态的任务，选择 queue_order 最小的
@@ -251,9 +383,17 @@ impl DownloadStateFull {
             .min_by(|a, b| a.task.queue_order.cmp(&b.task.queue_order))
     }
 
-    /// 更新任务进度
+    /// 更新任务进度（忽略已不在下载中的任务，进度按帧合并后可能晚于完成或暂停到达）
     pub fn update_progress(&mut self, id: usize, downloaded: u64, total: u64, speed: u64) {
-        if let Some(index) = self.tasks.iter().position(|t| t.task.id == id) {
+        if let Some(index) = self
+            .tasks
+            .iter()
+            .position(|t| t.task.id == id && t.task.status == DownloadStatus::Downloading)
+        {
+            // 累计本次更新新下载的字节数（用于吞吐量曲线，重新下载时已下载大小会变小）
+            let delta = downloaded.saturating_sub(self.tasks[index].task.downloaded_size);
+            self.statistics.bytes_since_sample += delta;
+
             self.tasks[index].task.downloaded_size = downloaded;
             self.tasks[index].task.total_size = total;
             self.tasks[index].task.speed = speed;
@@ -276,6 +416,14 @@ impl DownloadStateFull {
         }
     }
 
+    /// 重置任务的自动重试状态（用户手动重试时调用）
+    pub fn reset_retry_state(&mut self, id: usize) {
+        if let Some(index) = self.tasks.iter().position(|t| t.task.id == id) {
+            self.tasks[index].task.retry_count = 0;
+            self.tasks[index].task.next_retry_at = None;
+        }
+    }
+
     /// 获取任务（通过索引避免借用冲突）
     pub fn get_task_by_index(&mut self, index: usize) -> Option<&mut DownloadTaskFull> {
         self.tasks.get_mut(index)
@@ -312,10 +460,8 @@ impl DownloadStateFull {
     /// 取消任务
     pub fn cancel_task(&mut self, id: usize) {
         if let Some(task_full) = self.tasks.iter_mut().find(|t| t.task.id == id) {
-            // 设置取消标志
-            if let Some(cancel_token) = &task_full.task.cancel_token {
-                cancel_token.store(true, Ordering::Relaxed);
-            }
+            // 取消请求上下文，正在进行的下载会立即停止
+            task_full.task.request_context.cancel();
             // 注意：不在这里更新状态，让调用者决定最终状态
         }
     }
@@ -333,4 +479,61 @@ impl DownloadStateFull {
             }
         }
     }
+
+    /// 获取指定状态的任务数
+    pub fn count_by_status(&self, status: &DownloadStatus) -> usize {
+        self.tasks.iter().filter(|t| t.task.status.matches(status)).count()
+    }
+
+    /// 获取所有下载中任务的总速度（字节/秒）
+    pub fn total_throughput(&self) -> u64 {
+        self.tasks
+            .iter()
+            .filter(|t| t.task.status == DownloadStatus::Downloading)
+            .map(|t| t.task.speed)
+            .sum()
+    }
+
+    /// 获取下载中和等待中任务的剩余字节数（大小未知时使用 Wallhaven 提供的文件大小）
+    pub fn remaining_bytes(&self) -> u64 {
+        self.tasks
+            .iter()
+            .filter(|t| matches!(t.task.status, DownloadStatus::Downloading | DownloadStatus::Waiting))
+            .map(|t| t.remaining_size())
+            .sum()
+    }
+
+    /// 按当前总速度估算所有任务的剩余下载时间（秒），没有下载中的任务时返回 None
+    pub fn overall_eta_secs(&self) -> Option<u64> {
+        let throughput = self.total_throughput();
+        if throughput == 0 {
+            return None;
+        }
+        Some(self.remaining_bytes().div_ceil(throughput))
+    }
+
+    /// 记录一次吞吐量采样（每秒调用一次）
+    pub fn sample_throughput(&mut self) {
+        let statistics = &mut self.statistics;
+        statistics
+            .throughput_history
+            .push_back(std::mem::take(&mut statistics.bytes_since_sample));
+        while statistics.throughput_history.len() > THROUGHPUT_HISTORY_LEN {
+            statistics.throughput_history.pop_front();
+        }
+    }
+
+    /// 记录一个下载完成的文件到本次运行统计和数据库中的每日统计
+    pub fn record_completed_download(&mut self, bytes: u64) {
+        self.statistics.session_bytes += bytes;
+        self.statistics.session_files += 1;
+
+        let result = match &self.database {
+            Some(db) => db.record_download(bytes),
+            None => Ok(()),
+        };
+        if let Err(e) = result {
+            tracing::warn!("[下载统计] 保存失败: {}", e);
+        }
+    }
 }
@@ -15,6 +15,8 @@ pub enum DownloadStatus {
     Failed(String),
     /// 已取消
     Cancelled,
+    /// 文件损坏（完整性校验失败且重试次数已用尽）
+    Corrupt(String),
 }
 
 impl DownloadStatus {
@@ -27,6 +29,7 @@ impl DownloadStatus {
             DownloadStatus::Completed => "download-tasks.status-completed",
             DownloadStatus::Failed(_) => "download-tasks.status-failed",
             DownloadStatus::Cancelled => "download-tasks.status-cancelled",
+            DownloadStatus::Corrupt(_) => "download-tasks.status-corrupt",
         }
     }
 
@@ -34,6 +37,7 @@ impl DownloadStatus {
     pub fn matches(&self, other: &DownloadStatus) -> bool {
         match (self, other) {
             (DownloadStatus::Failed(_), DownloadStatus::Failed(_)) => true,
+            (DownloadStatus::Corrupt(_), DownloadStatus::Corrupt(_)) => true,
             _ => self == other,
         }
     }
@@ -62,12 +66,35 @@ pub struct DownloadTask {
     pub status: DownloadStatus,
     /// 下载开始时间（用于计算速度）
     pub start_time: Option<std::time::Instant>,
-    /// 取消令牌（用于终止下载）
-    pub cancel_token: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+    /// 请求上下文（用于终止下载，每次开始下载时重新创建）
+    pub request_context: crate::services::request_context::RequestContext,
     /// 任务创建时间
     pub created_at: chrono::DateTime<chrono::Local>,
     /// 排队顺序（用于记录用户加入排队的顺序，越小越先执行）
     pub queue_order: usize,
+    /// 已自动重试的次数
+    pub retry_count: u32,
+    /// 最近一次失败的错误信息
+    pub last_error: Option<String>,
+    /// 下次自动重试的时间（None表示没有待执行的重试）
+    pub next_retry_at: Option<std::time::Instant>,
+}
+
+impl DownloadTask {
+    /// 预计剩余下载时间（秒），不在下载中或速度、大小未知时返回 None
+    pub fn eta_secs(&self) -> Option<u64> {
+        if self.status != DownloadStatus::Downloading || self.speed == 0 || self.total_size == 0 {
+            return None;
+        }
+        let remaining = self.total_size.saturating_sub(self.downloaded_size);
+        Some(remaining.div_ceil(self.speed))
+    }
+
+    /// 为新一次下载创建请求上下文（暂停或取消后的上下文已处于取消状态，不能继续使用）
+    pub fn renew_request_context(&mut self) -> crate::services::request_context::RequestContext {
+        self.request_context = crate::services::request_context::RequestContext::new();
+        self.request_context.clone()
+    }
 }
 
 impl Default for DownloadTask {
@@ -83,9 +110,12 @@ impl Default for DownloadTask {
             speed: 0,
             status: DownloadStatus::Waiting,
             start_time: None,
-            cancel_token: None,
+            request_context: crate::services::request_context::RequestContext::new(),
             created_at: chrono::Local::now(),
             queue_order: 0,
+            retry_count: 0,
+            last_error: None,
+            next_retry_at: None,
         }
     }
 }
@@ -99,6 +129,29 @@ pub struct DownloadTaskFull {
     pub proxy: Option<String>,
     /// 原始文件类型
     pub file_type: String,
+    /// Wallhaven 提供的文件大小（字节，用于下载完成后的完整性校验，0表示未知）
+    pub expected_size: u64,
+    /// 壁纸元数据（下载完成后写入附属文件，导入的任务为 None）
+    pub metadata: Option<crate::services::sidecar::WallpaperMetadata>,
+}
+
+/// 新下载任务的信息（用于添加任务）
+#[derive(Debug, Clone)]
+pub struct DownloadTaskSpec {
+    /// 下载URL
+    pub url: String,
+    /// 保存路径
+    pub save_path: String,
+    /// 文件名
+    pub file_name: String,
+    /// 代理设置
+    pub proxy: Option<String>,
+    /// 原始文件类型
+    pub file_type: String,
+    /// Wallhaven 提供的文件大小（字节，0表示未知）
+    pub expected_size: u64,
+    /// 壁纸元数据（下载完成后写入附属文件）
+    pub metadata: Option<crate::services::sidecar::WallpaperMetadata>,
 }
 
 impl Default for DownloadTaskFull {
@@ -107,10 +160,24 @@ impl Default for DownloadTaskFull {
             task: DownloadTask::default(),
             proxy: None,
             file_type: "jpg".to_string(),
+            expected_size: 0,
+            metadata: None,
         }
     }
 }
 
+impl DownloadTaskFull {
+    /// 剩余需要下载的字节数（大小未知时使用 Wallhaven 提供的文件大小）
+    pub fn remaining_size(&self) -> u64 {
+        let total = if self.task.total_size > 0 {
+            self.task.total_size
+        } else {
+            self.expected_size
+        };
+        total.saturating_sub(self.task.downloaded_size)
+    }
+}
+
 /// 下载页面状态（扩展版，包含完整任务信息）
 #[derive(Debug, Default)]
 pub struct DownloadStateFull {
@@ -142,6 +209,22 @@ pub struct DownloadStateFull {
     pub select_all: bool,
     /// 已选中的任务ID集合
     pub selected_task_ids: std::collections::HashSet<usize>,
+    /// 自动重试的最大次数（用于状态列显示）
+    pub retry_max_attempts: u32,
+    /// 等待用户选择处理方式的重复下载
+    pub pending_duplicate: Option<PendingDuplicate>,
+    /// 导入下载任务对话框状态
+    pub import: ImportState,
+    /// 下载统计（吞吐量曲线、本次运行统计和统计面板）
+    pub statistics: DownloadStatistics,
+    /// 下载队列暂停的原因（磁盘空间不足或超出配额时不再自动开始等待中的任务）
+    pub queue_paused_reason: Option<String>,
+    /// 最近一次计算的目录占用空间（目录路径 -> 字节，用于检查配额）
+    pub directory_usage: std::collections::HashMap<String, u64>,
+    /// 是否正在后台计算目录占用空间
+    pub directory_usage_refreshing: bool,
+    /// 文件名模板用到的壁纸详情 (壁纸ID -> 详情，None表示获取失败)
+    pub wallpaper_details: std::collections::HashMap<String, Option<crate::services::wallhaven::OnlineWallpaper>>,
 }
 
 impl DownloadStateFull {
@@ -162,9 +245,22 @@ impl DownloadStateFull {
             queue_counter: 0,
             select_all: false,
             selected_task_ids: std::collections::HashSet::new(),
+            retry_max_attempts: 5,
+            pending_duplicate: None,
+            import: ImportState::default(),
+            statistics: DownloadStatistics::default(),
+            queue_paused_reason: None,
+            directory_usage: std::collections::HashMap::new(),
+            directory_usage_refreshing: false,
+            wallpaper_details: std::collections::HashMap::new(),
         }
     }
 
+    /// 检查是否有等待自动重试的任务
+    pub fn has_pending_retries(&self) -> bool {
+        self.tasks.iter().any(|task| task.task.next_retry_at.is_some())
+    }
+
     /// 检查是否有选中的任务
     pub fn has_selected_tasks(&self) -> bool {
         !self.selected_task_ids.is_empty()
@@ -254,3 +350,111 @@ impl SortColumn {
         }
     }
 }
+
+/// 下载前检测到的重复情况
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadDuplicate {
+    /// 相同壁纸的任务已在下载队列中
+    InQueue,
+    /// 壁纸已存在于壁纸库中（已有文件的路径）
+    Owned(std::path::PathBuf),
+}
+
+/// 重复下载的处理方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuplicateAction {
+    /// 跳过本次下载
+    Skip,
+    /// 重新下载并覆盖已有文件
+    Redownload,
+    /// 保留已有文件，新文件另存为新名称
+    KeepBoth,
+}
+
+/// 等待用户选择处理方式的重复下载
+#[derive(Debug, Clone)]
+pub struct PendingDuplicate {
+    /// 要下载的壁纸
+    pub wallpaper: crate::services::wallhaven::OnlineWallpaper,
+    /// 壁纸库中已有文件的路径
+    pub existing_path: std::path::PathBuf,
+}
+
+/// 导入下载任务对话框的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImportStage {
+    /// 对话框已关闭
+    #[default]
+    Closed,
+    /// 输入链接或ID（粘贴文本或选择文件）
+    Input,
+    /// 正在逐个获取壁纸详情
+    Resolving,
+    /// 预览获取结果并选择要下载的壁纸
+    Preview,
+}
+
+/// 导入列表中的一项
+#[derive(Debug, Clone)]
+pub struct ImportEntry {
+    /// 壁纸ID
+    pub id: String,
+    /// 获取到的壁纸详情（None表示尚未获取或获取失败）
+    pub wallpaper: Option<crate::services::wallhaven::OnlineWallpaper>,
+    /// 获取失败的错误信息
+    pub error: Option<String>,
+    /// 重复检测结果
+    pub duplicate: Option<DownloadDuplicate>,
+    /// 是否选中下载
+    pub selected: bool,
+}
+
+/// 导入下载任务对话框状态
+#[derive(Debug, Default)]
+pub struct ImportState {
+    /// 当前阶段
+    pub stage: ImportStage,
+    /// 输入框内容（粘贴的链接或ID，或从文件读取的内容）
+    pub input: iced::widget::text_editor::Content,
+    /// 提取出的壁纸列表
+    pub entries: Vec<ImportEntry>,
+    /// 已获取详情的数量
+    pub resolved_count: usize,
+    /// 请求上下文（关闭对话框时取消未完成的请求）
+    pub request_context: crate::services::request_context::RequestContext,
+}
+
+impl ImportEntry {
+    /// 是否可以选中下载（获取失败或已在下载队列中的壁纸不可选）
+    pub fn is_selectable(&self) -> bool {
+        self.wallpaper.is_some() && self.duplicate != Some(DownloadDuplicate::InQueue)
+    }
+}
+
+impl ImportState {
+    /// 已选中的壁纸
+    pub fn selected_wallpapers(&self) -> impl Iterator<Item = &crate::services::wallhaven::OnlineWallpaper> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.selected)
+            .filter_map(|entry| entry.wallpaper.as_ref())
+    }
+}
+
+/// 吞吐量曲线保留的采样数（每秒采样一次）
+pub const THROUGHPUT_HISTORY_LEN: usize = 60;
+
+/// 下载统计状态
+#[derive(Debug, Default)]
+pub struct DownloadStatistics {
+    /// 最近的吞吐量采样（字节/秒，最新的在末尾）
+    pub throughput_history: std::collections::VecDeque<u64>,
+    /// 上次采样后从下载进度更新中累计的字节数
+    pub bytes_since_sample: u64,
+    /// 本次运行下载完成的字节数
+    pub session_bytes: u64,
+    /// 本次运行下载完成的文件数
+    pub session_files: u64,
+    /// 统计面板中显示的每日统计（None 表示面板未打开）
+    pub daily_stats: Option<Vec<crate::ui::download::database::DailyDownloadStats>>,
+}
@@ -4,12 +4,12 @@
 //!
 //! 定义下载页面的界面渲染逻辑
 
-use super::state::DownloadStateFull;
+use super::state::{DownloadStateFull, ImportStage};
 use super::widget;
 use crate::i18n::I18n;
 use crate::ui::AppMessage;
 use crate::ui::style::ThemeConfig;
-use iced::widget::{Space, column, container, scrollable};
+use iced::widget::{Space, column, container, scrollable, stack};
 use iced::{Element, Length};
 
 /// 下载页面视图函数
@@ -38,6 +38,9 @@ pub fn download_view<'a>(
     // 添加工具栏
     let content = content.push(widget::create_toolbar(i18n, download_state, theme_config));
 
+    // 添加下载汇总栏
+    let content = content.push(widget::create_summary_bar(i18n, download_state, theme_config));
+
     // 添加垂直间距
     let content = content.push(container(Space::new()).height(Length::Fixed(10.0)));
 
@@ -52,9 +55,26 @@ pub fn download_view<'a>(
 
     let scrollable_content = scrollable(content).width(Length::Fill).height(Length::Fill);
 
-    container(scrollable_content)
+    let page_content = container(scrollable_content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20);
+
+    // 导入下载任务对话框和下载统计面板显示在页面内容之上
+    if download_state.import.stage != ImportStage::Closed {
+        stack![page_content, widget::create_import_dialog(i18n, &download_state.import)]
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    } else if let Some(daily_stats) = &download_state.statistics.daily_stats {
+        stack![
+            page_content,
+            widget::create_statistics_dialog(i18n, daily_stats, theme_config)
+        ]
         .width(Length::Fill)
         .height(Length::Fill)
-        .padding(20)
         .into()
+    } else {
+        page_content.into()
+    }
 }
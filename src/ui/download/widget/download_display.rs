@@ -6,11 +6,11 @@ use crate::ui::download::state::{DownloadStatus, DownloadTask};
 use crate::ui::style::ThemeConfig;
 use crate::utils::helpers;
 use iced::Element;
-use iced::widget::text;
+use iced::widget::{column, text};
 
-/// 创建下载显示
+/// 创建下载显示（速度和预计剩余时间）
 pub fn create_download_display<'a>(
-    _i18n: &'a I18n,
+    i18n: &'a I18n,
     task: &'a DownloadTask,
     theme_config: &'a ThemeConfig,
 ) -> Element<'a, AppMessage> {
@@ -21,10 +21,25 @@ pub fn create_download_display<'a>(
         _ => "0 B/s".to_string(),
     };
 
-    text(speed_text)
-        .size(12)
-        .style(move |_| text::Style {
-            color: Some(theme_colors.light_text_sub),
-        })
-        .into()
+    let speed = text(speed_text).size(12).style(move |_| text::Style {
+        color: Some(theme_colors.light_text_sub),
+    });
+
+    match task.eta_secs() {
+        Some(eta) => column![
+            speed,
+            text(format!(
+                "{} {}",
+                i18n.t("download-stats.eta"),
+                helpers::format_duration(eta)
+            ))
+            .size(11)
+            .style(move |_| text::Style {
+                color: Some(theme_colors.light_text_sub),
+            }),
+        ]
+        .spacing(2)
+        .into(),
+        None => speed.into(),
+    }
 }
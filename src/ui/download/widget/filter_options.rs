@@ -33,6 +33,10 @@ pub fn create_filter_options<'a>(
             Some(DownloadStatus::Failed(String::new())),
             i18n.t("download-tasks.status-failed"),
         ),
+        (
+            Some(DownloadStatus::Corrupt(String::new())),
+            i18n.t("download-tasks.status-corrupt"),
+        ),
         (
             Some(DownloadStatus::Cancelled),
             i18n.t("download-tasks.status-cancelled"),
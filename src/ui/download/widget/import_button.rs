@@ -0,0 +1,44 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::download::message::DownloadMessage;
+use crate::ui::style::BUTTON_COLOR_BLUE;
+use iced::widget::{button, row, text};
+use iced::{Alignment, Element};
+
+/// 创建从链接导入下载任务按钮
+pub fn create_import_button(i18n: &I18n) -> Element<'_, AppMessage> {
+    let button_content = row![
+        text("\u{F470}") // link-45deg
+            .font(iced::Font::with_name("bootstrap-icons"))
+            .size(14)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(iced::Color::WHITE),
+            }),
+        text(i18n.t("download-import.button"))
+            .size(13)
+            .style(|_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(iced::Color::WHITE),
+            }),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
+
+    button(button_content)
+        .style(
+            |_theme: &iced::Theme, _status: iced::widget::button::Status| iced::widget::button::Style {
+                text_color: iced::Color::WHITE,
+                background: Some(iced::Background::Color(BUTTON_COLOR_BLUE)),
+                border: iced::Border {
+                    color: BUTTON_COLOR_BLUE,
+                    width: 0.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            },
+        )
+        .on_press(AppMessage::Download(DownloadMessage::ShowImport))
+        .padding([6, 12])
+        .into()
+}
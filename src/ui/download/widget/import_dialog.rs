@@ -0,0 +1,228 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::download::message::DownloadMessage;
+use crate::ui::download::state::{DownloadDuplicate, ImportEntry, ImportStage, ImportState};
+use crate::ui::style::*;
+use crate::utils::helpers;
+use iced::widget::{Row, checkbox, column, container, row, scrollable, text, text_editor};
+use iced::{Alignment, Color, Element, Length};
+
+/// 导入对话框内容区域的宽度
+const IMPORT_CONTENT_WIDTH: f32 = 600.0;
+/// 输入框的高度
+const IMPORT_INPUT_HEIGHT: f32 = 220.0;
+/// 预览列表的高度
+const IMPORT_PREVIEW_HEIGHT: f32 = 300.0;
+/// 预览列表中各列的宽度（选中框、ID、分辨率、大小）
+const IMPORT_CHECKBOX_WIDTH: f32 = 40.0;
+const IMPORT_ID_WIDTH: f32 = 100.0;
+const IMPORT_RESOLUTION_WIDTH: f32 = 120.0;
+const IMPORT_SIZE_WIDTH: f32 = 100.0;
+/// 预览列表的文字大小
+const IMPORT_TABLE_TEXT_SIZE: f32 = 13.0;
+
+/// 创建导入下载任务对话框（根据当前阶段显示输入框、获取进度或预览列表）
+pub fn create_import_dialog<'a>(i18n: &'a I18n, state: &'a ImportState) -> Element<'a, AppMessage> {
+    let dialog_content = match state.stage {
+        ImportStage::Input => create_input_content(i18n, state),
+        ImportStage::Resolving => create_resolving_content(i18n, state),
+        ImportStage::Preview | ImportStage::Closed => create_preview_content(i18n, state),
+    };
+
+    common::create_modal_dialog(
+        column![dialog_content]
+            .padding(DIALOG_PADDING)
+            .align_x(Alignment::Center)
+            .width(Length::Shrink)
+            .into(),
+    )
+}
+
+/// 输入阶段：粘贴链接或ID，或从文件导入
+fn create_input_content<'a>(i18n: &'a I18n, state: &'a ImportState) -> Element<'a, AppMessage> {
+    let input = text_editor(&state.input)
+        .placeholder(i18n.t("download-import.placeholder"))
+        .on_action(|action| DownloadMessage::ImportInputAction(action).into())
+        .size(IMPORT_TABLE_TEXT_SIZE)
+        .height(Length::Fixed(IMPORT_INPUT_HEIGHT));
+
+    column![
+        text(i18n.t("download-import.title")).size(DIALOG_TITLE_SIZE),
+        text(i18n.t("download-import.hint")).size(DIALOG_MESSAGE_SIZE),
+        container(input).width(Length::Fixed(IMPORT_CONTENT_WIDTH)),
+        row![
+            common::create_colored_button(
+                i18n.t("download-import.select-file"),
+                BUTTON_COLOR_GRAY,
+                DownloadMessage::ImportSelectFile.into()
+            ),
+            common::create_colored_button(
+                i18n.t("download-import.resolve"),
+                BUTTON_COLOR_BLUE,
+                DownloadMessage::StartImportResolve.into()
+            ),
+            common::create_colored_button(
+                i18n.t("download-import.cancel"),
+                BUTTON_COLOR_GRAY,
+                DownloadMessage::CloseImport.into()
+            ),
+        ]
+        .spacing(DIALOG_BUTTON_SPACING),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+/// 获取阶段：显示已获取的数量
+fn create_resolving_content<'a>(i18n: &'a I18n, state: &'a ImportState) -> Element<'a, AppMessage> {
+    let progress_text = i18n
+        .t("download-import.resolving")
+        .replace("{current}", &state.resolved_count.to_string())
+        .replace("{total}", &state.entries.len().to_string());
+
+    column![
+        text(i18n.t("download-import.title")).size(DIALOG_TITLE_SIZE),
+        text(progress_text).size(DIALOG_MESSAGE_SIZE),
+        common::create_colored_button(
+            i18n.t("download-import.cancel"),
+            BUTTON_COLOR_GRAY,
+            DownloadMessage::CloseImport.into()
+        ),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+/// 预览阶段：显示每张壁纸的分辨率、大小和重复情况，选择要下载的壁纸
+fn create_preview_content<'a>(i18n: &'a I18n, state: &'a ImportState) -> Element<'a, AppMessage> {
+    let selected_count = state.selected_wallpapers().count();
+    let selected_size: u64 = state.selected_wallpapers().map(|wallpaper| wallpaper.file_size).sum();
+    let summary_text = i18n
+        .t("download-import.summary")
+        .replace("{total}", &state.entries.len().to_string())
+        .replace(
+            "{resolved}",
+            &state
+                .entries
+                .iter()
+                .filter(|entry| entry.wallpaper.is_some())
+                .count()
+                .to_string(),
+        )
+        .replace("{count}", &selected_count.to_string())
+        .replace("{size}", &helpers::format_file_size(selected_size));
+
+    let header = row![
+        text("").width(Length::Fixed(IMPORT_CHECKBOX_WIDTH)),
+        text(i18n.t("download-import.header-id"))
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fixed(IMPORT_ID_WIDTH)),
+        text(i18n.t("download-import.header-resolution"))
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fixed(IMPORT_RESOLUTION_WIDTH)),
+        text(i18n.t("download-import.header-size"))
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fixed(IMPORT_SIZE_WIDTH)),
+        text(i18n.t("download-import.header-status"))
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fill),
+    ]
+    .align_y(Alignment::Center);
+
+    let rows = state
+        .entries
+        .iter()
+        .enumerate()
+        .fold(column![].spacing(6), |rows, (index, entry)| {
+            rows.push(create_entry_row(i18n, index, entry))
+        });
+
+    let buttons: Row<'a, AppMessage> = if selected_count == 0 {
+        row![common::create_colored_button(
+            i18n.t("download-import.cancel"),
+            BUTTON_COLOR_GRAY,
+            DownloadMessage::CloseImport.into()
+        )]
+    } else {
+        row![
+            common::create_colored_button(
+                i18n.t("download-import.confirm"),
+                BUTTON_COLOR_GREEN,
+                DownloadMessage::ConfirmImport.into()
+            ),
+            common::create_colored_button(
+                i18n.t("download-import.cancel"),
+                BUTTON_COLOR_GRAY,
+                DownloadMessage::CloseImport.into()
+            ),
+        ]
+    };
+
+    column![
+        text(i18n.t("download-import.preview-title")).size(DIALOG_TITLE_SIZE),
+        text(summary_text).size(DIALOG_MESSAGE_SIZE),
+        column![
+            header,
+            scrollable(rows)
+                .width(Length::Fill)
+                .height(Length::Fixed(IMPORT_PREVIEW_HEIGHT)),
+        ]
+        .spacing(8)
+        .width(Length::Fixed(IMPORT_CONTENT_WIDTH)),
+        buttons.spacing(DIALOG_BUTTON_SPACING),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+/// 创建预览列表中的一行
+fn create_entry_row<'a>(i18n: &'a I18n, index: usize, entry: &'a ImportEntry) -> Element<'a, AppMessage> {
+    let (resolution, size) = match &entry.wallpaper {
+        Some(wallpaper) => (
+            wallpaper.resolution.clone(),
+            helpers::format_file_size(wallpaper.file_size),
+        ),
+        None => ("-".to_string(), "-".to_string()),
+    };
+
+    let (status, status_color): (String, Color) = if entry.error.is_some() {
+        (i18n.t("download-import.status-failed"), BUTTON_COLOR_RED)
+    } else {
+        match &entry.duplicate {
+            Some(DownloadDuplicate::InQueue) => (i18n.t("download-import.status-in-queue"), BUTTON_COLOR_YELLOW),
+            Some(DownloadDuplicate::Owned(_)) => (i18n.t("download-import.status-owned"), BUTTON_COLOR_YELLOW),
+            None => (i18n.t("download-import.status-ready"), BUTTON_COLOR_GREEN),
+        }
+    };
+
+    let entry_checkbox = if entry.is_selectable() {
+        checkbox(entry.selected).on_toggle(move |_| DownloadMessage::ToggleImportEntry(index).into())
+    } else {
+        checkbox(false)
+    };
+
+    row![
+        container(entry_checkbox).width(Length::Fixed(IMPORT_CHECKBOX_WIDTH)),
+        text(&entry.id)
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fixed(IMPORT_ID_WIDTH)),
+        text(resolution)
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fixed(IMPORT_RESOLUTION_WIDTH)),
+        text(size)
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .width(Length::Fixed(IMPORT_SIZE_WIDTH)),
+        text(status)
+            .size(IMPORT_TABLE_TEXT_SIZE)
+            .color(status_color)
+            .width(Length::Fill),
+    ]
+    .align_y(Alignment::Center)
+    .into()
+}
@@ -7,19 +7,28 @@ mod clear_completed;
 mod download_display;
 mod empty;
 mod filter_options;
+mod import_button;
+mod import_dialog;
 mod operation_buttons;
 mod separator;
+mod sparkline;
+mod statistics_dialog;
 mod status_display;
 mod status_filter;
+mod summary_bar;
 mod table;
 mod table_header;
 mod table_row;
 mod toolbar;
+mod transfer_buttons;
 
 // 重新导出需要公开访问的函数
 pub use checkbox::{create_checkbox_header, create_task_checkbox};
 pub use empty::create_filtered_empty_state;
+pub use import_dialog::create_import_dialog;
 pub use separator::{create_horizontal_separator, create_vertical_separator};
+pub use statistics_dialog::create_statistics_dialog;
+pub use summary_bar::create_summary_bar;
 pub use table::create_filtered_table;
 pub use table_header::create_table_header;
 pub use toolbar::create_toolbar;
@@ -27,6 +36,8 @@ pub use toolbar::create_toolbar;
 use {
     batch_button::create_batch_button, batch_operation::create_batch_operation_buttons,
     clear_completed::create_clear_completed_button, download_display::create_download_display,
-    filter_options::create_filter_options, operation_buttons::create_operation_buttons,
-    status_display::create_status_display, status_filter::create_status_filter_dropdown, table_row::create_table_row,
+    filter_options::create_filter_options, import_button::create_import_button,
+    operation_buttons::create_operation_buttons, sparkline::Sparkline, status_display::create_status_display,
+    status_filter::create_status_filter_dropdown, table_row::create_table_row,
+    transfer_buttons::create_transfer_buttons,
 };
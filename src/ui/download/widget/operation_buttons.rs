@@ -73,6 +73,10 @@ pub fn create_operation_buttons<'a>(i18n: &'a I18n, task: &'a DownloadTask) -> E
             // 下载失败：重新下载/复制下载链接/删除
             row![retry_button, copy_button, delete_button].spacing(6).into()
         }
+        DownloadStatus::Corrupt(_) => {
+            // 文件损坏：重新下载/复制下载链接/删除
+            row![retry_button, copy_button, delete_button].spacing(6).into()
+        }
         DownloadStatus::Cancelled => {
             // 已取消：重新下载/复制下载链接/删除
             row![retry_button, copy_button, delete_button].spacing(6).into()
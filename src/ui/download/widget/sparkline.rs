@@ -0,0 +1,76 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::download::state::THROUGHPUT_HISTORY_LEN;
+use iced::mouse;
+use iced::widget::canvas;
+use iced::{Color, Point, Rectangle, Renderer, Theme};
+use std::collections::VecDeque;
+
+/// 吞吐量曲线绘制程序（最新的采样在最右侧）
+pub struct Sparkline<'a> {
+    pub samples: &'a VecDeque<u64>,
+    pub color: Color,
+}
+
+impl<Message> canvas::Program<Message, Theme, Renderer> for Sparkline<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        // 基线
+        let baseline = canvas::Path::line(
+            Point::new(0.0, bounds.height - 0.5),
+            Point::new(bounds.width, bounds.height - 0.5),
+        );
+        frame.stroke(
+            &baseline,
+            canvas::Stroke {
+                style: canvas::Style::Solid(Color { a: 0.3, ..self.color }),
+                width: 1.0,
+                ..Default::default()
+            },
+        );
+
+        let max = self.samples.iter().copied().max().unwrap_or(0);
+        if self.samples.len() >= 2 && max > 0 {
+            // 按固定的采样数量划分横坐标，采样不足时曲线从右侧开始
+            let step = bounds.width / (THROUGHPUT_HISTORY_LEN - 1) as f32;
+            let offset = THROUGHPUT_HISTORY_LEN.saturating_sub(self.samples.len()) as f32 * step;
+            let height = bounds.height - 2.0;
+
+            let path = canvas::Path::new(|builder| {
+                for (i, sample) in self.samples.iter().enumerate() {
+                    let point = Point::new(
+                        offset + i as f32 * step,
+                        1.0 + height - (*sample as f32 / max as f32) * height,
+                    );
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+            });
+
+            frame.stroke(
+                &path,
+                canvas::Stroke {
+                    style: canvas::Style::Solid(self.color),
+                    width: 1.5,
+                    line_join: canvas::LineJoin::Round,
+                    ..Default::default()
+                },
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::download::database::DailyDownloadStats;
+use crate::ui::download::message::DownloadMessage;
+use crate::ui::style::*;
+use crate::utils::helpers;
+use iced::widget::{column, container, row, scrollable, text};
+use iced::{Alignment, Element, Length};
+
+/// 统计面板内容区域的宽度
+const STATS_CONTENT_WIDTH: f32 = 420.0;
+/// 统计列表的高度
+const STATS_LIST_HEIGHT: f32 = 320.0;
+/// 统计列表中各列的宽度（日期、文件数、大小）
+const STATS_DATE_WIDTH: f32 = 160.0;
+const STATS_FILES_WIDTH: f32 = 100.0;
+/// 统计列表的文字大小
+const STATS_TEXT_SIZE: f32 = 13.0;
+
+/// 创建下载统计面板（最近 30 天每天下载的文件数和大小）
+pub fn create_statistics_dialog<'a>(
+    i18n: &'a I18n,
+    daily_stats: &'a [DailyDownloadStats],
+    theme_config: &'a ThemeConfig,
+) -> Element<'a, AppMessage> {
+    let list: Element<'a, AppMessage> = if daily_stats.is_empty() {
+        container(text(i18n.t("download-stats.empty")).size(DIALOG_MESSAGE_SIZE))
+            .width(Length::Fill)
+            .padding(20)
+            .align_x(Alignment::Center)
+            .into()
+    } else {
+        let rows = daily_stats.iter().fold(column![].spacing(6), |rows, stats| {
+            rows.push(create_stats_row(
+                stats.date.clone(),
+                stats.files,
+                helpers::format_file_size(stats.bytes),
+            ))
+        });
+        scrollable(rows)
+            .width(Length::Fill)
+            .height(Length::Fixed(STATS_LIST_HEIGHT))
+            .into()
+    };
+
+    let total_files: u64 = daily_stats.iter().map(|stats| stats.files).sum();
+    let total_bytes: u64 = daily_stats.iter().map(|stats| stats.bytes).sum();
+
+    let header = row![
+        text(i18n.t("download-stats.date"))
+            .size(STATS_TEXT_SIZE)
+            .width(Length::Fixed(STATS_DATE_WIDTH)),
+        text(i18n.t("download-stats.files"))
+            .size(STATS_TEXT_SIZE)
+            .width(Length::Fixed(STATS_FILES_WIDTH)),
+        text(i18n.t("download-stats.bytes"))
+            .size(STATS_TEXT_SIZE)
+            .width(Length::Fill),
+    ];
+
+    let dialog_content = column![
+        text(i18n.t("download-stats.title")).size(DIALOG_TITLE_SIZE),
+        column![
+            header,
+            super::create_horizontal_separator(theme_config),
+            list,
+            super::create_horizontal_separator(theme_config),
+            create_stats_row(
+                i18n.t("download-stats.total"),
+                total_files,
+                helpers::format_file_size(total_bytes)
+            ),
+        ]
+        .spacing(8)
+        .width(Length::Fixed(STATS_CONTENT_WIDTH)),
+        common::create_colored_button(
+            i18n.t("download-stats.close"),
+            BUTTON_COLOR_GRAY,
+            DownloadMessage::CloseStatistics.into()
+        ),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center);
+
+    common::create_modal_dialog(
+        column![dialog_content]
+            .padding(DIALOG_PADDING)
+            .align_x(Alignment::Center)
+            .width(Length::Shrink)
+            .into(),
+    )
+}
+
+/// 创建统计列表中的一行
+fn create_stats_row<'a>(label: String, files: u64, size: String) -> Element<'a, AppMessage> {
+    row![
+        text(label).size(STATS_TEXT_SIZE).width(Length::Fixed(STATS_DATE_WIDTH)),
+        text(files.to_string())
+            .size(STATS_TEXT_SIZE)
+            .width(Length::Fixed(STATS_FILES_WIDTH)),
+        text(size).size(STATS_TEXT_SIZE).width(Length::Fill),
+    ]
+    .align_y(Alignment::Center)
+    .into()
+}
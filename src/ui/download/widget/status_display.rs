@@ -14,6 +14,7 @@ use iced::{Alignment, Element, Length};
 pub fn create_status_display<'a>(
     i18n: &'a I18n,
     task: &'a DownloadTask,
+    retry_max_attempts: u32,
     theme_config: &'a ThemeConfig,
 ) -> Element<'a, AppMessage> {
     let theme_colors = theme_config.get_theme_colors();
@@ -52,12 +53,36 @@ pub fn create_status_display<'a>(
                 color: Some(BUTTON_COLOR_GREEN),
             })
             .into(),
+        DownloadStatus::Failed(_msg) if task.next_retry_at.is_some() => {
+            // 等待自动重试：显示倒计时和重试次数
+            let seconds = task
+                .next_retry_at
+                .map(|retry_at| retry_at.saturating_duration_since(std::time::Instant::now()).as_secs())
+                .unwrap_or(0);
+            let retrying_text = i18n
+                .t("download-tasks.status-retrying")
+                .replace("{seconds}", &seconds.to_string())
+                .replace("{attempt}", &task.retry_count.to_string())
+                .replace("{max}", &retry_max_attempts.to_string());
+            text(retrying_text)
+                .size(12)
+                .style(|_| text::Style {
+                    color: Some(BUTTON_COLOR_YELLOW),
+                })
+                .into()
+        }
         DownloadStatus::Failed(_msg) => text(i18n.t("download-tasks.status-failed-error"))
             .size(12)
             .style(|_| text::Style {
                 color: Some(BUTTON_COLOR_RED),
             })
             .into(),
+        DownloadStatus::Corrupt(_msg) => text(i18n.t("download-tasks.status-corrupt"))
+            .size(12)
+            .style(|_| text::Style {
+                color: Some(BUTTON_COLOR_RED),
+            })
+            .into(),
         DownloadStatus::Cancelled => text(i18n.t("download-tasks.status-cancelled"))
             .size(12)
             .style(|_| text::Style {
@@ -0,0 +1,151 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::download::message::DownloadMessage;
+use crate::ui::download::state::{DownloadStateFull, DownloadStatus};
+use crate::ui::style::{BUTTON_COLOR_BLUE, BUTTON_COLOR_RED, ThemeConfig};
+use crate::utils::helpers;
+use iced::widget::{Space, button, canvas, column, container, row, text};
+use iced::{Alignment, Element, Length};
+
+/// 吞吐量曲线的尺寸
+const SPARKLINE_WIDTH: f32 = 180.0;
+const SPARKLINE_HEIGHT: f32 = 30.0;
+
+/// 创建下载汇总栏（任务数、总速度、剩余大小、预计剩余时间、本次运行统计和吞吐量曲线）
+pub fn create_summary_bar<'a>(
+    i18n: &'a I18n,
+    download_state: &'a DownloadStateFull,
+    theme_config: &'a ThemeConfig,
+) -> Element<'a, AppMessage> {
+    let theme_colors = theme_config.get_theme_colors();
+    let statistics = &download_state.statistics;
+
+    let eta_text = match download_state.overall_eta_secs() {
+        Some(eta) => helpers::format_duration(eta),
+        None => "-".to_string(),
+    };
+
+    let summary_text = [
+        format!(
+            "{}: {}",
+            i18n.t("download-stats.active"),
+            download_state.count_by_status(&DownloadStatus::Downloading)
+        ),
+        format!(
+            "{}: {}",
+            i18n.t("download-stats.waiting"),
+            download_state.count_by_status(&DownloadStatus::Waiting)
+        ),
+        format!(
+            "{}: {}/s",
+            i18n.t("download-stats.throughput"),
+            helpers::format_file_size(download_state.total_throughput())
+        ),
+        format!(
+            "{}: {}",
+            i18n.t("download-stats.remaining"),
+            helpers::format_file_size(download_state.remaining_bytes())
+        ),
+        format!("{}: {}", i18n.t("download-stats.eta"), eta_text),
+    ]
+    .join("  ·  ");
+
+    let session_text = i18n
+        .t("download-stats.session")
+        .replace("{files}", &statistics.session_files.to_string())
+        .replace("{size}", &helpers::format_file_size(statistics.session_bytes));
+
+    let sparkline = canvas(super::Sparkline {
+        samples: &statistics.throughput_history,
+        color: BUTTON_COLOR_BLUE,
+    })
+    .width(Length::Fixed(SPARKLINE_WIDTH))
+    .height(Length::Fixed(SPARKLINE_HEIGHT));
+
+    let statistics_button = button(
+        row![
+            text("\u{F17E}") // bar-chart
+                .font(iced::Font::with_name("bootstrap-icons"))
+                .size(14)
+                .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(theme_colors.text),
+                }),
+            text(i18n.t("download-stats.button"))
+                .size(13)
+                .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(theme_colors.text),
+                }),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .style(
+        move |_theme: &iced::Theme, _status: iced::widget::button::Status| iced::widget::button::Style {
+            text_color: theme_colors.text,
+            background: Some(iced::Background::Color(theme_colors.light_button)),
+            border: iced::Border {
+                color: theme_colors.border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        },
+    )
+    .on_press(DownloadMessage::ShowStatistics.into())
+    .padding([6, 12]);
+
+    // 下载队列因磁盘空间不足暂停时显示原因和恢复按钮
+    let paused_banner: Element<'a, AppMessage> = match &download_state.queue_paused_reason {
+        Some(reason) => row![
+            text(reason.as_str()).size(12).color(BUTTON_COLOR_RED),
+            common::create_colored_button(
+                i18n.t("download-space.resume-queue"),
+                BUTTON_COLOR_BLUE,
+                DownloadMessage::ResumeQueue.into()
+            ),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .into(),
+        None => Space::new().into(),
+    };
+
+    let summary_content = row![
+        column![
+            text(summary_text)
+                .size(13)
+                .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(theme_colors.text),
+                }),
+            text(session_text)
+                .size(12)
+                .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                    color: Some(theme_colors.light_text_sub),
+                }),
+            paused_banner,
+        ]
+        .spacing(4),
+        Space::new().width(Length::Fill),
+        sparkline,
+        statistics_button,
+    ]
+    .spacing(16)
+    .align_y(Alignment::Center);
+
+    container(summary_content)
+        .width(Length::Fill)
+        .padding([8, 20])
+        .style(move |_theme: &iced::Theme| container::Style {
+            background: Some(iced::Background::Color(theme_colors.light_bg)),
+            border: iced::Border {
+                color: theme_colors.border,
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+}
@@ -13,6 +13,7 @@ pub fn create_table_row<'a>(
     i18n: &'a I18n,
     task: &'a DownloadTask,
     is_selected: bool,
+    retry_max_attempts: u32,
     theme_config: &'a ThemeConfig,
 ) -> Element<'a, AppMessage> {
     let theme_colors = theme_config.get_theme_colors();
@@ -47,7 +48,7 @@ pub fn create_table_row<'a>(
         // 分隔线
         super::create_vertical_separator(theme_config),
         // 状态列
-        container(super::create_status_display(i18n, task, theme_config))
+        container(super::create_status_display(i18n, task, retry_max_attempts, theme_config))
             .width(Length::Fixed(220.0))
             .padding(5),
         // 分隔线
@@ -21,6 +21,12 @@ pub fn create_toolbar<'a>(
     // 创建删除所有已完成任务按钮
     let clear_completed_button = super::create_clear_completed_button(i18n, download_state, theme_config);
 
+    // 创建导入下载任务按钮
+    let import_button = super::create_import_button(i18n);
+
+    // 创建导出/导入下载任务列表按钮
+    let transfer_buttons = super::create_transfer_buttons(i18n, theme_config);
+
     // 创建状态筛选下拉框
     let filter_dropdown = super::create_status_filter_dropdown(i18n, download_state, theme_config);
 
@@ -38,6 +44,14 @@ pub fn create_toolbar<'a>(
             }),
         // 删除所有已完成任务区域
         container(clear_completed_button).padding(10).width(Length::Shrink),
+        // 导入下载任务区域
+        container(
+            row![import_button, transfer_buttons]
+                .spacing(8)
+                .align_y(Alignment::Center)
+        )
+        .padding(10)
+        .width(Length::Shrink),
         // 分隔线
         container(text(""))
             .width(Length::Fixed(1.0))
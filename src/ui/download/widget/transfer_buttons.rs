@@ -0,0 +1,71 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::download::message::DownloadMessage;
+use crate::ui::style::ThemeConfig;
+use iced::widget::{button, row, text};
+use iced::{Alignment, Element};
+
+/// 创建导出/导入下载任务列表按钮
+pub fn create_transfer_buttons<'a>(i18n: &'a I18n, theme_config: &'a ThemeConfig) -> Element<'a, AppMessage> {
+    row![
+        create_transfer_button(
+            "\u{F1C6}", // box-arrow-up
+            i18n.t("download-transfer.export"),
+            DownloadMessage::ExportTasks,
+            theme_config,
+        ),
+        create_transfer_button(
+            "\u{F1BC}", // box-arrow-in-down
+            i18n.t("download-transfer.import"),
+            DownloadMessage::ImportTasks,
+            theme_config,
+        ),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+fn create_transfer_button<'a>(
+    icon: &'static str,
+    label: String,
+    message: DownloadMessage,
+    theme_config: &'a ThemeConfig,
+) -> Element<'a, AppMessage> {
+    let theme_colors = theme_config.get_theme_colors();
+
+    let button_content = row![
+        text(icon)
+            .font(iced::Font::with_name("bootstrap-icons"))
+            .size(14)
+            .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(theme_colors.text),
+            }),
+        text(label)
+            .size(13)
+            .style(move |_theme: &iced::Theme| iced::widget::text::Style {
+                color: Some(theme_colors.text),
+            }),
+    ]
+    .spacing(6)
+    .align_y(Alignment::Center);
+
+    button(button_content)
+        .style(
+            move |_theme: &iced::Theme, _status: iced::widget::button::Status| iced::widget::button::Style {
+                text_color: theme_colors.text,
+                background: Some(iced::Background::Color(theme_colors.light_button)),
+                border: iced::Border {
+                    color: theme_colors.border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            },
+        )
+        .on_press(AppMessage::Download(message))
+        .padding([6, 12])
+        .into()
+}
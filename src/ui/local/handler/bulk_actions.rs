@@ -0,0 +1,263 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 批量操作处理模块
+//!
+//! 对选中的壁纸执行批量删除（移到回收站）、移动或复制到其他壁纸库目录、复制路径和导出为 zip
+
+use crate::services::async_task;
+use crate::services::database::UserMetadataRepository;
+use crate::services::library_transfer::{TransferMode, TransferReport};
+use crate::services::library_watcher::LibraryChanges;
+use crate::services::trash::TrashReport;
+use crate::ui::local::LocalMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use crate::utils::helpers;
+use iced::Task;
+use std::collections::HashSet;
+use tracing::{error, info, warn};
+
+impl App {
+    /// 显示批量删除确认对话框
+    pub(in crate::ui::local) fn show_local_bulk_delete_confirm(&mut self) -> Task<AppMessage> {
+        if !self.local_state.selected_paths.is_empty() {
+            self.local_state.bulk_delete_confirm_visible = true;
+        }
+        Task::none()
+    }
+
+    /// 关闭批量删除确认对话框
+    pub(in crate::ui::local) fn close_local_bulk_delete_confirm(&mut self) -> Task<AppMessage> {
+        self.local_state.bulk_delete_confirm_visible = false;
+        Task::none()
+    }
+
+    /// 把选中的壁纸移到回收站
+    pub(in crate::ui::local) fn confirm_local_bulk_delete(&mut self) -> Task<AppMessage> {
+        self.local_state.bulk_delete_confirm_visible = false;
+        if self.local_state.bulk_delete_running {
+            return Task::none();
+        }
+
+        let paths = self.local_state.ordered_selected_paths();
+        if paths.is_empty() {
+            return Task::none();
+        }
+
+        self.local_state.bulk_delete_running = true;
+        info!("[本地壁纸] [批量操作] 删除 {} 张壁纸", paths.len());
+        let roots = self.config.data.library_roots();
+        Task::perform(async_task::async_move_to_trash(paths, roots), |result| {
+            LocalMessage::SelectionDeleted(result).into()
+        })
+    }
+
+    /// 移到回收站完成，从列表中移除已删除的壁纸
+    pub(in crate::ui::local) fn local_selection_deleted(
+        &mut self,
+        result: Result<TrashReport, String>,
+    ) -> Task<AppMessage> {
+        self.local_state.bulk_delete_running = false;
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => {
+                error!("[本地壁纸] [批量操作] 删除失败: {}", e);
+                return self.show_notification(
+                    format!("{}: {}", self.i18n.t("local-list.delete-failed"), e),
+                    NotificationType::Error,
+                );
+            }
+        };
+
+        let deleted_paths: HashSet<String> = report.trashed.iter().map(|item| item.original_path.clone()).collect();
+        self.local_state.remove_paths(&deleted_paths);
+
+        let deleted = report.trashed.len();
+        info!("[本地壁纸] [批量操作] 已删除 {} 张，失败 {} 张", deleted, report.failed);
+
+        let (message, notification_type) = if report.failed > 0 {
+            let message = self
+                .i18n
+                .t("local-list.bulk-delete-failed")
+                .replace("{count}", &deleted.to_string())
+                .replace("{failed}", &report.failed.to_string());
+            (message, NotificationType::Error)
+        } else {
+            let message = self
+                .i18n
+                .t("local-list.bulk-delete-success")
+                .replace("{count}", &deleted.to_string());
+            (message, NotificationType::Success)
+        };
+        if report.trashed.is_empty() {
+            return self.show_notification(message, notification_type);
+        }
+
+        // 部分失败时也可以撤销已经移到回收站的壁纸
+        let trashed_ids = report.trashed.iter().map(|item| item.id).collect();
+        self.show_notification_with_action(
+            message,
+            notification_type,
+            self.i18n.t("local-list.undo"),
+            LocalMessage::RestoreFromTrash(trashed_ids).into(),
+        )
+    }
+
+    /// 把选中的壁纸移动或复制到壁纸库目录
+    pub(in crate::ui::local) fn transfer_local_selection(
+        &mut self,
+        target_dir: String,
+        mode: TransferMode,
+    ) -> Task<AppMessage> {
+        self.local_state.open_picker = None;
+        if self.local_state.selected_paths.is_empty() || self.local_state.transfer_running {
+            return Task::none();
+        }
+
+        self.local_state.transfer_running = true;
+        let paths = self.local_state.ordered_selected_paths();
+        info!(
+            "[本地壁纸] [批量操作] {:?} {} 张壁纸到: {}",
+            mode,
+            paths.len(),
+            target_dir
+        );
+        Task::perform(
+            async_task::async_transfer_files(paths, target_dir, mode),
+            move |result| LocalMessage::SelectionTransferred(mode, result).into(),
+        )
+    }
+
+    /// 移动或复制完成，更新列表并为新路径建立索引
+    pub(in crate::ui::local) fn local_selection_transferred(
+        &mut self,
+        mode: TransferMode,
+        result: Result<TransferReport, String>,
+    ) -> Task<AppMessage> {
+        self.local_state.transfer_running = false;
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => {
+                error!("[本地壁纸] [批量操作] 移动或复制失败: {}", e);
+                return self.show_notification(
+                    format!("{}: {}", self.i18n.t("local-list.transfer-failed"), e),
+                    NotificationType::Error,
+                );
+            }
+        };
+
+        let done = report.transferred.len();
+        let notification = if report.failed > 0 {
+            let message = self
+                .i18n
+                .t("local-list.transfer-partially-failed")
+                .replace("{count}", &done.to_string())
+                .replace("{failed}", &report.failed.to_string());
+            self.show_notification(message, NotificationType::Error)
+        } else {
+            let key = match mode {
+                TransferMode::Move => "local-list.moved",
+                TransferMode::Copy => "local-list.copied",
+            };
+            let mut message = self.i18n.t(key).replace("{count}", &done.to_string());
+            if report.skipped > 0 {
+                let skipped = self
+                    .i18n
+                    .t("local-list.transfer-skipped")
+                    .replace("{count}", &report.skipped.to_string());
+                message.push_str(&skipped);
+            }
+            self.show_notification(message, NotificationType::Success)
+        };
+        if done == 0 {
+            return notification;
+        }
+
+        if mode == TransferMode::Move {
+            let moved_paths: HashSet<String> = report.transferred.iter().map(|(from, _)| from.clone()).collect();
+            self.local_state.remove_paths(&moved_paths);
+        }
+
+        // 标签、收藏和评分已经复制到新路径，重新加载
+        match UserMetadataRepository::global().and_then(|repository| repository.load_all()) {
+            Ok(user_metadata) => {
+                self.local_state.user_metadata = user_metadata;
+                self.local_state.rebuild_known_tags();
+            }
+            Err(e) => warn!("[本地壁纸] [批量操作] 加载标签、收藏和评分失败: {}", e),
+        }
+
+        // 为新路径建立索引并加入列表（文件监听也会收到这些文件，重复处理不会重复加入列表）
+        let changes = LibraryChanges {
+            changed: report.transferred.into_iter().map(|(_, to)| to).collect(),
+            ..Default::default()
+        };
+        let cache_path = self.config.data.cache_path.clone();
+        let index_task =
+            Task::perform(
+                async_task::async_apply_library_changes(changes, cache_path),
+                |result| match result {
+                    Ok(entries) => LocalMessage::LibraryFilesIndexed(entries).into(),
+                    Err(e) => {
+                        warn!("[本地壁纸] [批量操作] 更新壁纸库索引失败: {}", e);
+                        AppMessage::None
+                    }
+                },
+            );
+        Task::batch([notification, index_task])
+    }
+
+    /// 复制选中壁纸的完整路径到剪贴板（每行一个）
+    pub(in crate::ui::local) fn copy_local_selected_paths(&mut self) -> Task<AppMessage> {
+        let paths: Vec<String> = self
+            .local_state
+            .ordered_selected_paths()
+            .iter()
+            .map(|path| helpers::get_absolute_path(path))
+            .collect();
+        if paths.is_empty() {
+            return Task::none();
+        }
+
+        let message = self
+            .i18n
+            .t("local-list.paths-copied")
+            .replace("{count}", &paths.len().to_string());
+        iced::clipboard::write(paths.join("\n")).chain(self.show_notification(message, NotificationType::Success))
+    }
+
+    /// 选择保存位置并把选中的壁纸导出为 zip 文件
+    pub(in crate::ui::local) fn export_local_selection(&mut self) -> Task<AppMessage> {
+        let paths = self.local_state.ordered_selected_paths();
+        if paths.is_empty() {
+            return Task::none();
+        }
+
+        let default_file_name = format!("wallpapers-{}.zip", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+        Task::perform(
+            async_task::async_export_wallpapers_zip(paths, default_file_name),
+            |result| LocalMessage::SelectionExported(result).into(),
+        )
+    }
+
+    pub(in crate::ui::local) fn local_selection_exported(
+        &mut self,
+        result: Result<Option<(String, usize)>, String>,
+    ) -> Task<AppMessage> {
+        match result {
+            Ok(Some((path, count))) => {
+                let message = self
+                    .i18n
+                    .t("local-list.exported")
+                    .replace("{count}", &count.to_string())
+                    .replace("{path}", &path);
+                self.show_notification(message, NotificationType::Success)
+            }
+            Ok(None) => Task::none(),
+            Err(e) => {
+                error!("[本地壁纸] [批量操作] 导出失败: {}", e);
+                let message = format!("{}: {}", self.i18n.t("local-list.export-failed"), e);
+                self.show_notification(message, NotificationType::Error)
+            }
+        }
+    }
+}
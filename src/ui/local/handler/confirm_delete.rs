@@ -1,8 +1,12 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::async_task;
+use crate::services::database::TrashItem;
+use crate::ui::local::LocalMessage;
 use crate::ui::{App, AppMessage, NotificationType};
-use crate::utils::helpers;
 use iced::Task;
+use std::collections::HashSet;
+use tracing::error;
 
 impl App {
     /// 确认删除壁纸
@@ -11,38 +15,43 @@ impl App {
         self.local_state.delete_confirm_visible = false;
         self.local_state.delete_target_index = None;
 
-        // 删除壁纸
-        if let Some(path) = self.local_state.all_paths.get(index) {
-            let full_path = helpers::get_absolute_path(path);
-
-            // 尝试删除文件
-            match std::fs::remove_file(&full_path) {
-                Ok(_) => {
-                    // 删除成功，从列表中移除
-                    self.local_state.all_paths.remove(index);
-                    self.local_state.wallpapers.remove(index);
-                    self.local_state.total_count -= 1;
+        // 在后台把文件移到回收站（同时移动元数据附属文件并删除壁纸库索引记录）
+        let Some(path) = self.local_state.all_paths.get(index).cloned() else {
+            return Task::none();
+        };
+        let roots = self.config.data.library_roots();
+        Task::perform(async_task::async_delete_wallpaper(path, roots), |result| {
+            LocalMessage::WallpaperDeleted(result).into()
+        })
+    }
 
-                    // 如果删除的是当前显示的图片，关闭模态窗口
-                    if self.local_state.modal_visible && self.local_state.current_image_index == index {
-                        self.local_state.modal_visible = false;
-                    } else if self.local_state.modal_visible && self.local_state.current_image_index > index {
-                        // 如果删除的图片在当前显示图片之前，调整索引
-                        self.local_state.current_image_index -= 1;
-                    }
+    /// 壁纸已移到回收站，从列表中移除
+    pub(in crate::ui::local) fn local_wallpaper_deleted(
+        &mut self,
+        result: Result<TrashItem, String>,
+    ) -> Task<AppMessage> {
+        match result {
+            Ok(item) => {
+                // 删除成功，从列表中移除（移动期间列表可能已变化，按路径移除）
+                self.local_state
+                    .remove_paths(&HashSet::from([item.original_path.clone()]));
 
-                    // 显示成功通知
-                    return self.show_notification(self.i18n.t("local-list.delete-success"), NotificationType::Success);
-                }
-                Err(e) => {
-                    // 删除失败，显示错误通知
-                    return self.show_notification(
-                        format!("{}: {}", self.i18n.t("local-list.delete-failed"), e),
-                        NotificationType::Error,
-                    );
-                }
+                // 显示成功通知（可以撤销）
+                self.show_notification_with_action(
+                    self.i18n.t("local-list.delete-success"),
+                    NotificationType::Success,
+                    self.i18n.t("local-list.undo"),
+                    LocalMessage::RestoreFromTrash(vec![item.id]).into(),
+                )
+            }
+            Err(e) => {
+                // 删除失败，显示错误通知
+                error!("[本地壁纸] 删除失败: {}", e);
+                self.show_notification(
+                    format!("{}: {}", self.i18n.t("local-list.delete-failed"), e),
+                    NotificationType::Error,
+                )
             }
         }
-        Task::none()
     }
 }
@@ -0,0 +1,156 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::database::LibraryEntry;
+use crate::services::trash::TrashReport;
+use crate::ui::local::LocalMessage;
+use crate::ui::local::state::DuplicateFinderStage;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use std::collections::HashSet;
+use tracing::{error, info};
+
+impl App {
+    /// 打开相似图片查找对话框并开始查找
+    pub(in crate::ui::local) fn find_local_duplicates(&mut self) -> Task<AppMessage> {
+        if self.local_state.duplicates.stage == DuplicateFinderStage::Scanning {
+            return Task::none();
+        }
+
+        self.local_state.duplicates.stage = DuplicateFinderStage::Scanning;
+        self.local_state.duplicates.groups.clear();
+
+        let roots = self.config.data.library_roots();
+        let threshold = self.config.local.duplicate_threshold;
+        info!("[本地壁纸] [相似图片] 开始查找，阈值: {}", threshold);
+        Task::perform(async_task::async_find_similar_groups(roots, threshold), |result| {
+            LocalMessage::DuplicatesFound(result).into()
+        })
+    }
+
+    /// 处理相似图片查找完成
+    pub(in crate::ui::local) fn local_duplicates_found(
+        &mut self,
+        result: Result<Vec<Vec<LibraryEntry>>, String>,
+    ) -> Task<AppMessage> {
+        // 查找过程中对话框已被关闭
+        if self.local_state.duplicates.stage != DuplicateFinderStage::Scanning {
+            return Task::none();
+        }
+
+        match result {
+            Ok(groups) => {
+                self.local_state.duplicates.set_groups(groups);
+                Task::none()
+            }
+            Err(e) => {
+                error!("[本地壁纸] [相似图片] 查找失败: {}", e);
+                self.local_state.duplicates.stage = DuplicateFinderStage::Closed;
+                self.show_notification(
+                    format!("{}: {}", self.i18n.t("duplicates.failed"), e),
+                    NotificationType::Error,
+                )
+            }
+        }
+    }
+
+    pub(in crate::ui::local) fn local_duplicate_threshold_changed(&mut self, threshold: u32) -> Task<AppMessage> {
+        info!(
+            "[本地壁纸] [相似图片] 阈值修改: {} -> {}",
+            self.config.local.duplicate_threshold, threshold
+        );
+        self.config.local.duplicate_threshold = threshold;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::local) fn local_duplicate_keep_toggled(
+        &mut self,
+        group_index: usize,
+        item_index: usize,
+        keep: bool,
+    ) -> Task<AppMessage> {
+        let Some(group) = self.local_state.duplicates.groups.get_mut(group_index) else {
+            return Task::none();
+        };
+
+        // 每组至少保留一张图片
+        if !keep && group.iter().filter(|item| item.keep).count() <= 1 {
+            return Task::none();
+        }
+        if let Some(item) = group.get_mut(item_index) {
+            item.keep = keep;
+        }
+        Task::none()
+    }
+
+    /// 把所有未保留的图片移到回收站
+    pub(in crate::ui::local) fn confirm_local_duplicate_cleanup(&mut self) -> Task<AppMessage> {
+        let paths = self.local_state.duplicates.paths_to_delete();
+        self.local_state.duplicates.close();
+        if paths.is_empty() {
+            return Task::none();
+        }
+
+        info!("[本地壁纸] [相似图片] 删除 {} 张图片", paths.len());
+        let roots = self.config.data.library_roots();
+        Task::perform(async_task::async_move_to_trash(paths, roots), |result| {
+            LocalMessage::DuplicatesDeleted(result).into()
+        })
+    }
+
+    /// 移到回收站完成，从列表中移除已删除的图片
+    pub(in crate::ui::local) fn local_duplicates_deleted(
+        &mut self,
+        result: Result<TrashReport, String>,
+    ) -> Task<AppMessage> {
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => {
+                error!("[本地壁纸] [相似图片] 删除失败: {}", e);
+                return self.show_notification(
+                    format!("{}: {}", self.i18n.t("local-list.delete-failed"), e),
+                    NotificationType::Error,
+                );
+            }
+        };
+
+        let deleted_paths: HashSet<String> = report.trashed.iter().map(|item| item.original_path.clone()).collect();
+        self.local_state.remove_paths(&deleted_paths);
+
+        let deleted = report.trashed.len();
+        info!("[本地壁纸] [相似图片] 已删除 {} 张，失败 {} 张", deleted, report.failed);
+
+        let (message, notification_type) = if report.failed > 0 {
+            let message = self
+                .i18n
+                .t("duplicates.delete-failed")
+                .replace("{count}", &deleted.to_string())
+                .replace("{failed}", &report.failed.to_string());
+            (message, NotificationType::Error)
+        } else {
+            let message = self
+                .i18n
+                .t("duplicates.deleted")
+                .replace("{count}", &deleted.to_string());
+            (message, NotificationType::Success)
+        };
+        if report.trashed.is_empty() {
+            return self.show_notification(message, notification_type);
+        }
+
+        // 部分失败时也可以撤销已经移到回收站的图片
+        let trashed_ids = report.trashed.iter().map(|item| item.id).collect();
+        self.show_notification_with_action(
+            message,
+            notification_type,
+            self.i18n.t("local-list.undo"),
+            LocalMessage::RestoreFromTrash(trashed_ids).into(),
+        )
+    }
+
+    pub(in crate::ui::local) fn close_local_duplicates(&mut self) -> Task<AppMessage> {
+        self.local_state.duplicates.close();
+        Task::none()
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::database::LibraryEntry;
+use crate::services::library;
+use crate::services::library_watcher::{LibraryChanges, LibraryWatcher};
+use crate::ui::local::{LocalMessage, WallpaperLoadStatus};
+use crate::ui::{ActivePage, App, AppMessage};
+use crate::utils::config::LibraryRoot;
+use iced::Task;
+use iced::futures::Stream;
+use std::path::Path;
+use tracing::{info, warn};
+
+impl App {
+    /// 创建壁纸库文件监听流（壁纸库目录配置变化时由订阅重新创建）
+    pub fn create_library_watch_stream(roots: Vec<LibraryRoot>) -> impl Stream<Item = AppMessage> {
+        async_stream::stream! {
+            let mut watcher = match LibraryWatcher::new(roots) {
+                Ok(watcher) => watcher,
+                Err(e) => {
+                    warn!("[本地壁纸] [监听] {}", e);
+                    return;
+                }
+            };
+
+            while let Some(changes) = watcher.next_changes().await {
+                yield LocalMessage::LibraryChanged(changes).into();
+            }
+        }
+    }
+
+    /// 处理壁纸库目录中的文件变化
+    pub(in crate::ui::local) fn local_library_changed(&mut self, changes: LibraryChanges) -> Task<AppMessage> {
+        info!(
+            "[本地壁纸] [监听] 壁纸库变化: 新增/修改 {}，删除 {}，重新扫描: {}",
+            changes.changed.len(),
+            changes.removed.len(),
+            changes.rescan
+        );
+        self.auto_change_state.apply_library_changes(&changes);
+
+        let local_page_active = self.active_page == ActivePage::LocalList;
+        if local_page_active {
+            // 移除已删除的文件（倒序移除，避免索引变化）
+            for index in (0..self.local_state.all_paths.len()).rev() {
+                let path = Path::new(&self.local_state.all_paths[index]);
+                if changes.removed.iter().any(|removed| path.starts_with(removed)) {
+                    self.local_state.remove_wallpaper(index);
+                }
+            }
+            // 被筛选掉（未显示）的文件
+            self.local_state.library_paths.retain(|path| {
+                !changes
+                    .removed
+                    .iter()
+                    .any(|removed| Path::new(path).starts_with(removed))
+            });
+        }
+
+        if changes.rescan {
+            // 有目录被创建或移入，重新扫描整个壁纸库（本地壁纸页面未打开时，下次打开时会重新扫描）
+            return if local_page_active {
+                Task::done(LocalMessage::LoadWallpapers.into())
+            } else {
+                Task::none()
+            };
+        }
+
+        let cache_path = self.config.data.cache_path.clone();
+        Task::perform(
+            async_task::async_apply_library_changes(changes, cache_path),
+            |result| match result {
+                Ok(entries) => LocalMessage::LibraryFilesIndexed(entries).into(),
+                Err(e) => {
+                    warn!("[本地壁纸] [监听] 更新壁纸库索引失败: {}", e);
+                    AppMessage::None
+                }
+            },
+        )
+    }
+
+    /// 处理新增或修改过的文件写入壁纸库索引完成，更新本地壁纸列表
+    pub(in crate::ui::local) fn local_library_files_indexed(&mut self, entries: Vec<LibraryEntry>) -> Task<AppMessage> {
+        if self.active_page != ActivePage::LocalList || entries.is_empty() {
+            return Task::none();
+        }
+
+        let mut tasks = Vec::new();
+        let mut added = false;
+        for entry in entries {
+            let path = entry.path.clone();
+            self.local_state.library_entries.insert(path.clone(), entry.clone());
+
+            match self.local_state.all_paths.iter().position(|p| *p == path) {
+                // 已加载的壁纸被修改，重新加载缩略图
+                Some(index) if index < self.local_state.wallpapers.len() => {
+                    self.local_state.wallpapers[index] = WallpaperLoadStatus::Loading;
+                    let cache_path = self.config.data.cache_path.clone();
+                    tasks.push(Task::perform(
+                        async_task::async_load_indexed_wallpaper(path, Some(entry), cache_path),
+                        move |result| match result {
+                            Ok(wallpaper) => LocalMessage::LoadPageSuccess(vec![(index, wallpaper)]).into(),
+                            Err(_) => AppMessage::None,
+                        },
+                    ));
+                }
+                Some(_) => {}
+                // 新增的壁纸符合筛选条件时追加到列表末尾，滚动到底部时加载
+                None => {
+                    if !self.local_state.library_paths.contains(&path) {
+                        self.local_state.library_paths.push(path.clone());
+                    }
+                    if library::matches_filter(&path, Some(&entry), &self.config.local.filter) {
+                        self.local_state.all_paths.push(path);
+                        self.local_state.total_count += 1;
+                        added = true;
+                    }
+                }
+            }
+        }
+
+        if added {
+            tasks.push(Task::done(LocalMessage::CheckAndLoadNextPage.into()));
+        }
+        Task::batch(tasks)
+    }
+}
@@ -28,9 +28,10 @@ impl App {
             let path = path.clone();
             let cache_path = self.config.data.cache_path.clone();
             let absolute_idx = start_idx + i;
+            let entry = self.local_state.library_entries.get(&path).cloned();
 
             tasks.push(Task::perform(
-                async_task::async_load_single_wallpaper_with_fallback(path.clone(), cache_path),
+                async_task::async_load_indexed_wallpaper(path.clone(), entry, cache_path),
                 move |result| match result {
                     Ok(wallpaper) => LocalMessage::LoadPageSuccess(vec![(absolute_idx, wallpaper)]).into(),
                     Err(_) => {
@@ -67,12 +68,17 @@ impl App {
         &mut self,
         wallpapers_with_idx: Vec<(usize, Wallpaper)>,
     ) -> Task<AppMessage> {
-        // 为每个加载完成的壁纸更新状态
+        // 为每个加载完成的壁纸更新状态（排序或筛选条件变化前发出的加载结果位置已经失效，直接丢弃）
+        let mut updated = false;
         for (idx, wallpaper) in wallpapers_with_idx {
-            if idx < self.local_state.wallpapers.len() {
-                self.local_state.wallpapers[idx] = WallpaperLoadStatus::Loaded(wallpaper);
+            if idx < self.local_state.wallpapers.len() && self.local_state.all_paths.get(idx) == Some(&wallpaper.path) {
+                self.local_state.wallpapers[idx] = WallpaperLoadStatus::Loaded(Box::new(wallpaper));
+                updated = true;
             }
         }
+        if !updated || self.local_state.current_page == 0 {
+            return Task::none();
+        }
 
         // 检查是否所有壁纸都已加载完成，如果是则更新loading_page状态
         let page_start = (self.local_state.current_page - 1) * self.local_state.page_size; // 上一页的起始位置
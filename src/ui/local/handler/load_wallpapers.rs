@@ -1,38 +1,102 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::async_task;
-use crate::ui::local::{LocalMessage, WallpaperLoadStatus};
-use crate::ui::{App, AppMessage};
+use crate::services::database::{LibraryEntry, UserMetadata};
+use crate::services::library::IndexStats;
+use crate::ui::local::LocalMessage;
+use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
-use tracing::error;
+use std::collections::{HashMap, HashSet};
+use tracing::{error, info, warn};
 
 impl App {
     /// 加载本地壁纸列表
+    ///
+    /// 先从壁纸库索引加载列表，同时在后台增量更新索引，索引有变化时重新加载列表
     pub(in crate::ui::local) fn load_local_wallpapers(&mut self) -> Task<AppMessage> {
-        let data_path = self.config.data.data_path.clone();
-        Task::perform(
-            async_task::async_load_wallpaper_paths(data_path),
-            |result| match result {
-                Ok(paths) => LocalMessage::LoadWallpapersSuccess(paths).into(),
-                Err(e) => {
-                    error!("[本地壁纸] 加载列表失败: {}", e);
-                    AppMessage::None
-                }
-            },
-        )
+        let roots = self.config.data.library_roots();
+        let cache_path = self.config.data.cache_path.clone();
+
+        Task::batch([
+            self.load_local_library_index(),
+            Task::perform(async_task::async_refresh_library_index(roots, cache_path), |result| {
+                LocalMessage::LibraryIndexRefreshed(result).into()
+            }),
+        ])
+    }
+
+    /// 从壁纸库索引加载壁纸列表
+    fn load_local_library_index(&self) -> Task<AppMessage> {
+        let roots = self.config.data.library_roots();
+        Task::perform(async_task::async_load_library_index(roots), |result| match result {
+            Ok((paths, entries, user_metadata)) => {
+                LocalMessage::LoadWallpapersSuccess(paths, entries, user_metadata).into()
+            }
+            Err(e) => {
+                error!("[本地壁纸] 加载列表失败: {}", e);
+                AppMessage::None
+            }
+        })
     }
 
     /// 处理本地壁纸列表加载成功
-    pub(in crate::ui::local) fn load_local_wallpapers_success(&mut self, paths: Vec<String>) -> Task<AppMessage> {
-        // 更新本地状态，初始化壁纸加载状态列表
-        self.local_state.all_paths = paths;
-        self.local_state.total_count = self.local_state.all_paths.len();
+    pub(in crate::ui::local) fn load_local_wallpapers_success(
+        &mut self,
+        paths: Vec<String>,
+        entries: Vec<LibraryEntry>,
+        user_metadata: HashMap<String, UserMetadata>,
+    ) -> Task<AppMessage> {
+        // 更新本地状态
+        self.local_state.library_paths = paths;
+        self.local_state.library_entries = entries.into_iter().map(|entry| (entry.path.clone(), entry)).collect();
+        self.local_state.user_metadata = user_metadata;
+        self.local_state.rebuild_known_tags();
+        // 重新加载后移除已不存在的壁纸的选中状态
+        let library_paths: HashSet<&String> = self.local_state.library_paths.iter().collect();
+        self.local_state
+            .selected_paths
+            .retain(|path| library_paths.contains(path));
+
+        // 按筛选条件和排序方式整理列表，并加载第一页
+        self.apply_local_sort_and_filter()
+    }
+
+    /// 处理壁纸库索引更新完成
+    pub(in crate::ui::local) fn local_library_index_refreshed(
+        &mut self,
+        result: Result<IndexStats, String>,
+    ) -> Task<AppMessage> {
+        match result {
+            Ok(stats) if stats.skipped => Task::none(),
+            Ok(stats) => {
+                let notification = self.notify_undecodable_images(stats.undecodable);
+                if !stats.has_changes() {
+                    return notification;
+                }
+                info!(
+                    "[本地壁纸] 壁纸库有变化（新增 {}，更新 {}，删除 {}），重新加载列表",
+                    stats.added, stats.updated, stats.removed
+                );
+                Task::batch([notification, self.load_local_library_index()])
+            }
+            Err(e) => {
+                warn!("[本地壁纸] 更新壁纸库索引失败: {}", e);
+                Task::none()
+            }
+        }
+    }
 
-        // 初始化壁纸状态为Loading，并加载第一页
-        let page_end = std::cmp::min(self.local_state.page_size, self.local_state.total_count);
-        self.local_state.wallpapers = vec![WallpaperLoadStatus::Loading; page_end];
+    /// 壁纸库中有当前构建无法解码的图片（未启用 `avif` 特性时的 AVIF 图片）时提示用户
+    fn notify_undecodable_images(&mut self, count: usize) -> Task<AppMessage> {
+        let notified = std::mem::replace(&mut self.local_state.undecodable_notified, count);
+        if count <= notified {
+            return Task::none();
+        }
 
-        // 触发第一页加载
-        Task::done(LocalMessage::LoadPage.into())
+        let message = self
+            .i18n
+            .t("local-list.avif-unavailable")
+            .replace("{count}", &count.to_string());
+        self.show_notification(message, NotificationType::Info)
     }
 }
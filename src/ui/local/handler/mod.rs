@@ -1,13 +1,21 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+mod bulk_actions;
 mod confirm_delete;
 mod delete_confirm;
+mod duplicates;
+mod library_watch;
 mod load_next_page;
 mod load_page;
 mod load_wallpapers;
 mod modal_image;
 mod next_image;
+mod open_source_page;
 mod previous_image;
 mod scroll_to_bottom;
+mod selection;
 mod set_wallpaper;
+mod sort_filter;
+mod trash;
+mod user_metadata;
 mod view_file;
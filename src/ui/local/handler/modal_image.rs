@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::local::LocalWallpaperService;
 use crate::ui::local::{LocalMessage, WallpaperLoadStatus};
 use crate::ui::{App, AppMessage};
 use iced::Task;
@@ -21,13 +22,14 @@ impl App {
         // 显示模态窗口，设置当前图片索引
         self.local_state.current_image_index = index;
         self.local_state.modal_visible = true;
+        self.local_state.tag_input.clear();
 
         // 显式释放旧的图片数据: 先将 Handle 移出,然后让新值覆盖
         let _old_handle = std::mem::replace(&mut self.local_state.modal_image_handle, None);
 
         // 异步加载图片数据
         if let Some(path) = self.local_state.all_paths.get(index).cloned() {
-            return Task::perform(async move { Handle::from_path(&path) }, |handle| {
+            return Task::perform(async move { LocalWallpaperService::preview_handle(&path) }, |handle| {
                 LocalMessage::ModalImageLoaded(handle).into()
             });
         }
@@ -48,6 +50,7 @@ impl App {
     pub fn close_local_modal(&mut self) -> Task<AppMessage> {
         // 关闭模态窗口
         self.local_state.modal_visible = false;
+        self.local_state.tag_input.clear();
 
         // 显式释放图片数据: 先将 Handle 移出,然后让新值覆盖
         let _old_handle = std::mem::replace(&mut self.local_state.modal_image_handle, None);
@@ -1,9 +1,9 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::local::LocalWallpaperService;
 use crate::ui::local::LocalMessage;
 use crate::ui::{App, AppMessage};
 use iced::Task;
-use iced::widget::image::Handle;
 
 impl App {
     /// 显示下一张图片
@@ -23,7 +23,7 @@ impl App {
                 return Task::perform(
                     async move {
                         // 异步加载图片数据
-                        Handle::from_path(&path)
+                        LocalWallpaperService::preview_handle(&path)
                     },
                     |handle| LocalMessage::ModalImageLoaded(handle).into(),
                 );
@@ -0,0 +1,15 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::{App, AppMessage};
+use iced::Task;
+use tracing::error;
+
+impl App {
+    /// 在浏览器中打开壁纸的 Wallhaven 详情页面
+    pub(in crate::ui::local) fn open_local_source_page(&mut self, url: String) -> Task<AppMessage> {
+        if let Err(e) = open::that(&url) {
+            error!("[本地壁纸] 打开详情页面失败 {}: {}", url, e);
+        }
+        Task::none()
+    }
+}
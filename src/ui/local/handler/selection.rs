@@ -0,0 +1,61 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 选中处理模块
+//!
+//! 处理本地壁纸的多选逻辑（按路径记录，重新加载列表后保持选中状态）
+
+use crate::ui::{App, AppMessage};
+use iced::Task;
+use iced::keyboard::Modifiers;
+
+impl App {
+    /// 切换壁纸的选中状态
+    ///
+    /// 按住 Shift 点击时选中上一次点击的壁纸到这张壁纸之间的所有壁纸（只增加选中，不取消选中）
+    pub(in crate::ui::local) fn toggle_local_selection(&mut self, index: usize) -> Task<AppMessage> {
+        let Some(path) = self.local_state.all_paths.get(index).cloned() else {
+            return Task::none();
+        };
+
+        let anchor_index = self
+            .local_state
+            .selection_anchor
+            .as_ref()
+            .and_then(|anchor| self.local_state.all_paths.iter().position(|p| p == anchor));
+        match anchor_index {
+            Some(anchor_index) if self.local_state.modifiers.shift() => {
+                let range = anchor_index.min(index)..=anchor_index.max(index);
+                let paths = self.local_state.all_paths[range].iter().cloned();
+                self.local_state.selected_paths.extend(paths);
+            }
+            _ => {
+                if !self.local_state.selected_paths.remove(&path) {
+                    self.local_state.selected_paths.insert(path.clone());
+                }
+            }
+        }
+
+        self.local_state.selection_anchor = Some(path);
+        Task::none()
+    }
+
+    /// 选中所有符合筛选条件的壁纸（包括还没有加载到页面中的壁纸）
+    pub(in crate::ui::local) fn select_all_local(&mut self) -> Task<AppMessage> {
+        let paths = self.local_state.all_paths.iter().cloned();
+        self.local_state.selected_paths.extend(paths);
+        Task::none()
+    }
+
+    /// 取消选中所有壁纸
+    pub(in crate::ui::local) fn clear_local_selection(&mut self) -> Task<AppMessage> {
+        self.local_state.selected_paths.clear();
+        self.local_state.selection_anchor = None;
+        self.local_state.bulk_tag_input.clear();
+        Task::none()
+    }
+
+    pub(in crate::ui::local) fn local_modifiers_changed(&mut self, modifiers: Modifiers) -> Task<AppMessage> {
+        self.local_state.modifiers = modifiers;
+        Task::none()
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::library;
+use crate::services::wallhaven::{AspectRatioGroup, ColorOption};
+use crate::ui::local::state::LocalPicker;
+use crate::ui::local::{LocalMessage, WallpaperLoadStatus};
+use crate::ui::main::MainMessage;
+use crate::ui::{App, AppMessage};
+use crate::utils::config::{LocalFilter, LocalSort, Orientation};
+use iced::Task;
+use std::collections::HashSet;
+use tracing::info;
+
+impl App {
+    /// 按筛选条件和排序方式重新整理壁纸列表，并从第一页开始重新加载
+    pub(in crate::ui::local) fn apply_local_sort_and_filter(&mut self) -> Task<AppMessage> {
+        let state = &mut self.local_state;
+        state.all_paths = library::sort_and_filter(&state.library_paths, &state.library_entries, &self.config.local);
+        state.total_count = state.all_paths.len();
+
+        // 取消选中被筛选条件隐藏的壁纸，批量操作只作用于列表中可以看到的壁纸
+        if !state.selected_paths.is_empty() {
+            let visible: HashSet<&String> = state.all_paths.iter().collect();
+            state.selected_paths.retain(|path| visible.contains(path));
+        }
+        state.current_page = 0;
+        state.loading_page = false;
+
+        // 列表顺序变化后模态窗口中的图片索引已经失效
+        state.modal_visible = false;
+        state.modal_image_handle = None;
+
+        // 初始化壁纸状态为Loading，并加载第一页
+        let page_end = std::cmp::min(state.page_size, state.total_count);
+        state.wallpapers = vec![WallpaperLoadStatus::Loading; page_end];
+
+        Task::batch([
+            Task::done(LocalMessage::LoadPage.into()),
+            Task::done(MainMessage::ScrollToTop("local_wallpapers_scroll".to_string()).into()),
+        ])
+    }
+
+    /// 保存排序方式或筛选条件，并重新整理壁纸列表
+    fn save_local_sort_and_filter(&mut self) -> Task<AppMessage> {
+        self.local_state.open_picker = None;
+        self.config.save_to_file();
+        self.apply_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_sort_changed(&mut self, sort: LocalSort) -> Task<AppMessage> {
+        info!("[本地壁纸] [排序] 排序方式: {:?} -> {:?}", self.config.local.sort, sort);
+        self.config.local.sort = sort;
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn toggle_local_sort_direction(&mut self) -> Task<AppMessage> {
+        self.config.local.sort_descending = !self.config.local.sort_descending;
+        info!("[本地壁纸] [排序] 降序: {}", self.config.local.sort_descending);
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_min_resolution_changed(&mut self, width: u32, height: u32) -> Task<AppMessage> {
+        let filter = &mut self.config.local.filter;
+        info!(
+            "[本地壁纸] [筛选] 最低分辨率: {}x{} -> {}x{}",
+            filter.min_width, filter.min_height, width, height
+        );
+        filter.min_width = width;
+        filter.min_height = height;
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_orientation_filter_changed(
+        &mut self,
+        orientation: Option<Orientation>,
+    ) -> Task<AppMessage> {
+        info!(
+            "[本地壁纸] [筛选] 方向: {:?} -> {:?}",
+            self.config.local.filter.orientation, orientation
+        );
+        self.config.local.filter.orientation = orientation;
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_aspect_ratio_group_filter_changed(
+        &mut self,
+        group: Option<AspectRatioGroup>,
+    ) -> Task<AppMessage> {
+        info!(
+            "[本地壁纸] [筛选] 比例: {:?} -> {:?}",
+            self.config.local.filter.aspect_ratio_group, group
+        );
+        self.config.local.filter.aspect_ratio_group = group;
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_format_filter_changed(&mut self, format: Option<String>) -> Task<AppMessage> {
+        info!(
+            "[本地壁纸] [筛选] 格式: {:?} -> {:?}",
+            self.config.local.filter.format, format
+        );
+        self.config.local.filter.format = format;
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_color_filter_changed(&mut self, color: ColorOption) -> Task<AppMessage> {
+        let color = (color != ColorOption::Any).then(|| color.value().to_string());
+        info!(
+            "[本地壁纸] [筛选] 颜色: {:?} -> {:?}",
+            self.config.local.filter.color, color
+        );
+        self.config.local.filter.color = color;
+        self.save_local_sort_and_filter()
+    }
+
+    pub(in crate::ui::local) fn local_name_pattern_input_changed(&mut self, value: String) -> Task<AppMessage> {
+        self.local_state.name_pattern_input = value;
+        Task::none()
+    }
+
+    /// 应用文件名筛选输入框中的内容
+    pub(in crate::ui::local) fn apply_local_name_pattern(&mut self) -> Task<AppMessage> {
+        let pattern = self.local_state.name_pattern_input.trim().to_string();
+        if pattern == self.config.local.filter.name_pattern {
+            return Task::none();
+        }
+        info!(
+            "[本地壁纸] [筛选] 文件名: {:?} -> {:?}",
+            self.config.local.filter.name_pattern, pattern
+        );
+        self.config.local.filter.name_pattern = pattern;
+        self.save_local_sort_and_filter()
+    }
+
+    /// 清除所有筛选条件（保留排序方式）
+    pub(in crate::ui::local) fn reset_local_filters(&mut self) -> Task<AppMessage> {
+        info!("[本地壁纸] [筛选] 清除所有筛选条件");
+        self.config.local.filter = LocalFilter::default();
+        self.local_state.name_pattern_input.clear();
+        self.save_local_sort_and_filter()
+    }
+
+    /// 展开或收起工具栏中的下拉选择器
+    pub(in crate::ui::local) fn toggle_local_picker(&mut self, picker: LocalPicker) -> Task<AppMessage> {
+        self.local_state.open_picker = if self.local_state.open_picker == Some(picker) {
+            None
+        } else {
+            Some(picker)
+        };
+        Task::none()
+    }
+
+    pub(in crate::ui::local) fn close_local_picker(&mut self) -> Task<AppMessage> {
+        self.local_state.open_picker = None;
+        Task::none()
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+//! 回收站处理模块
+//!
+//! 打开回收站对话框、恢复（撤销删除）和彻底删除回收站中的壁纸
+
+use crate::services::async_task;
+use crate::services::database::{TrashItem, TrashRepository, UserMetadataRepository};
+use crate::services::trash::{self, RestoreReport};
+use crate::ui::local::LocalMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use tracing::{info, warn};
+
+impl App {
+    /// 打开回收站对话框
+    pub(in crate::ui::local) fn open_local_trash(&mut self) -> Task<AppMessage> {
+        let result = TrashRepository::global().and_then(|repository| repository.load_all());
+        match result {
+            Ok(items) => {
+                self.local_state.trash.items = items;
+                self.local_state.trash.visible = true;
+                Task::none()
+            }
+            Err(e) => {
+                warn!("[本地壁纸] [回收站] 加载回收站失败: {}", e);
+                self.show_notification(
+                    format!("{}: {}", self.i18n.t("trash.load-failed"), e),
+                    NotificationType::Error,
+                )
+            }
+        }
+    }
+
+    pub(in crate::ui::local) fn close_local_trash(&mut self) -> Task<AppMessage> {
+        self.local_state.trash.visible = false;
+        self.local_state.trash.items.clear();
+        Task::none()
+    }
+
+    /// 恢复回收站中的壁纸（删除后点击通知中的撤销按钮时也使用此方法）
+    pub(in crate::ui::local) fn restore_local_trash_items(&mut self, ids: Vec<i64>) -> Task<AppMessage> {
+        if ids.is_empty() {
+            return Task::none();
+        }
+
+        // 在后台移回原位置并重新建立索引
+        let cache_path = self.config.data.cache_path.clone();
+        Task::perform(async_task::async_restore_from_trash(ids, cache_path), |result| {
+            LocalMessage::TrashRestored(result).into()
+        })
+    }
+
+    /// 恢复完成，把恢复的壁纸加入列表
+    pub(in crate::ui::local) fn local_trash_restored(
+        &mut self,
+        result: Result<RestoreReport, String>,
+    ) -> Task<AppMessage> {
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => {
+                warn!("[本地壁纸] [回收站] 恢复失败: {}", e);
+                return self.show_notification(
+                    format!("{}: {}", self.i18n.t("trash.restore-failed"), e),
+                    NotificationType::Error,
+                );
+            }
+        };
+
+        let restored = report.restored_ids.len();
+        info!("[本地壁纸] [回收站] 已恢复 {} 张，失败 {} 张", restored, report.failed);
+        self.local_state
+            .trash
+            .items
+            .retain(|item| !report.restored_ids.contains(&item.id));
+
+        let notification = match report.last_error {
+            Some(e) => self.show_notification(
+                format!("{}: {}", self.i18n.t("trash.restore-failed"), e),
+                NotificationType::Error,
+            ),
+            None => self.show_notification(
+                self.i18n.t("trash.restored").replace("{count}", &restored.to_string()),
+                NotificationType::Success,
+            ),
+        };
+        if restored == 0 {
+            return notification;
+        }
+
+        // 标签、收藏和评分在移到回收站时保留在数据库中，重新加载
+        match UserMetadataRepository::global().and_then(|repository| repository.load_all()) {
+            Ok(user_metadata) => {
+                self.local_state.user_metadata = user_metadata;
+                self.local_state.rebuild_known_tags();
+            }
+            Err(e) => warn!("[本地壁纸] [回收站] 加载标签、收藏和评分失败: {}", e),
+        }
+
+        // 把重新建立索引的壁纸加入列表（文件监听也会收到这些文件，重复处理不会重复加入列表）
+        let index_task = self.local_library_files_indexed(report.entries);
+        Task::batch([notification, index_task])
+    }
+
+    /// 彻底删除回收站中的壁纸
+    pub(in crate::ui::local) fn purge_local_trash_items(&mut self, ids: Vec<i64>) -> Task<AppMessage> {
+        let items: Vec<_> = self
+            .local_state
+            .trash
+            .items
+            .iter()
+            .filter(|item| ids.contains(&item.id))
+            .cloned()
+            .collect();
+        self.purge_trash(items)
+    }
+
+    /// 清空回收站
+    pub(in crate::ui::local) fn empty_local_trash(&mut self) -> Task<AppMessage> {
+        let items = self.local_state.trash.items.clone();
+        self.purge_trash(items)
+    }
+
+    pub(in crate::ui::local) fn local_trash_retention_changed(&mut self, days: u32) -> Task<AppMessage> {
+        info!(
+            "[本地壁纸] [回收站] 保留天数: {} -> {}",
+            self.config.local.trash_retention_days, days
+        );
+        self.config.local.trash_retention_days = days;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    fn purge_trash(&mut self, items: Vec<TrashItem>) -> Task<AppMessage> {
+        if items.is_empty() {
+            return Task::none();
+        }
+
+        if let Err(e) = trash::purge(&items) {
+            warn!("[本地壁纸] [回收站] 彻底删除失败: {}", e);
+            return self.show_notification(
+                format!("{}: {}", self.i18n.t("trash.purge-failed"), e),
+                NotificationType::Error,
+            );
+        }
+
+        self.local_state
+            .trash
+            .items
+            .retain(|item| !items.iter().any(|purged| purged.id == item.id));
+        let message = self.i18n.t("trash.purged").replace("{count}", &items.len().to_string());
+        self.show_notification(message, NotificationType::Success)
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::database::UserMetadataRepository;
+use crate::services::library;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use tracing::{error, info};
+
+impl App {
+    /// 切换壁纸是否收藏
+    pub(in crate::ui::local) fn toggle_local_favorite(&mut self, index: usize) -> Task<AppMessage> {
+        let Some(path) = self.local_state.all_paths.get(index).cloned() else {
+            return Task::none();
+        };
+        let favorite = !self
+            .local_state
+            .metadata_of(index)
+            .is_some_and(|metadata| metadata.favorite);
+
+        let result = UserMetadataRepository::global().and_then(|repository| repository.set_favorite(&path, favorite));
+        if let Err(e) = result {
+            return self.user_metadata_save_failed(e);
+        }
+        info!("[本地壁纸] [收藏] {}: {}", path, favorite);
+        self.local_state.user_metadata.entry(path).or_default().favorite = favorite;
+        Task::none()
+    }
+
+    /// 设置壁纸评分，再次点击当前评分时清除评分
+    pub(in crate::ui::local) fn set_local_rating(&mut self, index: usize, rating: u8) -> Task<AppMessage> {
+        let Some(path) = self.local_state.all_paths.get(index).cloned() else {
+            return Task::none();
+        };
+        let current = self
+            .local_state
+            .metadata_of(index)
+            .map_or(0, |metadata| metadata.rating);
+        let rating = if rating == current { 0 } else { rating };
+
+        let result = UserMetadataRepository::global().and_then(|repository| repository.set_rating(&path, rating));
+        if let Err(e) = result {
+            return self.user_metadata_save_failed(e);
+        }
+        info!("[本地壁纸] [评分] {}: {} -> {}", path, current, rating);
+        self.local_state.user_metadata.entry(path).or_default().rating = rating;
+        Task::none()
+    }
+
+    pub(in crate::ui::local) fn local_tag_input_changed(&mut self, value: String) -> Task<AppMessage> {
+        self.local_state.tag_input = value;
+        Task::none()
+    }
+
+    /// 为壁纸添加标签
+    pub(in crate::ui::local) fn add_local_tag(&mut self, index: usize, tag: String) -> Task<AppMessage> {
+        let Some(path) = self.local_state.all_paths.get(index).cloned() else {
+            return Task::none();
+        };
+        let Some(tag) = library::normalize_tag(&tag) else {
+            return Task::none();
+        };
+        self.local_state.tag_input.clear();
+
+        if self
+            .local_state
+            .metadata_of(index)
+            .is_some_and(|metadata| metadata.has_tag(&tag))
+        {
+            return Task::none();
+        }
+        let result = UserMetadataRepository::global()
+            .and_then(|repository| repository.add_tags(std::slice::from_ref(&path), std::slice::from_ref(&tag)));
+        if let Err(e) = result {
+            return self.user_metadata_save_failed(e);
+        }
+        info!("[本地壁纸] [标签] 添加标签: {}, {}", path, tag);
+        self.insert_local_tag(path, tag);
+        self.local_state.rebuild_known_tags();
+        Task::none()
+    }
+
+    /// 删除壁纸的标签
+    pub(in crate::ui::local) fn remove_local_tag(&mut self, index: usize, tag: String) -> Task<AppMessage> {
+        let Some(path) = self.local_state.all_paths.get(index).cloned() else {
+            return Task::none();
+        };
+
+        let result = UserMetadataRepository::global().and_then(|repository| repository.remove_tag(&path, &tag));
+        if let Err(e) = result {
+            return self.user_metadata_save_failed(e);
+        }
+        info!("[本地壁纸] [标签] 删除标签: {}, {}", path, tag);
+        if let Some(metadata) = self.local_state.user_metadata.get_mut(&path) {
+            metadata.tags.retain(|t| !t.eq_ignore_ascii_case(&tag));
+        }
+        self.local_state.rebuild_known_tags();
+        Task::none()
+    }
+
+    pub(in crate::ui::local) fn local_bulk_tag_input_changed(&mut self, value: String) -> Task<AppMessage> {
+        self.local_state.bulk_tag_input = value;
+        Task::none()
+    }
+
+    /// 为所有选中的壁纸添加标签
+    pub(in crate::ui::local) fn add_tag_to_local_selection(&mut self, tag: String) -> Task<AppMessage> {
+        let Some(tag) = library::normalize_tag(&tag) else {
+            return Task::none();
+        };
+        if self.local_state.selected_paths.is_empty() {
+            return Task::none();
+        }
+
+        let mut paths: Vec<String> = self.local_state.selected_paths.iter().cloned().collect();
+        paths.sort();
+        let result =
+            UserMetadataRepository::global().and_then(|repository| repository.add_tags(&paths, std::slice::from_ref(&tag)));
+        if let Err(e) = result {
+            return self.user_metadata_save_failed(e);
+        }
+        info!("[本地壁纸] [标签] 为 {} 张壁纸添加标签: {}", paths.len(), tag);
+
+        let count = paths.len();
+        for path in paths {
+            self.insert_local_tag(path, tag.clone());
+        }
+        self.local_state.rebuild_known_tags();
+        self.local_state.bulk_tag_input.clear();
+
+        let message = self
+            .i18n
+            .t("local-list.bulk-tag-added")
+            .replace("{tag}", &tag)
+            .replace("{count}", &count.to_string());
+        self.show_notification(message, NotificationType::Success)
+    }
+
+    /// 在本地状态中为壁纸添加标签（已有时忽略），标签按名称排序（与数据库一致）
+    fn insert_local_tag(&mut self, path: String, tag: String) {
+        let metadata = self.local_state.user_metadata.entry(path).or_default();
+        if !metadata.has_tag(&tag) {
+            metadata.tags.push(tag);
+            metadata.tags.sort_by_cached_key(|t| t.to_ascii_lowercase());
+        }
+    }
+
+    /// 标签、收藏或评分保存失败
+    fn user_metadata_save_failed(&mut self, e: String) -> Task<AppMessage> {
+        error!("[本地壁纸] 保存标签、收藏或评分失败: {}", e);
+        self.show_notification(
+            format!("{}: {}", self.i18n.t("local-list.metadata-save-failed"), e),
+            NotificationType::Error,
+        )
+    }
+}
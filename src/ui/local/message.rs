@@ -4,18 +4,34 @@
 //!
 //! 定义本地壁纸页面的消息类型
 
+use crate::services::database::{LibraryEntry, TrashItem, UserMetadata};
+use crate::services::library::IndexStats;
+use crate::services::library_transfer::{TransferMode, TransferReport};
+use crate::services::library_watcher::LibraryChanges;
 use crate::services::local::Wallpaper;
+use crate::services::trash::{RestoreReport, TrashReport};
+use crate::services::wallhaven::{AspectRatioGroup, ColorOption};
+use crate::ui::local::state::LocalPicker;
 use crate::ui::{App, AppMessage};
+use crate::utils::config::{LocalSort, Orientation};
 use iced::Task;
+use iced::keyboard::Modifiers;
 use iced::widget::image::Handle;
+use std::collections::HashMap;
 
 /// 本地壁纸页面消息
 #[derive(Debug, Clone)]
 pub enum LocalMessage {
     /// 加载壁纸列表
     LoadWallpapers,
-    /// 壁纸列表加载成功
-    LoadWallpapersSuccess(Vec<String>),
+    /// 壁纸列表加载成功（壁纸路径列表、壁纸库索引记录和用户数据）
+    LoadWallpapersSuccess(Vec<String>, Vec<LibraryEntry>, HashMap<String, UserMetadata>),
+    /// 壁纸库索引更新完成
+    LibraryIndexRefreshed(Result<IndexStats, String>),
+    /// 壁纸库目录中的文件发生变化（文件监听）
+    LibraryChanged(LibraryChanges),
+    /// 新增或修改过的文件已写入壁纸库索引
+    LibraryFilesIndexed(Vec<LibraryEntry>),
     /// 加载页面
     LoadPage,
     /// 页面加载成功
@@ -42,8 +58,104 @@ pub enum LocalMessage {
     CloseDeleteConfirm,
     /// 确认删除
     ConfirmDelete(usize),
+    /// 壁纸已移到回收站
+    WallpaperDeleted(Result<TrashItem, String>),
     /// 模态窗口图片加载完成
     ModalImageLoaded(Handle),
+    /// 在浏览器中打开壁纸详情页面
+    OpenSourcePage(String),
+    /// 打开相似图片查找对话框并开始查找
+    FindDuplicates,
+    /// 相似图片查找完成
+    DuplicatesFound(Result<Vec<Vec<LibraryEntry>>, String>),
+    /// 相似度阈值变化
+    DuplicateThresholdChanged(u32),
+    /// 切换是否保留图片 (组索引, 图片索引, 是否保留)
+    DuplicateKeepToggled(usize, usize, bool),
+    /// 删除所有未保留的图片
+    ConfirmDuplicateCleanup,
+    /// 未保留的图片已移到回收站
+    DuplicatesDeleted(Result<TrashReport, String>),
+    /// 关闭相似图片查找对话框
+    CloseDuplicates,
+    /// 打开回收站对话框
+    OpenTrash,
+    /// 关闭回收站对话框
+    CloseTrash,
+    /// 恢复回收站中的壁纸（回收站记录 ID），也用于撤销删除
+    RestoreFromTrash(Vec<i64>),
+    /// 回收站中的壁纸已恢复
+    TrashRestored(Result<RestoreReport, String>),
+    /// 彻底删除回收站中的壁纸（回收站记录 ID）
+    PurgeFromTrash(Vec<i64>),
+    /// 清空回收站
+    EmptyTrash,
+    /// 回收站保留天数变化
+    TrashRetentionChanged(u32),
+    /// 切换是否收藏
+    ToggleFavorite(usize),
+    /// 设置评分 (壁纸索引, 评分)，再次点击当前评分时清除评分
+    SetRating(usize, u8),
+    /// 模态窗口中标签输入框内容变化
+    TagInputChanged(String),
+    /// 添加标签 (壁纸索引, 标签)
+    AddTag(usize, String),
+    /// 删除标签 (壁纸索引, 标签)
+    RemoveTag(usize, String),
+    /// 切换壁纸的选中状态（按住 Shift 时选中一个范围）
+    ToggleSelection(usize),
+    /// 选中所有符合筛选条件的壁纸
+    SelectAll,
+    /// 取消选中所有壁纸
+    ClearSelection,
+    /// 修饰键状态变化
+    ModifiersChanged(Modifiers),
+    /// 显示批量删除确认对话框
+    ShowBulkDeleteConfirm,
+    /// 关闭批量删除确认对话框
+    CloseBulkDeleteConfirm,
+    /// 把选中的壁纸移到回收站
+    ConfirmBulkDelete,
+    /// 选中的壁纸已移到回收站
+    SelectionDeleted(Result<TrashReport, String>),
+    /// 把选中的壁纸移动或复制到壁纸库目录 (目录路径, 方式)
+    TransferSelection(String, TransferMode),
+    /// 移动或复制完成
+    SelectionTransferred(TransferMode, Result<TransferReport, String>),
+    /// 复制选中壁纸的路径到剪贴板
+    CopySelectedPaths,
+    /// 把选中的壁纸导出为 zip 文件
+    ExportSelection,
+    /// 导出完成（用户取消时为 None）
+    SelectionExported(Result<Option<(String, usize)>, String>),
+    /// 批量添加标签输入框内容变化
+    BulkTagInputChanged(String),
+    /// 为所有选中的壁纸添加标签
+    AddTagToSelection(String),
+    /// 排序方式变化
+    SortChanged(LocalSort),
+    /// 切换升序/降序
+    ToggleSortDirection,
+    /// 最低分辨率筛选变化 (宽, 高)，(0, 0) 表示不限
+    MinResolutionChanged(u32, u32),
+    /// 方向筛选变化
+    OrientationFilterChanged(Option<Orientation>),
+    /// 比例分组筛选变化
+    AspectRatioGroupFilterChanged(Option<AspectRatioGroup>),
+    /// 文件格式筛选变化
+    FormatFilterChanged(Option<String>),
+    /// 主色调筛选变化
+    ColorFilterChanged(ColorOption),
+    /// 文件名筛选输入框内容变化
+    NamePatternInputChanged(String),
+    /// 应用文件名筛选
+    ApplyNamePattern,
+    /// 清除所有筛选条件
+    ResetFilters,
+    /// 展开或收起工具栏中的下拉选择器
+    TogglePicker(LocalPicker),
+    /// 收起工具栏中的下拉选择器
+    ClosePicker,
 }
 
 impl From<LocalMessage> for AppMessage {
@@ -58,7 +170,7 @@ pub enum WallpaperLoadStatus {
     /// 加载中
     Loading,
     /// 已加载
-    Loaded(Wallpaper),
+    Loaded(Box<Wallpaper>),
 }
 
 impl App {
@@ -66,7 +178,12 @@ impl App {
     pub fn handle_local_message(&mut self, msg: LocalMessage) -> Task<AppMessage> {
         match msg {
             LocalMessage::LoadWallpapers => self.load_local_wallpapers(),
-            LocalMessage::LoadWallpapersSuccess(paths) => self.load_local_wallpapers_success(paths),
+            LocalMessage::LoadWallpapersSuccess(paths, entries, user_metadata) => {
+                self.load_local_wallpapers_success(paths, entries, user_metadata)
+            }
+            LocalMessage::LibraryIndexRefreshed(result) => self.local_library_index_refreshed(result),
+            LocalMessage::LibraryChanged(changes) => self.local_library_changed(changes),
+            LocalMessage::LibraryFilesIndexed(entries) => self.local_library_files_indexed(entries),
             LocalMessage::LoadPage => self.load_local_page(),
             LocalMessage::LoadPageSuccess(wallpapers_with_idx) => self.load_local_page_success(wallpapers_with_idx),
             LocalMessage::ShowModal(index) => self.show_local_modal(index),
@@ -80,7 +197,57 @@ impl App {
             LocalMessage::ShowDeleteConfirm(index) => self.show_local_delete_confirm(index),
             LocalMessage::CloseDeleteConfirm => self.close_local_delete_confirm(),
             LocalMessage::ConfirmDelete(index) => self.confirm_local_delete(index),
+            LocalMessage::WallpaperDeleted(result) => self.local_wallpaper_deleted(result),
             LocalMessage::SetWallpaper(index) => self.local_set_as_wallpaper(index),
+            LocalMessage::OpenSourcePage(url) => self.open_local_source_page(url),
+            LocalMessage::FindDuplicates => self.find_local_duplicates(),
+            LocalMessage::DuplicatesFound(result) => self.local_duplicates_found(result),
+            LocalMessage::DuplicateThresholdChanged(threshold) => self.local_duplicate_threshold_changed(threshold),
+            LocalMessage::DuplicateKeepToggled(group, item, keep) => {
+                self.local_duplicate_keep_toggled(group, item, keep)
+            }
+            LocalMessage::ConfirmDuplicateCleanup => self.confirm_local_duplicate_cleanup(),
+            LocalMessage::DuplicatesDeleted(result) => self.local_duplicates_deleted(result),
+            LocalMessage::CloseDuplicates => self.close_local_duplicates(),
+            LocalMessage::OpenTrash => self.open_local_trash(),
+            LocalMessage::CloseTrash => self.close_local_trash(),
+            LocalMessage::RestoreFromTrash(ids) => self.restore_local_trash_items(ids),
+            LocalMessage::TrashRestored(result) => self.local_trash_restored(result),
+            LocalMessage::PurgeFromTrash(ids) => self.purge_local_trash_items(ids),
+            LocalMessage::EmptyTrash => self.empty_local_trash(),
+            LocalMessage::TrashRetentionChanged(days) => self.local_trash_retention_changed(days),
+            LocalMessage::ToggleFavorite(index) => self.toggle_local_favorite(index),
+            LocalMessage::SetRating(index, rating) => self.set_local_rating(index, rating),
+            LocalMessage::TagInputChanged(value) => self.local_tag_input_changed(value),
+            LocalMessage::AddTag(index, tag) => self.add_local_tag(index, tag),
+            LocalMessage::RemoveTag(index, tag) => self.remove_local_tag(index, tag),
+            LocalMessage::ToggleSelection(index) => self.toggle_local_selection(index),
+            LocalMessage::SelectAll => self.select_all_local(),
+            LocalMessage::ClearSelection => self.clear_local_selection(),
+            LocalMessage::ModifiersChanged(modifiers) => self.local_modifiers_changed(modifiers),
+            LocalMessage::ShowBulkDeleteConfirm => self.show_local_bulk_delete_confirm(),
+            LocalMessage::CloseBulkDeleteConfirm => self.close_local_bulk_delete_confirm(),
+            LocalMessage::ConfirmBulkDelete => self.confirm_local_bulk_delete(),
+            LocalMessage::SelectionDeleted(result) => self.local_selection_deleted(result),
+            LocalMessage::TransferSelection(target_dir, mode) => self.transfer_local_selection(target_dir, mode),
+            LocalMessage::SelectionTransferred(mode, result) => self.local_selection_transferred(mode, result),
+            LocalMessage::CopySelectedPaths => self.copy_local_selected_paths(),
+            LocalMessage::ExportSelection => self.export_local_selection(),
+            LocalMessage::SelectionExported(result) => self.local_selection_exported(result),
+            LocalMessage::BulkTagInputChanged(value) => self.local_bulk_tag_input_changed(value),
+            LocalMessage::AddTagToSelection(tag) => self.add_tag_to_local_selection(tag),
+            LocalMessage::SortChanged(sort) => self.local_sort_changed(sort),
+            LocalMessage::ToggleSortDirection => self.toggle_local_sort_direction(),
+            LocalMessage::MinResolutionChanged(width, height) => self.local_min_resolution_changed(width, height),
+            LocalMessage::OrientationFilterChanged(orientation) => self.local_orientation_filter_changed(orientation),
+            LocalMessage::AspectRatioGroupFilterChanged(group) => self.local_aspect_ratio_group_filter_changed(group),
+            LocalMessage::FormatFilterChanged(format) => self.local_format_filter_changed(format),
+            LocalMessage::ColorFilterChanged(color) => self.local_color_filter_changed(color),
+            LocalMessage::NamePatternInputChanged(value) => self.local_name_pattern_input_changed(value),
+            LocalMessage::ApplyNamePattern => self.apply_local_name_pattern(),
+            LocalMessage::ResetFilters => self.reset_local_filters(),
+            LocalMessage::TogglePicker(picker) => self.toggle_local_picker(picker),
+            LocalMessage::ClosePicker => self.close_local_picker(),
         }
     }
 }
@@ -1,12 +1,47 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use super::message::WallpaperLoadStatus;
+use crate::services::database::{LibraryEntry, TrashItem, UserMetadata};
+use iced::keyboard::Modifiers;
 use iced::widget::image::Handle;
+use std::collections::{HashMap, HashSet};
+
+/// 标签自动补全最多显示的候选数量
+const TAG_SUGGESTION_LIMIT: usize = 8;
 
 #[derive(Debug)]
 pub struct LocalState {
     pub wallpapers: Vec<WallpaperLoadStatus>,
+    /// 壁纸库中的所有壁纸路径（未筛选、未排序）
+    pub library_paths: Vec<String>,
+    /// 上次提示过的当前构建无法解码的图片数（数量增加时才再次提示）
+    pub undecodable_notified: usize,
+    /// 按筛选条件和排序方式整理后的壁纸路径（页面中显示的列表）
     pub all_paths: Vec<String>,
+    /// 壁纸库索引记录（按路径），已建立索引的壁纸不需要重新读取图片文件
+    pub library_entries: HashMap<String, LibraryEntry>,
+    /// 标签、收藏和评分（按路径，只包含设置过的壁纸）
+    pub user_metadata: HashMap<String, UserMetadata>,
+    /// 所有已使用的标签及使用次数（按使用次数降序），用于标签自动补全
+    pub known_tags: Vec<(String, usize)>,
+    /// 模态窗口中标签输入框的内容
+    pub tag_input: String,
+    /// 已选中的壁纸路径
+    pub selected_paths: HashSet<String>,
+    /// 上一次点击选中的壁纸路径（按住 Shift 点击时选中从这里到点击位置之间的所有壁纸）
+    pub selection_anchor: Option<String>,
+    /// 当前按下的修饰键
+    pub modifiers: Modifiers,
+    /// 是否正在移动或复制选中的壁纸
+    pub transfer_running: bool,
+    /// 是否正在把选中的壁纸移到回收站
+    pub bulk_delete_running: bool,
+    /// 批量添加标签输入框的内容
+    pub bulk_tag_input: String,
+    /// 工具栏中展开的下拉选择器
+    pub open_picker: Option<LocalPicker>,
+    /// 文件名筛选输入框的内容（提交后才应用到筛选条件）
+    pub name_pattern_input: String,
     pub loading_page: bool,
     pub current_page: usize,
     pub page_size: usize,
@@ -15,14 +50,34 @@ pub struct LocalState {
     pub current_image_index: usize,
     pub delete_confirm_visible: bool,
     pub delete_target_index: Option<usize>,
+    /// 是否显示批量删除确认对话框
+    pub bulk_delete_confirm_visible: bool,
     pub modal_image_handle: Option<Handle>,
+    /// 相似图片查找对话框状态
+    pub duplicates: DuplicateFinderState,
+    /// 回收站对话框状态
+    pub trash: TrashState,
 }
 
 impl Default for LocalState {
     fn default() -> Self {
         Self {
             wallpapers: Vec::new(),
+            library_paths: Vec::new(),
+            undecodable_notified: 0,
             all_paths: Vec::new(),
+            library_entries: HashMap::new(),
+            user_metadata: HashMap::new(),
+            known_tags: Vec::new(),
+            tag_input: String::new(),
+            selected_paths: HashSet::new(),
+            selection_anchor: None,
+            modifiers: Modifiers::default(),
+            transfer_running: false,
+            bulk_delete_running: false,
+            bulk_tag_input: String::new(),
+            open_picker: None,
+            name_pattern_input: String::new(),
             loading_page: false,
             current_page: 0,
             page_size: 20,
@@ -31,12 +86,232 @@ impl Default for LocalState {
             current_image_index: 0,
             delete_confirm_visible: false,
             delete_target_index: None,
+            bulk_delete_confirm_visible: false,
             modal_image_handle: None,
+            duplicates: DuplicateFinderState::default(),
+            trash: TrashState::default(),
         }
     }
 }
 
+/// 本地壁纸工具栏中的下拉选择器
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalPicker {
+    Sort,
+    MinResolution,
+    Orientation,
+    AspectRatioGroup,
+    Format,
+    Color,
+    /// 把选中的壁纸移动到壁纸库目录
+    MoveTo,
+    /// 把选中的壁纸复制到壁纸库目录
+    CopyTo,
+}
+
+/// 相似图片查找对话框的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateFinderStage {
+    /// 对话框未打开
+    #[default]
+    Closed,
+    /// 正在计算感知哈希并分组
+    Scanning,
+    /// 显示查找结果，选择要保留的图片
+    Review,
+}
+
+/// 相似图片组中的一张图片
+#[derive(Debug, Clone)]
+pub struct DuplicateItem {
+    pub entry: LibraryEntry,
+    /// 缩略图 Handle（创建时生成，避免每次渲染都重新创建）
+    pub handle: Handle,
+    /// 是否保留（未保留的图片在确认后删除）
+    pub keep: bool,
+}
+
+/// 相似图片查找对话框状态
+#[derive(Debug, Default)]
+pub struct DuplicateFinderState {
+    pub stage: DuplicateFinderStage,
+    /// 相似图片组（每组第一张是默认保留的最佳版本）
+    pub groups: Vec<Vec<DuplicateItem>>,
+}
+
+impl DuplicateFinderState {
+    /// 设置查找结果，每组默认只保留第一张（分辨率最高、文件最大）
+    pub fn set_groups(&mut self, groups: Vec<Vec<LibraryEntry>>) {
+        self.groups = groups
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, entry)| DuplicateItem {
+                        handle: Handle::from_path(&entry.thumbnail_path),
+                        entry,
+                        keep: index == 0,
+                    })
+                    .collect()
+            })
+            .collect();
+        self.stage = DuplicateFinderStage::Review;
+    }
+
+    /// 关闭对话框并清空查找结果
+    pub fn close(&mut self) {
+        self.stage = DuplicateFinderStage::Closed;
+        self.groups.clear();
+    }
+
+    /// 将要删除（未保留）的图片路径
+    pub fn paths_to_delete(&self) -> Vec<String> {
+        self.groups
+            .iter()
+            .flatten()
+            .filter(|item| !item.keep)
+            .map(|item| item.entry.path.clone())
+            .collect()
+    }
+}
+
+/// 回收站对话框状态
+#[derive(Debug, Default)]
+pub struct TrashState {
+    pub visible: bool,
+    /// 回收站中的壁纸（最近删除的排在最前面）
+    pub items: Vec<TrashItem>,
+}
+
 impl LocalState {
+    /// 从列表中移除壁纸（文件已被删除），并调整模态窗口中显示的图片索引
+    pub fn remove_wallpaper(&mut self, index: usize) {
+        if index >= self.all_paths.len() {
+            return;
+        }
+
+        let path = self.all_paths.remove(index);
+        self.library_paths.retain(|p| *p != path);
+        self.library_entries.remove(&path);
+        self.selected_paths.remove(&path);
+        if self.user_metadata.remove(&path).is_some() {
+            self.rebuild_known_tags();
+        }
+        if index < self.wallpapers.len() {
+            self.wallpapers.remove(index);
+        }
+        self.total_count = self.total_count.saturating_sub(1);
+
+        // 如果删除的是当前显示的图片，关闭模态窗口
+        if self.modal_visible && self.current_image_index == index {
+            self.modal_visible = false;
+        } else if self.modal_visible && self.current_image_index > index {
+            // 如果删除的图片在当前显示图片之前，调整索引
+            self.current_image_index -= 1;
+        }
+    }
+
+    /// 按路径批量移除壁纸（文件已被删除），壁纸可能因为筛选条件没有显示在列表中
+    pub fn remove_paths(&mut self, paths: &HashSet<String>) {
+        if paths.is_empty() {
+            return;
+        }
+
+        // 如果删除了当前显示的图片，关闭模态窗口，否则按前面删除的图片数量调整索引
+        if self.modal_visible {
+            match self.all_paths.get(self.current_image_index) {
+                Some(current) if paths.contains(current) => self.modal_visible = false,
+                _ => {
+                    let removed_before = self.all_paths[..self.current_image_index.min(self.all_paths.len())]
+                        .iter()
+                        .filter(|path| paths.contains(*path))
+                        .count();
+                    self.current_image_index -= removed_before;
+                }
+            }
+        }
+
+        // 已加载的壁纸与 all_paths 按索引一一对应
+        let mut removed = self.all_paths.iter().map(|path| paths.contains(path));
+        self.wallpapers.retain(|_| !removed.next().unwrap_or(false));
+        let count_before = self.all_paths.len();
+        self.all_paths.retain(|path| !paths.contains(path));
+        self.total_count = self.total_count.saturating_sub(count_before - self.all_paths.len());
+
+        self.library_paths.retain(|path| !paths.contains(path));
+        self.library_entries.retain(|path, _| !paths.contains(path));
+        self.selected_paths.retain(|path| !paths.contains(path));
+        let metadata_count = self.user_metadata.len();
+        self.user_metadata.retain(|path, _| !paths.contains(path));
+        if self.user_metadata.len() != metadata_count {
+            self.rebuild_known_tags();
+        }
+    }
+
+    /// 选中的壁纸路径（按列表中的顺序）
+    pub fn ordered_selected_paths(&self) -> Vec<String> {
+        self.all_paths
+            .iter()
+            .filter(|path| self.selected_paths.contains(*path))
+            .cloned()
+            .collect()
+    }
+
+    /// 壁纸的标签、收藏和评分（未设置过时为 None）
+    pub fn metadata_of(&self, index: usize) -> Option<&UserMetadata> {
+        self.all_paths.get(index).and_then(|path| self.user_metadata.get(path))
+    }
+
+    /// 获取指定索引的壁纸的壁纸库索引记录
+    pub fn entry_of(&self, index: usize) -> Option<&LibraryEntry> {
+        self.all_paths
+            .get(index)
+            .and_then(|path| self.library_entries.get(path))
+    }
+
+    /// 重新统计所有已使用的标签
+    pub fn rebuild_known_tags(&mut self) {
+        // 标签不区分大小写（与数据库一致），显示时使用第一次出现时的大小写
+        let mut counts: HashMap<String, (String, usize)> = HashMap::new();
+        for tag in self.user_metadata.values().flat_map(|metadata| &metadata.tags) {
+            counts
+                .entry(tag.to_ascii_lowercase())
+                .or_insert_with(|| (tag.clone(), 0))
+                .1 += 1;
+        }
+
+        let mut known_tags: Vec<(String, usize)> = counts.into_values().collect();
+        known_tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        self.known_tags = known_tags;
+    }
+
+    /// 标签自动补全候选
+    ///
+    /// 输入为空时返回最常用的标签；否则返回包含输入内容的标签（以输入内容开头的排在前面），
+    /// 不包含 `exclude` 中已有的标签
+    pub fn tag_suggestions(&self, input: &str, exclude: Option<&UserMetadata>) -> Vec<&str> {
+        let input = input.trim().to_ascii_lowercase();
+        let mut prefixed = Vec::new();
+        let mut contained = Vec::new();
+
+        for (tag, _) in &self.known_tags {
+            if exclude.is_some_and(|metadata| metadata.has_tag(tag)) {
+                continue;
+            }
+            let lower = tag.to_ascii_lowercase();
+            if lower.starts_with(&input) {
+                prefixed.push(tag.as_str());
+            } else if lower.contains(&input) {
+                contained.push(tag.as_str());
+            }
+        }
+
+        prefixed.extend(contained);
+        prefixed.truncate(TAG_SUGGESTION_LIMIT);
+        prefixed
+    }
+
     /// 查找下一个有效的图片索引
     pub fn find_next_valid_image_index(&self, start_index: usize, direction: i32) -> Option<usize> {
         if self.all_paths.is_empty() {
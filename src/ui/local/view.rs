@@ -5,26 +5,26 @@
 //! 定义本地壁纸页面的界面渲染逻辑
 
 use super::message::LocalMessage;
-use super::state::LocalState;
+use super::state::{DuplicateFinderStage, LocalState};
 use super::widget;
 use crate::i18n::I18n;
 use crate::ui::AppMessage;
 use crate::ui::common;
 use crate::ui::style::ThemeConfig;
 use crate::utils::config::Config;
-use iced::widget::{Id, container, scrollable};
+use iced::widget::{Id, column, container, scrollable};
 use iced::{Element, Length};
 
 /// 本地壁纸页面视图函数
 pub fn local_view<'a>(
     i18n: &'a I18n,
-    _config: &'a Config,
+    config: &'a Config,
     window_width: u32,
     local_state: &'a LocalState,
     theme_config: &'a ThemeConfig,
 ) -> Element<'a, AppMessage> {
     let content = if local_state.all_paths.is_empty() {
-        widget::create_empty_content(i18n, theme_config)
+        widget::create_empty_content(i18n, local_state, theme_config)
     } else {
         widget::create_content(i18n, window_width, local_state, theme_config)
     };
@@ -70,7 +70,14 @@ pub fn local_view<'a>(
             }
         });
 
-    let mut layers = vec![base_layer.into()];
+    let main_content = column![
+        widget::create_toolbar(i18n, config, local_state, theme_config),
+        base_layer
+    ]
+    .width(Length::Fill)
+    .height(Length::Fill);
+
+    let mut layers = vec![main_content.into()];
 
     // 图片预览模态窗口
     if local_state.modal_visible && !local_state.all_paths.is_empty() {
@@ -91,6 +98,38 @@ pub fn local_view<'a>(
         layers.push(delete_confirm_dialog);
     }
 
+    // 批量删除确认模态窗口
+    if local_state.bulk_delete_confirm_visible {
+        let bulk_delete_confirm_dialog = common::create_confirmation_dialog(
+            i18n.t("local-list.delete-confirm-title"),
+            i18n.t("local-list.bulk-delete-confirm-message")
+                .replace("{count}", &local_state.selected_paths.len().to_string()),
+            i18n.t("local-list.delete-confirm-confirm"),
+            i18n.t("local-list.delete-confirm-cancel"),
+            LocalMessage::ConfirmBulkDelete.into(),
+            LocalMessage::CloseBulkDeleteConfirm.into(),
+        );
+        layers.push(bulk_delete_confirm_dialog);
+    }
+
+    // 相似图片查找对话框
+    if local_state.duplicates.stage != DuplicateFinderStage::Closed {
+        layers.push(widget::create_duplicates_dialog(
+            i18n,
+            &local_state.duplicates,
+            config.local.duplicate_threshold,
+        ));
+    }
+
+    // 回收站对话框
+    if local_state.trash.visible {
+        layers.push(widget::create_trash_dialog(
+            i18n,
+            &local_state.trash,
+            config.local.trash_retention_days,
+        ));
+    }
+
     iced::widget::stack(layers)
         .width(Length::Fill)
         .height(Length::Fill)
@@ -42,7 +42,14 @@ pub fn create_content<'a>(
                     if wallpaper.name == "加载失败" {
                         super::create_error_placeholder(i18n, wallpaper, wallpaper_index, theme_config)
                     } else {
-                        super::create_loaded_wallpaper(i18n, wallpaper, wallpaper_index, theme_config)
+                        super::create_loaded_wallpaper(
+                            i18n,
+                            wallpaper,
+                            wallpaper_index,
+                            local_state.metadata_of(wallpaper_index),
+                            local_state.selected_paths.contains(&wallpaper.path),
+                            theme_config,
+                        )
                     }
                 }
             };
@@ -74,11 +81,20 @@ pub fn create_content<'a>(
     .into()
 }
 
-/// 创建空内容展示区
-pub fn create_empty_content<'a>(i18n: &'a I18n, theme_config: &'a ThemeConfig) -> Element<'a, AppMessage> {
+/// 创建空内容展示区（壁纸库非空但没有符合筛选条件的壁纸时提示调整筛选条件）
+pub fn create_empty_content<'a>(
+    i18n: &'a I18n,
+    local_state: &'a LocalState,
+    theme_config: &'a ThemeConfig,
+) -> Element<'a, AppMessage> {
     let theme_colors = theme_config.get_theme_colors();
+    let message_key = if local_state.library_paths.is_empty() {
+        "local-list.no-wallpapers"
+    } else {
+        "local-list.no-matching-wallpapers"
+    };
     column![
-        text(i18n.t("local-list.no-wallpapers"))
+        text(i18n.t(message_key))
             .size(EMPTY_STATE_TEXT_SIZE)
             .style(move |_theme: &iced::Theme| text::Style {
                 color: Some(theme_colors.text),
@@ -0,0 +1,175 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::local::message::LocalMessage;
+use crate::ui::local::state::{DuplicateFinderStage, DuplicateFinderState, DuplicateItem};
+use crate::ui::style::*;
+use crate::utils::helpers;
+use iced::widget::{Column, Row, column, container, row, scrollable, text, toggler};
+use iced::{Alignment, Element, Length};
+
+/// 相似图片对话框的宽度
+const DUPLICATES_DIALOG_WIDTH: f32 = 860.0;
+/// 相似图片列表的高度
+const DUPLICATES_LIST_HEIGHT: f32 = 420.0;
+/// 相似图片缩略图的宽度
+const DUPLICATE_THUMBNAIL_WIDTH: f32 = 160.0;
+/// 相似图片缩略图的高度
+const DUPLICATE_THUMBNAIL_HEIGHT: f32 = 100.0;
+
+/// 创建相似图片查找对话框（查找中显示进度，查找完成后显示相似图片组）
+pub fn create_duplicates_dialog<'a>(
+    i18n: &'a I18n,
+    state: &'a DuplicateFinderState,
+    threshold: u32,
+) -> Element<'a, AppMessage> {
+    let scanning = state.stage == DuplicateFinderStage::Scanning;
+
+    let threshold_row = row![
+        text(i18n.t("duplicates.threshold")).size(DIALOG_MESSAGE_SIZE),
+        iced_aw::NumberInput::new(&threshold, 0..=32, |n| {
+            LocalMessage::DuplicateThresholdChanged(n).into()
+        })
+        .width(Length::Fixed(80.0)),
+        common::create_colored_button(
+            i18n.t("duplicates.rescan"),
+            BUTTON_COLOR_BLUE,
+            LocalMessage::FindDuplicates.into()
+        ),
+    ]
+    .spacing(DIALOG_BUTTON_SPACING)
+    .align_y(Alignment::Center);
+
+    let body: Element<'a, AppMessage> = if scanning {
+        text(i18n.t("duplicates.scanning")).size(DIALOG_MESSAGE_SIZE).into()
+    } else if state.groups.is_empty() {
+        text(i18n.t("duplicates.none")).size(DIALOG_MESSAGE_SIZE).into()
+    } else {
+        let file_count: usize = state.groups.iter().map(Vec::len).sum();
+        let summary = i18n
+            .t("duplicates.summary")
+            .replace("{groups}", &state.groups.len().to_string())
+            .replace("{count}", &file_count.to_string());
+
+        let groups = state
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(group_index, group)| create_group(i18n, group_index, group));
+
+        column![
+            text(summary).size(DIALOG_MESSAGE_SIZE),
+            scrollable(
+                Column::with_children(groups)
+                    .spacing(DIALOG_SPACING)
+                    .width(Length::Fill)
+            )
+            .height(Length::Fixed(DUPLICATES_LIST_HEIGHT)),
+        ]
+        .spacing(DIALOG_SPACING)
+        .into()
+    };
+
+    let delete_count = state.paths_to_delete().len();
+    let delete_label = i18n
+        .t("duplicates.delete")
+        .replace("{count}", &delete_count.to_string());
+    let mut buttons = Row::new().spacing(DIALOG_BUTTON_SPACING);
+    if !scanning && delete_count > 0 {
+        buttons = buttons.push(common::create_colored_button(
+            delete_label,
+            BUTTON_COLOR_RED,
+            LocalMessage::ConfirmDuplicateCleanup.into(),
+        ));
+    }
+    buttons = buttons.push(common::create_colored_button(
+        i18n.t("duplicates.close"),
+        BUTTON_COLOR_GRAY,
+        LocalMessage::CloseDuplicates.into(),
+    ));
+
+    common::create_modal_dialog(
+        column![
+            text(i18n.t("duplicates.title")).size(DIALOG_TITLE_SIZE),
+            threshold_row,
+            body,
+            text(i18n.t("duplicates.help")).size(12).color(BUTTON_COLOR_GRAY),
+            buttons,
+        ]
+        .spacing(DIALOG_SPACING)
+        .padding(DIALOG_PADDING)
+        .align_x(Alignment::Center)
+        .width(Length::Fixed(DUPLICATES_DIALOG_WIDTH))
+        .into(),
+    )
+}
+
+/// 创建一组相似图片
+fn create_group<'a>(i18n: &'a I18n, group_index: usize, group: &'a [DuplicateItem]) -> Element<'a, AppMessage> {
+    let items = group
+        .iter()
+        .enumerate()
+        .map(|(item_index, item)| create_item(i18n, group_index, item_index, item));
+
+    container(Row::with_children(items).spacing(DIALOG_SPACING))
+        .padding(DIALOG_INNER_PADDING)
+        .width(Length::Fill)
+        .style(|_theme: &iced::Theme| container::Style {
+            border: iced::border::Border {
+                color: iced::Color::from_rgb(BORDER_COLOR_GRAY, BORDER_COLOR_GRAY, BORDER_COLOR_GRAY),
+                width: DIALOG_BORDER_WIDTH,
+                radius: iced::border::Radius::from(DIALOG_BORDER_RADIUS),
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+/// 创建相似图片组中的一张图片（缩略图、尺寸、文件大小和是否保留）
+fn create_item<'a>(
+    i18n: &'a I18n,
+    group_index: usize,
+    item_index: usize,
+    item: &'a DuplicateItem,
+) -> Element<'a, AppMessage> {
+    let entry = &item.entry;
+    let details = format!(
+        "{}x{} · {}",
+        entry.width,
+        entry.height,
+        helpers::format_file_size(entry.file_size)
+    );
+    let keep_label = if item.keep {
+        i18n.t("duplicates.keep")
+    } else {
+        i18n.t("duplicates.remove")
+    };
+    let keep_color = if item.keep {
+        BUTTON_COLOR_GREEN
+    } else {
+        BUTTON_COLOR_RED
+    };
+
+    column![
+        iced::widget::image(item.handle.clone())
+            .content_fit(iced::ContentFit::Contain)
+            .width(Length::Fixed(DUPLICATE_THUMBNAIL_WIDTH))
+            .height(Length::Fixed(DUPLICATE_THUMBNAIL_HEIGHT)),
+        text(&entry.file_name)
+            .size(12)
+            .width(Length::Fixed(DUPLICATE_THUMBNAIL_WIDTH)),
+        text(details).size(12),
+        row![
+            toggler(item.keep)
+                .on_toggle(move |keep| LocalMessage::DuplicateKeepToggled(group_index, item_index, keep).into()),
+            text(keep_label).size(12).color(keep_color),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(4)
+    .width(Length::Fixed(DUPLICATE_THUMBNAIL_WIDTH))
+    .into()
+}
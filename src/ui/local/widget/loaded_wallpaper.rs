@@ -1,13 +1,14 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::i18n::I18n;
+use crate::services::database::UserMetadata;
 use crate::services::local::Wallpaper;
 use crate::ui::AppMessage;
 use crate::ui::common;
 use crate::ui::local::LocalMessage;
 use crate::ui::style::{
-    BUTTON_COLOR_GREEN, BUTTON_COLOR_RED, BUTTON_COLOR_YELLOW, COLOR_OVERLAY_BG, COLOR_OVERLAY_TEXT, IMAGE_HEIGHT,
-    IMAGE_WIDTH, OVERLAY_HEIGHT, OVERLAY_TEXT_SIZE,
+    BUTTON_COLOR_BLUE, BUTTON_COLOR_GREEN, BUTTON_COLOR_RED, BUTTON_COLOR_YELLOW, COLOR_OVERLAY_BG, COLOR_OVERLAY_TEXT,
+    IMAGE_HEIGHT, IMAGE_WIDTH, OVERLAY_HEIGHT, OVERLAY_TEXT_SIZE,
 };
 use crate::ui::style::ThemeConfig;
 use crate::utils::helpers;
@@ -15,11 +16,18 @@ use iced::widget::image::Handle;
 use iced::widget::{Space, button, container, row, text, tooltip};
 use iced::{Alignment, Color, Length};
 
+/// 选中的壁纸卡片边框宽度
+const SELECTED_BORDER_WIDTH: f32 = 3.0;
+
 /// 创建已加载壁纸卡片
+///
+/// `metadata` 为壁纸的标签、收藏和评分，`selected` 为壁纸是否被选中
 pub fn create_loaded_wallpaper<'a>(
     i18n: &'a I18n,
     wallpaper: &'a Wallpaper,
     index: usize,
+    metadata: Option<&'a UserMetadata>,
+    selected: bool,
     theme_config: &'a ThemeConfig,
 ) -> button::Button<'a, AppMessage> {
     let theme_colors = theme_config.get_theme_colors();
@@ -36,6 +44,11 @@ pub fn create_loaded_wallpaper<'a>(
         .height(Length::Fill)
         .style(move |_theme| {
             let mut style = common::create_bordered_container_style_with_bg(theme_config)(_theme);
+            // 选中的壁纸显示蓝色边框
+            if selected {
+                style.border.color = BUTTON_COLOR_BLUE;
+                style.border.width = SELECTED_BORDER_WIDTH;
+            }
             // 添加阴影效果
             style.shadow = iced::Shadow {
                 color: theme_colors.overlay_bg,
@@ -58,6 +71,31 @@ pub fn create_loaded_wallpaper<'a>(
             color: Some(COLOR_OVERLAY_TEXT),
         });
 
+    let (select_icon, select_tooltip) = if selected {
+        ("\u{F26C}", i18n.t("local-list.tooltip-deselect"))
+    } else {
+        ("\u{F584}", i18n.t("local-list.tooltip-select"))
+    };
+    let select_button = common::create_button_with_tooltip(
+        common::create_icon_button(select_icon, BUTTON_COLOR_BLUE, LocalMessage::ToggleSelection(index).into()),
+        select_tooltip,
+        tooltip::Position::Top,
+        theme_config,
+    );
+
+    let favorite = metadata.is_some_and(|metadata| metadata.favorite);
+    let (favorite_icon, favorite_tooltip) = if favorite {
+        ("\u{F415}", i18n.t("local-list.tooltip-unfavorite"))
+    } else {
+        ("\u{F417}", i18n.t("local-list.tooltip-favorite"))
+    };
+    let favorite_button = common::create_button_with_tooltip(
+        common::create_icon_button(favorite_icon, BUTTON_COLOR_RED, LocalMessage::ToggleFavorite(index).into()),
+        favorite_tooltip,
+        tooltip::Position::Top,
+        theme_config,
+    );
+
     let view_button = common::create_button_with_tooltip(
         common::create_icon_button(
             "\u{F341}",
@@ -87,11 +125,13 @@ pub fn create_loaded_wallpaper<'a>(
         theme_config,
     );
 
-    // 左侧区域：文件大小
-    let left_area = container(file_size_text).align_y(Alignment::Center);
+    // 左侧区域：选中按钮和文件大小
+    let left_area = row![select_button, file_size_text]
+        .spacing(2.0)
+        .align_y(Alignment::Center);
 
     // 右侧区域：操作按钮
-    let right_area = row![view_button, set_wallpaper_button, delete_button]
+    let right_area = row![favorite_button, view_button, set_wallpaper_button, delete_button]
         .spacing(2.0)
         .align_y(Alignment::Center);
 
@@ -1,14 +1,21 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 mod content;
+mod duplicates_dialog;
 mod error_placeholder;
 mod loaded_wallpaper;
 mod loading_placeholder;
 mod modal;
 mod modal_loading_placeholder;
+mod modal_metadata;
+mod modal_user_metadata;
+mod toolbar;
+mod trash_dialog;
 
 pub(in crate::ui::local) use {
-    content::create_content, content::create_empty_content, error_placeholder::create_error_placeholder,
-    loaded_wallpaper::create_loaded_wallpaper, loading_placeholder::create_loading_placeholder, modal::create_modal,
-    modal_loading_placeholder::create_modal_loading_placeholder,
+    content::create_content, content::create_empty_content, duplicates_dialog::create_duplicates_dialog,
+    error_placeholder::create_error_placeholder, loaded_wallpaper::create_loaded_wallpaper,
+    loading_placeholder::create_loading_placeholder, modal::create_modal,
+    modal_loading_placeholder::create_modal_loading_placeholder, modal_metadata::create_modal_metadata,
+    modal_user_metadata::create_modal_user_metadata, toolbar::create_toolbar, trash_dialog::create_trash_dialog,
 };
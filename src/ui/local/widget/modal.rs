@@ -4,6 +4,7 @@ use crate::i18n::I18n;
 use crate::ui::AppMessage;
 use crate::ui::common;
 use crate::ui::local::message::LocalMessage;
+use crate::ui::local::message::WallpaperLoadStatus;
 use crate::ui::local::state::LocalState;
 use crate::ui::style::ThemeConfig;
 use crate::ui::style::{BUTTON_COLOR_BLUE, BUTTON_COLOR_GREEN, BUTTON_COLOR_RED, BUTTON_COLOR_YELLOW, COLOR_MODAL_BG};
@@ -81,21 +82,46 @@ pub fn create_modal<'a>(
         theme_config,
     );
 
+    // 当前壁纸的元数据（来自下载时写入的附属文件）
+    let metadata = match local_state.wallpapers.get(wallpaper_index) {
+        Some(WallpaperLoadStatus::Loaded(wallpaper)) => wallpaper.metadata.as_ref(),
+        _ => None,
+    };
+
+    let mut toolbar_items: Vec<Element<_>> = vec![
+        container(Space::new()).width(Length::Fill).into(),
+        prev_button,
+        next_button,
+        set_wallpaper_button,
+        locate_button,
+    ];
+    if let Some(source_url) = metadata
+        .map(|metadata| &metadata.source_url)
+        .filter(|url| !url.is_empty())
+    {
+        toolbar_items.push(
+            common::create_button_with_tooltip(
+                common::create_icon_button(
+                    "\u{F470}",
+                    BUTTON_COLOR_BLUE,
+                    LocalMessage::OpenSourcePage(source_url.clone()).into(),
+                ),
+                i18n.t("local-list.tooltip-open-source"),
+                tooltip::Position::Top,
+                theme_config,
+            ),
+        );
+    }
+    toolbar_items.push(close_button);
+    toolbar_items.push(container(Space::new()).width(Length::Fill).into());
+
     // 底部工具栏
     let toolbar = container(
-        row![
-            container(Space::new()).width(Length::Fill),
-            prev_button,
-            next_button,
-            set_wallpaper_button,
-            locate_button,
-            close_button,
-            container(Space::new()).width(Length::Fill),
-        ]
-        .width(Length::Fill)
-        .height(Length::Fill)
-        .align_y(Alignment::Center)
-        .spacing(50.0),
+        row(toolbar_items)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_y(Alignment::Center)
+            .spacing(50.0),
     )
     .height(Length::Fixed(30.0))
     .width(Length::Fill)
@@ -109,20 +135,23 @@ pub fn create_modal<'a>(
         ..Default::default()
     });
 
-    container(
-        column![
-            container(modal_image_content)
-                .width(Length::Fill)
-                .height(Length::Fill)
-                .padding(20),
-            toolbar,
-        ]
-        .width(Length::Fill)
-        .height(Length::Fill),
-    )
-    .style(|_theme: &iced::Theme| container::Style {
-        background: Some(iced::Background::Color(COLOR_MODAL_BG)),
-        ..Default::default()
-    })
-    .into()
+    let mut modal_content = column![
+        container(modal_image_content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20),
+    ]
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .push(super::create_modal_user_metadata(i18n, local_state));
+    if let Some(metadata) = metadata {
+        modal_content = modal_content.push(super::create_modal_metadata(i18n, metadata));
+    }
+
+    container(modal_content.push(toolbar))
+        .style(|_theme: &iced::Theme| container::Style {
+            background: Some(iced::Background::Color(COLOR_MODAL_BG)),
+            ..Default::default()
+        })
+        .into()
 }
@@ -0,0 +1,93 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::services::sidecar::WallpaperMetadata;
+use crate::ui::AppMessage;
+use iced::border::{Border, Radius};
+use iced::widget::{Space, column, container, row, text};
+use iced::{Alignment, Color, Element, Length};
+
+/// 元数据文字颜色
+pub(super) const METADATA_TEXT_COLOR: Color = Color::from_rgb(0.9, 0.9, 0.9);
+/// 颜色色块的边长
+const COLOR_SWATCH_SIZE: f32 = 14.0;
+
+/// 创建模态窗口的壁纸元数据信息栏（来自下载时写入的附属文件）
+pub fn create_modal_metadata<'a>(i18n: &'a I18n, metadata: &'a WallpaperMetadata) -> Element<'a, AppMessage> {
+    // 第一行：ID、分辨率、分类、纯净度、上传者
+    let mut summary = vec![format!(
+        "{}: {}",
+        i18n.t("local-list.metadata-id"),
+        metadata.wallhaven_id
+    )];
+    for value in [&metadata.resolution, &metadata.category, &metadata.purity] {
+        if !value.is_empty() {
+            summary.push(value.clone());
+        }
+    }
+    if !metadata.uploader.is_empty() {
+        summary.push(format!(
+            "{}: {}",
+            i18n.t("local-list.metadata-uploader"),
+            metadata.uploader
+        ));
+    }
+
+    let color_swatches = metadata.colors.iter().filter_map(|hex| hex.parse::<Color>().ok()).fold(
+        row![].spacing(4).align_y(Alignment::Center),
+        |swatches, color| {
+            swatches.push(
+                container(Space::new())
+                    .width(Length::Fixed(COLOR_SWATCH_SIZE))
+                    .height(Length::Fixed(COLOR_SWATCH_SIZE))
+                    .style(move |_theme: &iced::Theme| container::Style {
+                        background: Some(iced::Background::Color(color)),
+                        border: Border {
+                            color: METADATA_TEXT_COLOR,
+                            width: 1.0,
+                            radius: Radius::from(2.0),
+                        },
+                        ..Default::default()
+                    }),
+            )
+        },
+    );
+
+    let summary_row = row![
+        text(summary.join("  ·  ")).size(13).color(METADATA_TEXT_COLOR),
+        container(Space::new()).width(Length::Fill),
+        color_swatches,
+    ]
+    .align_y(Alignment::Center)
+    .spacing(10);
+
+    // 第二行：标签
+    let tags_text = if metadata.tags.is_empty() {
+        i18n.t("local-list.metadata-no-tags")
+    } else {
+        metadata
+            .tags
+            .iter()
+            .map(|tag| format!("#{}", tag))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    container(
+        column![summary_row, text(tags_text).size(12).color(METADATA_TEXT_COLOR)]
+            .spacing(4)
+            .width(Length::Fill),
+    )
+    .padding([6, 20])
+    .width(Length::Fill)
+    .style(|_theme: &iced::Theme| container::Style {
+        background: Some(iced::Background::Color(Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.5,
+        })),
+        ..Default::default()
+    })
+    .into()
+}
@@ -0,0 +1,208 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use super::modal_metadata::METADATA_TEXT_COLOR;
+use crate::i18n::I18n;
+use crate::services::color_palette;
+use crate::services::database::user_metadata::MAX_RATING;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::local::message::LocalMessage;
+use crate::ui::local::state::LocalState;
+use crate::ui::style::{BUTTON_COLOR_BLUE, BUTTON_COLOR_RED, BUTTON_COLOR_YELLOW};
+use iced::border::{Border, Radius};
+use iced::widget::{Row, Space, button, column, container, row, text, text_input};
+use iced::{Alignment, Color, Element, Length};
+
+/// 标签背景颜色
+const TAG_BG_COLOR: Color = Color::from_rgba(1.0, 1.0, 1.0, 0.15);
+/// 标签输入框的宽度
+const TAG_INPUT_WIDTH: f32 = 200.0;
+/// 主色调色块的大小
+const SWATCH_SIZE: f32 = 16.0;
+
+/// 创建模态窗口的收藏、评分和标签编辑栏
+pub fn create_modal_user_metadata<'a>(i18n: &'a I18n, local_state: &'a LocalState) -> Element<'a, AppMessage> {
+    let index = local_state.current_image_index;
+    let metadata = local_state.metadata_of(index);
+    let favorite = metadata.is_some_and(|metadata| metadata.favorite);
+    let rating = metadata.map_or(0, |metadata| metadata.rating);
+
+    // 收藏
+    let favorite_icon = if favorite { "\u{F415}" } else { "\u{F417}" };
+    let favorite_button = common::create_icon_button_with_size(
+        favorite_icon,
+        BUTTON_COLOR_RED,
+        16,
+        LocalMessage::ToggleFavorite(index).into(),
+    );
+
+    // 评分（1–5 星）
+    let stars = (1..=MAX_RATING).fold(row![].align_y(Alignment::Center), |stars, star| {
+        let icon = if star <= rating { "\u{F586}" } else { "\u{F588}" };
+        stars.push(common::create_icon_button_with_size(
+            icon,
+            BUTTON_COLOR_YELLOW,
+            16,
+            LocalMessage::SetRating(index, star).into(),
+        ))
+    });
+
+    // 添加标签
+    let tag_input = text_input(&i18n.t("local-list.tag-placeholder"), &local_state.tag_input)
+        .on_input(|value| LocalMessage::TagInputChanged(value).into())
+        .on_submit(LocalMessage::AddTag(index, local_state.tag_input.clone()).into())
+        .padding(4)
+        .size(13)
+        .width(Length::Fixed(TAG_INPUT_WIDTH));
+    let add_tag_button = common::create_icon_button_with_size(
+        "\u{F64D}",
+        BUTTON_COLOR_BLUE,
+        14,
+        LocalMessage::AddTag(index, local_state.tag_input.clone()).into(),
+    );
+
+    // 主色调（点击色块按最接近的颜色筛选）
+    let palette = local_state
+        .entry_of(index)
+        .map_or(&[][..], |entry| entry.palette.as_slice());
+    let swatches = palette
+        .iter()
+        .fold(row![].spacing(4).align_y(Alignment::Center), |swatches, rgb| {
+            swatches.push(create_swatch(*rgb))
+        });
+
+    let header = row![
+        favorite_button,
+        stars,
+        swatches,
+        container(Space::new()).width(Length::Fill),
+        tag_input,
+        add_tag_button,
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    // 已有标签
+    let tags: Element<'a, AppMessage> = match metadata.filter(|metadata| !metadata.tags.is_empty()) {
+        Some(metadata) => metadata
+            .tags
+            .iter()
+            .fold(Row::new().spacing(6), |tags, tag| {
+                tags.push(create_tag_chip(index, tag))
+            })
+            .wrap()
+            .vertical_spacing(4)
+            .into(),
+        None => text(i18n.t("local-list.no-user-tags"))
+            .size(12)
+            .color(METADATA_TEXT_COLOR)
+            .into(),
+    };
+
+    let mut content = column![header, tags].spacing(4).width(Length::Fill);
+
+    // 标签自动补全（输入内容后显示匹配的已有标签）
+    if !local_state.tag_input.trim().is_empty() {
+        let suggestions = local_state.tag_suggestions(&local_state.tag_input, metadata);
+        if !suggestions.is_empty() {
+            content = content.push(create_tag_suggestions(index, suggestions));
+        }
+    }
+
+    container(content)
+        .padding([6, 20])
+        .width(Length::Fill)
+        .style(|_theme: &iced::Theme| container::Style {
+            background: Some(iced::Background::Color(Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.5,
+            })),
+            ..Default::default()
+        })
+        .into()
+}
+
+/// 创建主色调色块（点击时按最接近的 Wallhaven 颜色筛选）
+fn create_swatch<'a>(rgb: [u8; 3]) -> Element<'a, AppMessage> {
+    let [r, g, b] = rgb;
+    let color = Color::from_rgb8(r, g, b);
+    button(
+        Space::new()
+            .width(Length::Fixed(SWATCH_SIZE))
+            .height(Length::Fixed(SWATCH_SIZE)),
+    )
+    .padding(0)
+    .on_press(LocalMessage::ColorFilterChanged(color_palette::nearest_color_option(rgb)).into())
+    .style(move |_theme: &iced::Theme, status| button::Style {
+        background: Some(iced::Background::Color(color)),
+        border: Border {
+            color: match status {
+                button::Status::Hovered => METADATA_TEXT_COLOR,
+                _ => TAG_BG_COLOR,
+            },
+            width: 1.0,
+            radius: Radius::from(3.0),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// 创建标签（带删除按钮）
+fn create_tag_chip<'a>(index: usize, tag: &'a str) -> Element<'a, AppMessage> {
+    container(
+        row![
+            text(tag).size(12).color(METADATA_TEXT_COLOR),
+            common::create_icon_button_with_size(
+                "\u{F659}",
+                METADATA_TEXT_COLOR,
+                9,
+                LocalMessage::RemoveTag(index, tag.to_string()).into(),
+            ),
+        ]
+        .spacing(2)
+        .align_y(Alignment::Center),
+    )
+    .padding([0, 6])
+    .style(|_theme: &iced::Theme| container::Style {
+        background: Some(iced::Background::Color(TAG_BG_COLOR)),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: Radius::from(8.0),
+        },
+        ..Default::default()
+    })
+    .into()
+}
+
+/// 创建标签自动补全候选（点击候选标签时添加该标签）
+fn create_tag_suggestions<'a>(index: usize, suggestions: Vec<&'a str>) -> Element<'a, AppMessage> {
+    suggestions
+        .into_iter()
+        .fold(Row::new().spacing(4), |row, tag| {
+            row.push(
+                button(text(tag).size(12))
+                    .padding([2, 8])
+                    .on_press(LocalMessage::AddTag(index, tag.to_string()).into())
+                    .style(|_theme: &iced::Theme, status| button::Style {
+                        background: Some(iced::Background::Color(match status {
+                            button::Status::Hovered => BUTTON_COLOR_BLUE,
+                            _ => TAG_BG_COLOR,
+                        })),
+                        text_color: METADATA_TEXT_COLOR,
+                        border: Border {
+                            color: Color::TRANSPARENT,
+                            width: 0.0,
+                            radius: Radius::from(8.0),
+                        },
+                        ..Default::default()
+                    }),
+            )
+        })
+        .wrap()
+        .vertical_spacing(4)
+        .into()
+}
@@ -0,0 +1,464 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::services::image_format::ImageFormat;
+use crate::services::library_transfer::TransferMode;
+use crate::services::wallhaven::{self, AspectRatioGroup};
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::local::message::LocalMessage;
+use crate::ui::local::state::{LocalPicker, LocalState};
+use crate::ui::style::*;
+use crate::utils::config::{Config, LocalSort, Orientation};
+use crate::utils::helpers;
+use iced::border::{Border, Radius};
+use iced::widget::{Row, Space, button, column, container, opaque, row, text, text_input};
+use iced::{Alignment, Color, Element, Length};
+use iced_aw::{DropDown, drop_down};
+
+/// 工具栏中最多显示的标签自动补全候选数量
+const TOOLBAR_TAG_SUGGESTION_LIMIT: usize = 5;
+/// 最低分辨率筛选的可选项（宽, 高），(0, 0) 表示不限
+const MIN_RESOLUTIONS: [(u32, u32); 5] = [(0, 0), (1280, 720), (1920, 1080), (2560, 1440), (3840, 2160)];
+/// 移动到/复制到菜单的宽度（菜单中显示壁纸库目录的完整路径）
+const TRANSFER_MENU_WIDTH: f32 = 320.0;
+
+/// 下拉选择器的可选项（显示文本, 是否选中, 点击消息）
+type PickerOption = (String, bool, AppMessage);
+
+/// 创建本地壁纸页面顶部的工具栏
+///
+/// 第一行为排序和筛选，有选中的壁纸时第二行显示批量操作，第三行显示批量添加标签
+pub fn create_toolbar<'a>(
+    i18n: &'a I18n,
+    config: &'a Config,
+    local_state: &'a LocalState,
+    theme_config: &'a ThemeConfig,
+) -> Element<'a, AppMessage> {
+    let theme_colors = theme_config.get_theme_colors();
+    let toolbar_button = move |label: String, message: AppMessage| {
+        button(text(label).size(14))
+            .on_press(message)
+            .padding(6)
+            .style(move |_theme, _status| button::Style {
+                background: Some(iced::Background::Color(theme_colors.light_button)),
+                text_color: theme_colors.light_text,
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.0,
+                    radius: Radius::from(4.0),
+                },
+                ..button::text(_theme, _status)
+            })
+    };
+
+    let text_input_style = move |_theme: &iced::Theme, _status| text_input::Style {
+        background: iced::Background::Color(theme_colors.light_button),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: Radius::from(4.0),
+        },
+        icon: theme_colors.light_text_sub,
+        placeholder: theme_colors.light_text_sub,
+        value: theme_colors.light_text,
+        selection: theme_colors.text_input_selection_color,
+    };
+
+    let sort = config.local.sort;
+    let filter = &config.local.filter;
+
+    // 排序方式
+    let sort_options: Vec<PickerOption> = LocalSort::all()
+        .into_iter()
+        .map(|option| {
+            (
+                i18n.t(option.display_name()),
+                option == sort,
+                LocalMessage::SortChanged(option).into(),
+            )
+        })
+        .collect();
+    let (direction_icon, direction_tooltip) = if config.local.sort_descending {
+        ("\u{F575}", "local-list.sort-descending")
+    } else {
+        ("\u{F57B}", "local-list.sort-ascending")
+    };
+
+    // 最低分辨率
+    let min_resolution = (filter.min_width, filter.min_height);
+    let min_resolution_options: Vec<PickerOption> = MIN_RESOLUTIONS
+        .into_iter()
+        .map(|(width, height)| {
+            let label = if width == 0 && height == 0 {
+                i18n.t("local-list.min-resolution-any")
+            } else {
+                format!("≥ {}×{}", width, height)
+            };
+            (
+                label,
+                (width, height) == min_resolution,
+                LocalMessage::MinResolutionChanged(width, height).into(),
+            )
+        })
+        .collect();
+
+    // 方向
+    let orientation_options: Vec<PickerOption> = std::iter::once(None)
+        .chain(Orientation::all().into_iter().map(Some))
+        .map(|option| {
+            let label = match option {
+                Some(orientation) => i18n.t(orientation.display_name()),
+                None => i18n.t("local-list.orientation-any"),
+            };
+            (
+                label,
+                option == filter.orientation,
+                LocalMessage::OrientationFilterChanged(option).into(),
+            )
+        })
+        .collect();
+
+    // 比例分组
+    let aspect_ratio_group_options: Vec<PickerOption> = std::iter::once(None)
+        .chain(AspectRatioGroup::all().into_iter().map(Some))
+        .map(|option| {
+            let label = match option {
+                Some(group) => i18n.t(group.display_name()),
+                None => i18n.t("local-list.ratio-group-any"),
+            };
+            (
+                label,
+                option == filter.aspect_ratio_group,
+                LocalMessage::AspectRatioGroupFilterChanged(option).into(),
+            )
+        })
+        .collect();
+
+    // 文件格式
+    let format_options: Vec<PickerOption> = std::iter::once(None)
+        .chain(ImageFormat::supported().map(|format| Some(format.name().to_string())))
+        .map(|option| {
+            let label = match &option {
+                Some(format) => format.to_uppercase(),
+                None => i18n.t("local-list.format-any"),
+            };
+            (
+                label,
+                option == filter.format,
+                LocalMessage::FormatFilterChanged(option).into(),
+            )
+        })
+        .collect();
+
+    // 文件名
+    let name_pattern_input = text_input(
+        &i18n.t("local-list.name-pattern-placeholder"),
+        &local_state.name_pattern_input,
+    )
+    .on_input(|value| LocalMessage::NamePatternInputChanged(value).into())
+    .on_submit(LocalMessage::ApplyNamePattern.into())
+    .padding(6)
+    .size(14)
+    .width(Length::Fixed(160.0))
+    .style(text_input_style);
+
+    let mut filter_row = row![
+        create_picker(local_state, LocalPicker::Sort, sort_options, 110.0, theme_colors),
+        common::create_icon_button_with_tooltip(
+            direction_icon,
+            theme_colors.light_text,
+            LocalMessage::ToggleSortDirection.into(),
+            i18n.t(direction_tooltip),
+        ),
+        create_picker(
+            local_state,
+            LocalPicker::MinResolution,
+            min_resolution_options,
+            130.0,
+            theme_colors,
+        ),
+        create_picker(
+            local_state,
+            LocalPicker::Orientation,
+            orientation_options,
+            90.0,
+            theme_colors,
+        ),
+        create_picker(
+            local_state,
+            LocalPicker::AspectRatioGroup,
+            aspect_ratio_group_options,
+            100.0,
+            theme_colors,
+        ),
+        create_picker(local_state, LocalPicker::Format, format_options, 80.0, theme_colors),
+        common::create_color_picker(
+            i18n.t("local-list.color-label"),
+            filter
+                .color
+                .as_deref()
+                .map_or(wallhaven::ColorOption::Any, wallhaven::parse_color),
+            local_state.open_picker == Some(LocalPicker::Color),
+            LocalMessage::TogglePicker(LocalPicker::Color).into(),
+            LocalMessage::ClosePicker.into(),
+            |color| LocalMessage::ColorFilterChanged(color).into(),
+            theme_colors,
+        ),
+        name_pattern_input,
+    ]
+    .spacing(4)
+    .align_y(Alignment::Center);
+
+    if filter.is_active() {
+        filter_row = filter_row.push(toolbar_button(
+            i18n.t("local-list.reset-filters"),
+            LocalMessage::ResetFilters.into(),
+        ));
+    }
+
+    filter_row = filter_row
+        .push(Space::new().width(Length::Fill))
+        .push(toolbar_button(
+            i18n.t("local-list.select-all"),
+            LocalMessage::SelectAll.into(),
+        ))
+        .push(toolbar_button(
+            i18n.t("local-list.find-duplicates"),
+            LocalMessage::FindDuplicates.into(),
+        ))
+        .push(toolbar_button(
+            i18n.t("local-list.trash"),
+            LocalMessage::OpenTrash.into(),
+        ));
+
+    let mut toolbar_content = column![filter_row].spacing(6);
+
+    if !local_state.selected_paths.is_empty() {
+        // 批量操作
+        let selected_text = i18n
+            .t("local-list.selected-count")
+            .replace("{count}", &local_state.selected_paths.len().to_string());
+        let library_roots = config.data.library_roots();
+        let root_options = |mode: TransferMode| -> Vec<PickerOption> {
+            library_roots
+                .iter()
+                .map(|root| {
+                    (
+                        helpers::normalize_path(&helpers::get_absolute_path(&root.path)),
+                        false,
+                        LocalMessage::TransferSelection(root.path.clone(), mode).into(),
+                    )
+                })
+                .collect()
+        };
+
+        let actions_row = row![
+            text(selected_text).size(14).color(theme_colors.light_text),
+            create_menu(
+                local_state,
+                LocalPicker::MoveTo,
+                i18n.t("local-list.move-to"),
+                root_options(TransferMode::Move),
+                90.0,
+                TRANSFER_MENU_WIDTH,
+                theme_colors,
+            ),
+            create_menu(
+                local_state,
+                LocalPicker::CopyTo,
+                i18n.t("local-list.copy-to"),
+                root_options(TransferMode::Copy),
+                90.0,
+                TRANSFER_MENU_WIDTH,
+                theme_colors,
+            ),
+            toolbar_button(i18n.t("local-list.copy-paths"), LocalMessage::CopySelectedPaths.into()),
+            toolbar_button(i18n.t("local-list.export-zip"), LocalMessage::ExportSelection.into()),
+            toolbar_button(
+                i18n.t("local-list.bulk-delete"),
+                LocalMessage::ShowBulkDeleteConfirm.into()
+            ),
+            Space::new().width(Length::Fill),
+            toolbar_button(
+                i18n.t("local-list.clear-selection"),
+                LocalMessage::ClearSelection.into()
+            ),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center);
+        toolbar_content = toolbar_content.push(actions_row);
+
+        // 批量添加标签
+        let mut toolbar_row = Row::new().spacing(4).align_y(Alignment::Center);
+
+        let bulk_tag_input = text_input(&i18n.t("local-list.bulk-tag-placeholder"), &local_state.bulk_tag_input)
+            .on_input(|value| LocalMessage::BulkTagInputChanged(value).into())
+            .on_submit(LocalMessage::AddTagToSelection(local_state.bulk_tag_input.clone()).into())
+            .padding(6)
+            .size(14)
+            .width(Length::Fixed(180.0))
+            .style(text_input_style);
+
+        toolbar_row = toolbar_row.push(bulk_tag_input).push(toolbar_button(
+            i18n.t("local-list.bulk-tag-add"),
+            LocalMessage::AddTagToSelection(local_state.bulk_tag_input.clone()).into(),
+        ));
+
+        // 标签自动补全
+        if !local_state.bulk_tag_input.trim().is_empty() {
+            for tag in local_state
+                .tag_suggestions(&local_state.bulk_tag_input, None)
+                .into_iter()
+                .take(TOOLBAR_TAG_SUGGESTION_LIMIT)
+            {
+                toolbar_row = toolbar_row.push(toolbar_button(
+                    format!("#{}", tag),
+                    LocalMessage::AddTagToSelection(tag.to_string()).into(),
+                ));
+            }
+        }
+
+        toolbar_content = toolbar_content.push(toolbar_row);
+    }
+
+    container(row![
+        Space::new().width(Length::Fixed(2.0)),
+        container(toolbar_content)
+            .width(Length::Fill)
+            .padding(8)
+            .style(move |_theme: &iced::Theme| container::Style {
+                background: Some(iced::Background::Color(theme_colors.light_bg)),
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.0,
+                    radius: Radius::from(4.0),
+                },
+                shadow: shadows::FILTER_BAR_SHADOW,
+                ..Default::default()
+            })
+    ])
+    .into()
+}
+
+/// 创建工具栏中的下拉选择器
+///
+/// # 参数
+/// - `picker`: 选择器类型（用于判断是否展开）
+/// - `options`: 可选项
+/// - `width`: 触发按钮的宽度
+fn create_picker<'a>(
+    local_state: &'a LocalState,
+    picker: LocalPicker,
+    options: Vec<PickerOption>,
+    width: f32,
+    theme_colors: ThemeColors,
+) -> Element<'a, AppMessage> {
+    let label = options
+        .iter()
+        .find(|(_, selected, _)| *selected)
+        .map(|(label, _, _)| label.clone())
+        .unwrap_or_default();
+    create_menu(local_state, picker, label, options, width, width + 20.0, theme_colors)
+}
+
+/// 创建工具栏中的下拉菜单
+///
+/// # 参数
+/// - `picker`: 菜单类型（用于判断是否展开）
+/// - `label`: 触发按钮上显示的文本
+/// - `options`: 菜单项
+/// - `width`: 触发按钮的宽度
+/// - `menu_width`: 展开的菜单的宽度
+fn create_menu<'a>(
+    local_state: &'a LocalState,
+    picker: LocalPicker,
+    label: String,
+    options: Vec<PickerOption>,
+    width: f32,
+    menu_width: f32,
+    theme_colors: ThemeColors,
+) -> Element<'a, AppMessage> {
+    // 创建触发按钮（underlay）
+    let underlay = row![
+        text(label).size(14),
+        Space::new().width(Length::Fill),
+        container(text("⏷").color(theme_colors.light_text_sub))
+            .height(Length::Fill)
+            .padding(iced::Padding {
+                top: -2.0,
+                bottom: 0.0,
+                left: 0.0,
+                right: 0.0,
+            }),
+    ]
+    .spacing(4)
+    .align_y(Alignment::Center)
+    .padding(iced::Padding {
+        top: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+        right: -2.0,
+    });
+
+    let trigger = button(underlay)
+        .padding(6)
+        .width(Length::Fixed(width))
+        .on_press(LocalMessage::TogglePicker(picker).into())
+        .style(move |_theme, _status| button::Style {
+            background: Some(iced::Background::Color(theme_colors.light_button)),
+            text_color: theme_colors.light_text,
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: 0.0,
+                radius: Radius::from(4.0),
+            },
+            ..button::text(_theme, _status)
+        });
+
+    // 创建可选项（overlay）
+    let options_content = column(options.into_iter().map(|(label, is_selected, message)| {
+        button(text(label).size(14))
+            .padding(6)
+            .width(Length::Fill)
+            .on_press(message)
+            .style(move |_theme, _status| button::Style {
+                background: if is_selected {
+                    Some(iced::Background::Color(COLOR_SELECTED_BLUE))
+                } else {
+                    Some(iced::Background::Color(Color::TRANSPARENT))
+                },
+                text_color: if is_selected {
+                    Color::WHITE
+                } else {
+                    theme_colors.light_text
+                },
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.0,
+                    radius: Radius::from(4.0),
+                },
+                ..button::text(_theme, _status)
+            })
+            .into()
+    }))
+    .spacing(2);
+
+    let picker_content = container(options_content)
+        .padding(8)
+        .width(Length::Fixed(menu_width))
+        .style(move |_theme: &iced::Theme| container::Style {
+            background: Some(iced::Background::Color(theme_colors.light_button)),
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: 0.0,
+                radius: Radius::from(8.0),
+            },
+            ..Default::default()
+        });
+
+    DropDown::new(trigger, opaque(picker_content), local_state.open_picker == Some(picker))
+        .on_dismiss(LocalMessage::ClosePicker.into())
+        .alignment(drop_down::Alignment::Bottom)
+        .into()
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::services::database::TrashItem;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::local::message::LocalMessage;
+use crate::ui::local::state::TrashState;
+use crate::ui::style::*;
+use crate::utils::helpers;
+use chrono::{Local, TimeZone};
+use iced::widget::{Column, Row, Space, column, container, row, scrollable, text};
+use iced::{Alignment, Element, Length};
+use std::path::Path;
+
+/// 回收站对话框的宽度
+const TRASH_DIALOG_WIDTH: f32 = 640.0;
+/// 回收站列表的高度
+const TRASH_LIST_HEIGHT: f32 = 360.0;
+
+/// 创建回收站对话框（恢复或彻底删除壁纸）
+pub fn create_trash_dialog<'a>(i18n: &'a I18n, state: &'a TrashState, retention_days: u32) -> Element<'a, AppMessage> {
+    let retention_row = row![
+        text(i18n.t("trash.retention")).size(DIALOG_MESSAGE_SIZE),
+        iced_aw::NumberInput::new(&retention_days, 0..=365, |days| {
+            LocalMessage::TrashRetentionChanged(days).into()
+        })
+        .width(Length::Fixed(80.0)),
+    ]
+    .spacing(DIALOG_BUTTON_SPACING)
+    .align_y(Alignment::Center);
+
+    let body: Element<'a, AppMessage> = if state.items.is_empty() {
+        text(i18n.t("trash.empty")).size(DIALOG_MESSAGE_SIZE).into()
+    } else {
+        let total_size: u64 = state.items.iter().map(|item| item.file_size).sum();
+        let summary = i18n
+            .t("trash.summary")
+            .replace("{count}", &state.items.len().to_string())
+            .replace("{size}", &helpers::format_file_size(total_size));
+
+        column![
+            text(summary).size(DIALOG_MESSAGE_SIZE),
+            scrollable(
+                Column::with_children(state.items.iter().map(|item| create_item(i18n, item)))
+                    .spacing(6)
+                    .width(Length::Fill)
+            )
+            .height(Length::Fixed(TRASH_LIST_HEIGHT)),
+        ]
+        .spacing(DIALOG_SPACING)
+        .into()
+    };
+
+    let mut buttons = Row::new().spacing(DIALOG_BUTTON_SPACING);
+    if !state.items.is_empty() {
+        buttons = buttons.push(common::create_colored_button(
+            i18n.t("trash.empty-trash"),
+            BUTTON_COLOR_RED,
+            LocalMessage::EmptyTrash.into(),
+        ));
+    }
+    buttons = buttons.push(common::create_colored_button(
+        i18n.t("trash.close"),
+        BUTTON_COLOR_GRAY,
+        LocalMessage::CloseTrash.into(),
+    ));
+
+    common::create_modal_dialog(
+        column![
+            text(i18n.t("trash.title")).size(DIALOG_TITLE_SIZE),
+            retention_row,
+            body,
+            buttons,
+        ]
+        .spacing(DIALOG_SPACING)
+        .padding(DIALOG_PADDING)
+        .align_x(Alignment::Center)
+        .width(Length::Fixed(TRASH_DIALOG_WIDTH))
+        .into(),
+    )
+}
+
+/// 创建回收站中的一张壁纸（文件名、原位置、大小、删除时间和操作按钮）
+fn create_item<'a>(i18n: &'a I18n, item: &'a TrashItem) -> Element<'a, AppMessage> {
+    let original_dir = Path::new(&item.original_path)
+        .parent()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let deleted_at = Local
+        .timestamp_opt(item.deleted_at, 0)
+        .single()
+        .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    let details = format!(
+        "{} · {}",
+        helpers::format_file_size(item.file_size),
+        i18n.t("trash.deleted-at").replace("{time}", &deleted_at)
+    );
+
+    let info = column![
+        text(&item.file_name).size(13),
+        text(original_dir).size(12).color(BUTTON_COLOR_GRAY),
+        text(details).size(12).color(BUTTON_COLOR_GRAY),
+    ]
+    .spacing(2);
+
+    container(
+        row![
+            info,
+            Space::new().width(Length::Fill),
+            common::create_icon_button_with_tooltip(
+                "\u{F117}",
+                BUTTON_COLOR_BLUE,
+                LocalMessage::RestoreFromTrash(vec![item.id]).into(),
+                i18n.t("trash.restore"),
+            ),
+            common::create_icon_button_with_tooltip(
+                "\u{F5DE}",
+                BUTTON_COLOR_RED,
+                LocalMessage::PurgeFromTrash(vec![item.id]).into(),
+                i18n.t("trash.purge"),
+            ),
+        ]
+        .spacing(DIALOG_BUTTON_SPACING)
+        .align_y(Alignment::Center),
+    )
+    .padding(DIALOG_INNER_PADDING)
+    .width(Length::Fill)
+    .style(|_theme: &iced::Theme| container::Style {
+        border: iced::border::Border {
+            color: iced::Color::from_rgb(BORDER_COLOR_GRAY, BORDER_COLOR_GRAY, BORDER_COLOR_GRAY),
+            width: DIALOG_BORDER_WIDTH,
+            radius: iced::border::Radius::from(DIALOG_BORDER_RADIUS),
+        },
+        ..Default::default()
+    })
+    .into()
+}
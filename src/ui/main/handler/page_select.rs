@@ -21,6 +21,7 @@ impl App {
             ActivePage::LocalList => {
                 // 重置本地状态，以便重新加载壁纸
                 self.local_state = local::LocalState::default();
+                self.local_state.name_pattern_input = self.config.local.filter.name_pattern.clone();
                 return Task::batch(vec![
                     Task::done(local::LocalMessage::LoadWallpapers.into()),
                     Task::done(MainMessage::ScrollToTop("local_wallpapers_scroll".to_string()).into()),
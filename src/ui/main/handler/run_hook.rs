@@ -0,0 +1,67 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::hooks::{self, HookContext, HookEvent, WallpaperSource};
+use crate::ui::main::MainMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use crate::utils::helpers;
+use iced::Task;
+use std::time::Duration;
+
+impl App {
+    /// 执行下载完成或设置壁纸后的钩子命令（未启用或命令为空时不执行）
+    ///
+    /// # 参数
+    /// - `event`: 触发事件
+    /// - `file_path`: 壁纸文件路径
+    /// - `url`: 下载URL（用于识别壁纸ID，没有时传空字符串）
+    /// - `source`: 壁纸来源
+    pub(in crate::ui) fn run_hook_command(
+        &self,
+        event: HookEvent,
+        file_path: &str,
+        url: &str,
+        source: WallpaperSource,
+    ) -> Task<AppMessage> {
+        let hooks_config = &self.config.hooks;
+        let (enabled, command) = match event {
+            HookEvent::Download => (hooks_config.post_download_enabled, &hooks_config.post_download_command),
+            HookEvent::SetWallpaper => (hooks_config.post_set_enabled, &hooks_config.post_set_command),
+        };
+        if !enabled || command.trim().is_empty() {
+            return Task::none();
+        }
+
+        let command = command.clone();
+        let file_path = helpers::get_absolute_path(file_path);
+        // 壁纸ID优先使用下载任务的元数据（文件名模板可能不包含壁纸ID）
+        let wallhaven_id = self
+            .download_state
+            .tasks
+            .iter()
+            .find(|task| helpers::get_absolute_path(&task.task.save_path) == file_path)
+            .and_then(|task| task.metadata.as_ref())
+            .map(|metadata| metadata.wallhaven_id.clone())
+            .unwrap_or_default();
+        let url = url.to_string();
+        let timeout = Duration::from_secs(hooks_config.timeout_secs.max(1) as u64);
+        let failed_message = self.i18n.t("hooks.failed").to_string();
+
+        Task::perform(
+            async move {
+                // 读取图片分辨率需要访问文件，放到阻塞线程中执行
+                let context = tokio::task::spawn_blocking(move || {
+                    HookContext::new(event, &file_path, &wallhaven_id, &url, source)
+                })
+                .await
+                .map_err(|e| e.to_string())?;
+                hooks::run_hook(command, context, timeout).await
+            },
+            move |result| match result {
+                Ok(()) => AppMessage::None,
+                Err(e) => {
+                    MainMessage::ShowNotification(format!("{}: {}", failed_message, e), NotificationType::Error).into()
+                }
+            },
+        )
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::async_task;
+use crate::services::hooks::WallpaperSource;
 use crate::ui::auto_change::AutoChangeMessage;
 use crate::ui::main::MainMessage;
 use crate::ui::{App, AppMessage, NotificationType};
@@ -33,7 +34,11 @@ impl App {
         let failed_message = self.i18n.t("local-list.set-wallpaper-failed").to_string();
 
         iced::Task::perform(
-            async_task::async_set_wallpaper(previous_wallpaper.clone(), wallpaper_mode),
+            async_task::async_set_wallpaper(
+                previous_wallpaper.clone(),
+                wallpaper_mode,
+                self.config.data.cache_path.clone(),
+            ),
             move |result| match result {
                 Ok(_) => {
                     // 切换成功，将当前壁纸从历史记录末尾移除
@@ -53,27 +58,28 @@ impl App {
         // 根据定时切换模式执行不同的逻辑
         match self.config.wallpaper.auto_change_mode {
             WallpaperAutoChangeMode::Local => {
-                // 本地模式：获取支持的图片文件列表
-                let data_path = self.config.data.data_path.clone();
-                Task::perform(
-                    async_task::async_get_supported_images(data_path),
-                    |result| match result {
-                        Ok(paths) => {
-                            // 获取到图片列表后，立即尝试设置随机壁纸
-                            if paths.is_empty() {
-                                MainMessage::ShowNotification(no_valid_wallpapers_message, NotificationType::Error)
-                                    .into()
-                            } else {
-                                // 发送一个消息来触发设置随机壁纸
-                                AutoChangeMessage::GetSupportedImagesSuccess(paths).into()
-                            }
-                        }
-                        Err(e) => {
-                            let error_message = format!("获取壁纸列表失败: {}", e);
-                            MainMessage::ShowNotification(error_message, NotificationType::Error).into()
+                // 本地模式：获取支持的图片文件列表（优先使用缓存的候选列表）
+                let roots = self.config.data.library_roots();
+                if let Some(paths) = self.auto_change_state.cached_candidates(&roots) {
+                    return Task::done(
+                        AutoChangeMessage::GetSupportedImagesSuccess(paths, WallpaperSource::Tray).into(),
+                    );
+                }
+                Task::perform(async_task::async_get_supported_images(roots), |result| match result {
+                    Ok(paths) => {
+                        // 获取到图片列表后，立即尝试设置随机壁纸
+                        if paths.is_empty() {
+                            MainMessage::ShowNotification(no_valid_wallpapers_message, NotificationType::Error).into()
+                        } else {
+                            // 发送一个消息来触发设置随机壁纸
+                            AutoChangeMessage::GetSupportedImagesSuccess(paths, WallpaperSource::Tray).into()
                         }
-                    },
-                )
+                    }
+                    Err(e) => {
+                        let error_message = format!("获取壁纸列表失败: {}", e);
+                        MainMessage::ShowNotification(error_message, NotificationType::Error).into()
+                    }
+                })
             }
             WallpaperAutoChangeMode::Online => {
                 // 在线模式：从Wallhaven获取随机壁纸
@@ -82,7 +88,7 @@ impl App {
                 Task::perform(
                     async_task::async_set_random_online_wallpaper(config, auto_change_running),
                     |result| match result {
-                        Ok(path) => AutoChangeMessage::SetRandomWallpaperSuccess(path).into(),
+                        Ok(path) => AutoChangeMessage::SetRandomWallpaperSuccess(path, WallpaperSource::Tray).into(),
                         Err(e) => {
                             let error_message = format!("设置壁纸失败: {}", e);
                             MainMessage::ShowNotification(error_message, NotificationType::Error).into()
@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::hooks::{HookEvent, WallpaperSource};
 use crate::ui::{App, AppMessage};
 use iced::Task;
 use std::path::Path;
@@ -28,12 +29,19 @@ impl App {
         !wallpaper_path.starts_with(data_dir)
     }
 
-    pub(in crate::ui::main) fn add_to_wallpaper_history(&mut self, path: String) -> Task<AppMessage> {
+    pub(in crate::ui::main) fn add_to_wallpaper_history(
+        &mut self,
+        path: String,
+        source: WallpaperSource,
+    ) -> Task<AppMessage> {
         // 检查历史记录中是否已存在该路径，如果存在则先移除
         if let Some(pos) = self.wallpaper_history.iter().position(|p| p == &path) {
             self.wallpaper_history.remove(pos);
         }
 
+        // 执行设置壁纸后的钩子命令
+        let hook_task = self.run_hook_command(HookEvent::SetWallpaper, &path, "", source);
+
         // 记录路径用于日志输出
         let path_for_log = path.clone();
 
@@ -67,7 +75,7 @@ impl App {
         self.tray_manager
             .update_save_current_item(self.can_save_current_wallpaper());
 
-        Task::none()
+        hook_task
     }
 
     pub(in crate::ui::main) fn remove_last_from_wallpaper_history(&mut self) -> Task<AppMessage> {
@@ -96,6 +104,10 @@ impl App {
         self.tray_manager
             .update_save_current_item(self.can_save_current_wallpaper());
 
-        Task::none()
+        // 托盘切换到上一张壁纸后，对当前壁纸执行设置壁纸后的钩子命令
+        match self.wallpaper_history.last() {
+            Some(current) => self.run_hook_command(HookEvent::SetWallpaper, current, "", WallpaperSource::Tray),
+            None => Task::none(),
+        }
     }
 }
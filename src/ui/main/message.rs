@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::hooks::WallpaperSource;
 use crate::ui::{ActivePage, App, AppMessage, CloseConfirmationAction, NotificationType};
 use iced::Task;
 
@@ -50,8 +51,8 @@ pub enum MainMessage {
     TraySwitchNextWallpaper,
     /// 托盘保存当前壁纸到库事件
     TraySaveCurrentWallpaper,
-    /// 添加壁纸到历史记录
-    AddToWallpaperHistory(String),
+    /// 壁纸设置成功，添加到历史记录并执行设置壁纸后的钩子命令 (壁纸路径, 来源)
+    AddToWallpaperHistory(String, WallpaperSource),
     /// 从历史记录末尾移除壁纸
     RemoveLastFromWallpaperHistory,
     /// 外部实例触发事件
@@ -106,7 +107,7 @@ impl App {
             MainMessage::TraySwitchPreviousWallpaper => self.tray_switch_previous_wallpaper(),
             MainMessage::TraySwitchNextWallpaper => self.tray_switch_next_wallpaper(),
             MainMessage::TraySaveCurrentWallpaper => self.tray_save_current_wallpaper(),
-            MainMessage::AddToWallpaperHistory(path) => self.add_to_wallpaper_history(path),
+            MainMessage::AddToWallpaperHistory(path, source) => self.add_to_wallpaper_history(path, source),
             MainMessage::RemoveLastFromWallpaperHistory => self.remove_last_from_wallpaper_history(),
             MainMessage::ExternalInstanceTriggered(payload) => self.external_instance_triggered(payload),
             MainMessage::TitleBarDrag => self.title_bar_drag(),
@@ -1,6 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
-use crate::ui::NotificationType;
+use crate::ui::{AppMessage, NotificationType};
 use crate::utils::config::Config;
 
 /// 主窗口相关状态
@@ -23,6 +23,7 @@ pub struct MainState {
     pub notification_message: String,
     pub notification_type: NotificationType,
     pub notification_version: u64,  // 通知版本号，用于防止旧版本的隐藏任务关闭新显示的通知
+    pub notification_action: Option<(String, AppMessage)>, // 通知中的操作按钮（按钮文字, 点击时发送的消息），例如撤销删除
 
     // 其他
     pub initial_loaded: bool,
@@ -43,6 +44,7 @@ impl Default for MainState {
             notification_message: String::new(),
             notification_type: NotificationType::Success,
             notification_version: 0,
+            notification_action: None,
             initial_loaded: false,
         }
     }
@@ -0,0 +1,249 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::services::request_context::RequestContext;
+use crate::services::wallhaven;
+use crate::ui::download::DownloadMessage;
+use crate::ui::main::MainMessage;
+use crate::ui::online::{BulkDownloadStage, BulkDownloadState, OnlineMessage};
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use std::collections::HashSet;
+use tracing::{error, info};
+
+/// 批量获取搜索结果时最多请求的页数，避免全部壁纸都被跳过时无限翻页
+const BULK_MAX_PAGES: usize = 50;
+
+impl App {
+    pub(in crate::ui::online) fn show_bulk_download(&mut self) -> Task<AppMessage> {
+        // 保留上次的选项，清空上次的获取结果
+        let bulk = &self.online_state.bulk_download;
+        self.online_state.bulk_download = BulkDownloadState {
+            stage: BulkDownloadStage::Options,
+            max_count: bulk.max_count,
+            max_total_size_mb: bulk.max_total_size_mb,
+            skip_owned: bulk.skip_owned,
+            ..BulkDownloadState::default()
+        };
+        Task::none()
+    }
+
+    pub(in crate::ui::online) fn close_bulk_download(&mut self) -> Task<AppMessage> {
+        let bulk = &mut self.online_state.bulk_download;
+        bulk.request_context.cancel();
+        bulk.stage = BulkDownloadStage::Closed;
+        bulk.selected.clear();
+        Task::none()
+    }
+
+    pub(in crate::ui::online) fn start_bulk_collect(&mut self) -> Task<AppMessage> {
+        let bulk = &mut self.online_state.bulk_download;
+        bulk.stage = BulkDownloadStage::Collecting;
+        bulk.request_context.cancel();
+        bulk.request_context = RequestContext::new();
+
+        info!(
+            "[批量下载] 开始获取搜索结果, 最多 {} 张, 大小上限 {} MB, 跳过已拥有: {}",
+            bulk.max_count, bulk.max_total_size_mb, bulk.skip_owned
+        );
+
+        self.load_bulk_page(1)
+    }
+
+    pub(in crate::ui::online) fn bulk_page_loaded(
+        &mut self,
+        wallpapers: Vec<wallhaven::OnlineWallpaper>,
+        last_page: bool,
+        total_pages: usize,
+        current_page: usize,
+    ) -> Task<AppMessage> {
+        if self.online_state.bulk_download.stage != BulkDownloadStage::Collecting {
+            // 对话框已关闭，丢弃结果
+            return Task::none();
+        }
+
+        if !self.online_state.bulk_download.skip_owned {
+            return self.bulk_page_checked(wallpapers, HashSet::new(), last_page, total_pages, current_page);
+        }
+
+        // 在后台线程中一次检查整页壁纸是否已拥有
+        let queries = wallpapers
+            .iter()
+            .map(|wallpaper| self.owned_wallpaper_query(wallpaper))
+            .collect();
+        Task::perform(
+            async_task::async_find_owned_wallpapers(queries),
+            move |result| match result {
+                Ok(owned) => OnlineMessage::BulkPageChecked(
+                    wallpapers,
+                    owned.into_keys().collect(),
+                    last_page,
+                    total_pages,
+                    current_page,
+                )
+                .into(),
+                Err(e) => OnlineMessage::BulkPageFailed(e).into(),
+            },
+        )
+    }
+
+    /// 从已检查过已拥有壁纸的一页搜索结果中选择要下载的壁纸
+    pub(in crate::ui::online) fn bulk_page_checked(
+        &mut self,
+        wallpapers: Vec<wallhaven::OnlineWallpaper>,
+        owned_ids: HashSet<String>,
+        last_page: bool,
+        total_pages: usize,
+        current_page: usize,
+    ) -> Task<AppMessage> {
+        if self.online_state.bulk_download.stage != BulkDownloadStage::Collecting {
+            return Task::none();
+        }
+
+        // 先检查下载队列，避免与下面的可变借用冲突
+        let checked: Vec<_> = wallpapers
+            .into_iter()
+            .map(|wallpaper| {
+                let in_queue = self.is_download_queued(&wallpaper);
+                (wallpaper, in_queue)
+            })
+            .collect();
+
+        let bulk = &mut self.online_state.bulk_download;
+        bulk.pages_loaded += 1;
+        bulk.total_pages = total_pages;
+
+        let max_count = bulk.max_count as usize;
+        let max_total_size = bulk.max_total_size_mb as u64 * 1024 * 1024;
+        let mut selected_ids: HashSet<String> = bulk.selected.iter().map(|w| w.id.clone()).collect();
+
+        for (wallpaper, in_queue) in checked {
+            if bulk.selected.len() >= max_count {
+                break;
+            }
+            bulk.scanned_count += 1;
+
+            // 翻页期间列表可能变化，同一张壁纸只保留一次
+            if selected_ids.contains(&wallpaper.id) {
+                continue;
+            }
+
+            if in_queue {
+                bulk.skipped_in_queue += 1;
+                continue;
+            }
+            if bulk.skip_owned && owned_ids.contains(&wallpaper.id) {
+                bulk.skipped_owned += 1;
+                continue;
+            }
+
+            if max_total_size > 0 && bulk.selected_size + wallpaper.file_size > max_total_size {
+                bulk.size_cap_reached = true;
+                break;
+            }
+
+            bulk.selected_size += wallpaper.file_size;
+            selected_ids.insert(wallpaper.id.clone());
+            bulk.selected.push(wallpaper);
+        }
+
+        let finished = last_page
+            || current_page >= total_pages
+            || bulk.selected.len() >= max_count
+            || bulk.size_cap_reached
+            || bulk.pages_loaded >= BULK_MAX_PAGES;
+
+        if finished {
+            info!(
+                "[批量下载] 获取完成, 共检查 {} 张, 选择 {} 张",
+                bulk.scanned_count,
+                bulk.selected.len()
+            );
+            bulk.stage = BulkDownloadStage::Summary;
+            return Task::none();
+        }
+
+        self.load_bulk_page(current_page + 1)
+    }
+
+    pub(in crate::ui::online) fn bulk_page_failed(&mut self, error: String) -> Task<AppMessage> {
+        if self.online_state.bulk_download.stage != BulkDownloadStage::Collecting {
+            return Task::none();
+        }
+
+        error!("[批量下载] 获取搜索结果失败: {}", error);
+        self.online_state.bulk_download.stage = BulkDownloadStage::Closed;
+        self.online_state.bulk_download.selected.clear();
+
+        let failed_message = format!("{}: {}", self.i18n.t("bulk-download.failed"), error);
+        self.show_notification(failed_message, NotificationType::Error)
+    }
+
+    pub(in crate::ui::online) fn confirm_bulk_download(&mut self) -> Task<AppMessage> {
+        let bulk = &mut self.online_state.bulk_download;
+        bulk.stage = BulkDownloadStage::Closed;
+        let selected = std::mem::take(&mut bulk.selected);
+
+        if selected.is_empty() {
+            let message = self.i18n.t("bulk-download.nothing-to-download").to_string();
+            return self.show_notification(message, NotificationType::Info);
+        }
+
+        info!("[批量下载] 添加 {} 个下载任务", selected.len());
+
+        let added_message = self
+            .i18n
+            .t("bulk-download.added")
+            .replace("{count}", &selected.len().to_string());
+        let add_tasks: Vec<Task<AppMessage>> = selected
+            .into_iter()
+            .map(|wallpaper| Task::done(DownloadMessage::AddTask(Box::new(wallpaper)).into()))
+            .collect();
+
+        // 所有任务添加完成后再显示汇总通知，避免被单个任务的通知覆盖
+        Task::batch(add_tasks).chain(Task::done(
+            MainMessage::ShowNotification(added_message, NotificationType::Success).into(),
+        ))
+    }
+
+    /// 按当前筛选条件获取指定页的搜索结果（遵守 API 频率限制）
+    fn load_bulk_page(&self, page: usize) -> Task<AppMessage> {
+        let state = &self.online_state;
+        let api_key = if self.config.wallhaven.api_key.is_empty() {
+            None
+        } else {
+            Some(self.config.wallhaven.api_key.clone())
+        };
+        let proxy = if self.config.global.proxy_enabled && !self.config.global.proxy.is_empty() {
+            Some(self.config.global.proxy.clone())
+        } else {
+            None
+        };
+
+        Task::perform(
+            async_task::async_load_online_wallpapers_rate_limited(
+                state.categories,
+                state.sorting,
+                state.purities,
+                state.color,
+                state.search_text.clone(),
+                state.time_range,
+                state.get_atleast_param(),
+                state.get_resolutions_param(),
+                state.get_ratios_param(),
+                page,
+                api_key,
+                proxy,
+                self.config.global.proxy_enabled,
+                true, // 启用环境变量回退
+                state.bulk_download.request_context.clone(),
+            ),
+            |result| match result {
+                Ok((wallpapers, last_page, total_pages, current_page)) => {
+                    OnlineMessage::BulkPageLoaded(wallpapers, last_page, total_pages, current_page).into()
+                }
+                Err(e) => OnlineMessage::BulkPageFailed(e.to_string()).into(),
+            },
+        )
+    }
+}
@@ -1,7 +1,9 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::download::DownloadService;
-use crate::services::wallhaven;
+use crate::services::sidecar::WallpaperMetadata;
+use crate::ui::download::{DownloadDuplicate, PendingDuplicate};
+use crate::ui::online::OnlineMessage;
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
 use std::path::PathBuf;
@@ -10,29 +12,42 @@ use tracing::error;
 impl App {
     pub(in crate::ui::online) fn download_from_cache(&mut self, index: usize) -> Task<AppMessage> {
         // 从缓存复制文件到 data_path
-        if let Some(wallpaper) = self.online_state.wallpapers_data.get(index) {
+        if let Some(wallpaper) = self.online_state.wallpapers_data.get(index).cloned() {
+            // 文件名模板用到标签或上传者时，先获取壁纸详情
+            let retry_message = AppMessage::from(OnlineMessage::DownloadFromCache(index));
+            if let Some(task) = self.fetch_template_details(&wallpaper, retry_message) {
+                return task;
+            }
+
             let url = wallpaper.path.clone();
             let id = wallpaper.id.clone();
-            let file_type = wallpaper.file_type.clone();
             let file_size = wallpaper.file_size;
+            let wallpaper_metadata = WallpaperMetadata::from_wallpaper(&wallpaper);
 
-            // 生成目标文件路径
-            let file_name = wallhaven::generate_file_name(&id, file_type.split('/').last().unwrap_or("jpg"));
-            let data_path = self.config.data.data_path.clone();
-            let target_path = PathBuf::from(&data_path).join(&file_name);
+            // 按文件名模板生成目标文件路径
+            let target_path = self.resolve_download_path(&wallpaper);
+            let file_name = target_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let target_dir = target_path.parent().map(PathBuf::from).unwrap_or_default();
 
-            // 1. 检查目标文件是否已存在于 data_path 中
-            if let Ok(metadata) = std::fs::metadata(&target_path) {
-                let actual_size = metadata.len();
-                if actual_size == file_size {
-                    // 文件已存在且大小匹配
-                    let success_message = format!(
-                        "{}: {}",
-                        self.i18n.t("download-tasks.file-already-exists").to_string(),
-                        file_name
-                    );
-                    return self.show_notification(success_message, NotificationType::Info);
+            // 1. 检查下载队列和壁纸库中是否已有相同的壁纸
+            match self.find_download_duplicate(&wallpaper) {
+                Some(DownloadDuplicate::InQueue) => {
+                    // 任务已在下载队列中
+                    let info_message = self.i18n.t("download-tasks.task-already-in-queue").to_string();
+                    return self.show_notification(info_message, NotificationType::Info);
+                }
+                Some(DownloadDuplicate::Owned(existing_path)) => {
+                    // 壁纸已存在，由用户选择跳过、重新下载或保留两者
+                    self.download_state.pending_duplicate = Some(PendingDuplicate {
+                        wallpaper,
+                        existing_path,
+                    });
+                    return Task::none();
                 }
+                None => {}
             }
 
             // 2. 获取缓存文件路径
@@ -44,15 +59,21 @@ impl App {
                 let cache_path_buf = PathBuf::from(&cache_file_path);
                 if cache_path_buf.exists() {
                     // 缓存文件存在，复制到 data_path
-                    let _ = std::fs::create_dir_all(&data_path);
+                    let _ = std::fs::create_dir_all(&target_dir);
                     match std::fs::copy(&cache_path_buf, &target_path) {
                         Ok(_) => {
+                            self.online_state.owned_wallpaper_ids.insert(id.clone());
+                            // 与下载完成的任务走相同的收尾流程
+                            let finish_task = self.finish_cache_copy(target_path, url, wallpaper_metadata);
                             let success_message = format!(
                                 "{}: {}",
                                 self.i18n.t("download-tasks.copied-from-cache").to_string(),
                                 file_name
                             );
-                            return self.show_notification(success_message, NotificationType::Success);
+                            return Task::batch([
+                                finish_task,
+                                self.show_notification(success_message, NotificationType::Success),
+                            ]);
                         }
                         Err(e) => {
                             error!("[模态窗口下载] [ID:{}] 从缓存复制失败: {}", id, e);
@@ -1,8 +1,9 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use crate::services::download::DownloadService;
-use crate::services::wallhaven;
-use crate::ui::download::DownloadStatus;
+use crate::services::sidecar::WallpaperMetadata;
+use crate::ui::download::{DownloadDuplicate, PendingDuplicate};
+use crate::ui::online::OnlineMessage;
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
 use std::path::PathBuf;
@@ -11,29 +12,43 @@ use tracing::error;
 impl App {
     pub(in crate::ui::online) fn download_online_wallpaper(&mut self, index: usize) -> Task<AppMessage> {
         // 下载壁纸
-        if let Some(wallpaper) = self.online_state.wallpapers_data.get(index) {
+        if let Some(wallpaper) = self.online_state.wallpapers_data.get(index).cloned() {
+            // 文件名模板用到标签或上传者时，先获取壁纸详情
+            let retry_message = AppMessage::from(OnlineMessage::DownloadWallpaper(index));
+            if let Some(task) = self.fetch_template_details(&wallpaper, retry_message) {
+                return task;
+            }
+
             let url = wallpaper.path.clone();
             let id = wallpaper.id.clone();
             let file_type = wallpaper.file_type.clone();
             let file_size = wallpaper.file_size;
+            let wallpaper_metadata = WallpaperMetadata::from_wallpaper(&wallpaper);
 
-            // 生成目标文件路径
-            let file_name = wallhaven::generate_file_name(&id, file_type.split('/').last().unwrap_or("jpg"));
-            let data_path = self.config.data.data_path.clone();
-            let target_path = PathBuf::from(&data_path).join(&file_name);
+            // 按文件名模板生成目标文件路径
+            let target_path = self.resolve_download_path(&wallpaper);
+            let file_name = target_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let target_dir = target_path.parent().map(PathBuf::from).unwrap_or_default();
 
-            // 1. 检查目标文件是否已存在于 data_path 中
-            if let Ok(metadata) = std::fs::metadata(&target_path) {
-                let actual_size = metadata.len();
-                if actual_size == file_size {
-                    // 文件已存在且大小匹配
-                    let success_message = format!(
-                        "{}: {}",
-                        self.i18n.t("download-tasks.file-already-exists").to_string(),
-                        file_name
-                    );
-                    return self.show_notification(success_message, NotificationType::Info);
+            // 1. 检查下载队列和壁纸库中是否已有相同的壁纸
+            match self.find_download_duplicate(&wallpaper) {
+                Some(DownloadDuplicate::InQueue) => {
+                    // 任务已在下载队列中
+                    let info_message = self.i18n.t("download-tasks.task-already-in-queue").to_string();
+                    return self.show_notification(info_message, NotificationType::Info);
+                }
+                Some(DownloadDuplicate::Owned(existing_path)) => {
+                    // 壁纸已存在，由用户选择跳过、重新下载或保留两者
+                    self.download_state.pending_duplicate = Some(PendingDuplicate {
+                        wallpaper,
+                        existing_path,
+                    });
+                    return Task::none();
                 }
+                None => {}
             }
 
             // 2. 检查缓存文件是否存在且大小匹配
@@ -45,15 +60,21 @@ impl App {
                     let cache_size = metadata.len();
                     if cache_size == file_size {
                         // 缓存文件存在且大小匹配，直接复制到 data_path
-                        let _ = std::fs::create_dir_all(&data_path);
+                        let _ = std::fs::create_dir_all(&target_dir);
                         match std::fs::copy(&cache_file_path, &target_path) {
                             Ok(_) => {
+                                self.online_state.owned_wallpaper_ids.insert(id.clone());
+                                // 与下载完成的任务走相同的收尾流程
+                                let finish_task = self.finish_cache_copy(target_path, url, wallpaper_metadata);
                                 let success_message = format!(
                                     "{}: {}",
                                     self.i18n.t("download-tasks.copied-from-cache").to_string(),
                                     file_name
                                 );
-                                return self.show_notification(success_message, NotificationType::Success);
+                                return Task::batch([
+                                    finish_task,
+                                    self.show_notification(success_message, NotificationType::Success),
+                                ]);
                             }
                             Err(e) => {
                                 error!("[在线壁纸] [ID:{}] 从缓存复制失败: {}", id, e);
@@ -64,23 +85,9 @@ impl App {
                 }
             }
 
-            // 3. 检查下载任务列表中是否已有相同 URL 的任务
-            let has_duplicate = self.download_state.tasks.iter().any(|task| {
-                task.task.url == url
-                    && task.task.status != DownloadStatus::Completed
-                    && task.task.status != DownloadStatus::Cancelled
-                    && !matches!(task.task.status, DownloadStatus::Failed(_))
-            });
-
-            if has_duplicate {
-                // 任务已在下载队列中
-                let info_message = self.i18n.t("download-tasks.task-already-in-queue").to_string();
-                return self.show_notification(info_message, NotificationType::Info);
-            }
-
-            // 4. 开始下载
+            // 3. 开始下载
             let add_to_queue_message = self.i18n.t("download-tasks.added-to-download-queue").to_string();
-            let download_task = self.start_download(url, &id, &file_type);
+            let download_task = self.start_download(url, target_path, &file_type, file_size, Some(wallpaper_metadata));
             
             // 显示添加到下载队列的通知
             return Task::batch([
@@ -6,8 +6,6 @@ use crate::ui::online::{OnlineMessage, PageInfo, WallpaperLoadStatus};
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
 use iced::widget::image::Handle;
-use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
 use tracing::error;
 
 impl App {
@@ -59,17 +57,12 @@ impl App {
             let proxy = proxy.clone();
             let cache_path = cache_path.clone();
 
-            // 创建取消令牌
-            let cancel_token = Arc::new(AtomicBool::new(false));
-            self.online_state.thumb_load_cancel_tokens.push(cancel_token.clone());
+            // 缩略图加载从搜索上下文派生，重新搜索时自动取消
+            let context = self.online_state.search_context.child();
 
             tasks.push(Task::perform(
                 async_task::async_load_online_wallpaper_thumb_with_cache_with_cancel(
-                    url,
-                    file_size,
-                    cache_path,
-                    proxy,
-                    cancel_token,
+                    url, file_size, cache_path, proxy, context,
                 ),
                 move |result| match result {
                     Ok(handle) => OnlineMessage::ThumbLoaded(idx, handle).into(),
@@ -94,6 +87,7 @@ impl App {
         }
 
         self.online_state.loading_page = false;
+        tasks.push(self.refresh_owned_wallpapers());
 
         Task::batch(tasks)
     }
@@ -9,16 +9,14 @@ impl App {
     pub(in crate::ui::online) fn load_online_wallpapers(&mut self) -> Task<AppMessage> {
         // 设置加载状态
         self.online_state.loading_page = true;
-        // 取消所有缩略图加载任务
-        self.online_state.cancel_thumb_loads();
+        // 取消上一次搜索的所有请求（分页请求、缩略图加载和大图预览）
+        self.online_state.cancel_search();
         // 清空当前数据，准备加载新数据
         self.online_state.wallpapers.clear();
         self.online_state.wallpapers_data.clear();
         self.online_state.page_info.clear();
         self.online_state.has_loaded = false;
 
-        // 创建新的请求上下文并取消之前的请求
-        self.online_state.cancel_and_new_context();
         let context = self.online_state.request_context.clone();
 
         // 异步加载在线壁纸
@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+mod bulk_download;
 mod download_from_cache;
 mod download_wallpaper;
 mod filter;
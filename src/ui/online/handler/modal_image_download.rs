@@ -19,7 +19,7 @@ impl App {
         self.online_state.modal_download_progress = 0.0;
         self.online_state.modal_downloaded_bytes = 0;
         self.online_state.modal_total_bytes = 0;
-        self.online_state.modal_download_cancel_token = None;
+        self.online_state.modal_download_context = None;
         Task::none()
     }
 
@@ -30,7 +30,7 @@ impl App {
         self.online_state.modal_download_progress = 0.0;
         self.online_state.modal_downloaded_bytes = 0;
         self.online_state.modal_total_bytes = 0;
-        self.online_state.modal_download_cancel_token = None;
+        self.online_state.modal_download_context = None;
         Task::none()
     }
 }
@@ -4,8 +4,6 @@ use crate::services::async_task;
 use crate::ui::online::OnlineMessage;
 use crate::ui::{App, AppMessage};
 use iced::Task;
-use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
 
 impl App {
     pub(in crate::ui::online) fn next_online_image(&mut self) -> Task<AppMessage> {
@@ -30,18 +28,14 @@ impl App {
                     None
                 };
 
-                // 创建取消令牌
-                let cancel_token = Arc::new(AtomicBool::new(false));
-                self.online_state.modal_download_cancel_token = Some(cancel_token.clone());
+                // 大图预览从搜索上下文派生，重新搜索时自动取消
+                let context = self.online_state.search_context.child();
+                self.online_state.modal_download_context = Some(context.clone());
 
                 // 启动下载任务
                 return Task::perform(
                     async_task::async_load_online_wallpaper_image_with_streaming(
-                        url,
-                        file_size,
-                        cache_path,
-                        proxy,
-                        cancel_token.clone(),
+                        url, file_size, cache_path, proxy, context,
                     ),
                     |result| match result {
                         Ok(handle) => OnlineMessage::ModalImageDownloaded(handle).into(),
@@ -2,8 +2,10 @@
 
 use crate::services::async_task;
 use crate::services::download::DownloadService;
-use crate::services::wallhaven;
+use crate::services::hooks::WallpaperSource;
+use crate::services::sidecar::WallpaperMetadata;
 use crate::ui::main::MainMessage;
+use crate::ui::online::OnlineMessage;
 use crate::ui::{App, AppMessage, NotificationType};
 use crate::utils::helpers;
 use iced::Task;
@@ -14,37 +16,43 @@ impl App {
     pub(in crate::ui::online) fn set_wallpaper_from_cache(&mut self, index: usize) -> Task<AppMessage> {
         // 从缓存或 data_path 设置壁纸
         if let Some(wallpaper) = self.online_state.wallpapers_data.get(index) {
+            // 文件名模板用到标签或上传者时，先获取壁纸详情
+            let retry_message = AppMessage::from(OnlineMessage::SetAsWallpaperFromCache(index));
+            if let Some(task) = self.fetch_template_details(wallpaper, retry_message) {
+                return task;
+            }
+
             let url = wallpaper.path.clone();
             let id = wallpaper.id.clone();
-            let file_type = wallpaper.file_type.clone();
             let file_size = wallpaper.file_size;
+            let wallpaper_metadata = WallpaperMetadata::from_wallpaper(wallpaper);
 
-            // 生成目标文件路径
-            let file_name = wallhaven::generate_file_name(&id, file_type.split('/').last().unwrap_or("jpg"));
-            let data_path = self.config.data.data_path.clone();
-            let target_path = PathBuf::from(&data_path).join(&file_name);
+            // 按文件名模板生成目标文件路径
+            let target_path = self.resolve_download_path(wallpaper);
+            let target_dir = target_path.parent().map(PathBuf::from).unwrap_or_default();
 
-            // 1. 检查目标文件是否已存在于 data_path 中
-            if let Ok(metadata) = std::fs::metadata(&target_path) {
-                let actual_size = metadata.len();
-                if actual_size == file_size {
-                    // 文件已存在且大小匹配，直接设置壁纸
-                    let full_path = helpers::get_absolute_path(&target_path.to_string_lossy().to_string());
-                    let wallpaper_mode = self.config.wallpaper.mode;
-                    let failed_message = self.i18n.t("local-list.set-wallpaper-failed").to_string();
+            // 1. 检查壁纸库中是否已有相同的壁纸
+            if let Some(existing_path) = self.find_owned_wallpaper(wallpaper) {
+                // 壁纸已存在，直接使用已有文件设置壁纸
+                let full_path = helpers::get_absolute_path(&existing_path.to_string_lossy());
+                let wallpaper_mode = self.config.wallpaper.mode;
+                let failed_message = self.i18n.t("local-list.set-wallpaper-failed").to_string();
 
-                    return Task::perform(
-                        async_task::async_set_wallpaper(full_path.clone(), wallpaper_mode),
-                        move |result| match result {
-                            Ok(_) => MainMessage::AddToWallpaperHistory(full_path).into(),
-                            Err(e) => MainMessage::ShowNotification(
-                                format!("{}: {}", failed_message, e),
-                                NotificationType::Error,
-                            )
-                            .into(),
-                        },
-                    );
-                }
+                return Task::perform(
+                    async_task::async_set_wallpaper(
+                        full_path.clone(),
+                        wallpaper_mode,
+                        self.config.data.cache_path.clone(),
+                    ),
+                    move |result| match result {
+                        Ok(_) => MainMessage::AddToWallpaperHistory(full_path, WallpaperSource::Manual).into(),
+                        Err(e) => MainMessage::ShowNotification(
+                            format!("{}: {}", failed_message, e),
+                            NotificationType::Error,
+                        )
+                        .into(),
+                    },
+                );
             }
 
             // 2. 获取缓存文件路径
@@ -56,18 +64,23 @@ impl App {
                 let cache_path_buf = PathBuf::from(&cache_file_path);
                 if cache_path_buf.exists() {
                     // 缓存文件存在，复制到 data_path
-                    let _ = std::fs::create_dir_all(&data_path);
+                    let _ = std::fs::create_dir_all(&target_dir);
                     match std::fs::copy(&cache_path_buf, &target_path) {
                         Ok(_) => {
+                            let metadata_task = self.save_wallpaper_metadata(&target_path, &wallpaper_metadata);
                             // 复制成功，设置壁纸
                             let full_path = helpers::get_absolute_path(&target_path.to_string_lossy().to_string());
                             let wallpaper_mode = self.config.wallpaper.mode;
                             let failed_message = self.i18n.t("local-list.set-wallpaper-failed").to_string();
 
-                            return Task::perform(
-                                async_task::async_set_wallpaper(full_path.clone(), wallpaper_mode),
+                            let set_task = Task::perform(
+                                async_task::async_set_wallpaper(
+                                    full_path.clone(),
+                                    wallpaper_mode,
+                                    self.config.data.cache_path.clone(),
+                                ),
                                 move |result| match result {
-                                    Ok(_) => MainMessage::AddToWallpaperHistory(full_path).into(),
+                                    Ok(_) => MainMessage::AddToWallpaperHistory(full_path, WallpaperSource::Manual).into(),
                                     Err(e) => MainMessage::ShowNotification(
                                         format!("{}: {}", failed_message, e),
                                         NotificationType::Error,
@@ -75,6 +88,7 @@ impl App {
                                     .into(),
                                 },
                             );
+                            return Task::batch([metadata_task, set_task]);
                         }
                         Err(e) => {
                             error!("[模态窗口设置壁纸] [ID:{}] 从缓存复制失败: {}", id, e);
@@ -12,7 +12,7 @@ impl App {
                 // 缓存 Handle
                 wallpaper.image_handle = Some(handle);
                 // 更新状态为 Loaded（Handle 已缓存到 wallpaper 中）
-                self.online_state.wallpapers[idx] = WallpaperLoadStatus::Loaded(wallpaper.clone());
+                self.online_state.wallpapers[idx] = WallpaperLoadStatus::Loaded(Box::new(wallpaper.clone()));
             }
         }
         Task::none()
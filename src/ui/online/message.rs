@@ -3,6 +3,7 @@
 use crate::services::wallhaven;
 use crate::ui::{App, AppMessage};
 use iced::Task;
+use std::collections::HashSet;
 
 /// 在线壁纸页面消息类型
 #[derive(Debug, Clone)]
@@ -104,6 +105,29 @@ pub enum OnlineMessage {
     TimeRangePickerExpanded,
     /// 关闭时间范围选择器
     TimeRangePickerDismiss,
+    // 批量下载相关消息
+    /// 打开批量下载搜索结果对话框
+    ShowBulkDownload,
+    /// 关闭批量下载对话框（同时取消正在进行的获取）
+    CloseBulkDownload,
+    /// 修改最多下载数量
+    BulkMaxCountChanged(u32),
+    /// 修改总大小上限（MB）
+    BulkMaxSizeChanged(u32),
+    /// 切换是否跳过已拥有的壁纸
+    BulkSkipOwnedToggled(bool),
+    /// 开始逐页获取搜索结果
+    StartBulkCollect,
+    /// 批量获取的一页搜索结果加载成功
+    BulkPageLoaded(Vec<wallhaven::OnlineWallpaper>, bool, usize, usize),
+    /// 批量获取的一页搜索结果已检查已拥有的壁纸（已拥有的壁纸ID）
+    BulkPageChecked(Vec<wallhaven::OnlineWallpaper>, HashSet<String>, bool, usize, usize),
+    /// 批量获取搜索结果失败
+    BulkPageFailed(String),
+    /// 确认将选中的壁纸加入下载队列
+    ConfirmBulkDownload,
+    /// 列表中已拥有的壁纸检查完成（已拥有的壁纸ID）
+    OwnedWallpapersFound(HashSet<String>),
 }
 
 impl From<OnlineMessage> for AppMessage {
@@ -169,6 +193,33 @@ impl App {
             OnlineMessage::SortingPickerDismiss => self.online_filter_sorting_picker_dismiss(),
             OnlineMessage::TimeRangePickerExpanded => self.online_filter_time_range_picker_expanded(),
             OnlineMessage::TimeRangePickerDismiss => self.online_filter_time_range_picker_dismiss(),
+            OnlineMessage::ShowBulkDownload => self.show_bulk_download(),
+            OnlineMessage::CloseBulkDownload => self.close_bulk_download(),
+            OnlineMessage::BulkMaxCountChanged(count) => {
+                self.online_state.bulk_download.max_count = count.max(1);
+                Task::none()
+            }
+            OnlineMessage::BulkMaxSizeChanged(size_mb) => {
+                self.online_state.bulk_download.max_total_size_mb = size_mb;
+                Task::none()
+            }
+            OnlineMessage::BulkSkipOwnedToggled(skip_owned) => {
+                self.online_state.bulk_download.skip_owned = skip_owned;
+                Task::none()
+            }
+            OnlineMessage::StartBulkCollect => self.start_bulk_collect(),
+            OnlineMessage::BulkPageLoaded(wallpapers, last_page, total_pages, current_page) => {
+                self.bulk_page_loaded(wallpapers, last_page, total_pages, current_page)
+            }
+            OnlineMessage::BulkPageChecked(wallpapers, owned_ids, last_page, total_pages, current_page) => {
+                self.bulk_page_checked(wallpapers, owned_ids, last_page, total_pages, current_page)
+            }
+            OnlineMessage::BulkPageFailed(error) => self.bulk_page_failed(error),
+            OnlineMessage::ConfirmBulkDownload => self.confirm_bulk_download(),
+            OnlineMessage::OwnedWallpapersFound(owned_ids) => {
+                self.online_state.owned_wallpaper_ids = owned_ids;
+                Task::none()
+            }
         }
     }
 }
@@ -1,8 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
-use super::OnlineState;
+use super::{OnlineState, ResolutionMode};
 use crate::services::request_context::RequestContext;
-use std::sync::atomic::Ordering;
 
 impl OnlineState {
     /// 获取分类API参数字符串
@@ -15,33 +14,78 @@ impl OnlineState {
         format!("{:03b}", self.purities)
     }
 
+    /// 获取最小分辨率API参数（仅"至少"模式下有效）
+    pub fn get_atleast_param(&self) -> Option<String> {
+        if self.resolution_mode == ResolutionMode::AtLeast {
+            self.atleast_resolution.map(|r| r.value().to_string())
+        } else {
+            None
+        }
+    }
+
+    /// 获取精确分辨率API参数（仅"精确"模式下有效，逗号分隔）
+    pub fn get_resolutions_param(&self) -> Option<String> {
+        if self.resolution_mode == ResolutionMode::Exactly && !self.selected_resolutions.is_empty() {
+            let res_list: Vec<String> = self.selected_resolutions.iter().map(|r| r.value().to_string()).collect();
+            Some(res_list.join(","))
+        } else {
+            None
+        }
+    }
+
+    /// 获取比例API参数（逗号分隔，未选择时为 None）
+    pub fn get_ratios_param(&self) -> Option<String> {
+        let mut ratios_vec = Vec::new();
+        if self.ratio_landscape_selected {
+            ratios_vec.push("landscape".to_string());
+        }
+        if self.ratio_portrait_selected {
+            ratios_vec.push("portrait".to_string());
+        }
+        for ratio in &self.selected_ratios {
+            ratios_vec.push(ratio.value().to_string());
+        }
+
+        if ratios_vec.is_empty() {
+            None
+        } else {
+            Some(ratios_vec.join(","))
+        }
+    }
+
     /// 检查是否需要加载下一页
     pub fn should_load_next_page(&self) -> bool {
         !self.last_page && !self.loading_page && self.has_loaded
     }
 
-    /// 取消当前正在进行的请求，并创建一个新的请求上下文
+    /// 取消当前正在进行的分页请求，并从搜索上下文创建一个新的请求上下文
     pub fn cancel_and_new_context(&mut self) {
         self.request_context.cancel();
-        self.request_context = RequestContext::new();
+        self.request_context = self.search_context.child();
+    }
+
+    /// 取消当前搜索的所有请求（分页请求、缩略图加载和大图预览），并创建新的搜索上下文
+    pub fn cancel_search(&mut self) {
+        self.search_context.cancel();
+        self.search_context = RequestContext::new();
+        self.request_context = self.search_context.child();
     }
 
     /// 取消模态窗口图片下载
     pub fn cancel_modal_download(&mut self) {
-        if let Some(cancel_token) = &self.modal_download_cancel_token {
-            cancel_token.store(true, Ordering::Relaxed);
+        if let Some(context) = &self.modal_download_context {
+            context.cancel();
         }
-        self.modal_download_cancel_token = None;
+        self.modal_download_context = None;
         self.modal_download_progress = 0.0;
         self.modal_downloaded_bytes = 0;
         self.modal_total_bytes = 0;
     }
 
-    /// 取消所有缩略图加载任务
-    pub fn cancel_thumb_loads(&mut self) {
-        for cancel_token in &self.thumb_load_cancel_tokens {
-            cancel_token.store(true, Ordering::Relaxed);
+    /// 下载完成后，将对应的壁纸标记为已拥有
+    pub fn mark_owned_by_url(&mut self, url: &str) {
+        if let Some(wallpaper) = self.wallpapers_data.iter().find(|wallpaper| wallpaper.path == url) {
+            self.owned_wallpaper_ids.insert(wallpaper.id.clone());
         }
-        self.thumb_load_cancel_tokens.clear();
     }
 }
@@ -3,8 +3,6 @@
 use crate::services::request_context::RequestContext;
 use crate::services::wallhaven;
 use iced::widget::image::Handle;
-use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
 
 /// 壁纸加载状态
 #[derive(Debug, Clone)]
@@ -12,7 +10,7 @@ pub enum WallpaperLoadStatus {
     /// 加载中
     Loading,
     /// 已加载（包含缓存的图片 Handle）
-    Loaded(wallhaven::OnlineWallpaper),
+    Loaded(Box<wallhaven::OnlineWallpaper>),
 }
 
 /// 分页信息，记录每页的结束索引和对应的页码
@@ -35,6 +33,70 @@ pub enum ResolutionMode {
     Exactly,
 }
 
+/// 批量下载搜索结果的流程阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkDownloadStage {
+    /// 未打开
+    Closed,
+    /// 设置批量下载选项
+    Options,
+    /// 正在逐页获取搜索结果
+    Collecting,
+    /// 显示汇总信息，等待确认开始下载
+    Summary,
+}
+
+/// 批量下载搜索结果状态
+#[derive(Debug, Clone)]
+pub struct BulkDownloadState {
+    /// 当前阶段
+    pub stage: BulkDownloadStage,
+    /// 最多下载数量
+    pub max_count: u32,
+    /// 总大小上限（MB），0 表示不限制
+    pub max_total_size_mb: u32,
+    /// 是否跳过壁纸库中已拥有的壁纸
+    pub skip_owned: bool,
+    /// 已获取的页数
+    pub pages_loaded: usize,
+    /// 搜索结果总页数
+    pub total_pages: usize,
+    /// 已检查的壁纸数量
+    pub scanned_count: usize,
+    /// 待下载的壁纸
+    pub selected: Vec<wallhaven::OnlineWallpaper>,
+    /// 待下载壁纸的总大小（字节）
+    pub selected_size: u64,
+    /// 因已拥有而跳过的数量
+    pub skipped_owned: usize,
+    /// 因已在下载队列中而跳过的数量
+    pub skipped_in_queue: usize,
+    /// 是否因达到总大小上限而停止获取
+    pub size_cap_reached: bool,
+    /// 请求上下文（用于取消获取）
+    pub request_context: RequestContext,
+}
+
+impl Default for BulkDownloadState {
+    fn default() -> Self {
+        Self {
+            stage: BulkDownloadStage::Closed,
+            max_count: 100,
+            max_total_size_mb: 0,
+            skip_owned: true,
+            pages_loaded: 0,
+            total_pages: 0,
+            scanned_count: 0,
+            selected: Vec::new(),
+            selected_size: 0,
+            skipped_owned: 0,
+            skipped_in_queue: 0,
+            size_cap_reached: false,
+            request_context: RequestContext::new(),
+        }
+    }
+}
+
 /// 在线壁纸页面状态
 #[derive(Debug)]
 pub struct OnlineState {
@@ -107,24 +169,29 @@ pub struct OnlineState {
     pub sorting_picker_expanded: bool,
     /// 时间范围选择器展开状态
     pub time_range_picker_expanded: bool,
-    /// 请求上下文，用于取消正在进行的请求
+    /// 搜索上下文，当前搜索的分页请求、缩略图加载和大图预览都从它派生，重新搜索时一起取消
+    pub search_context: RequestContext,
+    /// 分页请求上下文（搜索上下文的子上下文），用于取消正在进行的分页请求
     pub request_context: RequestContext,
     /// 待设置壁纸的文件名（用于在下载完成后自动设置壁纸）
     pub pending_set_wallpaper_filename: Option<String>,
-    /// 模态窗口图片下载取消令牌
-    pub modal_download_cancel_token: Option<Arc<AtomicBool>>,
+    /// 模态窗口图片下载上下文（搜索上下文的子上下文）
+    pub modal_download_context: Option<RequestContext>,
     /// 模态窗口图片下载进度
     pub modal_download_progress: f32,
     /// 模态窗口已下载字节数
     pub modal_downloaded_bytes: u64,
     /// 模态窗口总字节数
     pub modal_total_bytes: u64,
-    /// 缩略图加载任务的取消令牌列表
-    pub thumb_load_cancel_tokens: Vec<Arc<AtomicBool>>,
+    /// 壁纸库中已拥有的壁纸ID（用于列表中的已拥有标记）
+    pub owned_wallpaper_ids: std::collections::HashSet<String>,
+    /// 批量下载搜索结果状态
+    pub bulk_download: BulkDownloadState,
 }
 
 impl Default for OnlineState {
     fn default() -> Self {
+        let search_context = RequestContext::new();
         Self {
             wallpapers: Vec::new(),
             wallpapers_data: Vec::new(),
@@ -159,13 +226,15 @@ impl Default for OnlineState {
             ratio_all_selected: false,
             sorting_picker_expanded: false,
             time_range_picker_expanded: false,
-            request_context: RequestContext::new(),
+            request_context: search_context.child(),
+            search_context,
             pending_set_wallpaper_filename: None,
-            modal_download_cancel_token: None,
+            modal_download_context: None,
             modal_download_progress: 0.0,
             modal_downloaded_bytes: 0,
             modal_total_bytes: 0,
-            thumb_load_cancel_tokens: Vec::new(),
+            owned_wallpaper_ids: std::collections::HashSet::new(),
+            bulk_download: BulkDownloadState::default(),
         }
     }
 }
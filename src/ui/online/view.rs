@@ -1,6 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
-use super::OnlineState;
+use super::{BulkDownloadStage, OnlineState};
 use super::widget;
 use crate::i18n::I18n;
 use crate::ui::AppMessage;
@@ -34,5 +34,10 @@ pub fn online_view<'a>(
         layers.push(widget::create_modal(i18n, online_state, theme_config));
     }
 
+    // 批量下载搜索结果对话框
+    if online_state.bulk_download.stage != BulkDownloadStage::Closed {
+        layers.push(widget::create_bulk_download_dialog(i18n, &online_state.bulk_download));
+    }
+
     stack(layers).width(Length::Fill).height(Length::Fill).into()
 }
@@ -0,0 +1,161 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::i18n::I18n;
+use crate::ui::AppMessage;
+use crate::ui::common;
+use crate::ui::online::{BulkDownloadStage, BulkDownloadState, OnlineMessage};
+use crate::ui::style::*;
+use crate::utils::helpers;
+use iced::widget::{Row, column, row, text, toggler};
+use iced::{Alignment, Element, Length};
+
+/// 批量下载对话框中设置项标签的宽度
+const OPTION_LABEL_WIDTH: f32 = 240.0;
+
+/// 创建批量下载搜索结果对话框（根据当前阶段显示选项、获取进度或汇总信息）
+pub fn create_bulk_download_dialog<'a>(i18n: &'a I18n, state: &'a BulkDownloadState) -> Element<'a, AppMessage> {
+    let dialog_content = match state.stage {
+        BulkDownloadStage::Options => create_options_content(i18n, state),
+        BulkDownloadStage::Collecting => create_collecting_content(i18n, state),
+        BulkDownloadStage::Summary | BulkDownloadStage::Closed => create_summary_content(i18n, state),
+    };
+
+    common::create_modal_dialog(
+        column![dialog_content]
+            .padding(DIALOG_PADDING)
+            .align_x(Alignment::Center)
+            .width(Length::Shrink)
+            .max_width(DIALOG_MAX_WIDTH)
+            .into(),
+    )
+}
+
+/// 选项阶段：最多数量、总大小上限、是否跳过已拥有
+fn create_options_content<'a>(i18n: &'a I18n, state: &'a BulkDownloadState) -> Element<'a, AppMessage> {
+    let option_row = |label: String, widget: Element<'a, AppMessage>| {
+        row![
+            text(label)
+                .size(DIALOG_MESSAGE_SIZE)
+                .width(Length::Fixed(OPTION_LABEL_WIDTH)),
+            widget,
+        ]
+        .spacing(DIALOG_BUTTON_SPACING)
+        .align_y(Alignment::Center)
+    };
+
+    column![
+        text(i18n.t("bulk-download.title")).size(DIALOG_TITLE_SIZE),
+        option_row(
+            i18n.t("bulk-download.max-count"),
+            iced_aw::NumberInput::new(&state.max_count, 1..=10000, |n| {
+                OnlineMessage::BulkMaxCountChanged(n).into()
+            })
+            .width(Length::Fixed(120.0))
+            .into(),
+        ),
+        option_row(
+            i18n.t("bulk-download.max-size"),
+            iced_aw::NumberInput::new(&state.max_total_size_mb, 0..=1_000_000, |n| {
+                OnlineMessage::BulkMaxSizeChanged(n).into()
+            })
+            .width(Length::Fixed(120.0))
+            .into(),
+        ),
+        option_row(
+            i18n.t("bulk-download.skip-owned"),
+            toggler(state.skip_owned)
+                .on_toggle(|checked| OnlineMessage::BulkSkipOwnedToggled(checked).into())
+                .into(),
+        ),
+        row![
+            common::create_colored_button(
+                i18n.t("bulk-download.start"),
+                BUTTON_COLOR_BLUE,
+                OnlineMessage::StartBulkCollect.into()
+            ),
+            common::create_colored_button(
+                i18n.t("bulk-download.cancel"),
+                BUTTON_COLOR_GRAY,
+                OnlineMessage::CloseBulkDownload.into()
+            ),
+        ]
+        .spacing(DIALOG_BUTTON_SPACING),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+/// 获取阶段：显示已获取的页数和已选择的数量
+fn create_collecting_content<'a>(i18n: &'a I18n, state: &'a BulkDownloadState) -> Element<'a, AppMessage> {
+    let progress_text = i18n
+        .t("bulk-download.collecting")
+        .replace("{page}", &state.pages_loaded.to_string())
+        .replace("{total}", &state.total_pages.max(state.pages_loaded).to_string())
+        .replace("{count}", &state.selected.len().to_string());
+
+    column![
+        text(i18n.t("bulk-download.title")).size(DIALOG_TITLE_SIZE),
+        text(progress_text).size(DIALOG_MESSAGE_SIZE),
+        common::create_colored_button(
+            i18n.t("bulk-download.cancel"),
+            BUTTON_COLOR_GRAY,
+            OnlineMessage::CloseBulkDownload.into()
+        ),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .into()
+}
+
+/// 汇总阶段：显示将要下载的数量、总大小和跳过的数量
+fn create_summary_content<'a>(i18n: &'a I18n, state: &'a BulkDownloadState) -> Element<'a, AppMessage> {
+    let summary_text = i18n
+        .t("bulk-download.summary")
+        .replace("{scanned}", &state.scanned_count.to_string())
+        .replace("{count}", &state.selected.len().to_string())
+        .replace("{size}", &helpers::format_file_size(state.selected_size));
+    let skipped_text = i18n
+        .t("bulk-download.summary-skipped")
+        .replace("{owned}", &state.skipped_owned.to_string())
+        .replace("{queued}", &state.skipped_in_queue.to_string());
+
+    // 达到总大小上限时提示停止获取的原因
+    let size_cap_text = state.size_cap_reached.then(|| {
+        text(i18n.t("bulk-download.summary-size-cap"))
+            .size(DIALOG_MESSAGE_SIZE)
+            .color(BUTTON_COLOR_YELLOW)
+    });
+
+    let buttons: Row<'a, AppMessage> = if state.selected.is_empty() {
+        row![common::create_colored_button(
+            i18n.t("bulk-download.cancel"),
+            BUTTON_COLOR_GRAY,
+            OnlineMessage::CloseBulkDownload.into()
+        )]
+    } else {
+        row![
+            common::create_colored_button(
+                i18n.t("bulk-download.confirm"),
+                BUTTON_COLOR_GREEN,
+                OnlineMessage::ConfirmBulkDownload.into()
+            ),
+            common::create_colored_button(
+                i18n.t("bulk-download.cancel"),
+                BUTTON_COLOR_GRAY,
+                OnlineMessage::CloseBulkDownload.into()
+            ),
+        ]
+    };
+
+    column![
+        text(i18n.t("bulk-download.summary-title")).size(DIALOG_TITLE_SIZE),
+        text(summary_text).size(DIALOG_MESSAGE_SIZE),
+        text(skipped_text).size(DIALOG_MESSAGE_SIZE),
+        size_cap_text,
+        buttons.spacing(DIALOG_BUTTON_SPACING),
+    ]
+    .spacing(DIALOG_SPACING)
+    .align_x(Alignment::Center)
+    .into()
+}
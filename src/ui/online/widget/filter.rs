@@ -8,7 +8,7 @@ use crate::ui::online::{OnlineMessage, OnlineState};
 use crate::ui::style::*;
 use crate::utils::config::Config;
 use iced::border::{Border, Radius};
-use iced::widget::{Space, button, container, row, text, text_input};
+use iced::widget::{Space, button, container, row, text, text_input, tooltip};
 use iced::{Alignment, Color, Element, Length};
 
 /// 创建筛选栏
@@ -84,6 +84,29 @@ pub fn create_filter_bar<'a>(
             },
         );
 
+    // 批量下载搜索结果按钮
+    let bulk_download_button = common::create_button_with_tooltip(
+        common::create_icon_button_with_size(
+            "\u{F30A}",
+            BUTTON_COLOR_BLUE,
+            20,
+            OnlineMessage::ShowBulkDownload.into(),
+        )
+        .style(move |_theme, _status| button::Style {
+            background: Some(iced::Background::Color(theme_colors.light_button)),
+            text_color: theme_colors.light_text,
+            border: Border {
+                color: Color::TRANSPARENT,
+                width: 0.0,
+                radius: Radius::from(4.0),
+            },
+            ..button::text(_theme, _status)
+        }),
+        i18n.t("bulk-download.tooltip"),
+        tooltip::Position::Bottom,
+        theme_config,
+    );
+
     // 组合所有元素
     let filter_row = row![
         search_container,
@@ -251,6 +274,7 @@ pub fn create_filter_bar<'a>(
             None
         },
         refresh_button,
+        bulk_download_button,
     ]
     .spacing(4)
     .align_y(Alignment::Center);
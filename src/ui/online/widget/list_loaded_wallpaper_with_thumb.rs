@@ -8,13 +8,16 @@ use crate::ui::online::OnlineMessage;
 use crate::ui::style::*;
 use crate::utils::helpers;
 use iced::widget::{Space, button, container, row, text, tooltip};
-use iced::{Alignment, Element, Length};
+use iced::{Alignment, Element, Font, Length};
 
 /// 创建已加载的壁纸卡片
+///
+/// `is_owned` 为 true 时在左上角显示已拥有标记
 pub fn create_loaded_wallpaper_with_thumb<'a>(
     i18n: &'a I18n,
     wallpaper: &'a OnlineWallpaper,
     index: usize,
+    is_owned: bool,
     theme_config: &'a ThemeConfig,
 ) -> Element<'a, AppMessage> {
     let theme_colors = theme_config.get_theme_colors();
@@ -135,7 +138,7 @@ pub fn create_loaded_wallpaper_with_thumb<'a>(
         });
 
     // 使用 stack 将遮罩覆盖在图片内部下方
-    let card_content = iced::widget::stack(vec![
+    let mut card_layers: Vec<Element<'a, AppMessage>> = vec![
         styled_image.into(),
         container(overlay)
             .width(Length::Fill)
@@ -143,7 +146,54 @@ pub fn create_loaded_wallpaper_with_thumb<'a>(
             .align_x(Alignment::Center)
             .align_y(Alignment::End)
             .into(),
-    ]);
+    ];
+
+    // 已拥有标记：壁纸已存在于壁纸库中
+    if is_owned {
+        let owned_badge = container(
+            text("\u{F26A}")
+                .font(Font::with_name("bootstrap-icons"))
+                .size(OVERLAY_TEXT_SIZE)
+                .color(BUTTON_COLOR_GREEN),
+        )
+        .padding([2, 6])
+        .style(|_theme: &iced::Theme| container::Style {
+            background: Some(iced::Background::Color(COLOR_OVERLAY_BG)),
+            border: iced::border::Border {
+                radius: iced::border::Radius::from(4.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        card_layers.push(
+            container(
+                tooltip(
+                    owned_badge,
+                    text(i18n.t("online-wallpapers.tooltip-owned")).color(theme_colors.text),
+                    tooltip::Position::Bottom,
+                )
+                .gap(5.0)
+                .style(move |_theme: &iced::Theme| container::Style {
+                    background: Some(iced::Background::Color(theme_colors.tooltip_bg_color)),
+                    border: iced::border::Border {
+                        color: theme_colors.tooltip_border_color,
+                        width: TOOLTIP_BORDER_WIDTH,
+                        radius: iced::border::Radius::from(TOOLTIP_BORDER_RADIUS),
+                    },
+                    ..Default::default()
+                }),
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(6)
+            .align_x(Alignment::Start)
+            .align_y(Alignment::Start)
+            .into(),
+        );
+    }
+
+    let card_content = iced::widget::stack(card_layers);
 
     button(card_content)
         .padding(0)
@@ -48,6 +48,7 @@ pub fn create_wallpaper_grid<'a>(
                             i18n,
                             wallpaper,
                             wallpaper_index,
+                            online_state.owned_wallpaper_ids.contains(&wallpaper.id),
                             theme_config,
                         )
                     }
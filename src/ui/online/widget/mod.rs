@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+mod bulk_download_dialog;
 mod diagonal_line;
 mod filter;
 mod filter_color_grid_options;
@@ -35,5 +36,6 @@ use {
 use modal_loading_placeholder::create_modal_loading_placeholder;
 
 pub(in crate::ui::online) use {
-    filter::create_filter_bar, list_wallpapers::create_wallpaper_list, modal::create_modal,
+    bulk_download_dialog::create_bulk_download_dialog, filter::create_filter_bar,
+    list_wallpapers::create_wallpaper_list, modal::create_modal,
 };
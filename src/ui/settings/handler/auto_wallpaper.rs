@@ -1,6 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
-use crate::services::wallhaven::{Sorting, TimeRange};
+use crate::services::wallhaven::{ColorOption, Sorting, TimeRange};
 use crate::ui::{App, AppMessage, NotificationType};
 use crate::utils::config::{WallpaperAutoChangeInterval, WallpaperAutoChangeMode, WallpaperMode};
 use iced::Task;
@@ -193,4 +193,33 @@ impl App {
         self.settings_state.time_range_picker_expanded = false;
         Task::none()
     }
+
+    /// 本地定时切换只选择主色调匹配所选颜色的壁纸
+    pub(in crate::ui::settings) fn settings_auto_change_local_color_changed(
+        &mut self,
+        color: ColorOption,
+    ) -> Task<AppMessage> {
+        info!(
+            "[设置] [本地定时切换颜色] 修改: {:?} -> {:?}",
+            self.settings_state.auto_change_local_color, color
+        );
+        self.settings_state.auto_change_local_color = color;
+
+        // 立即保存到配置文件
+        self.config.wallpaper.auto_change_local_color = color.value().to_string();
+        self.config.save_to_file();
+
+        // 选择后关闭选择器
+        self.settings_state.local_color_picker_expanded = false;
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_local_color_picker_expanded(&mut self) -> Task<AppMessage> {
+        self.settings_state.local_color_picker_expanded = !self.settings_state.local_color_picker_expanded;
+        Task::none()
+    }
+    pub(in crate::ui::settings) fn settings_local_color_picker_dismiss(&mut self) -> Task<AppMessage> {
+        self.settings_state.local_color_picker_expanded = false;
+        Task::none()
+    }
 }
@@ -0,0 +1,120 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::wallhaven;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use tracing::info;
+
+impl App {
+    pub(in crate::ui::settings) fn settings_write_sidecar_toggled(&mut self, enabled: bool) -> Task<AppMessage> {
+        let old_value = self.config.download.write_sidecar;
+        info!("[设置] [写入元数据文件] 修改: {} -> {}", old_value, enabled);
+        self.config.download.write_sidecar = enabled;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_write_xmp_sidecar_toggled(&mut self, enabled: bool) -> Task<AppMessage> {
+        let old_value = self.config.download.write_xmp_sidecar;
+        info!("[设置] [写入XMP文件] 修改: {} -> {}", old_value, enabled);
+        self.config.download.write_xmp_sidecar = enabled;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_auto_retry_toggled(&mut self, enabled: bool) -> Task<AppMessage> {
+        let old_value = self.config.download.auto_retry_enabled;
+        info!("[设置] [自动重试] 修改: {} -> {}", old_value, enabled);
+        self.config.download.auto_retry_enabled = enabled;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_retry_max_attempts_changed(&mut self, attempts: u32) -> Task<AppMessage> {
+        // 限制最小值为1
+        let attempts = attempts.max(1);
+        let old_value = self.config.download.retry_max_attempts;
+        info!("[设置] [最大重试次数] 修改: {} -> {}", old_value, attempts);
+        self.config.download.retry_max_attempts = attempts;
+        self.config.save_to_file();
+
+        // 同步到下载页面的重试次数显示
+        self.download_state.retry_max_attempts = attempts;
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_retry_base_delay_changed(&mut self, seconds: u32) -> Task<AppMessage> {
+        let seconds = seconds.max(1);
+        let old_value = self.config.download.retry_base_delay_secs;
+        info!("[设置] [重试等待时间] 修改: {}秒 -> {}秒", old_value, seconds);
+        self.config.download.retry_base_delay_secs = seconds;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_retry_max_delay_changed(&mut self, seconds: u32) -> Task<AppMessage> {
+        let seconds = seconds.max(1);
+        let old_value = self.config.download.retry_max_delay_secs;
+        info!("[设置] [最长重试等待时间] 修改: {}秒 -> {}秒", old_value, seconds);
+        self.config.download.retry_max_delay_secs = seconds;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_disk_reserve_changed(&mut self, megabytes: u32) -> Task<AppMessage> {
+        let old_value = self.config.download.disk_reserve_mb;
+        info!("[设置] [磁盘保留空间] 修改: {}MB -> {}MB", old_value, megabytes);
+        self.config.download.disk_reserve_mb = megabytes;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_library_quota_changed(&mut self, megabytes: u32) -> Task<AppMessage> {
+        let old_value = self.config.download.library_quota_mb;
+        info!("[设置] [壁纸库配额] 修改: {}MB -> {}MB", old_value, megabytes);
+        self.config.download.library_quota_mb = megabytes;
+        self.config.save_to_file();
+        self.refresh_directory_usage(false)
+    }
+
+    pub(in crate::ui::settings) fn settings_cache_quota_changed(&mut self, megabytes: u32) -> Task<AppMessage> {
+        let old_value = self.config.download.cache_quota_mb;
+        info!("[设置] [缓存配额] 修改: {}MB -> {}MB", old_value, megabytes);
+        self.config.download.cache_quota_mb = megabytes;
+        self.config.save_to_file();
+        self.refresh_directory_usage(false)
+    }
+
+    pub(in crate::ui::settings) fn settings_file_name_template_changed(
+        &mut self,
+        template: String,
+    ) -> Task<AppMessage> {
+        // 只更新临时状态（用于实时预览），不保存到配置文件
+        self.settings_state.file_name_template = template;
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_save_file_name_template(&mut self) -> Task<AppMessage> {
+        let template = self.settings_state.file_name_template.trim().to_string();
+        let template = if template.is_empty() {
+            wallhaven::DEFAULT_FILE_NAME_TEMPLATE.to_string()
+        } else {
+            template
+        };
+        info!(
+            "[设置] [文件名模板] 保存: {} -> {}",
+            self.config.download.file_name_template, template
+        );
+        self.settings_state.file_name_template = template.clone();
+        self.config.download.file_name_template = template;
+        self.config.save_to_file();
+
+        let success_message = self.i18n.t("settings.save-success").to_string();
+        self.show_notification(success_message, NotificationType::Success)
+    }
+
+    pub(in crate::ui::settings) fn settings_restore_default_file_name_template(&mut self) -> Task<AppMessage> {
+        self.settings_state.file_name_template = wallhaven::DEFAULT_FILE_NAME_TEMPLATE.to_string();
+        self.settings_save_file_name_template()
+    }
+}
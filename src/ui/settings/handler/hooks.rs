@@ -0,0 +1,74 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use tracing::info;
+
+impl App {
+    pub(in crate::ui::settings) fn settings_post_download_hook_toggled(&mut self, enabled: bool) -> Task<AppMessage> {
+        let old_value = self.config.hooks.post_download_enabled;
+        info!("[设置] [下载完成钩子] 修改: {} -> {}", old_value, enabled);
+        self.config.hooks.post_download_enabled = enabled;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_post_download_command_changed(
+        &mut self,
+        command: String,
+    ) -> Task<AppMessage> {
+        // 只更新临时状态，点击保存后才写入配置文件
+        self.settings_state.post_download_command = command;
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_save_post_download_command(&mut self) -> Task<AppMessage> {
+        let command = self.settings_state.post_download_command.trim().to_string();
+        info!(
+            "[设置] [下载完成钩子命令] 保存: {} -> {}",
+            self.config.hooks.post_download_command, command
+        );
+        self.settings_state.post_download_command = command.clone();
+        self.config.hooks.post_download_command = command;
+        self.config.save_to_file();
+
+        let success_message = self.i18n.t("settings.save-success").to_string();
+        self.show_notification(success_message, NotificationType::Success)
+    }
+
+    pub(in crate::ui::settings) fn settings_post_set_hook_toggled(&mut self, enabled: bool) -> Task<AppMessage> {
+        let old_value = self.config.hooks.post_set_enabled;
+        info!("[设置] [设置壁纸钩子] 修改: {} -> {}", old_value, enabled);
+        self.config.hooks.post_set_enabled = enabled;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_post_set_command_changed(&mut self, command: String) -> Task<AppMessage> {
+        self.settings_state.post_set_command = command;
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_save_post_set_command(&mut self) -> Task<AppMessage> {
+        let command = self.settings_state.post_set_command.trim().to_string();
+        info!(
+            "[设置] [设置壁纸钩子命令] 保存: {} -> {}",
+            self.config.hooks.post_set_command, command
+        );
+        self.settings_state.post_set_command = command.clone();
+        self.config.hooks.post_set_command = command;
+        self.config.save_to_file();
+
+        let success_message = self.i18n.t("settings.save-success").to_string();
+        self.show_notification(success_message, NotificationType::Success)
+    }
+
+    pub(in crate::ui::settings) fn settings_hook_timeout_changed(&mut self, seconds: u32) -> Task<AppMessage> {
+        let seconds = seconds.max(1);
+        let old_value = self.config.hooks.timeout_secs;
+        info!("[设置] [钩子命令超时时间] 修改: {}秒 -> {}秒", old_value, seconds);
+        self.config.hooks.timeout_secs = seconds;
+        self.config.save_to_file();
+        Task::none()
+    }
+}
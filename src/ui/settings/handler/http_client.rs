@@ -0,0 +1,82 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::http_client;
+use crate::ui::{App, AppMessage, NotificationType};
+use iced::Task;
+use tracing::info;
+
+impl App {
+    pub(in crate::ui::settings) fn settings_http_connect_timeout_changed(&mut self, seconds: u32) -> Task<AppMessage> {
+        let seconds = seconds.max(1);
+        let old_value = self.config.http.connect_timeout_secs;
+        info!("[设置] [连接超时时间] 修改: {}秒 -> {}秒", old_value, seconds);
+        self.config.http.connect_timeout_secs = seconds;
+        self.apply_http_config();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_http_request_timeout_changed(&mut self, seconds: u32) -> Task<AppMessage> {
+        let old_value = self.config.http.request_timeout_secs;
+        info!("[设置] [请求超时时间] 修改: {}秒 -> {}秒", old_value, seconds);
+        self.config.http.request_timeout_secs = seconds;
+        self.apply_http_config();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_http_user_agent_changed(&mut self, user_agent: String) -> Task<AppMessage> {
+        // 只更新临时状态，点击保存后才写入配置文件
+        self.settings_state.http_user_agent = user_agent;
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_save_http_user_agent(&mut self) -> Task<AppMessage> {
+        let user_agent = self.settings_state.http_user_agent.trim().to_string();
+        info!(
+            "[设置] [User-Agent] 保存: {} -> {}",
+            self.config.http.user_agent, user_agent
+        );
+        self.settings_state.http_user_agent = user_agent.clone();
+        self.config.http.user_agent = user_agent;
+        self.apply_http_config();
+
+        let success_message = self.i18n.t("settings.save-success").to_string();
+        self.show_notification(success_message, NotificationType::Success)
+    }
+
+    pub(in crate::ui::settings) fn settings_http2_only_toggled(&mut self, enabled: bool) -> Task<AppMessage> {
+        let old_value = self.config.http.http2_only;
+        info!("[设置] [强制使用 HTTP/2] 修改: {} -> {}", old_value, enabled);
+        self.config.http.http2_only = enabled;
+        self.apply_http_config();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_http_pool_max_idle_changed(
+        &mut self,
+        connections: u32,
+    ) -> Task<AppMessage> {
+        let old_value = self.config.http.pool_max_idle_per_host;
+        info!("[设置] [每个主机的空闲连接数] 修改: {} -> {}", old_value, connections);
+        self.config.http.pool_max_idle_per_host = connections;
+        self.apply_http_config();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_http_pool_idle_timeout_changed(
+        &mut self,
+        seconds: u32,
+    ) -> Task<AppMessage> {
+        let seconds = seconds.max(1);
+        let old_value = self.config.http.pool_idle_timeout_secs;
+        info!("[设置] [空闲连接保留时间] 修改: {}秒 -> {}秒", old_value, seconds);
+        self.config.http.pool_idle_timeout_secs = seconds;
+        self.apply_http_config();
+        Task::none()
+    }
+
+    /// 保存网络设置并让共享 HTTP 客户端按新设置重新创建
+    fn apply_http_config(&self) {
+        self.config.save_to_file();
+        http_client::configure(&self.config.http);
+    }
+}
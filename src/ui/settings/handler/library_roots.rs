@@ -0,0 +1,160 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::async_task;
+use crate::ui::settings::SettingsMessage;
+use crate::ui::{App, AppMessage, NotificationType};
+use crate::utils::config::{LibraryRoot, SymlinkPolicy};
+use crate::utils::helpers;
+use iced::Task;
+use tracing::info;
+
+impl App {
+    pub(in crate::ui::settings) fn settings_add_library_root(&mut self) -> Task<AppMessage> {
+        Task::perform(async_task::select_folder_async(), |selected_path| {
+            SettingsMessage::LibraryRootSelected(selected_path).into()
+        })
+    }
+
+    pub(in crate::ui::settings) fn settings_library_root_selected(&mut self, path: String) -> Task<AppMessage> {
+        // 用户取消选择
+        if path.is_empty() {
+            return Task::none();
+        }
+
+        let absolute_path = helpers::get_absolute_path(&path);
+        let exists = self
+            .config
+            .data
+            .library_roots()
+            .iter()
+            .any(|root| helpers::get_absolute_path(&root.path) == absolute_path);
+        if exists {
+            info!("[设置] [壁纸库目录] 目录已存在，忽略: {}", path);
+            return self.show_notification(self.i18n.t("settings.library-root-exists"), NotificationType::Error);
+        }
+
+        info!("[设置] [壁纸库目录] 添加: {}", path);
+        self.config.data.library_roots.push(LibraryRoot::new(path));
+        self.settings_state.library_root_includes.push(String::new());
+        self.settings_state.library_root_excludes.push(String::new());
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_remove_library_root(&mut self, index: usize) -> Task<AppMessage> {
+        if index >= self.config.data.library_roots.len() {
+            return Task::none();
+        }
+
+        let root = self.config.data.library_roots.remove(index);
+        info!("[设置] [壁纸库目录] 移除: {}", root.path);
+        if index < self.settings_state.library_root_includes.len() {
+            self.settings_state.library_root_includes.remove(index);
+        }
+        if index < self.settings_state.library_root_excludes.len() {
+            self.settings_state.library_root_excludes.remove(index);
+        }
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_library_root_recursive_toggled(
+        &mut self,
+        index: usize,
+        enabled: bool,
+    ) -> Task<AppMessage> {
+        if let Some(root) = self.config.data.library_roots.get_mut(index) {
+            info!(
+                "[设置] [壁纸库目录] [{}] 包含子目录: {} -> {}",
+                root.path, root.recursive, enabled
+            );
+            root.recursive = enabled;
+            self.config.save_to_file();
+        }
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_library_root_follow_symlinks_toggled(
+        &mut self,
+        index: usize,
+        enabled: bool,
+    ) -> Task<AppMessage> {
+        if let Some(root) = self.config.data.library_roots.get_mut(index) {
+            let policy = if enabled {
+                SymlinkPolicy::Follow
+            } else {
+                SymlinkPolicy::Skip
+            };
+            info!(
+                "[设置] [壁纸库目录] [{}] 符号链接: {:?} -> {:?}",
+                root.path, root.symlinks, policy
+            );
+            root.symlinks = policy;
+            self.config.save_to_file();
+        }
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_library_root_include_changed(
+        &mut self,
+        index: usize,
+        patterns: String,
+    ) -> Task<AppMessage> {
+        // 只更新临时状态，点击保存后才写入配置文件
+        if let Some(include) = self.settings_state.library_root_includes.get_mut(index) {
+            *include = patterns;
+        }
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_library_root_exclude_changed(
+        &mut self,
+        index: usize,
+        patterns: String,
+    ) -> Task<AppMessage> {
+        if let Some(exclude) = self.settings_state.library_root_excludes.get_mut(index) {
+            *exclude = patterns;
+        }
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_save_library_root_patterns(&mut self, index: usize) -> Task<AppMessage> {
+        let Some(root) = self.config.data.library_roots.get_mut(index) else {
+            return Task::none();
+        };
+
+        let include = split_patterns(self.settings_state.library_root_includes.get(index));
+        let exclude = split_patterns(self.settings_state.library_root_excludes.get(index));
+        info!(
+            "[设置] [壁纸库目录] [{}] 过滤模式: 包含 {:?} -> {:?}, 排除 {:?} -> {:?}",
+            root.path, root.include, include, root.exclude, exclude
+        );
+
+        if let Some(state) = self.settings_state.library_root_includes.get_mut(index) {
+            *state = include.join(", ");
+        }
+        if let Some(state) = self.settings_state.library_root_excludes.get_mut(index) {
+            *state = exclude.join(", ");
+        }
+        root.include = include;
+        root.exclude = exclude;
+        self.config.save_to_file();
+
+        let success_message = self.i18n.t("settings.save-success").to_string();
+        self.show_notification(success_message, NotificationType::Success)
+    }
+}
+
+/// 把逗号分隔的模式拆分为列表
+fn split_patterns(patterns: Option<&String>) -> Vec<String> {
+    patterns
+        .map(|patterns| {
+            patterns
+                .split(',')
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
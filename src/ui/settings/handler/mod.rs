@@ -3,7 +3,11 @@
 mod auto_startup_toggle;
 mod auto_wallpaper;
 mod close_action_select;
+mod download_config;
+mod hooks;
+mod http_client;
 mod language_select;
+mod library_roots;
 mod logging_toggle;
 mod open_path;
 mod open_url;
@@ -11,5 +15,6 @@ mod path_clear;
 mod path_select;
 mod proxy_change;
 mod restore_default_path;
+mod transcode;
 mod wallhaven_api_key;
 mod widget_state;
@@ -1,5 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::http_client;
 use crate::ui::{App, AppMessage, NotificationType};
 use iced::Task;
 use tracing::info;
@@ -26,6 +27,7 @@ impl App {
         self.settings_state.proxy_enabled = enabled;
         self.config.global.proxy_enabled = enabled;
         self.config.save_to_file();
+        http_client::clear();
         info!(
             "[设置] [代理] 开关状态已切换: {}",
             if enabled { "开启" } else { "关闭" }
@@ -53,6 +55,7 @@ impl App {
                 self.config.set_proxy(proxy_url);
                 self.config.global.proxy_enabled = true;
                 self.config.save_to_file();
+                http_client::clear();
                 // 显示成功通知
                 self.show_notification(self.i18n.t("settings.proxy-save-success"), NotificationType::Success)
             } else {
@@ -66,6 +69,7 @@ impl App {
             info!("[设置] [代理] 保存（已禁用）: proxy_enabled=false, proxy={}", old_proxy);
             self.config.global.proxy_enabled = false;
             self.config.save_to_file();
+            http_client::clear();
             // 显示成功通知
             self.show_notification(self.i18n.t("settings.proxy-disabled"), NotificationType::Success)
         }
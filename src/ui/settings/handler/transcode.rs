@@ -0,0 +1,47 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::{App, AppMessage};
+use crate::utils::config::TranscodeFormat;
+use iced::Task;
+use tracing::info;
+
+impl App {
+    pub(in crate::ui::settings) fn settings_transcode_format_selected(
+        &mut self,
+        format: TranscodeFormat,
+    ) -> Task<AppMessage> {
+        let old_value = self.config.transcode.format;
+        info!("[设置] [下载后转码格式] 修改: {:?} -> {:?}", old_value, format);
+        self.config.transcode.format = format;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_transcode_quality_changed(&mut self, quality: u32) -> Task<AppMessage> {
+        let quality = quality.clamp(1, 100);
+        let old_value = self.config.transcode.quality;
+        info!("[设置] [JPEG 转码质量] 修改: {} -> {}", old_value, quality);
+        self.config.transcode.quality = quality;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_transcode_min_size_changed(&mut self, megabytes: u32) -> Task<AppMessage> {
+        let old_value = self.config.transcode.min_size_mb;
+        info!("[设置] [转码最小文件大小] 修改: {}MB -> {}MB", old_value, megabytes);
+        self.config.transcode.min_size_mb = megabytes;
+        self.config.save_to_file();
+        Task::none()
+    }
+
+    pub(in crate::ui::settings) fn settings_transcode_keep_original_toggled(
+        &mut self,
+        enabled: bool,
+    ) -> Task<AppMessage> {
+        let old_value = self.config.transcode.keep_original;
+        info!("[设置] [转码后保留原文件] 修改: {} -> {}", old_value, enabled);
+        self.config.transcode.keep_original = enabled;
+        self.config.save_to_file();
+        Task::none()
+    }
+}
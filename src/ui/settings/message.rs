@@ -1,8 +1,10 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
-use crate::services::wallhaven::{Sorting, TimeRange};
+use crate::services::wallhaven::{ColorOption, Sorting, TimeRange};
 use crate::ui::{App, AppMessage};
-use crate::utils::config::{CloseAction, WallpaperAutoChangeInterval, WallpaperAutoChangeMode, WallpaperMode};
+use crate::utils::config::{
+    CloseAction, TranscodeFormat, WallpaperAutoChangeInterval, WallpaperAutoChangeMode, WallpaperMode,
+};
 use iced::Task;
 
 /// 主界面页面消息
@@ -22,6 +24,22 @@ pub enum SettingsMessage {
     DataPathSelected(String),
     /// 缓存路径选择
     CachePathSelected(String),
+    /// 添加壁纸库目录（打开目录选择对话框）
+    AddLibraryRoot,
+    /// 选择了要添加的壁纸库目录（空字符串表示取消选择）
+    LibraryRootSelected(String),
+    /// 移除壁纸库目录
+    RemoveLibraryRoot(usize),
+    /// 壁纸库目录包含子目录开关切换
+    LibraryRootRecursiveToggled(usize, bool),
+    /// 壁纸库目录跟随符号链接开关切换
+    LibraryRootFollowSymlinksToggled(usize, bool),
+    /// 壁纸库目录包含模式更改
+    LibraryRootIncludeChanged(usize, String),
+    /// 壁纸库目录排除模式更改
+    LibraryRootExcludeChanged(usize, String),
+    /// 保存壁纸库目录过滤模式
+    SaveLibraryRootPatterns(usize),
     /// 打开路径
     OpenPath(String),
     /// 打开日志目录
@@ -68,6 +86,68 @@ pub enum SettingsMessage {
     AutoChangeTimeRangeChanged(TimeRange),
     /// 保存定时切换时间范围
     SaveAutoChangeTimeRange,
+    /// 本地定时切换颜色变化
+    AutoChangeLocalColorChanged(ColorOption),
+    /// 写入元数据附属文件开关切换
+    WriteSidecarToggled(bool),
+    /// 写入 XMP 附属文件开关切换
+    WriteXmpSidecarToggled(bool),
+    /// 下载失败自动重试开关切换
+    AutoRetryToggled(bool),
+    /// 最大重试次数变化
+    RetryMaxAttemptsChanged(u32),
+    /// 首次重试等待秒数变化
+    RetryBaseDelayChanged(u32),
+    /// 最长重试等待秒数变化
+    RetryMaxDelayChanged(u32),
+    /// 磁盘保留空间变化（MB）
+    DiskReserveChanged(u32),
+    /// 壁纸库目录配额变化（MB，0 表示不限制）
+    LibraryQuotaChanged(u32),
+    /// 缓存目录配额变化（MB，0 表示不限制）
+    CacheQuotaChanged(u32),
+    /// 下载后转码格式选择
+    TranscodeFormatSelected(TranscodeFormat),
+    /// JPEG 转码质量变化
+    TranscodeQualityChanged(u32),
+    /// 转码的最小文件大小变化（MB）
+    TranscodeMinSizeChanged(u32),
+    /// 转码后保留原文件开关切换
+    TranscodeKeepOriginalToggled(bool),
+    /// 连接超时秒数变化
+    HttpConnectTimeoutChanged(u32),
+    /// 请求超时秒数变化（0 表示不限制）
+    HttpRequestTimeoutChanged(u32),
+    /// User-Agent 更改
+    HttpUserAgentChanged(String),
+    /// 保存 User-Agent
+    SaveHttpUserAgent,
+    /// 强制使用 HTTP/2 开关切换
+    Http2OnlyToggled(bool),
+    /// 每个主机的空闲连接数变化
+    HttpPoolMaxIdleChanged(u32),
+    /// 空闲连接保留秒数变化
+    HttpPoolIdleTimeoutChanged(u32),
+    /// 文件名模板更改
+    FileNameTemplateChanged(String),
+    /// 保存文件名模板
+    SaveFileNameTemplate,
+    /// 恢复默认文件名模板
+    RestoreDefaultFileNameTemplate,
+    /// 下载完成钩子开关切换
+    PostDownloadHookToggled(bool),
+    /// 下载完成钩子命令更改
+    PostDownloadCommandChanged(String),
+    /// 保存下载完成钩子命令
+    SavePostDownloadCommand,
+    /// 设置壁纸钩子开关切换
+    PostSetHookToggled(bool),
+    /// 设置壁纸钩子命令更改
+    PostSetCommandChanged(String),
+    /// 保存设置壁纸钩子命令
+    SavePostSetCommand,
+    /// 钩子命令超时秒数变化
+    HookTimeoutChanged(u32),
     /// 展开语言选择器
     LanguagePickerExpanded,
     /// 关闭语言选择器
@@ -88,6 +168,10 @@ pub enum SettingsMessage {
     TimeRangePickerExpanded,
     /// 关闭时间范围选择器
     TimeRangePickerDismiss,
+    /// 展开本地定时切换颜色选择器
+    LocalColorPickerExpanded,
+    /// 关闭本地定时切换颜色选择器
+    LocalColorPickerDismiss,
 }
 
 impl From<SettingsMessage> for AppMessage {
@@ -107,6 +191,22 @@ impl App {
             SettingsMessage::OpenUrl(url) => self.settings_open_url(url),
             SettingsMessage::DataPathSelected(path) => self.settings_data_path_selected(path),
             SettingsMessage::CachePathSelected(path) => self.settings_cache_path_selected(path),
+            SettingsMessage::AddLibraryRoot => self.settings_add_library_root(),
+            SettingsMessage::LibraryRootSelected(path) => self.settings_library_root_selected(path),
+            SettingsMessage::RemoveLibraryRoot(index) => self.settings_remove_library_root(index),
+            SettingsMessage::LibraryRootRecursiveToggled(index, enabled) => {
+                self.settings_library_root_recursive_toggled(index, enabled)
+            }
+            SettingsMessage::LibraryRootFollowSymlinksToggled(index, enabled) => {
+                self.settings_library_root_follow_symlinks_toggled(index, enabled)
+            }
+            SettingsMessage::LibraryRootIncludeChanged(index, patterns) => {
+                self.settings_library_root_include_changed(index, patterns)
+            }
+            SettingsMessage::LibraryRootExcludeChanged(index, patterns) => {
+                self.settings_library_root_exclude_changed(index, patterns)
+            }
+            SettingsMessage::SaveLibraryRootPatterns(index) => self.settings_save_library_root_patterns(index),
             SettingsMessage::OpenPath(path_type) => self.settings_open_path(path_type),
             SettingsMessage::OpenLogsPath => self.settings_open_logs_path(),
             SettingsMessage::ShowPathClearConfirmation(path_type) => self.settings_show_path_clear_confirm(path_type),
@@ -134,6 +234,39 @@ impl App {
             SettingsMessage::SaveAutoChangeSorting => self.settings_save_auto_change_sorting(),
             SettingsMessage::AutoChangeTimeRangeChanged(time_range) => self.settings_auto_change_time_range_changed(time_range),
             SettingsMessage::SaveAutoChangeTimeRange => self.settings_save_auto_change_time_range(),
+            SettingsMessage::AutoChangeLocalColorChanged(color) => self.settings_auto_change_local_color_changed(color),
+            SettingsMessage::WriteSidecarToggled(enabled) => self.settings_write_sidecar_toggled(enabled),
+            SettingsMessage::WriteXmpSidecarToggled(enabled) => self.settings_write_xmp_sidecar_toggled(enabled),
+            SettingsMessage::AutoRetryToggled(enabled) => self.settings_auto_retry_toggled(enabled),
+            SettingsMessage::RetryMaxAttemptsChanged(attempts) => self.settings_retry_max_attempts_changed(attempts),
+            SettingsMessage::RetryBaseDelayChanged(seconds) => self.settings_retry_base_delay_changed(seconds),
+            SettingsMessage::RetryMaxDelayChanged(seconds) => self.settings_retry_max_delay_changed(seconds),
+            SettingsMessage::DiskReserveChanged(megabytes) => self.settings_disk_reserve_changed(megabytes),
+            SettingsMessage::LibraryQuotaChanged(megabytes) => self.settings_library_quota_changed(megabytes),
+            SettingsMessage::CacheQuotaChanged(megabytes) => self.settings_cache_quota_changed(megabytes),
+            SettingsMessage::TranscodeFormatSelected(format) => self.settings_transcode_format_selected(format),
+            SettingsMessage::TranscodeQualityChanged(quality) => self.settings_transcode_quality_changed(quality),
+            SettingsMessage::TranscodeMinSizeChanged(megabytes) => self.settings_transcode_min_size_changed(megabytes),
+            SettingsMessage::TranscodeKeepOriginalToggled(enabled) => {
+                self.settings_transcode_keep_original_toggled(enabled)
+            }
+            SettingsMessage::HttpConnectTimeoutChanged(seconds) => self.settings_http_connect_timeout_changed(seconds),
+            SettingsMessage::HttpRequestTimeoutChanged(seconds) => self.settings_http_request_timeout_changed(seconds),
+            SettingsMessage::HttpUserAgentChanged(user_agent) => self.settings_http_user_agent_changed(user_agent),
+            SettingsMessage::SaveHttpUserAgent => self.settings_save_http_user_agent(),
+            SettingsMessage::Http2OnlyToggled(enabled) => self.settings_http2_only_toggled(enabled),
+            SettingsMessage::HttpPoolMaxIdleChanged(connections) => self.settings_http_pool_max_idle_changed(connections),
+            SettingsMessage::HttpPoolIdleTimeoutChanged(seconds) => self.settings_http_pool_idle_timeout_changed(seconds),
+            SettingsMessage::FileNameTemplateChanged(template) => self.settings_file_name_template_changed(template),
+            SettingsMessage::SaveFileNameTemplate => self.settings_save_file_name_template(),
+            SettingsMessage::RestoreDefaultFileNameTemplate => self.settings_restore_default_file_name_template(),
+            SettingsMessage::PostDownloadHookToggled(enabled) => self.settings_post_download_hook_toggled(enabled),
+            SettingsMessage::PostDownloadCommandChanged(command) => self.settings_post_download_command_changed(command),
+            SettingsMessage::SavePostDownloadCommand => self.settings_save_post_download_command(),
+            SettingsMessage::PostSetHookToggled(enabled) => self.settings_post_set_hook_toggled(enabled),
+            SettingsMessage::PostSetCommandChanged(command) => self.settings_post_set_command_changed(command),
+            SettingsMessage::SavePostSetCommand => self.settings_save_post_set_command(),
+            SettingsMessage::HookTimeoutChanged(seconds) => self.settings_hook_timeout_changed(seconds),
             SettingsMessage::LanguagePickerExpanded => self.settings_language_picker_expanded(),
             SettingsMessage::LanguagePickerDismiss => self.settings_language_picker_dismiss(),
             SettingsMessage::ProxyProtocolPickerExpanded => self.settings_proxy_protocol_picker_expanded(),
@@ -144,6 +277,8 @@ impl App {
             SettingsMessage::SortingPickerDismiss => self.settings_sorting_picker_dismiss(),
             SettingsMessage::TimeRangePickerExpanded => self.settings_time_range_picker_expanded(),
             SettingsMessage::TimeRangePickerDismiss => self.settings_time_range_picker_dismiss(),
+            SettingsMessage::LocalColorPickerExpanded => self.settings_local_color_picker_expanded(),
+            SettingsMessage::LocalColorPickerDismiss => self.settings_local_color_picker_dismiss(),
         }
     }
 }
@@ -1,7 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use super::SettingsState;
-use crate::services::wallhaven::{Sorting, TimeRange};
+use crate::services::wallhaven::{self, Sorting, TimeRange};
 use crate::utils::config::Config;
 
 impl SettingsState {
@@ -26,7 +26,13 @@ impl SettingsState {
             proxy_protocol,
             proxy_address,
             proxy_port,
+            library_root_includes: config.data.library_roots.iter().map(|r| r.include.join(", ")).collect(),
+            library_root_excludes: config.data.library_roots.iter().map(|r| r.exclude.join(", ")).collect(),
             wallhaven_api_key: config.wallhaven.api_key.clone(),
+            file_name_template: config.download.file_name_template.clone(),
+            post_download_command: config.hooks.post_download_command.clone(),
+            post_set_command: config.hooks.post_set_command.clone(),
+            http_user_agent: config.http.user_agent.clone(),
             wallpaper_mode: config.wallpaper.mode,
             auto_change_mode: config.wallpaper.auto_change_mode,
             auto_change_interval: config.wallpaper.auto_change_interval,
@@ -36,6 +42,8 @@ impl SettingsState {
             auto_change_time_range,
             sorting_picker_expanded: false,
             time_range_picker_expanded: false,
+            auto_change_local_color: wallhaven::parse_color(&config.wallpaper.auto_change_local_color),
+            local_color_picker_expanded: false,
             show_path_clear_confirmation: false,
             path_to_clear: String::new(),
         }
@@ -1,6 +1,6 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
-use crate::services::wallhaven::{Sorting, TimeRange};
+use crate::services::wallhaven::{ColorOption, Sorting, TimeRange};
 use crate::utils::config::{WallpaperAutoChangeInterval, WallpaperAutoChangeMode, WallpaperMode};
 
 /// 设置页面相关状态
@@ -17,9 +17,23 @@ pub struct SettingsState {
     pub proxy_address: String,
     pub proxy_port: u32,
 
+    // 壁纸库目录过滤模式临时状态（逗号分隔，与配置中的额外壁纸库目录一一对应）
+    pub library_root_includes: Vec<String>,
+    pub library_root_excludes: Vec<String>,
+
     // API 设置临时状态
     pub wallhaven_api_key: String,
 
+    // 下载设置临时状态
+    pub file_name_template: String,
+
+    // 钩子命令临时状态
+    pub post_download_command: String,
+    pub post_set_command: String,
+
+    // 网络设置临时状态
+    pub http_user_agent: String,
+
     // 壁纸设置临时状态
     pub wallpaper_mode: WallpaperMode,
     pub auto_change_mode: WallpaperAutoChangeMode,
@@ -30,6 +44,8 @@ pub struct SettingsState {
     pub auto_change_time_range: TimeRange,
     pub sorting_picker_expanded: bool,
     pub time_range_picker_expanded: bool,
+    pub auto_change_local_color: ColorOption,
+    pub local_color_picker_expanded: bool,
 
     // 对话框状态
     pub show_path_clear_confirmation: bool,
@@ -46,7 +62,13 @@ impl Default for SettingsState {
             proxy_protocol: "http".to_string(),
             proxy_address: String::new(),
             proxy_port: 1080,
+            library_root_includes: Vec::new(),
+            library_root_excludes: Vec::new(),
             wallhaven_api_key: String::new(),
+            file_name_template: crate::services::wallhaven::DEFAULT_FILE_NAME_TEMPLATE.to_string(),
+            post_download_command: String::new(),
+            post_set_command: String::new(),
+            http_user_agent: crate::utils::config::default_http_user_agent(),
             wallpaper_mode: WallpaperMode::default(),
             auto_change_mode: WallpaperAutoChangeMode::default(),
             auto_change_interval: WallpaperAutoChangeInterval::default(),
@@ -56,6 +78,8 @@ impl Default for SettingsState {
             auto_change_time_range: TimeRange::Month,
             sorting_picker_expanded: false,
             time_range_picker_expanded: false,
+            auto_change_local_color: ColorOption::Any,
+            local_color_picker_expanded: false,
             show_path_clear_confirmation: false,
             path_to_clear: String::new(),
         }
@@ -8,7 +8,11 @@ use iced::{Alignment, Element, Length};
 
 pub fn settings_view(app: &App) -> Element<'_, AppMessage> {
     let system_config_section = widget::create_system_config_section(app);
+    let network_config_section = widget::create_network_config_section(app);
     let data_config_section = widget::create_data_config_section(app);
+    let download_config_section = widget::create_download_config_section(app);
+    let transcode_config_section = widget::create_transcode_config_section(app);
+    let hooks_config_section = widget::create_hooks_config_section(app);
     let api_config_section = widget::create_api_config_section(app);
     let wallpaper_config_section = widget::create_wallpaper_config_section(app);
     let about_info_section = widget::create_about_info_section(app);
@@ -16,7 +20,11 @@ pub fn settings_view(app: &App) -> Element<'_, AppMessage> {
     scrollable(
         column![
             system_config_section,
+            network_config_section,
             data_config_section,
+            download_config_section,
+            transcode_config_section,
+            hooks_config_section,
             api_config_section,
             wallpaper_config_section,
             about_info_section,
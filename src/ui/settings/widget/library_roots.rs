@@ -0,0 +1,135 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::common;
+use crate::ui::settings::SettingsMessage;
+use crate::ui::style::{
+    BUTTON_COLOR_BLUE, BUTTON_COLOR_GREEN, BUTTON_COLOR_RED, BUTTON_SPACING, INPUT_PADDING, TEXT_INPUT_SIZE,
+    ThemeColors,
+};
+use crate::ui::{App, AppMessage};
+use crate::utils::config::SymlinkPolicy;
+use crate::utils::helpers;
+use iced::border::{Border, Radius};
+use iced::widget::{Space, container, row, text, text_input, toggler};
+use iced::{Alignment, Color, Element, Length};
+
+/// 创建额外壁纸库目录的设置行
+///
+/// 每个目录占两行：目录选项（递归、符号链接、移除）和过滤模式，最后是添加按钮和说明
+pub fn create_library_roots_rows<'a>(app: &'a App) -> Vec<Element<'a, AppMessage>> {
+    let theme_colors = app.theme_colors;
+    let mut rows = Vec::new();
+
+    for (index, root) in app.config.data.library_roots.iter().enumerate() {
+        rows.push(super::create_setting_row(
+            app.i18n.t("settings.library-root"),
+            row![
+                text_input("", &helpers::get_absolute_path(&root.path))
+                    .width(Length::Fill)
+                    .size(TEXT_INPUT_SIZE)
+                    .padding(INPUT_PADDING)
+                    .style(move |_theme: &iced::Theme, _status| text_input_style(theme_colors)),
+                container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+                toggler(root.recursive)
+                    .on_toggle(move |state| SettingsMessage::LibraryRootRecursiveToggled(index, state).into()),
+                text(app.i18n.t("settings.library-root-recursive")).color(theme_colors.text),
+                container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+                toggler(root.symlinks == SymlinkPolicy::Follow)
+                    .on_toggle(move |state| SettingsMessage::LibraryRootFollowSymlinksToggled(index, state).into()),
+                text(app.i18n.t("settings.library-root-follow-symlinks")).color(theme_colors.text),
+                container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+                common::create_colored_button(
+                    app.i18n.t("settings.library-root-remove"),
+                    BUTTON_COLOR_RED,
+                    SettingsMessage::RemoveLibraryRoot(index).into()
+                ),
+            ]
+            .width(Length::FillPortion(3))
+            .align_y(Alignment::Center)
+            .spacing(0),
+            &app.theme_config,
+        ));
+
+        let include = app
+            .settings_state
+            .library_root_includes
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or_default();
+        let exclude = app
+            .settings_state
+            .library_root_excludes
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or_default();
+
+        rows.push(super::create_setting_row(
+            app.i18n.t("settings.library-root-patterns"),
+            row![
+                text_input(&app.i18n.t("settings.library-root-include-placeholder"), include)
+                    .width(Length::Fill)
+                    .size(TEXT_INPUT_SIZE)
+                    .on_input(move |s| SettingsMessage::LibraryRootIncludeChanged(index, s).into())
+                    .on_submit(SettingsMessage::SaveLibraryRootPatterns(index).into())
+                    .padding(INPUT_PADDING)
+                    .style(move |_theme: &iced::Theme, _status| text_input_style(theme_colors)),
+                container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+                text_input(&app.i18n.t("settings.library-root-exclude-placeholder"), exclude)
+                    .width(Length::Fill)
+                    .size(TEXT_INPUT_SIZE)
+                    .on_input(move |s| SettingsMessage::LibraryRootExcludeChanged(index, s).into())
+                    .on_submit(SettingsMessage::SaveLibraryRootPatterns(index).into())
+                    .padding(INPUT_PADDING)
+                    .style(move |_theme: &iced::Theme, _status| text_input_style(theme_colors)),
+                container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+                common::create_colored_button(
+                    app.i18n.t("settings.save"),
+                    BUTTON_COLOR_BLUE,
+                    SettingsMessage::SaveLibraryRootPatterns(index).into()
+                ),
+            ]
+            .width(Length::FillPortion(3))
+            .align_y(Alignment::Center)
+            .spacing(0),
+            &app.theme_config,
+        ));
+    }
+
+    rows.push(super::create_setting_row(
+        app.i18n.t("settings.library-roots"),
+        row![
+            Space::new().width(Length::Fill),
+            common::create_colored_button(
+                app.i18n.t("settings.add-library-root"),
+                BUTTON_COLOR_GREEN,
+                SettingsMessage::AddLibraryRoot.into()
+            ),
+        ]
+        .width(Length::FillPortion(3))
+        .align_y(Alignment::Center),
+        &app.theme_config,
+    ));
+    rows.push(
+        text(app.i18n.t("settings.library-roots-help"))
+            .size(12)
+            .color(theme_colors.light_text_sub)
+            .into(),
+    );
+
+    rows
+}
+
+fn text_input_style(theme_colors: ThemeColors) -> text_input::Style {
+    text_input::Style {
+        background: iced::Background::Color(theme_colors.text_input_background),
+        border: Border {
+            color: Color::TRANSPARENT,
+            width: 0.0,
+            radius: Radius::from(4.0),
+        },
+        icon: theme_colors.light_text_sub,
+        placeholder: theme_colors.light_text_sub,
+        value: theme_colors.light_text,
+        selection: theme_colors.text_input_selection_color,
+    }
+}
@@ -4,13 +4,18 @@ mod about_link;
 mod config_section;
 mod info_row;
 mod language_picker;
+mod library_roots;
 mod logs_path;
 mod path_config;
 mod proxy_protocol_picker;
 mod section_about_info;
 mod section_api_config;
 mod section_data_config;
+mod section_download_config;
+mod section_hooks_config;
+mod section_network_config;
 mod section_system_config;
+mod section_transcode_config;
 mod section_wallpaper_config;
 mod setting_row;
 mod settings_sorting_picker;
@@ -19,14 +24,18 @@ mod theme_picker;
 
 use {
     about_link::create_about_link_row, config_section::create_config_section, info_row::create_info_row,
-    language_picker::create_language_picker, logs_path::create_logs_path_row, path_config::create_path_config_row,
-    proxy_protocol_picker::create_proxy_protocol_picker, setting_row::create_setting_row,
-    settings_sorting_picker::create_sorting_picker, settings_time_range_picker::create_time_range_picker,
-    theme_picker::create_theme_picker,
+    language_picker::create_language_picker, library_roots::create_library_roots_rows, logs_path::create_logs_path_row,
+    path_config::create_path_config_row,
+    proxy_protocol_picker::create_proxy_protocol_picker, section_download_config::create_number_input,
+    setting_row::create_setting_row, settings_sorting_picker::create_sorting_picker,
+    settings_time_range_picker::create_time_range_picker, theme_picker::create_theme_picker,
 };
 
 pub(in crate::ui::settings) use {
     section_about_info::create_about_info_section, section_api_config::create_api_config_section,
-    section_data_config::create_data_config_section, section_system_config::create_system_config_section,
+    section_data_config::create_data_config_section, section_download_config::create_download_config_section,
+    section_hooks_config::create_hooks_config_section, section_network_config::create_network_config_section,
+    section_system_config::create_system_config_section,
+    section_transcode_config::create_transcode_config_section,
     section_wallpaper_config::create_wallpaper_config_section,
 };
@@ -0,0 +1,247 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::services::wallhaven::{self, OnlineWallpaper};
+use crate::ui::common;
+use crate::ui::settings::SettingsMessage;
+use crate::ui::style::{
+    BUTTON_COLOR_BLUE, BUTTON_COLOR_GRAY, BUTTON_SPACING, INPUT_PADDING, TEXT_INPUT_SIZE, ThemeColors,
+};
+use crate::ui::{App, AppMessage};
+use iced::border::{Border, Radius};
+use iced::widget::{Space, column, container, row, text, text_input, toggler};
+use iced::{Alignment, Color, Element, Length};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+/// 创建下载配置区块
+pub fn create_download_config_section<'a>(app: &'a App) -> Element<'a, AppMessage> {
+    let theme_colors = app.theme_colors;
+    let download_config = &app.config.download;
+    super::create_config_section(
+        app.i18n.t("settings.download-config"),
+        vec![
+            super::create_setting_row(
+                app.i18n.t("settings.file-name-template"),
+                create_file_name_template_input(app),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.write-sidecar"),
+                toggler(download_config.write_sidecar)
+                    .on_toggle(|state| SettingsMessage::WriteSidecarToggled(state).into()),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.write-xmp-sidecar"),
+                // 只有写入元数据文件时才能开启 XMP 文件
+                toggler(download_config.write_sidecar && download_config.write_xmp_sidecar).on_toggle_maybe(
+                    download_config
+                        .write_sidecar
+                        .then_some(|state| SettingsMessage::WriteXmpSidecarToggled(state).into()),
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.auto-retry"),
+                toggler(download_config.auto_retry_enabled)
+                    .on_toggle(|state| SettingsMessage::AutoRetryToggled(state).into()),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.retry-max-attempts"),
+                create_number_input(
+                    &download_config.retry_max_attempts,
+                    1..=20,
+                    |n| SettingsMessage::RetryMaxAttemptsChanged(n).into(),
+                    app.i18n.t("settings.times"),
+                    theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.retry-base-delay"),
+                create_number_input(
+                    &download_config.retry_base_delay_secs,
+                    1..=3600,
+                    |n| SettingsMessage::RetryBaseDelayChanged(n).into(),
+                    app.i18n.t("settings.seconds"),
+                    theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.retry-max-delay"),
+                create_number_input(
+                    &download_config.retry_max_delay_secs,
+                    1..=86400,
+                    |n| SettingsMessage::RetryMaxDelayChanged(n).into(),
+                    app.i18n.t("settings.seconds"),
+                    theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.disk-reserve"),
+                create_number_input(
+                    &download_config.disk_reserve_mb,
+                    0..=1_048_576,
+                    |n| SettingsMessage::DiskReserveChanged(n).into(),
+                    app.i18n.t("settings.megabytes"),
+                    theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.library-quota"),
+                create_number_input(
+                    &download_config.library_quota_mb,
+                    0..=10_485_760,
+                    |n| SettingsMessage::LibraryQuotaChanged(n).into(),
+                    app.i18n.t("settings.megabytes"),
+                    theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.cache-quota"),
+                create_number_input(
+                    &download_config.cache_quota_mb,
+                    0..=10_485_760,
+                    |n| SettingsMessage::CacheQuotaChanged(n).into(),
+                    app.i18n.t("settings.megabytes"),
+                    theme_colors,
+                ),
+                &app.theme_config,
+            ),
+        ],
+        &app.theme_config,
+    )
+}
+
+/// 创建文件名模板输入框（带实时预览和占位符说明）
+fn create_file_name_template_input<'a>(app: &'a App) -> Element<'a, AppMessage> {
+    let theme_colors = app.theme_colors;
+
+    // 使用示例壁纸渲染模板，实时预览生成的保存路径
+    let preview_path = PathBuf::from(&app.config.data.data_path).join(wallhaven::render_file_template(
+        &app.settings_state.file_name_template,
+        &preview_wallpaper(),
+    ));
+    let preview_text = format!(
+        "{} {}",
+        app.i18n.t("settings.file-name-template-preview"),
+        preview_path.display()
+    );
+
+    column![
+        row![
+            text_input(
+                wallhaven::DEFAULT_FILE_NAME_TEMPLATE,
+                &app.settings_state.file_name_template
+            )
+            .width(Length::Fill)
+            .size(TEXT_INPUT_SIZE)
+            .on_input(|s| SettingsMessage::FileNameTemplateChanged(s).into())
+            .on_submit(SettingsMessage::SaveFileNameTemplate.into())
+            .padding(INPUT_PADDING)
+            .style(move |_theme: &iced::Theme, _status| text_input::Style {
+                background: iced::Background::Color(theme_colors.text_input_background),
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.0,
+                    radius: Radius::from(4.0),
+                },
+                icon: theme_colors.light_text_sub,
+                placeholder: theme_colors.light_text_sub,
+                value: theme_colors.light_text,
+                selection: theme_colors.text_input_selection_color,
+            }),
+            container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+            common::create_colored_button(
+                app.i18n.t("settings.save"),
+                BUTTON_COLOR_BLUE,
+                SettingsMessage::SaveFileNameTemplate.into()
+            ),
+            container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+            common::create_colored_button(
+                app.i18n.t("settings.restore-default"),
+                BUTTON_COLOR_GRAY,
+                SettingsMessage::RestoreDefaultFileNameTemplate.into()
+            ),
+        ]
+        .align_y(Alignment::Center)
+        .spacing(0),
+        text(preview_text).size(12).color(theme_colors.light_text),
+        text(app.i18n.t("settings.file-name-template-placeholders"))
+            .size(12)
+            .color(theme_colors.light_text_sub),
+    ]
+    .width(Length::FillPortion(3))
+    .spacing(4)
+    .into()
+}
+
+/// 文件名模板预览使用的示例壁纸
+fn preview_wallpaper() -> OnlineWallpaper {
+    OnlineWallpaper {
+        id: "8586my".to_string(),
+        url: String::new(),
+        path: String::new(),
+        thumb_large: String::new(),
+        thumb_original: String::new(),
+        thumb_small: String::new(),
+        width: 3840,
+        height: 2160,
+        resolution: "3840x2160".to_string(),
+        ratio: "1.78".to_string(),
+        file_size: 0,
+        file_type: "image/jpeg".to_string(),
+        category: "general".to_string(),
+        purity: "sfw".to_string(),
+        views: 0,
+        favorites: 0,
+        colors: vec!["#424153".to_string(), "#66cccc".to_string()],
+        tags: vec!["landscape".to_string(), "nature".to_string()],
+        created_at: "2024-01-01 12:00:00".to_string(),
+        uploader: "wallhaven".to_string(),
+        image_handle: None,
+    }
+}
+
+/// 创建带单位的数字输入框
+pub(super) fn create_number_input<'a>(
+    value: &'a u32,
+    bounds: RangeInclusive<u32>,
+    on_change: impl Fn(u32) -> AppMessage + Copy + 'static,
+    unit: String,
+    theme_colors: ThemeColors,
+) -> Element<'a, AppMessage> {
+    container(
+        row![
+            iced_aw::NumberInput::new(value, bounds, on_change)
+                .width(Length::Fill)
+                .padding(INPUT_PADDING)
+                .input_style(move |_theme: &iced::Theme, _status| text_input::Style {
+                    background: iced::Background::Color(theme_colors.text_input_background),
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        width: 0.0,
+                        radius: Radius::from(4.0),
+                    },
+                    icon: theme_colors.light_text_sub,
+                    placeholder: theme_colors.light_text_sub,
+                    value: theme_colors.light_text,
+                    selection: theme_colors.text_input_selection_color,
+                })
+                .style(move |_theme: &iced::Theme, _status| iced_aw::number_input::Style {
+                    button_background: Some(iced::Background::Color(theme_colors.text_input_background)),
+                    icon_color: theme_colors.light_text_sub,
+                }),
+            text(unit).size(14).color(theme_colors.light_text),
+        ]
+        .spacing(4)
+        .align_y(Alignment::Center),
+    )
+    .width(Length::Fixed(140.0))
+    .into()
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::common;
+use crate::ui::settings::SettingsMessage;
+use crate::ui::style::{BUTTON_COLOR_BLUE, BUTTON_SPACING, INPUT_PADDING, TEXT_INPUT_SIZE};
+use crate::ui::{App, AppMessage};
+use iced::border::{Border, Radius};
+use iced::widget::{Space, container, row, text, text_input, toggler};
+use iced::{Alignment, Color, Element, Length};
+
+/// 创建钩子命令配置区块
+pub fn create_hooks_config_section<'a>(app: &'a App) -> Element<'a, AppMessage> {
+    let hooks_config = &app.config.hooks;
+    super::create_config_section(
+        app.i18n.t("settings.hooks-config"),
+        vec![
+            super::create_setting_row(
+                app.i18n.t("settings.post-download-hook"),
+                create_hook_command_input(
+                    app,
+                    hooks_config.post_download_enabled,
+                    &app.settings_state.post_download_command,
+                    |state| SettingsMessage::PostDownloadHookToggled(state).into(),
+                    |s| SettingsMessage::PostDownloadCommandChanged(s).into(),
+                    SettingsMessage::SavePostDownloadCommand.into(),
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.post-set-hook"),
+                create_hook_command_input(
+                    app,
+                    hooks_config.post_set_enabled,
+                    &app.settings_state.post_set_command,
+                    |state| SettingsMessage::PostSetHookToggled(state).into(),
+                    |s| SettingsMessage::PostSetCommandChanged(s).into(),
+                    SettingsMessage::SavePostSetCommand.into(),
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.hook-timeout"),
+                super::create_number_input(
+                    &hooks_config.timeout_secs,
+                    1..=3600,
+                    |n| SettingsMessage::HookTimeoutChanged(n).into(),
+                    app.i18n.t("settings.seconds"),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            text(app.i18n.t("settings.hook-command-help"))
+                .size(12)
+                .color(app.theme_colors.light_text_sub)
+                .into(),
+        ],
+        &app.theme_config,
+    )
+}
+
+/// 创建钩子命令输入行（开关 + 命令输入框 + 保存按钮）
+fn create_hook_command_input<'a>(
+    app: &'a App,
+    enabled: bool,
+    command: &'a str,
+    on_toggle: impl Fn(bool) -> AppMessage + 'a,
+    on_input: impl Fn(String) -> AppMessage + 'a,
+    on_save: AppMessage,
+) -> Element<'a, AppMessage> {
+    let theme_colors = app.theme_colors;
+
+    row![
+        toggler(enabled).on_toggle(on_toggle),
+        container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+        text_input(&app.i18n.t("settings.hook-command-placeholder"), command)
+            .width(Length::Fill)
+            .size(TEXT_INPUT_SIZE)
+            .on_input(on_input)
+            .on_submit(on_save.clone())
+            .padding(INPUT_PADDING)
+            .style(move |_theme: &iced::Theme, _status| text_input::Style {
+                background: iced::Background::Color(theme_colors.text_input_background),
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    width: 0.0,
+                    radius: Radius::from(4.0),
+                },
+                icon: theme_colors.light_text_sub,
+                placeholder: theme_colors.light_text_sub,
+                value: theme_colors.light_text,
+                selection: theme_colors.text_input_selection_color,
+            }),
+        container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+        common::create_colored_button(app.i18n.t("settings.save"), BUTTON_COLOR_BLUE, on_save),
+    ]
+    .width(Length::FillPortion(3))
+    .align_y(Alignment::Center)
+    .spacing(0)
+    .into()
+}
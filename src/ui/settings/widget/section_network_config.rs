@@ -0,0 +1,111 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::common;
+use crate::ui::settings::SettingsMessage;
+use crate::ui::style::{BUTTON_COLOR_BLUE, BUTTON_SPACING, INPUT_PADDING, TEXT_INPUT_SIZE};
+use crate::ui::{App, AppMessage};
+use iced::border::{Border, Radius};
+use iced::widget::{Space, container, row, text, text_input, toggler};
+use iced::{Alignment, Color, Element, Length};
+
+/// 创建网络（共享 HTTP 客户端）配置区块
+pub fn create_network_config_section<'a>(app: &'a App) -> Element<'a, AppMessage> {
+    let http_config = &app.config.http;
+    let theme_colors = app.theme_colors;
+
+    super::create_config_section(
+        app.i18n.t("settings.network-config"),
+        vec![
+            super::create_setting_row(
+                app.i18n.t("settings.http-connect-timeout"),
+                super::create_number_input(
+                    &http_config.connect_timeout_secs,
+                    1..=300,
+                    |n| SettingsMessage::HttpConnectTimeoutChanged(n).into(),
+                    app.i18n.t("settings.seconds"),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.http-request-timeout"),
+                super::create_number_input(
+                    &http_config.request_timeout_secs,
+                    0..=3600,
+                    |n| SettingsMessage::HttpRequestTimeoutChanged(n).into(),
+                    app.i18n.t("settings.seconds"),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.http-user-agent"),
+                row![
+                    text_input(
+                        &app.i18n.t("settings.http-user-agent-placeholder"),
+                        &app.settings_state.http_user_agent
+                    )
+                    .width(Length::Fill)
+                    .size(TEXT_INPUT_SIZE)
+                    .on_input(|s| SettingsMessage::HttpUserAgentChanged(s).into())
+                    .on_submit(SettingsMessage::SaveHttpUserAgent.into())
+                    .padding(INPUT_PADDING)
+                    .style(move |_theme: &iced::Theme, _status| text_input::Style {
+                        background: iced::Background::Color(theme_colors.text_input_background),
+                        border: Border {
+                            color: Color::TRANSPARENT,
+                            width: 0.0,
+                            radius: Radius::from(4.0),
+                        },
+                        icon: theme_colors.light_text_sub,
+                        placeholder: theme_colors.light_text_sub,
+                        value: theme_colors.light_text,
+                        selection: theme_colors.text_input_selection_color,
+                    }),
+                    container(Space::new()).width(Length::Fixed(BUTTON_SPACING)),
+                    common::create_colored_button(
+                        app.i18n.t("settings.save"),
+                        BUTTON_COLOR_BLUE,
+                        SettingsMessage::SaveHttpUserAgent.into()
+                    ),
+                ]
+                .width(Length::FillPortion(3))
+                .align_y(Alignment::Center)
+                .spacing(0),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.http2-only"),
+                toggler(http_config.http2_only).on_toggle(|state| SettingsMessage::Http2OnlyToggled(state).into()),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.http-pool-max-idle"),
+                super::create_number_input(
+                    &http_config.pool_max_idle_per_host,
+                    0..=100,
+                    |n| SettingsMessage::HttpPoolMaxIdleChanged(n).into(),
+                    String::new(),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.http-pool-idle-timeout"),
+                super::create_number_input(
+                    &http_config.pool_idle_timeout_secs,
+                    1..=3600,
+                    |n| SettingsMessage::HttpPoolIdleTimeoutChanged(n).into(),
+                    app.i18n.t("settings.seconds"),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            text(app.i18n.t("settings.network-help"))
+                .size(12)
+                .color(app.theme_colors.light_text_sub)
+                .into(),
+        ],
+        &app.theme_config,
+    )
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2026 zsyo - GNU AGPL v3.0
+
+use crate::ui::settings::SettingsMessage;
+use crate::ui::style::ROW_SPACING;
+use crate::ui::{App, AppMessage};
+use crate::utils::config::TranscodeFormat;
+use iced::widget::{radio, row, text, toggler};
+use iced::{Color, Element};
+
+/// 创建下载后转码配置区块
+pub fn create_transcode_config_section<'a>(app: &'a App) -> Element<'a, AppMessage> {
+    let transcode_config = &app.config.transcode;
+    let theme_colors = app.theme_colors;
+
+    let format_radio = |label: String, format: TranscodeFormat| {
+        radio(label, format, Some(transcode_config.format), |format| {
+            SettingsMessage::TranscodeFormatSelected(format).into()
+        })
+        .style(move |theme: &iced::Theme, status| radio::Style {
+            text_color: Some(theme_colors.text),
+            background: iced::Background::Color(Color::TRANSPARENT),
+            ..radio::default(theme, status)
+        })
+    };
+
+    super::create_config_section(
+        app.i18n.t("settings.transcode-config"),
+        vec![
+            super::create_setting_row(
+                app.i18n.t("settings.transcode-format"),
+                row![
+                    format_radio(app.i18n.t("transcode-format-options.off"), TranscodeFormat::Off),
+                    format_radio(app.i18n.t("transcode-format-options.jpeg"), TranscodeFormat::Jpeg),
+                    format_radio(app.i18n.t("transcode-format-options.webp"), TranscodeFormat::Webp),
+                    format_radio(
+                        app.i18n.t("transcode-format-options.webp-lossless"),
+                        TranscodeFormat::WebpLossless
+                    ),
+                ]
+                .spacing(ROW_SPACING),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.transcode-quality"),
+                super::create_number_input(
+                    &transcode_config.quality,
+                    1..=100,
+                    |n| SettingsMessage::TranscodeQualityChanged(n).into(),
+                    String::new(),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.transcode-min-size"),
+                super::create_number_input(
+                    &transcode_config.min_size_mb,
+                    0..=1024,
+                    |n| SettingsMessage::TranscodeMinSizeChanged(n).into(),
+                    app.i18n.t("settings.megabytes"),
+                    app.theme_colors,
+                ),
+                &app.theme_config,
+            ),
+            super::create_setting_row(
+                app.i18n.t("settings.transcode-keep-original"),
+                toggler(transcode_config.keep_original)
+                    .on_toggle(|state| SettingsMessage::TranscodeKeepOriginalToggled(state).into()),
+                &app.theme_config,
+            ),
+            text(app.i18n.t("settings.transcode-help"))
+                .size(12)
+                .color(app.theme_colors.light_text_sub)
+                .into(),
+        ],
+        &app.theme_config,
+    )
+}
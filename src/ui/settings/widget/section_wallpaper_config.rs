@@ -258,6 +258,26 @@ pub fn create_wallpaper_config_section<'a>(app: &'a App) -> Element<'a, AppMessa
                 .spacing(ROW_SPACING),
                 &app.theme_config,
             ),
+            super::create_setting_row(
+                app.i18n.t("settings.auto-change-local-config"),
+                row![
+                    common::create_color_picker(
+                        app.i18n.t("settings.auto-change-local-color"),
+                        app.settings_state.auto_change_local_color,
+                        app.settings_state.local_color_picker_expanded,
+                        SettingsMessage::LocalColorPickerExpanded.into(),
+                        SettingsMessage::LocalColorPickerDismiss.into(),
+                        |color| SettingsMessage::AutoChangeLocalColorChanged(color).into(),
+                        theme_colors,
+                    ),
+                    text(app.i18n.t("settings.auto-change-local-color-hint"))
+                        .size(14)
+                        .color(theme_colors.light_text_sub),
+                ]
+                .spacing(ROW_SPACING)
+                .align_y(Alignment::Center),
+                &app.theme_config,
+            ),
         ],
         &app.theme_config,
     )
@@ -1,9 +1,11 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
 use super::{App, AppMessage};
+use crate::services::disk_space::DIRECTORY_USAGE_REFRESH_INTERVAL;
 use crate::ui::download::DownloadMessage;
+use crate::ui::local::LocalMessage;
 use crate::ui::main::MainMessage;
-use iced::{Event, Subscription, event, window};
+use iced::{Event, Subscription, event, keyboard, window};
 use std::time::Duration;
 
 // 用于下载进度订阅的唯一类型标识
@@ -33,6 +35,32 @@ impl App {
             Subscription::none()
         };
 
+        // 下载任务自动重试倒计时
+        let download_auto_retry = if self.download_state.has_pending_retries() {
+            iced::time::every(Duration::from_secs(1)).map(|_| DownloadMessage::AutoRetryTick.into())
+        } else {
+            Subscription::none()
+        };
+
+        // 下载吞吐量采样（有下载中的任务或还有未采样的字节时运行）
+        let download_throughput = if self.download_state.get_downloading_count() > 0
+            || self.download_state.statistics.bytes_since_sample > 0
+        {
+            iced::time::every(Duration::from_secs(1)).map(|_| DownloadMessage::ThroughputTick.into())
+        } else {
+            Subscription::none()
+        };
+
+        // 有下载中的任务且设置了目录配额时定时重新计算目录占用空间
+        let download_config = &self.config.download;
+        let directory_usage_refresh = if self.download_state.get_downloading_count() > 0
+            && (download_config.library_quota_mb > 0 || download_config.cache_quota_mb > 0)
+        {
+            iced::time::every(DIRECTORY_USAGE_REFRESH_INTERVAL).map(|_| DownloadMessage::RefreshDirectoryUsage.into())
+        } else {
+            Subscription::none()
+        };
+
         Subscription::batch(vec![
             // 窗口事件监听
             event::listen_with(|event, _status, _loop_status| match event {
@@ -41,6 +69,10 @@ impl App {
                 }
                 Event::Window(window::Event::CloseRequested) => Some(MainMessage::WindowCloseRequested.into()),
                 Event::Window(window::Event::Focused) => Some(MainMessage::WindowFocused.into()),
+                // 本地壁纸页面按住 Shift 点击时范围选择
+                Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                    Some(LocalMessage::ModifiersChanged(modifiers).into())
+                }
                 _ => None,
             }),
             // 托盘事件监听
@@ -70,34 +102,34 @@ impl App {
             auto_change_background,
             // 添加自动检测颜色模式定时器
             auto_detect_color_mode,
+            // 添加下载任务自动重试定时器
+            download_auto_retry,
+            // 添加下载吞吐量采样定时器
+            download_throughput,
+            // 添加目录占用空间计算定时器
+            directory_usage_refresh,
+            // 添加壁纸库文件监听（壁纸库目录配置变化时重新创建监听器）
+            Subscription::run_with(self.config.data.library_roots(), |roots| {
+                Self::create_library_watch_stream(roots.clone())
+            }),
             // 添加下载进度监听 - 使用run_with
             Subscription::run_with(DownloadProgressSubscription, |_state| {
-                // 初始化下载进度channel
-                crate::services::init_download_progress_channel();
-
-                // 获取channel接收器
-                let rx = if let Some(tx) = crate::services::DOWNLOAD_PROGRESS_TX.get() {
-                    Some(tx.subscribe())
-                } else {
-                    None
-                };
+                use crate::services::download_progress::{DOWNLOAD_PROGRESS, PROGRESS_FRAME_INTERVAL};
 
                 async_stream::stream! {
-                    if let Some(mut rx) = rx {
-                        loop {
-                            match rx.recv().await {
-                                Ok(update) => {
-                                    yield DownloadMessage::DownloadProgress(update.task_id,update.downloaded,update.total,update.speed).into();
-                                }
-                                Err(_) => {
-                                    // Channel关闭，退出循环
-                                    break;
-                                }
-                            }
+                    // 每个任务的进度接收器
+                    let mut receivers = std::collections::HashMap::new();
+
+                    loop {
+                        // 等待任务报告进度，再按固定帧率把这段时间内所有任务的最新进度合并为一条消息
+                        DOWNLOAD_PROGRESS.wait_for_update().await;
+
+                        let updates = DOWNLOAD_PROGRESS.collect_updates(&mut receivers);
+                        if !updates.is_empty() {
+                            yield DownloadMessage::DownloadProgress(updates).into();
                         }
-                    } else {
-                        // 如果channel未初始化，返回空stream
-                        std::future::pending::<()>().await;
+
+                        tokio::time::sleep(PROGRESS_FRAME_INTERVAL).await;
                     }
                 }
             }),
@@ -2,11 +2,18 @@
 
 use super::common;
 use super::{App, AppMessage, NotificationType};
+use crate::ui::download::{DownloadMessage, DuplicateAction};
 use crate::ui::main::{MainMessage, close_confirm_view, main_view};
 use crate::ui::settings::SettingsMessage;
 use crate::ui::style;
-use iced::widget::{container, stack, text};
-use iced::{Element, Length, Task};
+use iced::widget::{button, container, row, stack, text};
+use iced::{Alignment, Element, Length, Task};
+use std::time::Duration;
+
+/// 通知的显示时间
+const NOTIFICATION_DURATION: Duration = Duration::from_secs(3);
+/// 带操作按钮的通知的显示时间（留出点击按钮的时间）
+const ACTION_NOTIFICATION_DURATION: Duration = Duration::from_secs(8);
 
 impl App {
     pub fn view(&self) -> Element<'_, AppMessage> {
@@ -18,6 +25,8 @@ impl App {
             Self::create_stack(base_content, close_confirm_view(self))
         } else if self.settings_state.show_path_clear_confirmation {
             Self::create_stack(base_content, self.path_clear_confirmation_view())
+        } else if self.download_state.pending_duplicate.is_some() {
+            Self::create_stack(base_content, self.download_duplicate_view())
         } else {
             base_content
         };
@@ -53,6 +62,40 @@ impl App {
         )
     }
 
+    // 渲染重复下载处理对话框
+    fn download_duplicate_view(&self) -> iced::Element<'_, AppMessage> {
+        let existing_path = self
+            .download_state
+            .pending_duplicate
+            .as_ref()
+            .map(|pending| pending.existing_path.display().to_string())
+            .unwrap_or_default();
+
+        let message_text = format!("{}\n{}", self.i18n.t("download-duplicate.message"), existing_path);
+
+        common::create_choice_dialog(
+            self.i18n.t("download-duplicate.title"),
+            message_text,
+            vec![
+                (
+                    self.i18n.t("download-duplicate.skip"),
+                    style::BUTTON_COLOR_GRAY,
+                    DownloadMessage::ResolveDuplicate(DuplicateAction::Skip).into(),
+                ),
+                (
+                    self.i18n.t("download-duplicate.redownload"),
+                    style::BUTTON_COLOR_RED,
+                    DownloadMessage::ResolveDuplicate(DuplicateAction::Redownload).into(),
+                ),
+                (
+                    self.i18n.t("download-duplicate.keep-both"),
+                    style::BUTTON_COLOR_BLUE,
+                    DownloadMessage::ResolveDuplicate(DuplicateAction::KeepBoth).into(),
+                ),
+            ],
+        )
+    }
+
     // 渲染通知组件
     fn notification_view(&self) -> iced::Element<'_, AppMessage> {
         // 根据通知类型设置颜色
@@ -62,23 +105,49 @@ impl App {
             NotificationType::Info => (style::NOTIFICATION_INFO_BG, style::NOTIFICATION_TEXT_COLOR),
         };
 
-        let notification_content = container(text(&self.main_state.notification_message).size(14).style(
-            move |_theme| text::Style {
+        let message = text(&self.main_state.notification_message)
+            .size(14)
+            .style(move |_theme| text::Style {
                 color: Some(text_color),
-            },
-        ))
-        .padding(10)
-        .width(Length::Shrink)
-        .height(Length::Shrink)
-        .style(move |_theme| container::Style {
-            background: Some(iced::Background::Color(bg_color)),
-            border: iced::border::Border {
-                radius: iced::border::Radius::from(8.0),
-                width: 1.0,
-                color: iced::Color::TRANSPARENT,
-            },
-            ..Default::default()
-        });
+            });
+        let content: Element<'_, AppMessage> = match &self.main_state.notification_action {
+            Some((label, action)) => row![
+                message,
+                button(text(label).size(14))
+                    .padding([2, 10])
+                    .on_press(action.clone())
+                    .style(move |_theme, status| button::Style {
+                        background: Some(iced::Background::Color(match status {
+                            button::Status::Hovered => iced::Color::from_rgba(1.0, 1.0, 1.0, 0.25),
+                            _ => iced::Color::from_rgba(1.0, 1.0, 1.0, 0.15),
+                        })),
+                        text_color,
+                        border: iced::border::Border {
+                            radius: iced::border::Radius::from(4.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }),
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center)
+            .into(),
+            None => message.into(),
+        };
+
+        let notification_content = container(content)
+            .padding(10)
+            .width(Length::Shrink)
+            .height(Length::Shrink)
+            .style(move |_theme| container::Style {
+                background: Some(iced::Background::Color(bg_color)),
+                border: iced::border::Border {
+                    radius: iced::border::Radius::from(8.0),
+                    width: 1.0,
+                    color: iced::Color::TRANSPARENT,
+                },
+                ..Default::default()
+            });
 
         // 将通知放在窗口底部中央
         container(
@@ -113,6 +182,28 @@ impl App {
 
     // 辅助方法：显示通知
     pub fn show_notification(&mut self, message: String, notification_type: NotificationType) -> Task<AppMessage> {
+        self.main_state.notification_action = None;
+        self.show_notification_for(message, notification_type, NOTIFICATION_DURATION)
+    }
+
+    // 辅助方法：显示带操作按钮的通知（例如撤销删除），显示时间比普通通知更长
+    pub fn show_notification_with_action(
+        &mut self,
+        message: String,
+        notification_type: NotificationType,
+        action_label: String,
+        action: AppMessage,
+    ) -> Task<AppMessage> {
+        self.main_state.notification_action = Some((action_label, action));
+        self.show_notification_for(message, notification_type, ACTION_NOTIFICATION_DURATION)
+    }
+
+    fn show_notification_for(
+        &mut self,
+        message: String,
+        notification_type: NotificationType,
+        duration: Duration,
+    ) -> Task<AppMessage> {
         self.main_state.notification_message = message;
         self.main_state.notification_type = notification_type;
         self.main_state.show_notification = true;
@@ -122,7 +213,7 @@ impl App {
 
         Task::perform(
             async move {
-                tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+                tokio::time::sleep(duration).await;
                 current_version
             },
             |version| MainMessage::HideNotificationWithVersion(version).into(),
@@ -1,5 +1,7 @@
 // Copyright (C) 2026 zsyo - GNU AGPL v3.0
 
+use crate::services::wallhaven::AspectRatioGroup;
+use crate::utils::helpers;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -23,6 +25,16 @@ pub struct Config {
     pub wallhaven: WallhavenConfig,
     #[serde(default)]
     pub wallpaper: WallpaperConfig,
+    #[serde(default)]
+    pub download: DownloadConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub transcode: TranscodeConfig,
+    #[serde(default)]
+    pub http: HttpConfig,
+    #[serde(default)]
+    pub local: LocalConfig,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -68,6 +80,9 @@ pub struct DataConfig {
     pub data_path: String,
     #[serde(default = "default_cache_path")]
     pub cache_path: String,
+    /// 额外的壁纸库目录（数据目录始终是第一个壁纸库目录）
+    #[serde(default)]
+    pub library_roots: Vec<LibraryRoot>,
 }
 
 impl Default for DataConfig {
@@ -75,10 +90,77 @@ impl Default for DataConfig {
         Self {
             data_path: default_data_path(),
             cache_path: default_cache_path(),
+            library_roots: Vec::new(),
         }
     }
 }
 
+impl DataConfig {
+    /// 本地壁纸页面和本地定时切换扫描的全部壁纸库目录
+    ///
+    /// 数据目录排在最前面（递归扫描，不过滤）；额外目录中已包含数据目录时使用该目录的配置
+    pub fn library_roots(&self) -> Vec<LibraryRoot> {
+        let data_path = helpers::get_absolute_path(&self.data_path);
+        let data_root_configured = self
+            .library_roots
+            .iter()
+            .any(|root| helpers::get_absolute_path(&root.path) == data_path);
+
+        let mut roots = Vec::with_capacity(self.library_roots.len() + 1);
+        if !data_root_configured {
+            roots.push(LibraryRoot::new(self.data_path.clone()));
+        }
+        roots.extend(self.library_roots.iter().cloned());
+        roots
+    }
+}
+
+/// 壁纸库目录（扫描逻辑见 [`crate::services::library`]）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryRoot {
+    /// 目录路径
+    pub path: String,
+    /// 是否扫描子目录
+    #[serde(default = "default_library_recursive")]
+    pub recursive: bool,
+    /// 只包含匹配这些模式的文件（为空时包含全部图片）
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// 排除匹配这些模式的文件和目录
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// 符号链接的处理方式
+    #[serde(default)]
+    pub symlinks: SymlinkPolicy,
+}
+
+impl LibraryRoot {
+    pub fn new(path: String) -> Self {
+        Self {
+            path,
+            recursive: default_library_recursive(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            symlinks: SymlinkPolicy::default(),
+        }
+    }
+}
+
+fn default_library_recursive() -> bool {
+    true
+}
+
+/// 扫描壁纸库时符号链接的处理方式
+#[derive(Clone, Serialize, Deserialize, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SymlinkPolicy {
+    /// 跟随符号链接（指向已扫描目录的链接会被跳过，避免循环）
+    #[default]
+    Follow,
+    /// 忽略符号链接
+    Skip,
+}
+
 fn default_data_path() -> String {
     DEFAULT_DATA_PATH.to_string()
 }
@@ -87,6 +169,362 @@ fn default_cache_path() -> String {
     DEFAULT_CACHE_PATH.to_string()
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct DownloadConfig {
+    /// 下载失败后是否自动重试
+    #[serde(default = "default_auto_retry_enabled")]
+    pub auto_retry_enabled: bool,
+    /// 自动重试的最大次数
+    #[serde(default = "default_retry_max_attempts")]
+    pub retry_max_attempts: u32,
+    /// 首次重试的等待秒数（之后每次翻倍）
+    #[serde(default = "default_retry_base_delay_secs")]
+    pub retry_base_delay_secs: u32,
+    /// 重试等待秒数的上限
+    #[serde(default = "default_retry_max_delay_secs")]
+    pub retry_max_delay_secs: u32,
+    /// 下载文件名模板（`/` 表示子目录）
+    #[serde(default = "default_file_name_template")]
+    pub file_name_template: String,
+    /// 下载完成后是否写入元数据附属文件（`<文件名>.json`）
+    #[serde(default = "default_write_sidecar")]
+    pub write_sidecar: bool,
+    /// 是否同时写入 XMP 附属文件（`<文件名>.xmp`）
+    #[serde(default)]
+    pub write_xmp_sidecar: bool,
+    /// 开始下载前磁盘需要保留的最小可用空间（MB）
+    #[serde(default = "default_disk_reserve_mb")]
+    pub disk_reserve_mb: u32,
+    /// 数据路径配额（MB，0 表示不限制）
+    ///
+    /// 包括文件名模板生成的子目录；额外的壁纸库目录不会保存下载的文件，不计入配额
+    #[serde(default)]
+    pub library_quota_mb: u32,
+    /// 缓存目录配额（MB，0 表示不限制）
+    #[serde(default)]
+    pub cache_quota_mb: u32,
+}
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            auto_retry_enabled: default_auto_retry_enabled(),
+            retry_max_attempts: default_retry_max_attempts(),
+            retry_base_delay_secs: default_retry_base_delay_secs(),
+            retry_max_delay_secs: default_retry_max_delay_secs(),
+            file_name_template: default_file_name_template(),
+            write_sidecar: default_write_sidecar(),
+            write_xmp_sidecar: false,
+            disk_reserve_mb: default_disk_reserve_mb(),
+            library_quota_mb: 0,
+            cache_quota_mb: 0,
+        }
+    }
+}
+
+fn default_disk_reserve_mb() -> u32 {
+    1024
+}
+
+fn default_write_sidecar() -> bool {
+    true
+}
+
+fn default_auto_retry_enabled() -> bool {
+    true
+}
+
+fn default_retry_max_attempts() -> u32 {
+    5
+}
+
+fn default_retry_base_delay_secs() -> u32 {
+    30
+}
+
+fn default_retry_max_delay_secs() -> u32 {
+    600
+}
+
+fn default_file_name_template() -> String {
+    crate::services::wallhaven::DEFAULT_FILE_NAME_TEMPLATE.to_string()
+}
+
+/// 钩子命令配置（命令格式见 [`crate::services::hooks`]）
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct HooksConfig {
+    /// 是否启用下载完成后执行的命令
+    #[serde(default)]
+    pub post_download_enabled: bool,
+    /// 下载完成后执行的命令
+    #[serde(default)]
+    pub post_download_command: String,
+    /// 是否启用设置壁纸后执行的命令
+    #[serde(default)]
+    pub post_set_enabled: bool,
+    /// 设置壁纸后执行的命令（手动设置、托盘切换和定时切换）
+    #[serde(default)]
+    pub post_set_command: String,
+    /// 命令超时秒数，超时后终止命令
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u32,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            post_download_enabled: false,
+            post_download_command: String::new(),
+            post_set_enabled: false,
+            post_set_command: String::new(),
+            timeout_secs: default_hook_timeout_secs(),
+        }
+    }
+}
+
+fn default_hook_timeout_secs() -> u32 {
+    60
+}
+
+/// 下载完成后的转码配置（转码逻辑见 [`crate::services::transcode`]）
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TranscodeConfig {
+    /// 转码的目标格式（Off 表示不转码）
+    #[serde(default)]
+    pub format: TranscodeFormat,
+    /// JPEG 和有损 WebP 的质量（1-100）
+    #[serde(default = "default_transcode_quality")]
+    pub quality: u32,
+    /// 只转码大于此大小的文件（MB，0 表示全部转码）
+    #[serde(default = "default_transcode_min_size_mb")]
+    pub min_size_mb: u32,
+    /// 转码后是否保留原文件
+    #[serde(default)]
+    pub keep_original: bool,
+}
+
+impl Default for TranscodeConfig {
+    fn default() -> Self {
+        Self {
+            format: TranscodeFormat::default(),
+            quality: default_transcode_quality(),
+            min_size_mb: default_transcode_min_size_mb(),
+            keep_original: false,
+        }
+    }
+}
+
+fn default_transcode_quality() -> u32 {
+    85
+}
+
+fn default_transcode_min_size_mb() -> u32 {
+    10
+}
+
+/// HTTP 客户端配置（共享客户端见 [`crate::services::http_client`]）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct HttpConfig {
+    /// 连接超时时间（秒）
+    #[serde(default = "default_http_connect_timeout_secs")]
+    pub connect_timeout_secs: u32,
+    /// 请求总超时时间（秒，0 表示不限制）
+    #[serde(default = "default_http_request_timeout_secs")]
+    pub request_timeout_secs: u32,
+    /// 请求使用的 User-Agent（为空时使用默认值）
+    #[serde(default = "default_http_user_agent")]
+    pub user_agent: String,
+    /// 是否强制使用 HTTP/2（关闭时通过 ALPN 协商协议）
+    #[serde(default)]
+    pub http2_only: bool,
+    /// 每个主机保留的最大空闲连接数
+    #[serde(default = "default_http_pool_max_idle_per_host")]
+    pub pool_max_idle_per_host: u32,
+    /// 空闲连接的保留时间（秒）
+    #[serde(default = "default_http_pool_idle_timeout_secs")]
+    pub pool_idle_timeout_secs: u32,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout_secs: default_http_connect_timeout_secs(),
+            request_timeout_secs: default_http_request_timeout_secs(),
+            user_agent: default_http_user_agent(),
+            http2_only: false,
+            pool_max_idle_per_host: default_http_pool_max_idle_per_host(),
+            pool_idle_timeout_secs: default_http_pool_idle_timeout_secs(),
+        }
+    }
+}
+
+fn default_http_connect_timeout_secs() -> u32 {
+    30
+}
+
+fn default_http_request_timeout_secs() -> u32 {
+    300
+}
+
+pub fn default_http_user_agent() -> String {
+    format!("WallWarp/{}", env!("CARGO_PKG_VERSION"))
+}
+
+fn default_http_pool_max_idle_per_host() -> u32 {
+    10
+}
+
+fn default_http_pool_idle_timeout_secs() -> u32 {
+    90
+}
+
+/// 本地壁纸页面配置
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LocalConfig {
+    /// 查找相似图片时，感知哈希的最大汉明距离（0-64，越小越严格）
+    #[serde(default = "default_duplicate_threshold")]
+    pub duplicate_threshold: u32,
+    /// 排序方式
+    #[serde(default)]
+    pub sort: LocalSort,
+    /// 是否降序排列
+    #[serde(default)]
+    pub sort_descending: bool,
+    /// 筛选条件
+    #[serde(default)]
+    pub filter: LocalFilter,
+    /// 回收站中的壁纸保留天数，超过后彻底删除（0 表示不自动删除）
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u32,
+}
+
+impl Default for LocalConfig {
+    fn default() -> Self {
+        Self {
+            duplicate_threshold: default_duplicate_threshold(),
+            sort: LocalSort::default(),
+            sort_descending: false,
+            filter: LocalFilter::default(),
+            trash_retention_days: default_trash_retention_days(),
+        }
+    }
+}
+
+fn default_duplicate_threshold() -> u32 {
+    8
+}
+
+fn default_trash_retention_days() -> u32 {
+    30
+}
+
+/// 本地壁纸排序方式
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalSort {
+    /// 按文件名
+    #[default]
+    Name,
+    /// 按添加时间（文件修改时间，即下载或复制到壁纸库的时间）
+    DateAdded,
+    /// 按文件大小
+    FileSize,
+    /// 按分辨率（像素数）
+    Resolution,
+    /// 按宽高比
+    AspectRatio,
+}
+
+impl LocalSort {
+    pub fn all() -> [LocalSort; 5] {
+        [
+            LocalSort::Name,
+            LocalSort::DateAdded,
+            LocalSort::FileSize,
+            LocalSort::Resolution,
+            LocalSort::AspectRatio,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LocalSort::Name => "local-list.sort-name",
+            LocalSort::DateAdded => "local-list.sort-date-added",
+            LocalSort::FileSize => "local-list.sort-file-size",
+            LocalSort::Resolution => "local-list.sort-resolution",
+            LocalSort::AspectRatio => "local-list.sort-aspect-ratio",
+        }
+    }
+}
+
+/// 图片方向
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Orientation {
+    /// 横向（宽大于高）
+    Landscape,
+    /// 纵向（高大于宽）
+    Portrait,
+    /// 方形（宽高相等）
+    Square,
+}
+
+impl Orientation {
+    pub fn all() -> [Orientation; 3] {
+        [Orientation::Landscape, Orientation::Portrait, Orientation::Square]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Orientation::Landscape => "local-list.orientation-landscape",
+            Orientation::Portrait => "local-list.orientation-portrait",
+            Orientation::Square => "local-list.orientation-square",
+        }
+    }
+
+    /// 按图片尺寸判断方向
+    pub fn from_size(width: u32, height: u32) -> Orientation {
+        match width.cmp(&height) {
+            std::cmp::Ordering::Greater => Orientation::Landscape,
+            std::cmp::Ordering::Less => Orientation::Portrait,
+            std::cmp::Ordering::Equal => Orientation::Square,
+        }
+    }
+}
+
+/// 本地壁纸筛选条件（筛选逻辑见 [`crate::services::library::sort_and_filter`]）
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq, Default)]
+pub struct LocalFilter {
+    /// 最小宽度（与图片方向无关，按长边比较，0 表示不限）
+    #[serde(default)]
+    pub min_width: u32,
+    /// 最小高度（与图片方向无关，按短边比较，0 表示不限）
+    #[serde(default)]
+    pub min_height: u32,
+    /// 图片方向（None 表示不限）
+    #[serde(default)]
+    pub orientation: Option<Orientation>,
+    /// 比例分组（None 表示不限）
+    #[serde(default)]
+    pub aspect_ratio_group: Option<AspectRatioGroup>,
+    /// 文件格式（小写扩展名，`jpeg` 按 `jpg` 处理，None 表示不限）
+    #[serde(default)]
+    pub format: Option<String>,
+    /// 文件名模式（不区分大小写，含 `*` 或 `?` 时按通配符匹配整个文件名，否则匹配文件名中的任意部分）
+    #[serde(default)]
+    pub name_pattern: String,
+    /// 主色调（ColorOption 的值，例如 `660000`，None 表示不限）
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl LocalFilter {
+    /// 是否设置了任意筛选条件
+    pub fn is_active(&self) -> bool {
+        *self != LocalFilter::default()
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct DisplayConfig {
     #[serde(default = "default_window_width")]
@@ -199,6 +637,9 @@ pub struct WallpaperConfig {
     pub auto_change_sorting: String,
     #[serde(default = "default_auto_change_top_range")]
     pub auto_change_top_range: String,
+    /// 本地定时切换只选择主色调匹配该颜色的壁纸（ColorOption 的值，`any` 表示不限）
+    #[serde(default = "default_color")]
+    pub auto_change_local_color: String,
 }
 
 impl Default for WallpaperConfig {
@@ -210,6 +651,7 @@ impl Default for WallpaperConfig {
             auto_change_query: String::new(),
             auto_change_sorting: default_auto_change_sorting(),
             auto_change_top_range: default_auto_change_top_range(),
+            auto_change_local_color: default_color(),
         }
     }
 }
@@ -469,6 +911,32 @@ impl std::fmt::Display for CloseAction {
     }
 }
 
+/// 转码的目标格式
+#[derive(Clone, Serialize, Deserialize, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscodeFormat {
+    /// 不转码
+    #[default]
+    Off,
+    /// 按质量设置压缩为 JPEG
+    Jpeg,
+    /// 按质量设置压缩为有损 WebP（使用 libwebp 编码）
+    Webp,
+    /// 无损 WebP
+    WebpLossless,
+}
+
+impl TranscodeFormat {
+    /// 转码后文件的扩展名
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TranscodeFormat::Off => "",
+            TranscodeFormat::Jpeg => "jpg",
+            TranscodeFormat::Webp | TranscodeFormat::WebpLossless => "webp",
+        }
+    }
+}
+
 impl Config {
     pub fn new(lang: &str, available_langs: &Vec<String>) -> Self {
         let config_path = Path::new(CONFIG_FILE);
@@ -79,6 +79,17 @@ pub fn format_file_size(bytes: u64) -> String {
     }
 }
 
+/// 格式化时长（秒），如 `45s`、`3m 20s`、`1h 05m`
+pub fn format_duration(secs: u64) -> String {
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
 /// 获取绝对路径
 pub fn get_absolute_path(path: &str) -> String {
     let current_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
@@ -151,3 +162,18 @@ pub fn normalize_path(path: &str) -> String {
         path.to_string()
     }
 }
+
+/// 移动文件（不在同一个磁盘时先复制再删除原文件）
+///
+/// 删除原文件失败时删除复制出的文件，原文件保持不变
+pub fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    std::fs::copy(from, to)?;
+    if let Err(e) = std::fs::remove_file(from) {
+        let _ = std::fs::remove_file(to);
+        return Err(e);
+    }
+    Ok(())
+}